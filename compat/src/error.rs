@@ -0,0 +1,42 @@
+use std::fmt;
+use std::io;
+
+/// Error
+#[derive(Debug)]
+pub enum Error
+{
+	Io(io::Error),
+	Json(serde_json::Error),
+	InvalidRecording(String),
+}
+
+impl fmt::Display for Error
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			Self::Io(err) => write!(f, "I/O error: {}", err),
+			Self::Json(err) => write!(f, "JSON error: {}", err),
+			Self::InvalidRecording(message) => write!(f, "invalid recording: {}", message),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error
+{
+	fn from(err: io::Error) -> Self
+	{
+		Self::Io(err)
+	}
+}
+
+impl From<serde_json::Error> for Error
+{
+	fn from(err: serde_json::Error) -> Self
+	{
+		Self::Json(err)
+	}
+}
@@ -0,0 +1,14 @@
+//! Check whether a new build of a contract is compatible with an old one.
+//!
+//! Transactions verified by the tests are recorded to a corpus along with the cells and headers they use. The corpus is then
+//! replayed with the old and the new binary in place of the contract, keeping the original code hash so that existing
+//! scripts run the replacement. Any transaction which passes with one binary and fails with the other, or fails with a
+//! different error, is reported as a divergence.
+
+pub mod error;
+pub mod recording;
+pub mod replay;
+
+pub use error::Error;
+pub use recording::{record, Outcome, Recording};
+pub use replay::{check, replay, Report};
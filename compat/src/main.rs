@@ -0,0 +1,60 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use ckb_testtool::ckb_types::bytes::Bytes;
+
+// Constants
+const USAGE: &str = "Usage: compat --corpus <dir> --old <binary> --new <binary> [--max-cycles 100000000]";
+const DEFAULT_MAX_CYCLES: u64 = 100_000_000;
+
+fn run(args: &[String]) -> Result<bool, String>
+{
+	let mut corpus = None;
+	let mut old = None;
+	let mut new = None;
+	let mut max_cycles = DEFAULT_MAX_CYCLES;
+
+	let mut args = args.iter();
+	while let Some(flag) = args.next()
+	{
+		let value = args.next().ok_or_else(|| format!("missing value for {}", flag))?;
+		match flag.as_str()
+		{
+			"--corpus" => corpus = Some(PathBuf::from(value)),
+			"--old" => old = Some(PathBuf::from(value)),
+			"--new" => new = Some(PathBuf::from(value)),
+			"--max-cycles" => max_cycles = value.parse().map_err(|_| format!("invalid max cycles: {}", value))?,
+			other => return Err(format!("unknown option: {}", other)),
+		}
+	}
+
+	let (corpus, old, new) = match (corpus, old, new)
+	{
+		(Some(corpus), Some(old), Some(new)) => (corpus, old, new),
+		_ => return Err(String::from(USAGE)),
+	};
+	let old = Bytes::from(fs::read(&old).map_err(|err| format!("{}: {}", old.display(), err))?);
+	let new = Bytes::from(fs::read(&new).map_err(|err| format!("{}: {}", new.display(), err))?);
+
+	let report = compat::check(&corpus, &old, &new, max_cycles).map_err(|err| err.to_string())?;
+	println!("{}", report);
+
+	Ok(report.is_compatible())
+}
+
+fn main()
+{
+	let args: Vec<String> = env::args().skip(1).collect();
+	match run(&args)
+	{
+		Ok(true) => (),
+		Ok(false) => process::exit(1),
+		Err(message) =>
+		{
+			eprintln!("{}", message);
+			process::exit(2);
+		},
+	}
+}
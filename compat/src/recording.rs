@@ -0,0 +1,214 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ckb_testtool::ckb_error::Error as VerificationError;
+use ckb_testtool::ckb_jsonrpc_types as json;
+use ckb_testtool::ckb_types::bytes::Bytes;
+use ckb_testtool::ckb_types::core::{Cycle, DepType, TransactionView};
+use ckb_testtool::ckb_types::packed::{CellOutput, OutPoint, OutPointVec};
+use ckb_testtool::ckb_types::prelude::*;
+use ckb_testtool::ckb_types::H256;
+use ckb_testtool::context::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+// Constants
+/// Cell data larger than this is stored once in the blobs directory instead of in every recording that uses it.
+const BLOB_THRESHOLD: usize = 1_024;
+const BLOBS_DIR: &str = "blobs";
+const ERROR_CODE_PREFIX: &str = "error code "; // Precedes the exit code in the message of a validation failure.
+
+/// The result of verifying a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome
+{
+	Pass { cycles: Cycle },
+	Fail
+	{
+		/// The exit code of the script, for failures caused by a script returning an error.
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		code: Option<i8>,
+		error: String,
+	},
+}
+
+impl Outcome
+{
+	/// Create an outcome from a verification result.
+	pub fn from_result(result: &Result<Cycle, VerificationError>) -> Self
+	{
+		match result
+		{
+			Ok(cycles) => Self::Pass { cycles: *cycles },
+			Err(err) =>
+			{
+				// The exit code is not exposed by the script error, so it is read from the message.
+				let error = err.to_string();
+				let code = error.split(ERROR_CODE_PREFIX).nth(1).and_then(|code| code.split(' ').next()).and_then(|code| code.parse().ok());
+				Self::Fail { code, error }
+			},
+		}
+	}
+
+	/// Outcomes are compatible if both pass, or if both fail with the same error code. Cycles are allowed to differ.
+	/// Failures which are not caused by a script exit code are compared by their message.
+	pub fn is_compatible(&self, other: &Self) -> bool
+	{
+		match (self, other)
+		{
+			(Self::Pass { .. }, Self::Pass { .. }) => true,
+			(Self::Fail { code: Some(code), .. }, Self::Fail { code: Some(other_code), .. }) => code == other_code,
+			(Self::Fail { code: None, error }, Self::Fail { code: None, error: other_error }) => error == other_error,
+			_ => false,
+		}
+	}
+}
+
+impl fmt::Display for Outcome
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			Self::Pass { cycles } => write!(f, "pass ({} cycles)", cycles),
+			Self::Fail { error, .. } => write!(f, "fail: {}", error),
+		}
+	}
+}
+
+/// A cell used by a recorded transaction as an input, a cell dep, or a member of a dep group.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedCell
+{
+	pub out_point: json::OutPoint,
+	pub output: json::CellOutput,
+	/// Small data is stored inline.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub data: Option<json::JsonBytes>,
+	/// Large data, such as a contract binary, is stored in the blobs directory by data hash.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub data_hash: Option<H256>,
+	/// The block the cell was created in, for cells which were linked with a block.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub block: Option<RecordedBlock>,
+}
+
+/// The block and transaction index of a cell.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedBlock
+{
+	pub block_hash: H256,
+	pub tx_index: u32,
+}
+
+/// A transaction verified by a test, with everything needed to verify it again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Recording
+{
+	pub name: String,
+	pub transaction: json::Transaction,
+	pub cells: Vec<RecordedCell>,
+	pub headers: Vec<json::HeaderView>,
+	pub outcome: Outcome,
+}
+
+impl Recording
+{
+	/// Record a transaction along with the cells and headers it uses from the context.
+	pub fn new(name: &str, context: &Context, tx: &TransactionView, outcome: Outcome) -> Self
+	{
+		// The cells listed in the data of a dep group are recorded so the group can be resolved during replay.
+		let dep_group_out_points = tx.cell_deps_iter()
+			.filter(|cell_dep| cell_dep.dep_type() == DepType::DepGroup.into())
+			.filter_map(|cell_dep| context.cells.get(&cell_dep.out_point()))
+			.filter_map(|(_, data)| OutPointVec::from_slice(data).ok())
+			.flat_map(|out_points| out_points.into_iter().collect::<Vec<_>>());
+		let out_points = tx.input_pts_iter().chain(tx.cell_deps_iter().map(|cell_dep| cell_dep.out_point())).chain(dep_group_out_points);
+		let mut cells: Vec<RecordedCell> = Vec::new();
+		for out_point in out_points
+		{
+			if cells.iter().any(|cell| OutPoint::from(cell.out_point.clone()) == out_point)
+			{
+				continue;
+			}
+			// Cells which do not exist are left out so the replay fails in the same way.
+			if let Some((output, data)) = context.cells.get(&out_point)
+			{
+				let block = context.transaction_infos.get(&out_point)
+					.map(|info| RecordedBlock { block_hash: info.block_hash.unpack(), tx_index: info.index as u32 });
+				let (data, data_hash) = match data.len() > BLOB_THRESHOLD
+				{
+					true => (None, Some(CellOutput::calc_data_hash(data).unpack())),
+					false => (Some(json::JsonBytes::from_bytes(data.clone())), None),
+				};
+				cells.push(RecordedCell { out_point: out_point.into(), output: output.clone().into(), data, data_hash, block });
+			}
+		}
+
+		let mut headers: Vec<json::HeaderView> = context.headers.values().cloned().map(Into::into).collect();
+		headers.sort_by(|a, b| a.hash.cmp(&b.hash));
+
+		Self { name: name.to_string(), transaction: tx.data().into(), cells, headers, outcome }
+	}
+
+	/// Save the recording to the corpus directory, storing large cell data in the blobs directory.
+	pub fn save(&self, dir: &Path, context: &Context) -> Result<PathBuf, Error>
+	{
+		fs::create_dir_all(dir.join(BLOBS_DIR))?;
+		for cell in &self.cells
+		{
+			if let Some(data_hash) = &cell.data_hash
+			{
+				let path = dir.join(BLOBS_DIR).join(format!("{:x}", data_hash));
+				if !path.exists()
+				{
+					let (_, data) = context.cells.get(&cell.out_point.clone().into()).expect("recorded cell");
+					fs::write(path, data)?;
+				}
+			}
+		}
+
+		let path = dir.join(format!("{}.json", self.name.replace("::", ".")));
+		fs::write(&path, serde_json::to_string_pretty(self)?)?;
+
+		Ok(path)
+	}
+
+	/// Load a recording from a file.
+	pub fn load(path: &Path) -> Result<Self, Error>
+	{
+		Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+	}
+
+	/// Load the data of a recorded cell, reading it from the blobs directory of the corpus if needed.
+	pub fn cell_data(cell: &RecordedCell, dir: &Path) -> Result<Bytes, Error>
+	{
+		match (&cell.data, &cell.data_hash)
+		{
+			(Some(data), _) => Ok(data.clone().into_bytes()),
+			(None, Some(data_hash)) => Ok(Bytes::from(fs::read(dir.join(BLOBS_DIR).join(format!("{:x}", data_hash)))?)),
+			(None, None) => Err(Error::InvalidRecording(format!("cell {:?} has no data", cell.out_point))),
+		}
+	}
+}
+
+/// Record a transaction verified by a test to the corpus directory. Returns the path of the recording.
+pub fn record(dir: &Path, name: &str, context: &Context, tx: &TransactionView, result: &Result<Cycle, VerificationError>) -> Result<PathBuf, Error>
+{
+	Recording::new(name, context, tx, Outcome::from_result(result)).save(dir, context)
+}
+
+/// Load every recording in the corpus directory, sorted by file name.
+pub fn load_corpus(dir: &Path) -> Result<Vec<Recording>, Error>
+{
+	let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+		.filter_map(|entry| entry.ok().map(|entry| entry.path()))
+		.filter(|path| path.extension().is_some_and(|extension| extension == "json"))
+		.collect();
+	paths.sort();
+
+	paths.iter().map(|path| Recording::load(path)).collect()
+}
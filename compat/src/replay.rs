@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use ckb_testtool::ckb_script::TransactionScriptsVerifier;
+use ckb_testtool::ckb_types::bytes::Bytes;
+use ckb_testtool::ckb_types::core::cell::{CellMeta, CellMetaBuilder, ResolvedTransaction};
+use ckb_testtool::ckb_types::core::{DepType, HeaderView, TransactionInfo, TransactionView};
+use ckb_testtool::ckb_types::packed::{Byte32, CellOutput, Header, OutPoint, OutPointVec, Transaction};
+use ckb_testtool::ckb_types::prelude::*;
+use ckb_testtool::context::Context;
+
+use crate::error::Error;
+use crate::recording::{self, Outcome, Recording};
+
+/// A recorded transaction which behaves differently with the new binary.
+#[derive(Debug, Clone)]
+pub struct Divergence
+{
+	pub name: String,
+	pub old: Outcome,
+	pub new: Outcome,
+}
+
+/// The result of replaying a corpus against an old and a new binary.
+#[derive(Debug, Clone, Default)]
+pub struct Report
+{
+	/// The number of recordings which use the contract and were replayed.
+	pub replayed: usize,
+	/// The number of recordings which do not use the contract.
+	pub skipped: usize,
+	pub divergences: Vec<Divergence>,
+	/// The total cycles of the recordings which pass with both binaries.
+	pub old_cycles: u64,
+	pub new_cycles: u64,
+}
+
+impl Report
+{
+	/// The binaries are compatible if the corpus exercises the contract, and every replayed recording has a compatible
+	/// outcome. A corpus which never uses the contract cannot show that the new binary is compatible.
+	pub fn is_compatible(&self) -> bool
+	{
+		self.replayed > 0 && self.divergences.is_empty()
+	}
+}
+
+impl fmt::Display for Report
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		// Divergences are shown in the same style as a unified diff.
+		if !self.divergences.is_empty()
+		{
+			writeln!(f, "--- old")?;
+			writeln!(f, "+++ new")?;
+		}
+		for divergence in &self.divergences
+		{
+			writeln!(f, "@@ {} @@", divergence.name)?;
+			writeln!(f, "-{}", divergence.old)?;
+			writeln!(f, "+{}", divergence.new)?;
+		}
+
+		write!(f, "Replayed {} transactions, {} diverged, {} skipped. Cycles of passing transactions: {} old, {} new.",
+			self.replayed, self.divergences.len(), self.skipped, self.old_cycles, self.new_cycles)?;
+		if self.replayed == 0
+		{
+			write!(f, "\nThe contract was never exercised by a transaction in the corpus, so its compatibility was not checked.")?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Build a context containing the cells and headers of a recording, and return it with the recorded transaction.
+pub fn context(recording: &Recording, dir: &Path) -> Result<(Context, TransactionView), Error>
+{
+	let mut context = Context::default();
+	for header in &recording.headers
+	{
+		context.insert_header(Header::from(header.inner.clone()).into_view());
+	}
+	for cell in &recording.cells
+	{
+		let out_point: OutPoint = cell.out_point.clone().into();
+		context.cells.insert(out_point.clone(), (cell.output.clone().into(), Recording::cell_data(cell, dir)?));
+		if let Some(block) = &cell.block
+		{
+			let header: HeaderView = context.headers.get(&block.block_hash.pack()).cloned()
+				.ok_or_else(|| Error::InvalidRecording(format!("missing header {:#x}", block.block_hash)))?;
+			let info = TransactionInfo::new(header.number(), header.epoch(), header.hash(), block.tx_index as usize);
+			context.transaction_infos.insert(out_point, info);
+		}
+	}
+	let tx = Transaction::from(recording.transaction.clone()).into_view();
+
+	Ok((context, tx))
+}
+
+/// Replay a recording. Cell deps whose data hash is in the replacements have their data replaced, while keeping the original
+/// data hash so that scripts which reference the code by data hash run the replacement binary. Dep groups are resolved to
+/// the cells listed in their data, the same as on chain.
+pub fn replay(recording: &Recording, dir: &Path, replacements: &HashMap<Byte32, Bytes>, max_cycles: u64) -> Result<Outcome, Error>
+{
+	let (context, tx) = context(recording, dir)?;
+	if tx.outputs().len() != tx.outputs_data().len()
+	{
+		return Ok(Outcome::Fail { code: None, error: String::from("outputs and outputs data length mismatch") });
+	}
+
+	let resolve = |out_point: OutPoint, replace: bool| -> Result<CellMeta, Error>
+	{
+		let (output, data) = context.cells.get(&out_point).cloned()
+			.ok_or_else(|| Error::InvalidRecording(format!("missing cell {}", out_point)))?;
+		let mut builder = CellMetaBuilder::from_cell_output(output, data.clone()).out_point(out_point.clone());
+		if let Some(info) = context.transaction_infos.get(&out_point)
+		{
+			builder = builder.transaction_info(info.clone());
+		}
+		let mut cell_meta = builder.build();
+		if let Some(binary) = replacements.get(&CellOutput::calc_data_hash(&data)).filter(|_| replace)
+		{
+			cell_meta.data_bytes = binary.len() as u64;
+			cell_meta.mem_cell_data = Some(binary.clone());
+		}
+		Ok(cell_meta)
+	};
+	let mut resolved_cell_deps = Vec::new();
+	let mut resolved_dep_groups = Vec::new();
+	for cell_dep in tx.cell_deps_iter()
+	{
+		if cell_dep.dep_type() == DepType::DepGroup.into()
+		{
+			let dep_group = resolve(cell_dep.out_point(), false)?;
+			let out_points = match dep_group.mem_cell_data.as_ref().and_then(|data| OutPointVec::from_slice(data).ok())
+			{
+				Some(out_points) => out_points,
+				None => return Ok(Outcome::Fail { code: None, error: format!("invalid dep group {}", cell_dep.out_point()) }),
+			};
+			for out_point in out_points.into_iter()
+			{
+				resolved_cell_deps.push(resolve(out_point, true)?);
+			}
+			resolved_dep_groups.push(dep_group);
+		}
+		else
+		{
+			resolved_cell_deps.push(resolve(cell_dep.out_point(), true)?);
+		}
+	}
+	let resolved_rtx = ResolvedTransaction
+	{
+		resolved_inputs: tx.input_pts_iter().map(|out_point| resolve(out_point, false)).collect::<Result<_, _>>()?,
+		resolved_cell_deps,
+		resolved_dep_groups,
+		transaction: tx.clone(),
+	};
+
+	let mut verifier = TransactionScriptsVerifier::new(&resolved_rtx, &context);
+	verifier.set_debug_printer(|_id, _message| {});
+
+	Ok(Outcome::from_result(&verifier.verify(max_cycles)))
+}
+
+/// Replay every recording in the corpus which uses the old or new binary, once with each, and report any divergence.
+pub fn check(dir: &Path, old: &Bytes, new: &Bytes, max_cycles: u64) -> Result<Report, Error>
+{
+	let hashes = [CellOutput::calc_data_hash(old), CellOutput::calc_data_hash(new)];
+	let mut report = Report::default();
+
+	for recording in recording::load_corpus(dir)?
+	{
+		// The corpus may have been recorded with either binary, so cell deps containing either one are replaced. Cell deps
+		// include the members of dep groups, which are recorded along with the cell deps.
+		let uses_contract = recording.cells.iter()
+			.filter(|cell| !recording.transaction.inputs.iter().any(|input| input.previous_output == cell.out_point))
+			.filter_map(|cell| Recording::cell_data(cell, dir).ok())
+			.any(|data| hashes.contains(&CellOutput::calc_data_hash(&data)));
+		if !uses_contract
+		{
+			report.skipped += 1;
+			continue;
+		}
+
+		let old_replacements = hashes.iter().map(|hash| (hash.clone(), old.clone())).collect();
+		let new_replacements = hashes.iter().map(|hash| (hash.clone(), new.clone())).collect();
+		let old_outcome = replay(&recording, dir, &old_replacements, max_cycles)?;
+		let new_outcome = replay(&recording, dir, &new_replacements, max_cycles)?;
+		report.replayed += 1;
+
+		match (&old_outcome, &new_outcome)
+		{
+			(Outcome::Pass { cycles: old_cycles }, Outcome::Pass { cycles: new_cycles }) =>
+			{
+				report.old_cycles += old_cycles;
+				report.new_cycles += new_cycles;
+			},
+			_ if old_outcome.is_compatible(&new_outcome) => (),
+			_ => report.divergences.push(Divergence { name: recording.name.clone(), old: old_outcome, new: new_outcome }),
+		}
+	}
+
+	Ok(report)
+}
@@ -0,0 +1,145 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import heap related library from alloc.
+// https://doc.rust-lang.org/alloc/index.html
+use alloc::vec::Vec;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell_capacity, load_cell_data, load_cell_lock_hash, load_cell_type_hash, load_script, load_script_hash, QueryIter};
+
+// Import our local error codes.
+use crate::error::Error;
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const MINIMUM_CKB_LEN: usize = 8; // The minimum CKB amount is a u64, which is 8 bytes.
+const MINIMUM_SUDT_LEN: usize = 16; // The minimum SUDT amount is a u128, which is 16 bytes.
+const SUDT_DATA_LEN: usize = 16; // SUDT uses a u128, which is 16 bytes.
+
+/// Determine if owner mode is enabled.
+fn check_owner_mode(owner_lock_hash: &[u8]) -> bool
+{
+	// Compare the owner Lock Script Hash with the Lock Scripts
+	// of each input cell to determine if a match exists.
+	QueryIter::new(load_cell_lock_hash, Source::Input)
+		.any(|lock_hash|owner_lock_hash[..]==lock_hash[..])
+}
+
+/// Load the SUDT amount from the data of the specified cell.
+fn load_sudt_amount(index: usize, source: Source) -> Result<u128, Error>
+{
+	// Check that the length of the data is >= 16 bytes, the size of a u128.
+	let data = load_cell_data(index, source)?;
+	if data.len() < SUDT_DATA_LEN
+	{
+		return Err(Error::Encoding);
+	}
+
+	// Convert the binary data in the cell to a u128 value.
+	let mut buffer = [0u8; SUDT_DATA_LEN];
+	buffer.copy_from_slice(&data[0..SUDT_DATA_LEN]);
+
+	Ok(u128::from_le_bytes(buffer))
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+
+	// Verify that a valid length of the arguments was given.
+	// We expect the owner lock hash, optionally followed by a minimum CKB amount, optionally followed by a minimum SUDT amount.
+	let args_len = args.len();
+	if args_len != LOCK_HASH_LEN
+		&& args_len != LOCK_HASH_LEN + MINIMUM_CKB_LEN
+		&& args_len != LOCK_HASH_LEN + MINIMUM_CKB_LEN + MINIMUM_SUDT_LEN
+	{
+		return Err(Error::ArgsLength);
+	}
+
+	// Check if the cell is being unlocked by the owner and immediately return success if true.
+	if check_owner_mode(&args[0..LOCK_HASH_LEN])
+	{
+		return Ok(());
+	}
+
+	// Load the minimum CKB amount (in Shannons) from the args, if it was provided.
+	let mut minimum_ckb = 0u64;
+	if args_len >= LOCK_HASH_LEN + MINIMUM_CKB_LEN
+	{
+		let mut buffer = [0u8; MINIMUM_CKB_LEN];
+		buffer.copy_from_slice(&args[LOCK_HASH_LEN..LOCK_HASH_LEN+MINIMUM_CKB_LEN]);
+		minimum_ckb = u64::from_le_bytes(buffer);
+	}
+
+	// Load the minimum SUDT amount from the args, if it was provided.
+	let mut minimum_sudt = 0u128;
+	if args_len == LOCK_HASH_LEN + MINIMUM_CKB_LEN + MINIMUM_SUDT_LEN
+	{
+		let mut buffer = [0u8; MINIMUM_SUDT_LEN];
+		buffer.copy_from_slice(&args[LOCK_HASH_LEN+MINIMUM_CKB_LEN..]);
+		minimum_sudt = u128::from_le_bytes(buffer);
+	}
+
+	// Find the output cells which use this same lock.
+	let script_hash = load_script_hash()?;
+	let output_indexes: Vec<usize> = QueryIter::new(load_cell_lock_hash, Source::Output)
+		.enumerate()
+		.filter(|(_, lock_hash)|*lock_hash==script_hash)
+		.map(|(i, _)|i)
+		.collect();
+
+	// Each group input cell must be matched with exactly one output cell with the same lock and type.
+	let mut matched_output_indexes: Vec<usize> = Vec::new();
+	for (i, input_type_hash) in QueryIter::new(load_cell_type_hash, Source::GroupInput).enumerate()
+	{
+		// Locate the output cell with the same type as the input cell.
+		let mut matched_output_index = None;
+		for output_index in output_indexes.iter()
+		{
+			if load_cell_type_hash(*output_index, Source::Output)? == input_type_hash
+			{
+				if matched_output_index.is_some()
+				{
+					return Err(Error::InvalidTransactionStructure);
+				}
+				matched_output_index = Some(*output_index);
+			}
+		}
+		let output_index = matched_output_index.ok_or(Error::OutputNotFound)?;
+
+		// Two input cells cannot be matched with the same output cell.
+		if matched_output_indexes.contains(&output_index)
+		{
+			return Err(Error::InvalidTransactionStructure);
+		}
+		matched_output_indexes.push(output_index);
+
+		// The output cell must gain at least the minimum CKB amount.
+		let input_capacity = load_cell_capacity(i, Source::GroupInput)?;
+		let output_capacity = load_cell_capacity(output_index, Source::Output)?;
+		if output_capacity < input_capacity.saturating_add(minimum_ckb)
+		{
+			return Err(Error::InsufficientCapacity);
+		}
+
+		// If the cell holds SUDT tokens, the output cell must gain at least the minimum SUDT amount.
+		if input_type_hash.is_some()
+		{
+			let input_amount = load_sudt_amount(i, Source::GroupInput)?;
+			let output_amount = load_sudt_amount(output_index, Source::Output)?;
+			if output_amount < input_amount.saturating_add(minimum_sudt)
+			{
+				return Err(Error::InsufficientAmount);
+			}
+		}
+	}
+
+	Ok(())
+}
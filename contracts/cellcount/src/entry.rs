@@ -0,0 +1,117 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell, load_script, QueryIter};
+
+// Import local modules.
+use crate::error::Error;
+
+// Constants
+const ARGS_LEN: usize = 10; // Source (1) + Operator (1) + Operand (8)
+const ARGS_LEN_RANGE: usize = 18; // Source (1) + Operator (1) + Minimum (8) + Maximum (8)
+
+// The comparison operators which can be selected in the args.
+enum Operator
+{
+	Equal, // cell_count == operand
+	NotEqual, // cell_count != operand
+	LessThan, // cell_count < operand
+	LessThanOrEqual, // cell_count <= operand
+	GreaterThan, // cell_count > operand
+	GreaterThanOrEqual, // cell_count >= operand
+	Range, // minimum <= cell_count <= maximum
+}
+
+/// Convert the source byte from the args into a Source.
+fn parse_source(value: u8) -> Result<Source, Error>
+{
+	match value
+	{
+		0 => Ok(Source::Input),
+		1 => Ok(Source::Output),
+		2 => Ok(Source::GroupInput),
+		3 => Ok(Source::GroupOutput),
+		_ => Err(Error::InvalidSource),
+	}
+}
+
+/// Convert the operator byte from the args into an Operator.
+fn parse_operator(value: u8) -> Result<Operator, Error>
+{
+	match value
+	{
+		0 => Ok(Operator::Equal),
+		1 => Ok(Operator::NotEqual),
+		2 => Ok(Operator::LessThan),
+		3 => Ok(Operator::LessThanOrEqual),
+		4 => Ok(Operator::GreaterThan),
+		5 => Ok(Operator::GreaterThanOrEqual),
+		6 => Ok(Operator::Range),
+		_ => Err(Error::InvalidOperator),
+	}
+}
+
+/// Read a u64 LE value from the args at the specified offset.
+fn parse_u64(args: &Bytes, offset: usize) -> u64
+{
+	let mut buffer = [0u8; 8];
+	buffer.copy_from_slice(&args[offset..offset+8]);
+	u64::from_le_bytes(buffer)
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+
+	// Verify that the minimum length of the arguments was given.
+	if args.len() < 2
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	// Load the source and operator from the args.
+	let source = parse_source(args[0])?;
+	let operator = parse_operator(args[1])?;
+
+	// Verify that the correct length of the arguments was given for the operator.
+	let expected_args_len = match operator
+	{
+		Operator::Range => ARGS_LEN_RANGE,
+		_ => ARGS_LEN,
+	};
+	if args.len() != expected_args_len
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	// Count the number of cells in the source.
+	let cell_count = QueryIter::new(load_cell, source).count() as u64;
+
+	// Compare the cell count with the operands from the args.
+	let operand = parse_u64(&args, 2);
+	let valid = match operator
+	{
+		Operator::Equal => cell_count == operand,
+		Operator::NotEqual => cell_count != operand,
+		Operator::LessThan => cell_count < operand,
+		Operator::LessThanOrEqual => cell_count <= operand,
+		Operator::GreaterThan => cell_count > operand,
+		Operator::GreaterThanOrEqual => cell_count >= operand,
+		Operator::Range => cell_count >= operand && cell_count <= parse_u64(&args, 10),
+	};
+
+	// Return an error if the cell count does not satisfy the comparison.
+	if !valid
+	{
+		return Err(Error::Unauthorized);
+	}
+
+	Ok(())
+}
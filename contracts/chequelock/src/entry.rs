@@ -0,0 +1,77 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell_lock_hash, load_input_since, load_script, QueryIter};
+
+// Import our local error codes.
+use crate::error::Error;
+
+// Constants
+const LOCK_HASH_PREFIX_LEN: usize = 20; // Number of bytes of a lock hash used to identify the receiver and sender.
+const SINCE_FLAGS_MASK: u64 = 0xFF00_0000_0000_0000; // The flags are the highest byte of the since value.
+const SINCE_RELATIVE_EPOCH_FLAGS: u64 = 0xA000_0000_0000_0000; // Relative flag with the epoch metric flag.
+const SINCE_EPOCH_NUMBER_MASK: u64 = 0x0000_0000_00FF_FFFF; // The epoch number is the lowest 24 bits of the since value.
+const WITHDRAW_LOCK_EPOCHS: u64 = 6; // The number of epochs the sender must wait before withdrawing.
+
+/// Determine if an input cell exists with a lock hash that starts with the specified prefix.
+fn check_lock_hash_prefix(lock_hash_prefix: &[u8]) -> bool
+{
+	QueryIter::new(load_cell_lock_hash, Source::Input)
+		.any(|lock_hash|lock_hash_prefix[..]==lock_hash[0..LOCK_HASH_PREFIX_LEN])
+}
+
+/// Verify that every cheque cell is being withdrawn with a relative since of at least WITHDRAW_LOCK_EPOCHS.
+fn validate_withdraw_since() -> Result<(), Error>
+{
+	for since in QueryIter::new(load_input_since, Source::GroupInput)
+	{
+		// The since must be a relative epoch value.
+		if (since & SINCE_FLAGS_MASK) != SINCE_RELATIVE_EPOCH_FLAGS
+		{
+			return Err(Error::InvalidSince);
+		}
+
+		// The number of whole epochs must meet the lock period.
+		if (since & SINCE_EPOCH_NUMBER_MASK) < WITHDRAW_LOCK_EPOCHS
+		{
+			return Err(Error::InvalidSince);
+		}
+	}
+
+	Ok(())
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+
+	// Verify that the correct length of the arguments was given.
+	// We expect the receiver lock hash prefix followed by the sender lock hash prefix.
+	if args.len() != LOCK_HASH_PREFIX_LEN * 2
+	{
+		return Err(Error::ArgsLen);
+	}
+	let receiver_lock_hash_prefix = &args[0..LOCK_HASH_PREFIX_LEN];
+	let sender_lock_hash_prefix = &args[LOCK_HASH_PREFIX_LEN..LOCK_HASH_PREFIX_LEN*2];
+
+	// The receiver can claim at any time by including an input cell with their lock.
+	if check_lock_hash_prefix(receiver_lock_hash_prefix)
+	{
+		return Ok(());
+	}
+
+	// The sender can withdraw by including an input cell with their lock once the lock period has passed.
+	if check_lock_hash_prefix(sender_lock_hash_prefix)
+	{
+		return validate_withdraw_since();
+	}
+
+	Err(Error::Unauthorized)
+}
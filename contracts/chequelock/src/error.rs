@@ -11,12 +11,16 @@ pub enum Error
 	// Add customized errors here...
 	ArgsLen,
 	Unauthorized,
+	InvalidSince,
 }
 
-impl From<SysError> for Error {
-	fn from(err: SysError) -> Self {
+impl From<SysError> for Error
+{
+	fn from(err: SysError) -> Self
+	{
 		use SysError::*;
-		match err {
+		match err
+		{
 			IndexOutOfBound => Self::IndexOutOfBound,
 			ItemMissing => Self::ItemMissing,
 			LengthNotEnough(_) => Self::LengthNotEnough,
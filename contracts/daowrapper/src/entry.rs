@@ -0,0 +1,181 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, packed::Header, prelude::*};
+use ckb_std::high_level::{load_cell_capacity, load_cell_data, load_cell_lock, load_cell_occupied_capacity, load_cell_type_hash, load_header, load_script, load_script_hash, QueryIter};
+
+// Import our local error codes.
+use crate::error::Error;
+
+// Constants
+const TYPE_HASH_LEN: usize = 32; // Number of bytes for a type hash. (Blake2b 256-bit 32 bytes)
+const CODE_HASH_LEN: usize = 32; // Number of bytes for a code hash. (Blake2b 256-bit 32 bytes)
+const HASH_TYPE_LEN: usize = 1; // A hash type is a single byte.
+const ARGS_LEN: usize = TYPE_HASH_LEN + CODE_HASH_LEN + HASH_TYPE_LEN; // The total length of the args.
+const RECEIPT_DATA_LEN: usize = 16; // The receipt amount is a u128, which is 16 bytes, the same as SUDT.
+const DAO_DATA_LEN: usize = 8; // Nervos DAO cells hold a u64, which is zero for a deposit or the deposit block number when withdrawing.
+
+/// Determine if the specified cell is a Nervos DAO cell that is wrapped by this script.
+/// A wrapped cell has the DAO type and is locked by the pool lock code and hash type with the hash of this script as its args.
+fn is_wrapped_dao_cell(index: usize, source: Source, args: &Bytes, script_hash: &[u8]) -> Result<bool, Error>
+{
+	// The cell must use the Nervos DAO type script.
+	let dao_type_hash = &args[0..TYPE_HASH_LEN];
+	match load_cell_type_hash(index, source)?
+	{
+		Some(type_hash) if dao_type_hash[..] == type_hash[..] => (),
+		_ => return Ok(false),
+	}
+
+	// The cell must be locked by the pool lock, which requires this script to be present to unlock.
+	// The hash type is compared so the same code hash cannot refer to a different lock by its type script.
+	let pool_lock_code_hash = &args[TYPE_HASH_LEN..TYPE_HASH_LEN+CODE_HASH_LEN];
+	let pool_lock_hash_type = &args[TYPE_HASH_LEN+CODE_HASH_LEN..ARGS_LEN];
+	let lock = load_cell_lock(index, source)?;
+	let lock_args: Bytes = lock.args().unpack();
+	if lock.code_hash().as_slice() != pool_lock_code_hash || lock.hash_type().as_slice() != pool_lock_hash_type || lock_args[..] != script_hash[..]
+	{
+		return Ok(false);
+	}
+
+	Ok(true)
+}
+
+/// Load the u64 value from the data of a Nervos DAO cell.
+fn load_dao_data(index: usize, source: Source) -> Result<u64, Error>
+{
+	let data = load_cell_data(index, source)?;
+	if data.len() != DAO_DATA_LEN
+	{
+		return Err(Error::Encoding);
+	}
+
+	let mut buffer = [0u8; DAO_DATA_LEN];
+	buffer.copy_from_slice(&data[0..DAO_DATA_LEN]);
+
+	Ok(u64::from_le_bytes(buffer))
+}
+
+/// Extract the accumulated rate (AR) from the DAO field of a header.
+fn extract_accumulated_rate(header: &Header) -> u64
+{
+	// The DAO field holds four u64 values: C, AR, S, and U.
+	let dao = header.raw().dao();
+	let mut buffer = [0u8; 8];
+	buffer.copy_from_slice(&dao.as_slice()[8..16]);
+
+	u64::from_le_bytes(buffer)
+}
+
+/// Calculate the maximum capacity that can be withdrawn from a withdrawing DAO cell in the inputs.
+fn calculate_withdraw_capacity(index: usize) -> Result<u64, Error>
+{
+	// The deposit header is located in the header deps using the deposit block number stored in the cell data.
+	let deposit_block_number = load_dao_data(index, Source::Input)?;
+	let deposit_header = QueryIter::new(load_header, Source::HeaderDep)
+		.find(|header| header.raw().number().unpack() == deposit_block_number)
+		.ok_or(Error::MissingHeader)?;
+
+	// The withdraw header is the header of the block the withdrawing cell was created in.
+	let withdraw_header = load_header(index, Source::Input).map_err(|_| Error::MissingHeader)?;
+
+	// Interest is earned on the capacity that is not occupied, using the ratio of the accumulated rates.
+	let capacity = load_cell_capacity(index, Source::Input)?;
+	let occupied_capacity = load_cell_occupied_capacity(index, Source::Input)?;
+	let deposit_ar = extract_accumulated_rate(&deposit_header) as u128;
+	let withdraw_ar = extract_accumulated_rate(&withdraw_header) as u128;
+	if deposit_ar == 0
+	{
+		return Err(Error::MissingHeader);
+	}
+	let counted_capacity = capacity.saturating_sub(occupied_capacity) as u128;
+	let withdraw_counted_capacity = counted_capacity * withdraw_ar / deposit_ar;
+
+	u64::try_from(withdraw_counted_capacity + occupied_capacity as u128).map_err(|_| Error::ExcessiveWithdrawal)
+}
+
+/// Count the total receipt amount in the specified source. Source should be either GroupInput or GroupOutput.
+fn determine_receipt_amount(source: Source) -> Result<u128, Error>
+{
+	let mut total = 0u128;
+	for data in QueryIter::new(load_cell_data, source)
+	{
+		// Check that the length of the data is >= 16 bytes, the size of a u128.
+		if data.len() < RECEIPT_DATA_LEN
+		{
+			return Err(Error::Encoding);
+		}
+
+		let mut buffer = [0u8; RECEIPT_DATA_LEN];
+		buffer.copy_from_slice(&data[0..RECEIPT_DATA_LEN]);
+		total = total.checked_add(u128::from_le_bytes(buffer)).ok_or(Error::InvalidReceiptAmount)?;
+	}
+
+	Ok(total)
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+	let script_hash = load_script_hash()?;
+
+	// Verify that the correct length of the arguments was given.
+	// We expect the Nervos DAO type hash followed by the pool lock code hash and hash type.
+	if args.len() != ARGS_LEN
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	// Total the principal of the wrapped DAO cells in the inputs, and the maximum capacity they can be withdrawn for.
+	let mut input_principal = 0u128;
+	let mut input_withdraw_capacity = 0u128;
+	for (i, capacity) in QueryIter::new(load_cell_capacity, Source::Input).enumerate()
+	{
+		if is_wrapped_dao_cell(i, Source::Input, &args, &script_hash)?
+		{
+			input_principal = input_principal.checked_add(capacity as u128).ok_or(Error::Overflow)?;
+
+			// A DAO cell in the withdrawing phase can be withdrawn with interest.
+			if load_dao_data(i, Source::Input)? != 0
+			{
+				input_withdraw_capacity = input_withdraw_capacity.checked_add(calculate_withdraw_capacity(i)? as u128).ok_or(Error::Overflow)?;
+				continue;
+			}
+		}
+		input_withdraw_capacity = input_withdraw_capacity.checked_add(capacity as u128).ok_or(Error::Overflow)?;
+	}
+
+	// Total the principal of the wrapped DAO cells in the outputs, and the total capacity of the outputs.
+	let mut output_principal = 0u128;
+	let mut output_capacity = 0u128;
+	for (i, capacity) in QueryIter::new(load_cell_capacity, Source::Output).enumerate()
+	{
+		if is_wrapped_dao_cell(i, Source::Output, &args, &script_hash)?
+		{
+			output_principal = output_principal.checked_add(capacity as u128).ok_or(Error::Overflow)?;
+		}
+		output_capacity = output_capacity.checked_add(capacity as u128).ok_or(Error::Overflow)?;
+	}
+
+	// The outputs cannot hold more capacity than the inputs plus the interest earned on withdrawn DAO cells.
+	if output_capacity > input_withdraw_capacity
+	{
+		return Err(Error::ExcessiveWithdrawal);
+	}
+
+	// Receipts are minted 1:1 for capacity deposited and burned 1:1 for the principal withdrawn.
+	let input_receipt_amount = determine_receipt_amount(Source::GroupInput)?;
+	let output_receipt_amount = determine_receipt_amount(Source::GroupOutput)?;
+	if input_receipt_amount.checked_add(output_principal) != output_receipt_amount.checked_add(input_principal)
+	{
+		return Err(Error::InvalidReceiptAmount);
+	}
+
+	Ok(())
+}
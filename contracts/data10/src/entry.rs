@@ -4,8 +4,8 @@ use core::result::Result;
 // Import CKB syscalls and structures.
 // https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
 use ckb_std::ckb_constants::Source;
-use ckb_std::ckb_types::prelude::*;
-use ckb_std::high_level::{load_cell, load_cell_data, load_script, QueryIter};
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell_data, load_script, QueryIter};
 
 // Import our local error codes.
 use crate::error::Error;
@@ -16,32 +16,32 @@ const MAX_DATA_SIZE: usize = 10;
 // Main entry point.
 pub fn main() -> Result<(), Error>
 {
-	// Load the current script.
+	// Load arguments from the current script.
 	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
 
-	// Load each cell from the outputs.
-	for (i, cell) in QueryIter::new(load_cell, Source::Output).enumerate()
+	// Set the data size limit. This is a u32 LE value when the args are 4 bytes, using the same format as datacap.
+	// Args of any other length are ignored, as they were before the limit was added. This is a behaviour change for cells
+	// which already had 4 bytes of args: they used the default before, and now their args are read as the limit.
+	let cell_data_limit = match args.len()
 	{
-		// Check if there is a type script, and skip to the next cell if there is not.
-		let cell_type_script = &cell.type_();
-		if cell_type_script.is_none()
+		4 =>
 		{
-			continue;
-		}
-
-		// Convert the scripts to bytes and check if they are the same.
-		let cell_type_script = cell_type_script.to_opt().unwrap();
-		if *script.as_bytes() == *cell_type_script.as_bytes()
+			let mut buffer = [0u8; 4];
+			buffer.copy_from_slice(&args[0..4]);
+			u32::from_le_bytes(buffer) as usize
+		},
+		_ => MAX_DATA_SIZE,
+	};
+
+	// Load the cell data from each output cell in the script group.
+	for data in QueryIter::new(load_cell_data, Source::GroupOutput)
+	{
+		// If the data is larger than our limit.
+		if data.len() > cell_data_limit
 		{
-			// Load the cell's data.
-			let data = load_cell_data(i, Source::Output)?;
-
-			// If the data is larger than our limit.
-			if data.len() > MAX_DATA_SIZE
-			{
-				// Return a limit exceeded error.
-				return Err(Error::DataLimitExceeded);
-			}
+			// Return a limit exceeded error.
+			return Err(Error::DataLimitExceeded);
 		}
 	}
 
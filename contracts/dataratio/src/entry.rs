@@ -0,0 +1,85 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell_capacity, load_cell_data, load_cell_occupied_capacity, load_script, QueryIter};
+
+// Import our local error codes.
+use crate::error::Error;
+
+// Constants
+const SHANNONS_PER_CKBYTE: u64 = 100_000_000; // One CKByte is 100,000,000 Shannons.
+const MODE_PER_CELL: u8 = 0; // Each cell is limited by its own free capacity.
+const MODE_AGGREGATE: u8 = 1; // The total data of the group is limited by the total free capacity of the group.
+
+/// Determine the capacity in Shannons that a cell holds beyond its occupied capacity.
+fn free_capacity(index: usize, source: Source) -> Result<u64, Error>
+{
+	let capacity = load_cell_capacity(index, source)?;
+	let occupied_capacity = load_cell_occupied_capacity(index, source)?;
+
+	Ok(capacity.saturating_sub(occupied_capacity))
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+
+	// Verify that correct length of arguments was given.
+	// We expect a u32 LE bytes per CKByte value, optionally followed by a one byte mode.
+	if args.len() != 4 && args.len() != 5
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	// Load the bytes_per_ckbyte from the script args.
+	let mut buffer = [0u8; 4];
+	buffer.copy_from_slice(&args[0..4]);
+	let bytes_per_ckbyte = u32::from_le_bytes(buffer) as u64;
+
+	// Load the mode from the script args, defaulting to per cell.
+	let mode = if args.len() == 5 { args[4] } else { MODE_PER_CELL };
+
+	match mode
+	{
+		MODE_PER_CELL =>
+		{
+			// Check the data of each cell against the free capacity of that cell.
+			for (i, data) in QueryIter::new(load_cell_data, Source::GroupOutput).enumerate()
+			{
+				let cell_data_limit = (free_capacity(i, Source::GroupOutput)? / SHANNONS_PER_CKBYTE).saturating_mul(bytes_per_ckbyte);
+				if (data.len() as u64) > cell_data_limit
+				{
+					return Err(Error::DataLimitExceeded);
+				}
+			}
+		},
+		MODE_AGGREGATE =>
+		{
+			// Total the data and free capacity of all cells in the group.
+			let mut total_data_len = 0u64;
+			let mut total_free_capacity = 0u64;
+			for (i, data) in QueryIter::new(load_cell_data, Source::GroupOutput).enumerate()
+			{
+				total_data_len += data.len() as u64;
+				total_free_capacity += free_capacity(i, Source::GroupOutput)?;
+			}
+
+			// Check the total data against the total free capacity.
+			let total_data_limit = (total_free_capacity / SHANNONS_PER_CKBYTE).saturating_mul(bytes_per_ckbyte);
+			if total_data_len > total_data_limit
+			{
+				return Err(Error::DataLimitExceeded);
+			}
+		},
+		_ => return Err(Error::InvalidMode),
+	}
+
+	Ok(())
+}
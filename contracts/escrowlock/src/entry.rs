@@ -0,0 +1,161 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell_capacity, load_cell_lock_hash, load_input_since, load_script, load_script_hash, QueryIter};
+
+// Import our local error codes.
+use crate::error::Error;
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const SINCE_LEN: usize = 8; // A since value is a u64, which is 8 bytes.
+const BUYER_START: usize = 0; // The buyer lock hash is the first value in the args.
+const SELLER_START: usize = BUYER_START + LOCK_HASH_LEN; // The seller lock hash follows the buyer lock hash.
+const ARBITER_START: usize = SELLER_START + LOCK_HASH_LEN; // The arbiter lock hash follows the seller lock hash.
+const RELEASE_START: usize = ARBITER_START + LOCK_HASH_LEN; // The release lock hash follows the arbiter lock hash.
+const TIMEOUT_START: usize = RELEASE_START + LOCK_HASH_LEN; // The timeout since follows the release lock hash.
+const ARGS_LEN: usize = TIMEOUT_START + SINCE_LEN; // The total length of the args.
+const SINCE_FLAGS_MASK: u64 = 0xFF00_0000_0000_0000; // The flags are the highest byte of the since value.
+const SINCE_VALUE_MASK: u64 = 0x00FF_FFFF_FFFF_FFFF; // The value is the lower 56 bits of the since value.
+const SINCE_METRIC_MASK: u64 = 0x6000_0000_0000_0000; // The metric flag bits.
+const SINCE_RESERVED_MASK: u64 = 0x1F00_0000_0000_0000; // The reserved flag bits, which must be zero.
+const SINCE_METRIC_BLOCK_NUMBER: u64 = 0x0000_0000_0000_0000;
+const SINCE_METRIC_EPOCH: u64 = 0x2000_0000_0000_0000;
+const SINCE_METRIC_TIMESTAMP: u64 = 0x4000_0000_0000_0000;
+
+/// Determine if an input cell exists with the specified lock hash.
+fn check_lock_hash(lock_hash: &[u8]) -> bool
+{
+	QueryIter::new(load_cell_lock_hash, Source::Input)
+		.any(|input_lock_hash|lock_hash[..]==input_lock_hash[..])
+}
+
+/// Verify that every escrow cell is paid out to the output at the same index, which must have the specified lock hash
+/// and at least the capacity of the escrow cell. Matching by index prevents one output from paying out multiple escrow
+/// cells, including cells with different args which are validated by separate script groups.
+fn validate_payout(lock_hash: &[u8]) -> Result<(), Error>
+{
+	let script_hash = load_script_hash()?;
+	for (i, input_lock_hash) in QueryIter::new(load_cell_lock_hash, Source::Input).enumerate()
+	{
+		// Skip any input that is not an escrow cell of this script.
+		if input_lock_hash != script_hash
+		{
+			continue;
+		}
+
+		// A missing output is the same as an output with no capacity.
+		let escrow_capacity = load_cell_capacity(i, Source::Input)?;
+		let payout_lock_hash = load_cell_lock_hash(i, Source::Output).map_err(|_|Error::InsufficientCapacity)?;
+		let payout_capacity = load_cell_capacity(i, Source::Output)?;
+		if lock_hash[..] != payout_lock_hash[..] || payout_capacity < escrow_capacity
+		{
+			return Err(Error::InsufficientCapacity);
+		}
+	}
+
+	Ok(())
+}
+
+/// Determine if the epoch of a refund since has reached the epoch of the escrow timeout.
+/// A refund in the same epoch as the timeout must also be at or past its fraction of the epoch.
+fn epoch_reached(since: u64, timeout: u64) -> bool
+{
+	// The epoch of a since and of the timeout is a number (24 bits), an index (16 bits), and a length (16 bits).
+	let number = |e: u64| e & 0xFF_FFFF;
+	let index = |e: u64| (e >> 24) & 0xFFFF;
+	let length = |e: u64| (e >> 40) & 0xFFFF;
+
+	if number(since) != number(timeout)
+	{
+		return number(since) > number(timeout);
+	}
+
+	// Compare the fractions index / length by cross multiplying. A timeout or since with a length of zero is at the
+	// start of its epoch.
+	let (since_index, since_length) = if length(since) == 0 { (0, 1) } else { (index(since), length(since)) };
+	let (timeout_index, timeout_length) = if length(timeout) == 0 { (0, 1) } else { (index(timeout), length(timeout)) };
+
+	since_index * timeout_length >= timeout_index * since_length
+}
+
+/// Verify that every escrow cell has a since with the same flags as the timeout that meets the timeout.
+fn validate_timeout(timeout: u64) -> Result<(), Error>
+{
+	for since in QueryIter::new(load_input_since, Source::GroupInput)
+	{
+		// A cell without a since is not attempting a refund, and no other path was authorized.
+		if since == 0
+		{
+			return Err(Error::Unauthorized);
+		}
+
+		if (since & SINCE_FLAGS_MASK) != (timeout & SINCE_FLAGS_MASK)
+		{
+			return Err(Error::InvalidSince);
+		}
+
+		let reached = if (timeout & SINCE_METRIC_MASK) == SINCE_METRIC_EPOCH
+		{
+			epoch_reached(since & SINCE_VALUE_MASK, timeout & SINCE_VALUE_MASK)
+		}
+		else
+		{
+			(since & SINCE_VALUE_MASK) >= (timeout & SINCE_VALUE_MASK)
+		};
+		if !reached
+		{
+			return Err(Error::InvalidSince);
+		}
+	}
+
+	Ok(())
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+
+	// Verify that the correct length of the arguments was given.
+	// We expect the buyer, seller, arbiter, and release lock hashes followed by the timeout since.
+	if args.len() != ARGS_LEN
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	// Load the timeout since from the args and verify that it uses a valid metric.
+	let mut buffer = [0u8; SINCE_LEN];
+	buffer.copy_from_slice(&args[TIMEOUT_START..ARGS_LEN]);
+	let timeout = u64::from_le_bytes(buffer);
+	let metric = timeout & SINCE_METRIC_MASK;
+	if (timeout & SINCE_RESERVED_MASK) != 0 || (metric != SINCE_METRIC_BLOCK_NUMBER && metric != SINCE_METRIC_EPOCH && metric != SINCE_METRIC_TIMESTAMP)
+	{
+		return Err(Error::InvalidTimeout);
+	}
+
+	// Count the number of parties that have approved by including an input cell with their lock.
+	let buyer_lock_hash = &args[BUYER_START..SELLER_START];
+	let seller_lock_hash = &args[SELLER_START..ARBITER_START];
+	let arbiter_lock_hash = &args[ARBITER_START..RELEASE_START];
+	let approvals = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash].iter()
+		.filter(|lock_hash|check_lock_hash(lock_hash))
+		.count();
+
+	// The buyer and seller together, or the arbiter with either of them, can release the funds.
+	if approvals >= 2
+	{
+		let release_lock_hash = &args[RELEASE_START..TIMEOUT_START];
+		return validate_payout(release_lock_hash);
+	}
+
+	// Otherwise the funds can only be refunded to the buyer after the timeout.
+	validate_timeout(timeout)?;
+	validate_payout(buyer_lock_hash)
+}
@@ -0,0 +1,42 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell_lock_hash, load_script, QueryIter};
+
+// Import our local error codes.
+use crate::error::Error;
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+
+	// Verify that the arguments length matches the length of a Blake2b hash.
+	if args.len() != LOCK_HASH_LEN
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	// Compare the lock hash from the script args with the lock hash of each
+	// input cell. The owner lock is responsible for its own validation.
+	let is_owner_present = QueryIter::new(load_cell_lock_hash, Source::Input)
+		.any(|lock_hash|args[..]==lock_hash[..]);
+
+	// If no input cell has the owner lock, return an error.
+	if !is_owner_present
+	{
+		return Err(Error::Unauthorized);
+	}
+
+	// No errors were found during validation. Return success.
+	Ok(())
+}
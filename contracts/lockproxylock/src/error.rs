@@ -9,6 +9,7 @@ pub enum Error
 	LengthNotEnough,
 	Encoding,
 	// Add customized errors here...
+	ArgsLen,
 	Unauthorized,
 }
 
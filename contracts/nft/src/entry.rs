@@ -0,0 +1,260 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import the Blake2b reference library.
+use blake2b_ref::Blake2bBuilder;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, packed::Script, prelude::*};
+use ckb_std::high_level::{load_cell, load_cell_data, load_cell_type, load_cell_type_hash, load_input, load_script, load_script_hash, QueryIter};
+
+// Import local modules.
+use crate::error::Error;
+
+// Constants
+const NFT_ID_LEN: usize = 32; // Number of bytes for an NFT ID. (Blake2b 256-bit 32 bytes)
+const CLASS_TYPE_HASH_LEN: usize = 32; // Number of bytes for the type hash of a class cell.
+const CLASS_ID_LEN: usize = 32; // Number of bytes for a class ID. (Blake2b 256-bit 32 bytes)
+const CLASS_ARGS_LEN: usize = 33; // The args of a class cell are the class ID followed by the class mode byte.
+const CLASS_MODE: u8 = 1; // The last byte of the args of a class cell.
+const CLASS_DATA_LEN: usize = 16; // A class cell holds two u64 values: the maximum supply and the issued count.
+const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash"; // The Blake2b personalization used by CKB.
+
+// The modes of operation for the script.
+enum Mode
+{
+	Burn, // Consume an existing NFT or class cell.
+	Create, // Create a new NFT or class cell with a unique ID.
+	Transfer, // Transfer an existing NFT without changing the content, or update a class cell.
+}
+
+// Determines the mode of operation for the currently executing script.
+fn determine_mode() -> Result<Mode, Error>
+{
+	// Gather counts on the number of group input and group output cells.
+	let group_input_count = QueryIter::new(load_cell, Source::GroupInput).count();
+	let group_output_count = QueryIter::new(load_cell, Source::GroupOutput).count();
+
+	// Detect the operation based on the cell count.
+	if group_input_count == 1 && group_output_count == 0
+	{
+		return Ok(Mode::Burn);
+	}
+	if group_input_count == 0 && group_output_count == 1
+	{
+		return Ok(Mode::Create);
+	}
+	if group_input_count == 1 && group_output_count == 1
+	{
+		return Ok(Mode::Transfer);
+	}
+
+	// If no known code structure was used, return an error.
+	Err(Error::InvalidTransactionStructure)
+}
+
+/// Locate the index of the class cell with the specified type hash within the specified source.
+fn find_class(class_type_hash: &[u8], source: Source) -> Result<usize, Error>
+{
+	QueryIter::new(load_cell_type_hash, source)
+		.position(|type_hash| matches!(type_hash, Some(type_hash) if class_type_hash[..] == type_hash[..]))
+		.ok_or(Error::ClassNotFound)
+}
+
+/// Read the maximum supply and issued count from the class cell at the specified index.
+fn load_class_data(index: usize, source: Source) -> Result<(u64, u64), Error>
+{
+	// Verify the class data is the correct length.
+	let data = load_cell_data(index, source)?;
+	if data.len() != CLASS_DATA_LEN
+	{
+		return Err(Error::InvalidClassData);
+	}
+
+	// Convert the binary data to the maximum supply and issued count.
+	let mut buffer = [0u8; 8];
+	buffer.copy_from_slice(&data[0..8]);
+	let max_supply = u64::from_le_bytes(buffer);
+	buffer.copy_from_slice(&data[8..16]);
+	let issued = u64::from_le_bytes(buffer);
+
+	Ok((max_supply, issued))
+}
+
+/// Count the NFTs of the specified class that are being created in this transaction.
+fn count_created_nfts(script: &Script, class_type_hash: &[u8]) -> Result<u64, Error>
+{
+	let mut count = 0;
+
+	// Cycle through every output cell with a type script.
+	for (i, type_script) in QueryIter::new(load_cell_type, Source::Output).enumerate()
+	{
+		let type_script = match type_script
+		{
+			Some(type_script) => type_script,
+			None => continue,
+		};
+
+		// Skip any type script that is not an NFT of the same class.
+		let type_args: Bytes = type_script.args().unpack();
+		if type_script.code_hash().as_slice() != script.code_hash().as_slice()
+			|| type_script.hash_type().as_slice() != script.hash_type().as_slice()
+			|| type_args.len() != NFT_ID_LEN + CLASS_TYPE_HASH_LEN
+			|| type_args[NFT_ID_LEN..] != class_type_hash[..]
+		{
+			continue;
+		}
+
+		// An NFT that also exists in the inputs is being transferred, not created.
+		let type_hash = load_cell_type_hash(i, Source::Output)?;
+		if !QueryIter::new(load_cell_type_hash, Source::Input).any(|input_type_hash| input_type_hash == type_hash)
+		{
+			count += 1;
+		}
+	}
+
+	Ok(count)
+}
+
+/// Verify that the unique ID was derived from the first input of the transaction and the index of the group output cell.
+fn validate_unique_id(id: &[u8]) -> Result<(), Error>
+{
+	// Locate the index of the group output cell within all the output cells.
+	let script_hash = load_script_hash()?;
+	let output_index = QueryIter::new(load_cell_type_hash, Source::Output)
+		.position(|type_hash| type_hash == Some(script_hash))
+		.ok_or(Error::ItemMissing)?;
+
+	// Hash the first input of the transaction and the output index to create the ID.
+	let first_input = load_input(0, Source::Input)?;
+	let mut expected_id = [0u8; NFT_ID_LEN];
+	let mut blake2b = Blake2bBuilder::new(NFT_ID_LEN).personal(CKB_HASH_PERSONALIZATION).build();
+	blake2b.update(first_input.as_slice());
+	blake2b.update(&(output_index as u64).to_le_bytes());
+	blake2b.finalize(&mut expected_id);
+
+	// Verify that the ID in the args matches.
+	if id[..] != expected_id[..]
+	{
+		return Err(Error::InvalidNftId);
+	}
+
+	Ok(())
+}
+
+// Validate a transaction to create an NFT with a unique ID.
+fn validate_create(script: &Script, args: &Bytes) -> Result<(), Error>
+{
+	validate_unique_id(&args[0..NFT_ID_LEN])?;
+
+	// If no class was specified then there is no limit on the number issued.
+	if args.len() == NFT_ID_LEN
+	{
+		return Ok(());
+	}
+
+	// The class cell must be a class of this contract, which verifies that the issued count includes this NFT.
+	let class_type_hash = &args[NFT_ID_LEN..];
+	let class_index = find_class(class_type_hash, Source::Input)?;
+	let class_script = load_cell_type(class_index, Source::Input)?.ok_or(Error::ClassNotFound)?;
+	let class_args: Bytes = class_script.args().unpack();
+	if class_script.code_hash().as_slice() != script.code_hash().as_slice()
+		|| class_script.hash_type().as_slice() != script.hash_type().as_slice()
+		|| !is_class_args(&class_args)
+	{
+		return Err(Error::ClassNotFound);
+	}
+
+	// The class cell must be updated in the same transaction.
+	find_class(class_type_hash, Source::Output)?;
+
+	Ok(())
+}
+
+// Validate a transaction to create a class cell with a unique ID.
+fn validate_create_class(args: &Bytes) -> Result<(), Error>
+{
+	validate_unique_id(&args[0..CLASS_ID_LEN])?;
+
+	// A new class has not issued any NFTs.
+	let (_max_supply, issued) = load_class_data(0, Source::GroupOutput)?;
+	if issued != 0
+	{
+		return Err(Error::InvalidClassData);
+	}
+
+	Ok(())
+}
+
+// Validate a transaction to update a class cell.
+fn validate_update_class(script: &Script) -> Result<(), Error>
+{
+	let (input_max_supply, input_issued) = load_class_data(0, Source::GroupInput)?;
+	let (output_max_supply, output_issued) = load_class_data(0, Source::GroupOutput)?;
+
+	// The maximum supply cannot be changed and the issued count must increase by exactly the number of NFTs created.
+	let class_type_hash = load_script_hash()?;
+	let created = count_created_nfts(script, &class_type_hash)?;
+	if output_max_supply != input_max_supply || Some(output_issued) != input_issued.checked_add(created)
+	{
+		return Err(Error::InvalidClassData);
+	}
+
+	// The issued count cannot exceed the maximum supply.
+	if output_issued > output_max_supply
+	{
+		return Err(Error::ClassLimitExceeded);
+	}
+
+	Ok(())
+}
+
+// Validate a transaction to transfer an NFT.
+fn validate_transfer() -> Result<(), Error>
+{
+	// The content of the NFT is immutable.
+	let input_data = load_cell_data(0, Source::GroupInput)?;
+	let output_data = load_cell_data(0, Source::GroupOutput)?;
+	if input_data != output_data
+	{
+		return Err(Error::ContentChanged);
+	}
+
+	Ok(())
+}
+
+/// Determine if the args are for a class cell instead of an NFT.
+fn is_class_args(args: &[u8]) -> bool
+{
+	args.len() == CLASS_ARGS_LEN && args[CLASS_ID_LEN] == CLASS_MODE
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+
+	// Verify that the args contain an NFT ID, optionally followed by a class type hash, or the args of a class cell.
+	let is_class = is_class_args(&args);
+	if !is_class && args.len() != NFT_ID_LEN && args.len() != NFT_ID_LEN + CLASS_TYPE_HASH_LEN
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	// Determine the mode and validate as needed.
+	match determine_mode()
+	{
+		Ok(Mode::Burn) => return Ok(()),
+		Ok(Mode::Create) if is_class => validate_create_class(&args)?,
+		Ok(Mode::Create) => validate_create(&script, &args)?,
+		Ok(Mode::Transfer) if is_class => validate_update_class(&script)?,
+		Ok(Mode::Transfer) => validate_transfer()?,
+		Err(e) => return Err(e),
+	}
+
+	Ok(())
+}
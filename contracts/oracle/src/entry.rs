@@ -0,0 +1,134 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell, load_cell_data, load_cell_lock_hash, load_script, QueryIter};
+
+// Import local modules.
+use crate::error::Error;
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const ORACLE_DATA_LEN: usize = 24; // The oracle data holds three u64 values: the value, the timestamp, and the sequence.
+
+// The modes of operation for the script.
+enum Mode
+{
+	Burn, // Consume an existing oracle cell.
+	Create, // Create a new oracle cell.
+	Update, // Update the record in an existing oracle cell.
+}
+
+// Determines the mode of operation for the currently executing script.
+fn determine_mode() -> Result<Mode, Error>
+{
+	// Gather counts on the number of group input and group output cells.
+	let group_input_count = QueryIter::new(load_cell, Source::GroupInput).count();
+	let group_output_count = QueryIter::new(load_cell, Source::GroupOutput).count();
+
+	// Detect the operation based on the cell count.
+	if group_input_count == 1 && group_output_count == 0
+	{
+		return Ok(Mode::Burn);
+	}
+	if group_input_count == 0 && group_output_count == 1
+	{
+		return Ok(Mode::Create);
+	}
+	if group_input_count == 1 && group_output_count == 1
+	{
+		return Ok(Mode::Update);
+	}
+
+	// If no known code structure was used, return an error.
+	Err(Error::InvalidTransactionStructure)
+}
+
+/// Load the oracle record (value, timestamp, sequence) from the cell data of the first cell in the specified source.
+fn load_oracle_record(source: Source) -> Result<(u64, u64, u64), Error>
+{
+	// Verify the data is the correct length.
+	let data = load_cell_data(0, source)?;
+	if data.len() != ORACLE_DATA_LEN
+	{
+		return Err(Error::InvalidCellData);
+	}
+
+	// Convert the binary data into the value, timestamp, and sequence.
+	let mut buffer = [0u8; 8];
+	buffer.copy_from_slice(&data[0..8]);
+	let value = u64::from_le_bytes(buffer);
+	buffer.copy_from_slice(&data[8..16]);
+	let timestamp = u64::from_le_bytes(buffer);
+	buffer.copy_from_slice(&data[16..24]);
+	let sequence = u64::from_le_bytes(buffer);
+
+	Ok((value, timestamp, sequence))
+}
+
+// Validate a transaction to create a new oracle cell.
+fn validate_create() -> Result<(), Error>
+{
+	// The initial record must be valid.
+	load_oracle_record(Source::GroupOutput)?;
+
+	Ok(())
+}
+
+// Validate a transaction to update the record in an existing oracle cell.
+fn validate_update() -> Result<(), Error>
+{
+	let (_, input_timestamp, input_sequence) = load_oracle_record(Source::GroupInput)?;
+	let (_, output_timestamp, output_sequence) = load_oracle_record(Source::GroupOutput)?;
+
+	// Every update must strictly increase the sequence.
+	if output_sequence <= input_sequence
+	{
+		return Err(Error::StaleSequence);
+	}
+
+	// The timestamp can never move backwards.
+	if output_timestamp < input_timestamp
+	{
+		return Err(Error::StaleTimestamp);
+	}
+
+	Ok(())
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+
+	// Verify that the args contain exactly one updater lock hash.
+	if args.len() != LOCK_HASH_LEN
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	// Every operation requires an input cell with the updater lock.
+	// Reading the oracle through a cell dep does not execute this script.
+	let is_updater_present = QueryIter::new(load_cell_lock_hash, Source::Input)
+		.any(|lock_hash|args[..]==lock_hash[..]);
+	if !is_updater_present
+	{
+		return Err(Error::Unauthorized);
+	}
+
+	// Determine the mode and validate as needed.
+	match determine_mode()
+	{
+		Ok(Mode::Burn) => return Ok(()),
+		Ok(Mode::Create) => validate_create()?,
+		Ok(Mode::Update) => validate_update()?,
+		Err(e) => return Err(e),
+	}
+
+	Ok(())
+}
@@ -0,0 +1,101 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import heap related library from alloc.
+// https://doc.rust-lang.org/alloc/index.html
+use alloc::vec::Vec;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell_data, load_cell_lock_hash, load_cell_type_hash, load_script, QueryIter};
+
+// Import our local error codes.
+use crate::error::Error;
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const TYPE_HASH_LEN: usize = 32; // Number of bytes for a type hash. (Blake2b 256-bit 32 bytes)
+const SEQUENCE_LEN: usize = 8; // The minimum sequence is a u64, which is 8 bytes.
+const THRESHOLD_LEN: usize = 8; // The threshold is a u64 oracle value, which is 8 bytes.
+const ORACLE_DATA_LEN: usize = 24; // The oracle data holds three u64 values: the value, the timestamp, and the sequence.
+
+/// Load the oracle record (value, sequence) from the cell dep with the oracle type hash.
+/// A cell can only have the oracle type if the oracle type script accepted it, so a record in a cell with any other
+/// type, such as an oracle type with a different updater, is not found.
+fn load_oracle_record(oracle_type_hash: [u8; TYPE_HASH_LEN]) -> Result<(u64, u64), Error>
+{
+	// Locate the cell deps with the oracle type. There must be exactly one, so the record used is never ambiguous.
+	let indexes: Vec<usize> = QueryIter::new(load_cell_type_hash, Source::CellDep)
+		.enumerate()
+		.filter(|(_, type_hash)|*type_hash==Some(oracle_type_hash))
+		.map(|(i, _)|i)
+		.collect();
+	let index = match indexes.len()
+	{
+		0 => return Err(Error::OracleNotFound),
+		1 => indexes[0],
+		_ => return Err(Error::MultipleOracles),
+	};
+
+	// Verify the data is the correct length.
+	let data = load_cell_data(index, Source::CellDep)?;
+	if data.len() != ORACLE_DATA_LEN
+	{
+		return Err(Error::InvalidOracleData);
+	}
+
+	// Convert the binary data into the value and the sequence. The timestamp is not used.
+	let mut buffer = [0u8; 8];
+	buffer.copy_from_slice(&data[0..8]);
+	let value = u64::from_le_bytes(buffer);
+	buffer.copy_from_slice(&data[16..24]);
+	let sequence = u64::from_le_bytes(buffer);
+
+	Ok((value, sequence))
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+
+	// Verify that the correct length of the arguments was given.
+	// We expect the owner lock hash, the oracle type hash, the minimum sequence, and the threshold.
+	if args.len() != LOCK_HASH_LEN + TYPE_HASH_LEN + SEQUENCE_LEN + THRESHOLD_LEN
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	// The owner lock must be present in the inputs.
+	let owner_lock_hash = &args[0..LOCK_HASH_LEN];
+	if !QueryIter::new(load_cell_lock_hash, Source::Input).any(|lock_hash|owner_lock_hash[..]==lock_hash[..])
+	{
+		return Err(Error::Unauthorized);
+	}
+
+	// Load the oracle type hash, the minimum sequence, and the threshold from the args.
+	let mut oracle_type_hash = [0u8; TYPE_HASH_LEN];
+	oracle_type_hash.copy_from_slice(&args[LOCK_HASH_LEN..LOCK_HASH_LEN+TYPE_HASH_LEN]);
+	let mut buffer = [0u8; 8];
+	buffer.copy_from_slice(&args[LOCK_HASH_LEN+TYPE_HASH_LEN..LOCK_HASH_LEN+TYPE_HASH_LEN+SEQUENCE_LEN]);
+	let minimum_sequence = u64::from_le_bytes(buffer);
+	buffer.copy_from_slice(&args[LOCK_HASH_LEN+TYPE_HASH_LEN+SEQUENCE_LEN..]);
+	let threshold = u64::from_le_bytes(buffer);
+
+	// The record must be at least as recent as the minimum sequence, and its value must meet the threshold.
+	let (value, sequence) = load_oracle_record(oracle_type_hash)?;
+	if sequence < minimum_sequence
+	{
+		return Err(Error::StaleRecord);
+	}
+	if value < threshold
+	{
+		return Err(Error::ThresholdNotMet);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,163 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import heap related library from alloc.
+// https://doc.rust-lang.org/alloc/index.html
+use alloc::vec::Vec;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell, load_cell_capacity, load_cell_data, load_cell_lock_hash, load_cell_type_hash, load_script, load_script_hash, QueryIter};
+
+// Import our local error codes.
+use crate::error::Error;
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const CKB_AMOUNT_LEN: usize = 8; // The CKB amount of the exchange rate is a u64, which is 8 bytes.
+const SUDT_AMOUNT_LEN: usize = 16; // The SUDT amount of the exchange rate is a u128, which is 16 bytes.
+const SUDT_DATA_LEN: usize = 16; // SUDT uses a u128, which is 16 bytes.
+
+/// Determine if owner mode is enabled.
+fn check_owner_mode(maker_lock_hash: &[u8]) -> bool
+{
+	// Compare the maker Lock Script Hash with the Lock Scripts
+	// of each input cell to determine if a match exists.
+	QueryIter::new(load_cell_lock_hash, Source::Input)
+		.any(|lock_hash|maker_lock_hash[..]==lock_hash[..])
+}
+
+/// Load the SUDT amount from the data of the specified cell.
+fn load_sudt_amount(index: usize, source: Source) -> Result<u128, Error>
+{
+	// Check that the length of the data is >= 16 bytes, the size of a u128.
+	let data = load_cell_data(index, source)?;
+	if data.len() < SUDT_DATA_LEN
+	{
+		return Err(Error::Encoding);
+	}
+
+	// Convert the binary data in the cell to a u128 value.
+	let mut buffer = [0u8; SUDT_DATA_LEN];
+	buffer.copy_from_slice(&data[0..SUDT_DATA_LEN]);
+
+	Ok(u128::from_le_bytes(buffer))
+}
+
+/// Load the capacity of the payment output, which is at the same index as the order cell in the inputs and must have
+/// the specified lock hash. Matching by index prevents one output from paying for multiple orders, including orders
+/// with different args which are validated by separate script groups.
+fn load_payment_capacity(lock_hash: &[u8]) -> Result<u64, Error>
+{
+	// Locate the index of the order cell within all the input cells.
+	let script_hash = load_script_hash()?;
+	let order_index = QueryIter::new(load_cell_lock_hash, Source::Input)
+		.position(|lock_hash|lock_hash==script_hash)
+		.ok_or(Error::ItemMissing)?;
+
+	// A missing output or an output with a different lock does not pay the maker.
+	let payment_lock_hash = load_cell_lock_hash(order_index, Source::Output).map_err(|_|Error::InsufficientPayment)?;
+	if lock_hash[..] != payment_lock_hash[..]
+	{
+		return Err(Error::InsufficientPayment);
+	}
+
+	Ok(load_cell_capacity(order_index, Source::Output)?)
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+
+	// Verify that the correct length of the arguments was given.
+	// We expect the maker lock hash followed by the exchange rate as a CKB amount (in Shannons) for an SUDT amount.
+	if args.len() != LOCK_HASH_LEN + CKB_AMOUNT_LEN + SUDT_AMOUNT_LEN
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	// The maker can cancel the order at any time by including an input with their lock.
+	let maker_lock_hash = &args[0..LOCK_HASH_LEN];
+	if check_owner_mode(maker_lock_hash)
+	{
+		return Ok(());
+	}
+
+	// Load the exchange rate from the args.
+	let mut buffer = [0u8; CKB_AMOUNT_LEN];
+	buffer.copy_from_slice(&args[LOCK_HASH_LEN..LOCK_HASH_LEN+CKB_AMOUNT_LEN]);
+	let rate_ckb = u64::from_le_bytes(buffer);
+	let mut buffer = [0u8; SUDT_AMOUNT_LEN];
+	buffer.copy_from_slice(&args[LOCK_HASH_LEN+CKB_AMOUNT_LEN..]);
+	let rate_sudt = u128::from_le_bytes(buffer);
+	if rate_sudt == 0
+	{
+		return Err(Error::InvalidExchangeRate);
+	}
+
+	// Find the output cells which use this same lock. Lock scripts have no group outputs, so all outputs are checked.
+	let script_hash = load_script_hash()?;
+	let residual_indexes: Vec<usize> = QueryIter::new(load_cell_lock_hash, Source::Output)
+		.enumerate()
+		.filter(|(_, lock_hash)|*lock_hash==script_hash)
+		.map(|(i, _)|i)
+		.collect();
+
+	// Only a single order cell can be filled in a transaction, leaving at most one residual order cell.
+	let group_input_count = QueryIter::new(load_cell, Source::GroupInput).count();
+	if group_input_count != 1 || residual_indexes.len() > 1
+	{
+		return Err(Error::InvalidTransactionStructure);
+	}
+
+	// The order cell must hold SUDT tokens.
+	let order_type_hash = load_cell_type_hash(0, Source::GroupInput)?;
+	if order_type_hash.is_none()
+	{
+		return Err(Error::InvalidOrderCell);
+	}
+	let order_capacity = load_cell_capacity(0, Source::GroupInput)?;
+	let order_amount = load_sudt_amount(0, Source::GroupInput)?;
+
+	// Determine the amount of tokens sold and the capacity the maker must receive back.
+	let (sold_amount, returned_capacity) = if let Some(&residual_index) = residual_indexes.first()
+	{
+		// A partial fill leaves a residual order cell with the same lock, type, and capacity, and fewer tokens.
+		let residual_type_hash = load_cell_type_hash(residual_index, Source::Output)?;
+		let residual_capacity = load_cell_capacity(residual_index, Source::Output)?;
+		if residual_type_hash != order_type_hash || residual_capacity < order_capacity
+		{
+			return Err(Error::InvalidResidual);
+		}
+		let residual_amount = load_sudt_amount(residual_index, Source::Output)?;
+		if residual_amount >= order_amount
+		{
+			return Err(Error::InvalidResidual);
+		}
+
+		(order_amount - residual_amount, 0)
+	}
+	else
+	{
+		// A full fill sells every token and returns the capacity of the order cell to the maker.
+		(order_amount, order_capacity)
+	};
+
+	// The payment is rounded up so the maker always receives at least the exchange rate.
+	let value = sold_amount.checked_mul(rate_ckb as u128).ok_or(Error::InsufficientPayment)?;
+	let payment = value / rate_sudt + if value % rate_sudt != 0 { 1 } else { 0 };
+	let required_capacity = payment.checked_add(returned_capacity as u128).ok_or(Error::InsufficientPayment)?;
+
+	// The maker must receive at least the required capacity in the payment output.
+	if (load_payment_capacity(maker_lock_hash)? as u128) < required_capacity
+	{
+		return Err(Error::InsufficientPayment);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,173 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import heap related library from alloc.
+// https://doc.rust-lang.org/alloc/index.html
+use alloc::vec::Vec;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell, load_cell_capacity, load_cell_data, load_cell_lock_hash, load_input_since, load_script, load_script_hash, QueryIter};
+
+// Import our local error codes.
+use crate::error::Error;
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const LIMIT_LEN: usize = 8; // The per-window limit is a u64 amount of Shannons, which is 8 bytes.
+const DATA_LEN: usize = 16; // The cell data holds two u64 values: the current window number and the amount withdrawn in it.
+const SINCE_FLAGS_MASK: u64 = 0xFF00_0000_0000_0000; // The flags are the highest byte of the since value.
+const SINCE_RELATIVE_EPOCH_FLAGS: u64 = 0xA000_0000_0000_0000; // Relative flag with the epoch metric flag.
+const SINCE_EPOCH_NUMBER_MASK: u64 = 0x0000_0000_00FF_FFFF; // The epoch number is the lowest 24 bits of the since value.
+
+// The modes of operation for the script.
+enum Mode
+{
+	Burn, // Consume the cell, withdrawing all of its capacity.
+	Transfer(usize), // Re-create the cell at the output index with updated accounting.
+}
+
+// Determines the mode of operation for the currently executing script.
+// Lock scripts have no group outputs, so the outputs with the same lock are located instead.
+fn determine_mode() -> Result<Mode, Error>
+{
+	// Gather counts on the number of group input cells and output cells with the same lock.
+	let script_hash = load_script_hash()?;
+	let group_input_count = QueryIter::new(load_cell, Source::GroupInput).count();
+	let output_indexes: Vec<usize> = QueryIter::new(load_cell_lock_hash, Source::Output)
+		.enumerate()
+		.filter(|(_, lock_hash)|*lock_hash==script_hash)
+		.map(|(i, _)|i)
+		.collect();
+
+	// Detect the operation based on the cell count.
+	if group_input_count == 1 && output_indexes.is_empty()
+	{
+		return Ok(Mode::Burn);
+	}
+	if group_input_count == 1 && output_indexes.len() == 1
+	{
+		return Ok(Mode::Transfer(output_indexes[0]));
+	}
+
+	// If no known code structure was used, return an error.
+	Err(Error::InvalidTransactionStructure)
+}
+
+/// Parse cell data into the window number and the amount withdrawn in that window.
+fn parse_data(data: &[u8]) -> Option<(u64, u64)>
+{
+	if data.len() != DATA_LEN
+	{
+		return None;
+	}
+
+	let mut buffer = [0u8; 8];
+	buffer.copy_from_slice(&data[0..8]);
+	let window = u64::from_le_bytes(buffer);
+	buffer.copy_from_slice(&data[8..16]);
+	let withdrawn = u64::from_le_bytes(buffer);
+
+	Some((window, withdrawn))
+}
+
+/// Determine the current window and the total amount withdrawn in it after withdrawing the specified amount.
+fn update_accounting(limit: u64, amount: u64) -> Result<(u64, u64), Error>
+{
+	// Load the accounting from the input cell. The lock does not run when a cell is created, so a cell created with
+	// invalid data is treated as a new cell which has not withdrawn anything instead of being unspendable.
+	let input_data = load_cell_data(0, Source::GroupInput)?;
+	let (input_window, input_withdrawn) = parse_data(&input_data).unwrap_or((0, 0));
+
+	// Without a since the withdrawal is in the same window. A new window requires a relative since of at least one
+	// epoch, and since every spend re-creates the cell, a new window can start at most once per epoch. The window
+	// advances by exactly one and nothing carries over, no matter how long the cell was idle.
+	let since = load_input_since(0, Source::GroupInput)?;
+	let (current_window, previous_withdrawn) = if since == 0
+	{
+		(input_window, input_withdrawn)
+	}
+	else if (since & SINCE_FLAGS_MASK) == SINCE_RELATIVE_EPOCH_FLAGS && (since & SINCE_EPOCH_NUMBER_MASK) >= 1
+	{
+		(input_window.checked_add(1).ok_or(Error::InvalidInputCellData)?, 0)
+	}
+	else
+	{
+		return Err(Error::InvalidSince);
+	};
+
+	let total_withdrawn = previous_withdrawn.checked_add(amount).ok_or(Error::LimitExceeded)?;
+	if total_withdrawn > limit
+	{
+		return Err(Error::LimitExceeded);
+	}
+
+	Ok((current_window, total_withdrawn))
+}
+
+// Validate a transaction that consumes the cell, withdrawing all of its capacity.
+fn validate_burn(limit: u64) -> Result<(), Error>
+{
+	let input_capacity = load_cell_capacity(0, Source::GroupInput)?;
+	update_accounting(limit, input_capacity)?;
+
+	Ok(())
+}
+
+// Validate a transaction that re-creates the cell with updated accounting.
+fn validate_transfer(limit: u64, output_index: usize) -> Result<(), Error>
+{
+	// Determine the amount withdrawn in this transaction. Adding capacity to the cell is not a withdrawal.
+	let input_capacity = load_cell_capacity(0, Source::GroupInput)?;
+	let output_capacity = load_cell_capacity(output_index, Source::Output)?;
+	let (current_window, total_withdrawn) = update_accounting(limit, input_capacity.saturating_sub(output_capacity))?;
+
+	// The re-created cell must hold the updated accounting.
+	let output_data = load_cell_data(output_index, Source::Output)?;
+	let (output_window, output_withdrawn) = parse_data(&output_data).ok_or(Error::InvalidOutputCellData)?;
+	if output_window != current_window || output_withdrawn != total_withdrawn
+	{
+		return Err(Error::InvalidOutputCellData);
+	}
+
+	Ok(())
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+
+	// Verify that the correct length of the arguments was given.
+	// We expect the owner lock hash followed by the per-window limit.
+	if args.len() != LOCK_HASH_LEN + LIMIT_LEN
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	// The owner lock must be present in the inputs.
+	let owner_lock_hash = &args[0..LOCK_HASH_LEN];
+	if !QueryIter::new(load_cell_lock_hash, Source::Input).any(|lock_hash|owner_lock_hash[..]==lock_hash[..])
+	{
+		return Err(Error::Unauthorized);
+	}
+
+	// Load the per-window limit from the args.
+	let mut buffer = [0u8; LIMIT_LEN];
+	buffer.copy_from_slice(&args[LOCK_HASH_LEN..LOCK_HASH_LEN+LIMIT_LEN]);
+	let limit = u64::from_le_bytes(buffer);
+
+	// Determine the mode and validate as needed.
+	match determine_mode()
+	{
+		Ok(Mode::Burn) => validate_burn(limit)?,
+		Ok(Mode::Transfer(output_index)) => validate_transfer(limit, output_index)?,
+		Err(e) => return Err(e),
+	}
+
+	Ok(())
+}
@@ -0,0 +1,99 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell_lock_hash, load_input_since, load_script, QueryIter};
+
+// Import our local error codes.
+use crate::error::Error;
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const SINCE_LEN: usize = 8; // A since value is a u64, which is 8 bytes.
+const SINCE_FLAGS_MASK: u64 = 0xFF00_0000_0000_0000; // The flags are the highest byte of the since value.
+const SINCE_VALUE_MASK: u64 = 0x00FF_FFFF_FFFF_FFFF; // The value is the lower 56 bits of the since value.
+const SINCE_METRIC_MASK: u64 = 0x6000_0000_0000_0000; // The metric flag bits.
+const SINCE_RESERVED_MASK: u64 = 0x1F00_0000_0000_0000; // The reserved flag bits, which must be zero.
+const SINCE_METRIC_BLOCK_NUMBER: u64 = 0x0000_0000_0000_0000;
+const SINCE_METRIC_EPOCH: u64 = 0x2000_0000_0000_0000;
+const SINCE_METRIC_TIMESTAMP: u64 = 0x4000_0000_0000_0000;
+
+/// Compare two epoch since values, including the fractional part of the epoch.
+/// Returns true if the first epoch is equal to or later than the second epoch.
+fn epoch_reached(epoch: u64, threshold: u64) -> bool
+{
+	// An epoch is encoded as a number (24 bits), an index (16 bits), and a length (16 bits).
+	let number = |e: u64| e & 0xFF_FFFF;
+	let index = |e: u64| (e >> 24) & 0xFFFF;
+	let length = |e: u64| (e >> 40) & 0xFFFF;
+
+	if number(epoch) != number(threshold)
+	{
+		return number(epoch) > number(threshold);
+	}
+
+	// Compare the fractions index / length by cross multiplying. A length of zero is treated as no fraction.
+	let (epoch_index, epoch_length) = if length(epoch) == 0 { (0, 1) } else { (index(epoch), length(epoch)) };
+	let (threshold_index, threshold_length) = if length(threshold) == 0 { (0, 1) } else { (index(threshold), length(threshold)) };
+
+	epoch_index * threshold_length >= threshold_index * epoch_length
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+
+	// Verify that the correct length of the arguments was given.
+	// We expect the delegate lock hash followed by the since threshold.
+	if args.len() != LOCK_HASH_LEN + SINCE_LEN
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	// Load the since threshold from the args and verify that it uses a valid metric.
+	let mut buffer = [0u8; SINCE_LEN];
+	buffer.copy_from_slice(&args[LOCK_HASH_LEN..LOCK_HASH_LEN+SINCE_LEN]);
+	let threshold = u64::from_le_bytes(buffer);
+	let metric = threshold & SINCE_METRIC_MASK;
+	if (threshold & SINCE_RESERVED_MASK) != 0 || (metric != SINCE_METRIC_BLOCK_NUMBER && metric != SINCE_METRIC_EPOCH && metric != SINCE_METRIC_TIMESTAMP)
+	{
+		return Err(Error::InvalidThreshold);
+	}
+
+	// The delegate lock must be present in the inputs.
+	let delegate_lock_hash = &args[0..LOCK_HASH_LEN];
+	if !QueryIter::new(load_cell_lock_hash, Source::Input).any(|lock_hash|delegate_lock_hash[..]==lock_hash[..])
+	{
+		return Err(Error::Unauthorized);
+	}
+
+	// Every cell using this lock must have a since with the same flags that meets the threshold.
+	for since in QueryIter::new(load_input_since, Source::GroupInput)
+	{
+		if (since & SINCE_FLAGS_MASK) != (threshold & SINCE_FLAGS_MASK)
+		{
+			return Err(Error::InvalidSince);
+		}
+
+		let reached = if metric == SINCE_METRIC_EPOCH
+		{
+			epoch_reached(since & SINCE_VALUE_MASK, threshold & SINCE_VALUE_MASK)
+		}
+		else
+		{
+			(since & SINCE_VALUE_MASK) >= (threshold & SINCE_VALUE_MASK)
+		};
+		if !reached
+		{
+			return Err(Error::InvalidSince);
+		}
+	}
+
+	Ok(())
+}
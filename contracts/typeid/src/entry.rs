@@ -0,0 +1,102 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import the Blake2b reference library.
+use blake2b_ref::Blake2bBuilder;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell, load_cell_type_hash, load_input, load_script, load_script_hash, QueryIter};
+
+// Import local modules.
+use crate::error::Error;
+
+// Constants
+const TYPE_ID_LEN: usize = 32; // Number of bytes for a Type ID. (Blake2b 256-bit 32 bytes)
+const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash"; // The Blake2b personalization used by CKB.
+
+// The modes of operation for the script.
+enum Mode
+{
+	Burn, // Consume an existing cell.
+	Create, // Create a new cell with a unique Type ID.
+	Transfer, // Transfer (update) an existing cell, such as upgrading the code it contains.
+}
+
+// Determines the mode of operation for the currently executing script.
+fn determine_mode() -> Result<Mode, Error>
+{
+	// Gather counts on the number of group input and group output cells.
+	let group_input_count = QueryIter::new(load_cell, Source::GroupInput).count();
+	let group_output_count = QueryIter::new(load_cell, Source::GroupOutput).count();
+
+	// Detect the operation based on the cell count.
+	if group_input_count == 1 && group_output_count == 0
+	{
+		return Ok(Mode::Burn);
+	}
+	if group_input_count == 0 && group_output_count == 1
+	{
+		return Ok(Mode::Create);
+	}
+	if group_input_count == 1 && group_output_count == 1
+	{
+		return Ok(Mode::Transfer);
+	}
+
+	// If no known code structure was used, return an error.
+	Err(Error::InvalidTransactionStructure)
+}
+
+// Validate a transaction to create a cell with a unique Type ID.
+fn validate_create(args: &Bytes) -> Result<(), Error>
+{
+	// Locate the index of the group output cell within all the output cells.
+	let script_hash = load_script_hash()?;
+	let output_index = QueryIter::new(load_cell_type_hash, Source::Output)
+		.position(|type_hash| type_hash == Some(script_hash))
+		.ok_or(Error::ItemMissing)?;
+
+	// Hash the first input of the transaction and the output index to create the Type ID.
+	let first_input = load_input(0, Source::Input)?;
+	let mut type_id = [0u8; TYPE_ID_LEN];
+	let mut blake2b = Blake2bBuilder::new(TYPE_ID_LEN).personal(CKB_HASH_PERSONALIZATION).build();
+	blake2b.update(first_input.as_slice());
+	blake2b.update(&(output_index as u64).to_le_bytes());
+	blake2b.finalize(&mut type_id);
+
+	// Verify that the Type ID in the args matches.
+	if args[..] != type_id[..]
+	{
+		return Err(Error::InvalidTypeId);
+	}
+
+	Ok(())
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+
+	// Verify that the args contain exactly one Type ID.
+	if args.len() != TYPE_ID_LEN
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	// Determine the mode and validate as needed.
+	match determine_mode()
+	{
+		Ok(Mode::Burn) => return Ok(()),
+		Ok(Mode::Create) => validate_create(&args)?,
+		Ok(Mode::Transfer) => return Ok(()), // The unchanged args carry the Type ID forward.
+		Err(e) => return Err(e),
+	}
+
+	Ok(())
+}
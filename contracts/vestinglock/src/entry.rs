@@ -0,0 +1,175 @@
+// Import from core instead of from std since we are in no-std mode.
+use core::result::Result;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, prelude::*};
+use ckb_std::high_level::{load_cell_capacity, load_cell_data, load_cell_lock_hash, load_cell_type_hash, load_header_epoch_number, load_input_since, load_script, load_script_hash, QueryIter};
+
+// Import our local error codes.
+use crate::error::Error;
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const ARGS_LEN: usize = LOCK_HASH_LEN + 8 + 8 + 8 + 16; // Beneficiary lock hash, start epoch, cliff, duration, total amount.
+const SUDT_DATA_LEN: usize = 16; // SUDT uses a u128, which is 16 bytes.
+const SINCE_FLAGS_MASK: u64 = 0xFF00_0000_0000_0000; // The flags are the highest byte of the since value.
+const SINCE_ABSOLUTE_EPOCH_FLAGS: u64 = 0x2000_0000_0000_0000; // Absolute flag with the epoch metric flag.
+const SINCE_EPOCH_NUMBER_MASK: u64 = 0x0000_0000_00FF_FFFF; // The epoch number is the lowest 24 bits of the since value.
+
+// The vesting schedule which is specified in the args.
+struct Schedule
+{
+	beneficiary_lock_hash: [u8; LOCK_HASH_LEN],
+	start_epoch: u64,
+	cliff: u64,
+	duration: u64,
+	total_amount: u128,
+}
+
+/// Parse the vesting schedule from the script args.
+fn parse_schedule(args: &Bytes) -> Result<Schedule, Error>
+{
+	// Verify that the correct length of the arguments was given.
+	if args.len() != ARGS_LEN
+	{
+		return Err(Error::ArgsLen);
+	}
+
+	let mut beneficiary_lock_hash = [0u8; LOCK_HASH_LEN];
+	beneficiary_lock_hash.copy_from_slice(&args[0..32]);
+
+	let mut buffer = [0u8; 8];
+	buffer.copy_from_slice(&args[32..40]);
+	let start_epoch = u64::from_le_bytes(buffer);
+
+	buffer.copy_from_slice(&args[40..48]);
+	let cliff = u64::from_le_bytes(buffer);
+
+	buffer.copy_from_slice(&args[48..56]);
+	let duration = u64::from_le_bytes(buffer);
+
+	let mut buffer = [0u8; 16];
+	buffer.copy_from_slice(&args[56..72]);
+	let total_amount = u128::from_le_bytes(buffer);
+
+	Ok(Schedule { beneficiary_lock_hash, start_epoch, cliff, duration, total_amount })
+}
+
+/// Determine the current epoch number from the input since and the header deps.
+/// Both are guaranteed by the chain to be no later than the epoch the transaction is committed in.
+fn determine_current_epoch() -> Result<u64, Error>
+{
+	let mut current_epoch = None;
+
+	// An absolute epoch since on the vesting cell means the transaction cannot be committed before that epoch.
+	let since = load_input_since(0, Source::GroupInput)?;
+	if (since & SINCE_FLAGS_MASK) == SINCE_ABSOLUTE_EPOCH_FLAGS
+	{
+		current_epoch = Some(since & SINCE_EPOCH_NUMBER_MASK);
+	}
+
+	// A header dep means the transaction cannot be committed before the epoch of that block.
+	for epoch in QueryIter::new(load_header_epoch_number, Source::HeaderDep)
+	{
+		current_epoch = match current_epoch
+		{
+			Some(current_epoch) => Some(current_epoch.max(epoch)),
+			None => Some(epoch),
+		};
+	}
+
+	current_epoch.ok_or(Error::MissingEpoch)
+}
+
+/// Calculate the amount which has vested by the specified epoch.
+fn calculate_vested_amount(schedule: &Schedule, epoch: u64) -> Result<u128, Error>
+{
+	// Nothing is vested until the cliff has passed.
+	if epoch < schedule.start_epoch.saturating_add(schedule.cliff)
+	{
+		return Ok(0);
+	}
+
+	// Everything is vested once the duration has passed.
+	let elapsed = epoch - schedule.start_epoch;
+	if elapsed >= schedule.duration
+	{
+		return Ok(schedule.total_amount);
+	}
+
+	// Otherwise the amount vests linearly over the duration.
+	let vested_amount = schedule.total_amount.checked_mul(elapsed as u128).ok_or(Error::AmountOverflow)? / schedule.duration as u128;
+
+	Ok(vested_amount)
+}
+
+/// Load the vesting amount of a cell. This is the SUDT amount if the cell has a type script, otherwise it is the capacity.
+fn load_amount(index: usize, source: Source) -> Result<u128, Error>
+{
+	if load_cell_type_hash(index, source)?.is_none()
+	{
+		return Ok(load_cell_capacity(index, source)? as u128);
+	}
+
+	// Check that the length of the data is >= 16 bytes, the size of a u128.
+	let data = load_cell_data(index, source)?;
+	if data.len() < SUDT_DATA_LEN
+	{
+		return Err(Error::Encoding);
+	}
+
+	// Convert the binary data in the cell to a u128 value.
+	let mut buffer = [0u8; SUDT_DATA_LEN];
+	buffer.copy_from_slice(&data[0..SUDT_DATA_LEN]);
+
+	Ok(u128::from_le_bytes(buffer))
+}
+
+// Main entry point.
+pub fn main() -> Result<(), Error>
+{
+	// Load arguments from the current script.
+	let script = load_script()?;
+	let args: Bytes = script.args().unpack();
+	let schedule = parse_schedule(&args)?;
+
+	// Only one vesting cell can be unlocked per transaction.
+	if QueryIter::new(load_cell_capacity, Source::GroupInput).count() != 1
+	{
+		return Err(Error::InvalidTransactionStructure);
+	}
+
+	// The beneficiary must be present in the inputs.
+	if !QueryIter::new(load_cell_lock_hash, Source::Input).any(|lock_hash|lock_hash==schedule.beneficiary_lock_hash)
+	{
+		return Err(Error::Unauthorized);
+	}
+
+	// Determine the amount which must remain locked.
+	let current_epoch = determine_current_epoch()?;
+	let vested_amount = calculate_vested_amount(&schedule, current_epoch)?;
+	let locked_amount = schedule.total_amount - vested_amount;
+
+	// Count the amount returned to this lock in output cells with the same type as the vesting cell.
+	let script_hash = load_script_hash()?;
+	let input_type_hash = load_cell_type_hash(0, Source::GroupInput)?;
+	let mut remaining_amount = 0u128;
+	for (i, lock_hash) in QueryIter::new(load_cell_lock_hash, Source::Output).enumerate()
+	{
+		if lock_hash == script_hash && load_cell_type_hash(i, Source::Output)? == input_type_hash
+		{
+			remaining_amount = remaining_amount.checked_add(load_amount(i, Source::Output)?).ok_or(Error::AmountOverflow)?;
+		}
+	}
+
+	// The amount which has not vested yet must remain under the same lock, unless the cell holds less than that.
+	let input_amount = load_amount(0, Source::GroupInput)?;
+	if remaining_amount < locked_amount.min(input_amount)
+	{
+		return Err(Error::InsufficientRemainder);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,46 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::error::Error;
+
+/// The parts of `capsule.toml` that are used for deployment.
+#[derive(Debug, Deserialize)]
+pub struct CapsuleConfig
+{
+	/// The path of the deployment config file.
+	#[serde(default = "default_deployment")]
+	pub deployment: String,
+	/// The contracts in the workspace.
+	#[serde(default)]
+	pub contracts: Vec<Contract>,
+}
+
+/// A contract entry in `capsule.toml`.
+#[derive(Debug, Deserialize)]
+pub struct Contract
+{
+	pub name: String,
+	pub template_type: String,
+}
+
+fn default_deployment() -> String
+{
+	"deployment.toml".to_string()
+}
+
+impl CapsuleConfig
+{
+	/// Load `capsule.toml` from the specified path.
+	pub fn load(path: &Path) -> Result<Self, Error>
+	{
+		Ok(toml::from_str(&fs::read_to_string(path)?)?)
+	}
+
+	/// The names of all contracts, in the order they are listed.
+	pub fn contract_names(&self) -> Vec<String>
+	{
+		self.contracts.iter().map(|contract| contract.name.clone()).collect()
+	}
+}
@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use ckb_jsonrpc_types as json;
+use ckb_types::core::{Capacity, TransactionBuilder, TransactionView};
+use ckb_types::packed::{CellDep, CellOutput, OutPoint, Script};
+use ckb_types::prelude::*;
+use ckb_types::{bytes::Bytes, H256};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// A live cell on the chain.
+#[derive(Debug, Clone)]
+pub struct LiveCell
+{
+	pub out_point: OutPoint,
+	pub output: CellOutput,
+	pub data: Bytes,
+}
+
+/// The chain operations needed to plan and commit a deployment.
+pub trait Chain
+{
+	/// Find the live cells that use the specified lock.
+	fn live_cells_by_lock(&self, lock: &Script) -> Vec<LiveCell>;
+
+	/// Find a live cell by out point.
+	fn live_cell(&self, out_point: &OutPoint) -> Option<LiveCell>;
+
+	/// Commit a transaction, consuming its inputs and creating its outputs. Returns the transaction hash.
+	fn send_transaction(&mut self, tx: &TransactionView) -> Result<H256, Error>;
+}
+
+/// An offline chain which only tracks the live cell set. Scripts are not executed.
+#[derive(Debug, Clone, Default)]
+pub struct MockChain
+{
+	cells: BTreeMap<(H256, u32), LiveCell>,
+	issued: u32,
+}
+
+/// The JSON format of a MockChain snapshot. This can also be exported from a local devnet.
+#[derive(Serialize, Deserialize)]
+struct Snapshot
+{
+	#[serde(default)]
+	issued: u32,
+	cells: Vec<JsonLiveCell>,
+}
+
+/// A live cell in JSON. The data can also be named `output_data`, which is the name used by the `get_cells` RPC of the indexer.
+#[derive(Serialize, Deserialize)]
+pub struct JsonLiveCell
+{
+	pub out_point: json::OutPoint,
+	pub output: json::CellOutput,
+	#[serde(alias = "output_data")]
+	pub data: json::JsonBytes,
+}
+
+/// The formats accepted for a file of live cells.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LiveCellsFile
+{
+	Cells(Vec<JsonLiveCell>),
+	Snapshot { cells: Vec<JsonLiveCell> },
+	Indexer { objects: Vec<JsonLiveCell> },
+}
+
+impl From<JsonLiveCell> for LiveCell
+{
+	fn from(cell: JsonLiveCell) -> Self
+	{
+		Self { out_point: cell.out_point.into(), output: cell.output.into(), data: cell.data.into_bytes() }
+	}
+}
+
+impl From<LiveCell> for JsonLiveCell
+{
+	fn from(cell: LiveCell) -> Self
+	{
+		Self { out_point: cell.out_point.into(), output: cell.output.into(), data: json::JsonBytes::from_bytes(cell.data) }
+	}
+}
+
+/// Load live cells from a JSON file containing an array of cells, a MockChain snapshot, or the result of the `get_cells` RPC.
+pub fn load_live_cells(path: &Path) -> Result<Vec<LiveCell>, Error>
+{
+	let cells = match serde_json::from_str(&fs::read_to_string(path)?)?
+	{
+		LiveCellsFile::Cells(cells) | LiveCellsFile::Snapshot { cells } | LiveCellsFile::Indexer { objects: cells } => cells,
+	};
+
+	Ok(cells.into_iter().map(LiveCell::from).collect())
+}
+
+impl MockChain
+{
+	/// Create a new cell with the specified lock and capacity out of thin air, similar to a genesis cell.
+	pub fn issue_cell(&mut self, lock: Script, capacity: u64) -> OutPoint
+	{
+		// Each issued cell is created by a unique transaction without inputs.
+		let marker = OutPoint::new(Default::default(), self.issued);
+		self.issued += 1;
+		let output = CellOutput::new_builder().capacity(capacity.pack()).lock(lock).build();
+		let tx = TransactionBuilder::default()
+			.cell_dep(CellDep::new_builder().out_point(marker).build())
+			.output(output)
+			.output_data(Bytes::new().pack())
+			.build();
+		self.insert_outputs(&tx);
+
+		OutPoint::new(tx.hash(), 0)
+	}
+
+	/// The number of live cells.
+	pub fn live_cell_count(&self) -> usize
+	{
+		self.cells.len()
+	}
+
+	/// Load a snapshot of the live cell set from a JSON file.
+	pub fn load_snapshot(path: &Path) -> Result<Self, Error>
+	{
+		let snapshot: Snapshot = serde_json::from_str(&fs::read_to_string(path)?)?;
+		let mut chain = Self { cells: BTreeMap::new(), issued: snapshot.issued };
+		for cell in snapshot.cells
+		{
+			let live_cell = LiveCell::from(cell);
+			chain.cells.insert(key(&live_cell.out_point), live_cell);
+		}
+
+		Ok(chain)
+	}
+
+	/// Save a snapshot of the live cell set to a JSON file.
+	pub fn save_snapshot(&self, path: &Path) -> Result<(), Error>
+	{
+		let cells = self.cells.values().cloned().map(JsonLiveCell::from).collect();
+		let snapshot = Snapshot { issued: self.issued, cells };
+		fs::write(path, serde_json::to_string_pretty(&snapshot)?)?;
+
+		Ok(())
+	}
+
+	fn insert_outputs(&mut self, tx: &TransactionView)
+	{
+		for (i, (output, data)) in tx.outputs_with_data_iter().enumerate()
+		{
+			let out_point = OutPoint::new(tx.hash(), i as u32);
+			self.cells.insert(key(&out_point), LiveCell { out_point, output, data });
+		}
+	}
+}
+
+impl Chain for MockChain
+{
+	fn live_cells_by_lock(&self, lock: &Script) -> Vec<LiveCell>
+	{
+		self.cells.values()
+			.filter(|cell| cell.output.lock().as_slice() == lock.as_slice())
+			.cloned()
+			.collect()
+	}
+
+	fn live_cell(&self, out_point: &OutPoint) -> Option<LiveCell>
+	{
+		self.cells.get(&key(out_point)).cloned()
+	}
+
+	fn send_transaction(&mut self, tx: &TransactionView) -> Result<H256, Error>
+	{
+		// Every input must be live.
+		let mut input_capacity = Capacity::zero();
+		for out_point in tx.input_pts_iter()
+		{
+			let cell = self.live_cell(&out_point).ok_or_else(|| Error::DeadCell(format!("{}", out_point)))?;
+			input_capacity = input_capacity.safe_add(Capacity::shannons(cell.output.capacity().unpack())).expect("capacity overflow");
+		}
+
+		// The outputs cannot hold more capacity than the inputs.
+		let output_capacity = tx.outputs_capacity().expect("capacity overflow");
+		if output_capacity > input_capacity
+		{
+			return Err(Error::InsufficientCapacity(output_capacity.as_u64() - input_capacity.as_u64()));
+		}
+
+		// Consume the inputs and create the outputs.
+		for out_point in tx.input_pts_iter()
+		{
+			self.cells.remove(&key(&out_point));
+		}
+		self.insert_outputs(tx);
+
+		Ok(tx.hash().unpack())
+	}
+}
+
+fn key(out_point: &OutPoint) -> (H256, u32)
+{
+	(out_point.tx_hash().unpack(), out_point.index().unpack())
+}
@@ -0,0 +1,166 @@
+use std::fs;
+use std::path::Path;
+
+use ckb_types::core::ScriptHashType;
+use ckb_types::packed::Script;
+use ckb_types::prelude::*;
+use ckb_types::{bytes::Bytes, H256};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// The deployment config, in the same format as the `deployment.toml` used by Capsule.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeploymentConfig
+{
+	#[serde(default)]
+	pub cells: Vec<CellConfig>,
+	#[serde(default)]
+	pub dep_groups: Vec<DepGroupConfig>,
+	pub lock: Option<LockConfig>,
+}
+
+/// A cell to deploy, or a reference to an existing on-chain cell.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CellConfig
+{
+	pub name: String,
+	#[serde(default)]
+	pub enable_type_id: bool,
+	pub location: CellLocation,
+}
+
+/// The location of the data for a cell.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CellLocation
+{
+	File { file: String },
+	OnChain { tx_hash: H256, index: u32 },
+}
+
+/// A dep group cell which references other cells by name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepGroupConfig
+{
+	pub name: String,
+	pub cells: Vec<String>,
+}
+
+/// The lock used by the deployed cells.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockConfig
+{
+	pub code_hash: H256,
+	pub args: String,
+	pub hash_type: String,
+}
+
+impl DeploymentConfig
+{
+	/// Load a deployment config from the specified path.
+	pub fn load(path: &Path) -> Result<Self, Error>
+	{
+		Ok(toml::from_str(&fs::read_to_string(path)?)?)
+	}
+
+	/// Create a deployment config which deploys every contract binary from the specified build directory.
+	pub fn from_contracts(contract_names: &[String], build_dir: &str, lock: Option<LockConfig>) -> Self
+	{
+		let cells = contract_names.iter()
+			.map(|name| CellConfig { name: name.clone(), enable_type_id: false, location: CellLocation::File { file: format!("{}/{}", build_dir, name) } })
+			.collect();
+
+		Self { cells, dep_groups: Vec::new(), lock }
+	}
+
+	/// Render the deployment config in the same layout as the Capsule template.
+	pub fn to_toml(&self) -> String
+	{
+		let mut output = String::new();
+
+		for cell in &self.cells
+		{
+			output.push_str("[[cells]]\n");
+			output.push_str(&format!("name = \"{}\"\n", cell.name));
+			output.push_str(&format!("enable_type_id = {}\n", cell.enable_type_id));
+			match &cell.location
+			{
+				CellLocation::File { file } => output.push_str(&format!("location = {{ file = \"{}\" }}\n", file)),
+				CellLocation::OnChain { tx_hash, index } => output.push_str(&format!("location = {{ tx_hash = \"{:#x}\", index = {} }}\n", tx_hash, index)),
+			}
+			output.push('\n');
+		}
+
+		for dep_group in &self.dep_groups
+		{
+			output.push_str("[[dep_groups]]\n");
+			output.push_str(&format!("name = \"{}\"\n", dep_group.name));
+			output.push_str("cells = [\n");
+			for cell in &dep_group.cells
+			{
+				output.push_str(&format!("  \"{}\",\n", cell));
+			}
+			output.push_str("]\n\n");
+		}
+
+		if let Some(lock) = &self.lock
+		{
+			output.push_str("# Replace with your own lock if you want to unlock deployed cells.\n");
+			output.push_str("[lock]\n");
+			output.push_str(&format!("code_hash = \"{:#x}\"\n", lock.code_hash));
+			output.push_str(&format!("args = \"{}\"\n", lock.args));
+			output.push_str(&format!("hash_type = \"{}\"\n", lock.hash_type));
+		}
+
+		output
+	}
+}
+
+impl LockConfig
+{
+	/// Convert the lock config to a Script.
+	pub fn to_script(&self) -> Result<Script, Error>
+	{
+		let hash_type = match self.hash_type.as_str()
+		{
+			"data" => ScriptHashType::Data,
+			"type" => ScriptHashType::Type,
+			"data1" => ScriptHashType::Data1,
+			other => return Err(Error::InvalidConfig(format!("unknown hash_type: {}", other))),
+		};
+		let args = decode_hex(&self.args)?;
+
+		Ok(Script::new_builder()
+			.code_hash(self.code_hash.pack())
+			.hash_type(hash_type.into())
+			.args(Bytes::from(args).pack())
+			.build())
+	}
+}
+
+/// Decode a hex string with an optional 0x prefix.
+pub fn decode_hex(value: &str) -> Result<Vec<u8>, Error>
+{
+	let value = value.trim_start_matches("0x");
+	if value.len() % 2 != 0
+	{
+		return Err(Error::InvalidConfig(format!("invalid hex: {}", value)));
+	}
+
+	(0..value.len()).step_by(2)
+		.map(|i| u8::from_str_radix(&value[i..i + 2], 16).map_err(|_| Error::InvalidConfig(format!("invalid hex: {}", value))))
+		.collect()
+}
+
+/// Encode bytes as a hex string with a 0x prefix.
+pub fn encode_hex(value: &[u8]) -> String
+{
+	let mut output = String::from("0x");
+	for byte in value
+	{
+		output.push_str(&format!("{:02x}", byte));
+	}
+
+	output
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+use std::io;
+
+/// Error
+#[derive(Debug)]
+pub enum Error
+{
+	Io(io::Error),
+	Toml(toml::de::Error),
+	Json(serde_json::Error),
+	InvalidConfig(String),
+	UnknownCell(String),
+	DeadCell(String),
+	InsufficientCapacity(u64),
+	TransactionTooLarge(String),
+}
+
+impl fmt::Display for Error
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			Self::Io(err) => write!(f, "I/O error: {}", err),
+			Self::Toml(err) => write!(f, "TOML error: {}", err),
+			Self::Json(err) => write!(f, "JSON error: {}", err),
+			Self::InvalidConfig(message) => write!(f, "invalid config: {}", message),
+			Self::UnknownCell(name) => write!(f, "unknown cell: {}", name),
+			Self::DeadCell(out_point) => write!(f, "input cell is not live: {}", out_point),
+			Self::InsufficientCapacity(required) => write!(f, "insufficient capacity: {} more Shannons are required", required),
+			Self::TransactionTooLarge(name) => write!(f, "transaction too large: {} does not fit in a single transaction", name),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error
+{
+	fn from(err: io::Error) -> Self
+	{
+		Self::Io(err)
+	}
+}
+
+impl From<toml::de::Error> for Error
+{
+	fn from(err: toml::de::Error) -> Self
+	{
+		Self::Toml(err)
+	}
+}
+
+impl From<serde_json::Error> for Error
+{
+	fn from(err: serde_json::Error) -> Self
+	{
+		Self::Json(err)
+	}
+}
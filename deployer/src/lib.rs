@@ -0,0 +1,16 @@
+//! Plan and record the deployment of the contracts in `capsule.toml`.
+//!
+//! The planner reads the deployment config, hashes each contract binary, and commits the
+//! code cells, Type IDs, and dep groups to a chain. A migration file records where each cell
+//! was deployed. The MockChain allows this to work offline, optionally starting from a
+//! snapshot of the live cells of a local devnet.
+
+pub mod capsule;
+pub mod chain;
+pub mod deployment;
+pub mod error;
+pub mod migration;
+pub mod plan;
+pub mod transaction;
+
+pub use error::Error;
@@ -0,0 +1,257 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use ckb_jsonrpc_types as json;
+use ckb_types::core::{DepType, FeeRate};
+use ckb_types::packed::{CellDep, OutPoint, Script};
+use ckb_types::prelude::*;
+use ckb_types::{bytes::Bytes, H256};
+use deployer::capsule::CapsuleConfig;
+use deployer::chain::{self, MockChain};
+use deployer::deployment::{CellLocation, DeploymentConfig, LockConfig};
+use deployer::migration::Migration;
+use deployer::plan::{self, CellAction};
+use deployer::transaction::{DeployOutput, TransactionGenerator, DEFAULT_FEE_RATE};
+use deployer::Error;
+
+// Constants
+const USAGE: &str = "Usage:
+  deployer init [--capsule capsule.toml] [--build-dir build/release] [--output deployment.toml]
+  deployer plan [--deployment deployment.toml] [--migrations migrations] [--snapshot snapshot.json]
+  deployer deploy [--deployment deployment.toml] [--migrations migrations] [--snapshot snapshot.json] [--fee-rate 1000] [--cell-dep <tx_hash>:<index>[:code]]...
+  deployer generate --cells cells.json [--deployment deployment.toml] [--binary <path>]... [--fee-rate 1000] [--cell-dep <tx_hash>:<index>[:code]]... [--output txs.json]";
+const DEFAULT_LOCK_CODE_HASH: &str = "0x9bd7e06f3ecf4be0f2fcd2188b23f1b9fcc88e5d4b65a8637b17723bbda3cce8";
+const DEFAULT_LOCK_ARGS: &str = "0x0000000000000000000000000000000000000000";
+const MOCK_CHAIN_CAPACITY: u64 = 10_000_000 * 100_000_000;
+
+/// Options parsed from the command line, with defaults for anything not specified.
+struct Options
+{
+	capsule: PathBuf,
+	build_dir: String,
+	output: Option<PathBuf>,
+	deployment: PathBuf,
+	migrations: PathBuf,
+	snapshot: Option<PathBuf>,
+	cells: Option<PathBuf>,
+	binaries: Vec<PathBuf>,
+	fee_rate: u64,
+	cell_deps: Vec<CellDep>,
+}
+
+impl Options
+{
+	fn parse(args: &[String]) -> Result<Self, Error>
+	{
+		let mut options = Options
+		{
+			capsule: PathBuf::from("capsule.toml"),
+			build_dir: String::from("build/release"),
+			output: None,
+			deployment: PathBuf::from("deployment.toml"),
+			migrations: PathBuf::from("migrations"),
+			snapshot: None,
+			cells: None,
+			binaries: Vec::new(),
+			fee_rate: DEFAULT_FEE_RATE,
+			cell_deps: Vec::new(),
+		};
+
+		let mut args = args.iter();
+		while let Some(flag) = args.next()
+		{
+			let value = args.next().ok_or_else(|| Error::InvalidConfig(format!("missing value for {}", flag)))?;
+			match flag.as_str()
+			{
+				"--capsule" => options.capsule = PathBuf::from(value),
+				"--build-dir" => options.build_dir = value.clone(),
+				"--output" => options.output = Some(PathBuf::from(value)),
+				"--deployment" => options.deployment = PathBuf::from(value),
+				"--migrations" => options.migrations = PathBuf::from(value),
+				"--snapshot" => options.snapshot = Some(PathBuf::from(value)),
+				"--cells" => options.cells = Some(PathBuf::from(value)),
+				"--binary" => options.binaries.push(PathBuf::from(value)),
+				"--fee-rate" => options.fee_rate = value.parse().map_err(|_| Error::InvalidConfig(format!("invalid fee rate: {}", value)))?,
+				"--cell-dep" => options.cell_deps.push(parse_cell_dep(value)?),
+				other => return Err(Error::InvalidConfig(format!("unknown option: {}", other))),
+			}
+		}
+
+		Ok(options)
+	}
+}
+
+/// Parse a cell dep in the format `<tx_hash>:<index>[:code]`. The dep type defaults to a dep group.
+fn parse_cell_dep(value: &str) -> Result<CellDep, Error>
+{
+	let invalid = || Error::InvalidConfig(format!("invalid cell dep: {}", value));
+	let parts: Vec<&str> = value.split(':').collect();
+	let tx_hash: H256 = parts[0].trim_start_matches("0x").parse().map_err(|_| invalid())?;
+	let index: u32 = parts.get(1).and_then(|index| index.parse().ok()).ok_or_else(invalid)?;
+	let dep_type = match parts.get(2).copied()
+	{
+		None | Some("dep_group") => DepType::DepGroup,
+		Some("code") => DepType::Code,
+		Some(_) => return Err(invalid()),
+	};
+
+	Ok(CellDep::new_builder().out_point(OutPoint::new(tx_hash.pack(), index)).dep_type(dep_type.into()).build())
+}
+
+/// Create a transaction generator for the lock using the fee rate and cell deps from the options.
+fn generator(options: &Options, lock: Script) -> TransactionGenerator
+{
+	TransactionGenerator { cell_deps: options.cell_deps.clone(), fee_rate: FeeRate::from_u64(options.fee_rate), ..TransactionGenerator::new(lock) }
+}
+
+/// The directory that file locations in the deployment config are relative to.
+fn base_dir(options: &Options) -> &Path
+{
+	options.deployment.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or_else(|| Path::new("."))
+}
+
+/// Create a deployment config for every contract in capsule.toml.
+fn init(options: &Options) -> Result<(), Error>
+{
+	let capsule = CapsuleConfig::load(&options.capsule)?;
+	let lock = LockConfig
+	{
+		code_hash: DEFAULT_LOCK_CODE_HASH.trim_start_matches("0x").parse().map_err(|_| Error::InvalidConfig(String::from("invalid lock code hash")))?,
+		args: String::from(DEFAULT_LOCK_ARGS),
+		hash_type: String::from("type"),
+	};
+	let config = DeploymentConfig::from_contracts(&capsule.contract_names(), &options.build_dir, Some(lock));
+	let output = options.output.clone().unwrap_or_else(|| PathBuf::from("deployment.toml"));
+	fs::write(&output, config.to_toml())?;
+
+	println!("Wrote {} cells to {}.", config.cells.len(), output.display());
+
+	Ok(())
+}
+
+/// Load the chain from a snapshot, or create a mock chain funded with capacity for the lock.
+fn load_chain(options: &Options, lock: &Script) -> Result<MockChain, Error>
+{
+	if let Some(snapshot) = options.snapshot.as_ref().filter(|snapshot| snapshot.exists())
+	{
+		return MockChain::load_snapshot(snapshot);
+	}
+
+	let mut chain = MockChain::default();
+	chain.issue_cell(lock.clone(), MOCK_CHAIN_CAPACITY);
+
+	Ok(chain)
+}
+
+/// Plan the deployment and print it. If commit is set the plan is executed and a migration is written.
+fn deploy(options: &Options, commit: bool) -> Result<(), Error>
+{
+	let config = DeploymentConfig::load(&options.deployment)?;
+	let lock = config.lock.as_ref().ok_or_else(|| Error::InvalidConfig(String::from("deployment config has no [lock]")))?.to_script()?;
+	let previous = Migration::load_latest(&options.migrations)?;
+	let mut chain = load_chain(options, &lock)?;
+
+	let plan = plan::plan(&config, base_dir(options), &chain, previous.as_ref())?;
+	for cell in &plan.cells
+	{
+		let action = match &cell.action
+		{
+			CellAction::Deploy => "deploy",
+			CellAction::Reuse(_) => "reuse",
+			CellAction::Upgrade(_) => "upgrade",
+			CellAction::Reference(_) => "reference",
+		};
+		println!("{:<10} {:<20} {:#x} ({} bytes)", action, cell.name, cell.data_hash, cell.data.len());
+	}
+
+	if !commit
+	{
+		return Ok(());
+	}
+
+	match plan::execute(&plan, &mut chain, &generator(options, lock), previous.as_ref())?
+	{
+		Some(migration) => println!("Wrote migration to {}.", migration.save(&options.migrations)?.display()),
+		None => println!("Nothing to deploy."),
+	}
+	if let Some(snapshot) = &options.snapshot
+	{
+		chain.save_snapshot(snapshot)?;
+	}
+
+	Ok(())
+}
+
+/// Generate unsigned transactions which deploy the binaries using the live cells from a JSON file for capacity.
+/// Without any binaries, every file cell in the deployment config is deployed.
+fn generate(options: &Options) -> Result<(), Error>
+{
+	let config = DeploymentConfig::load(&options.deployment)?;
+	let lock = config.lock.as_ref().ok_or_else(|| Error::InvalidConfig(String::from("deployment config has no [lock]")))?.to_script()?;
+	let generator = generator(options, lock);
+	let cells = options.cells.as_ref().ok_or_else(|| Error::InvalidConfig(String::from("--cells is required")))?;
+	let live_cells = chain::load_live_cells(cells)?;
+
+	let mut outputs: Vec<DeployOutput> = Vec::new();
+	for path in &options.binaries
+	{
+		let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+		outputs.push(generator.output(&name, None, Bytes::from(fs::read(path)?)));
+	}
+	if options.binaries.is_empty()
+	{
+		for cell in &config.cells
+		{
+			if let CellLocation::File { file } = &cell.location
+			{
+				let data = Bytes::from(fs::read(base_dir(options).join(file))?);
+				outputs.push(match cell.enable_type_id
+				{
+					true => generator.type_id_output(&cell.name, data),
+					false => generator.output(&cell.name, None, data),
+				});
+			}
+		}
+	}
+
+	let deployment = generator.generate(outputs, generator.funding_cells(live_cells))?;
+	let transactions: Vec<json::TransactionView> = deployment.transactions.iter().cloned().map(Into::into).collect();
+	let output = serde_json::to_string_pretty(&transactions)?;
+	match &options.output
+	{
+		Some(path) => fs::write(path, output)?,
+		None => println!("{}", output),
+	}
+
+	eprintln!("Generated {} transactions for {} cells.", deployment.transactions.len(), deployment.out_points.len());
+
+	Ok(())
+}
+
+fn main()
+{
+	let args: Vec<String> = env::args().skip(1).collect();
+	let result = Options::parse(args.get(1..).unwrap_or_default()).and_then(|options|
+	{
+		match args.first().map(String::as_str)
+		{
+			Some("init") => init(&options),
+			Some("plan") => deploy(&options, false),
+			Some("deploy") => deploy(&options, true),
+			Some("generate") => generate(&options),
+			_ =>
+			{
+				eprintln!("{}", USAGE);
+				process::exit(2);
+			},
+		}
+	});
+
+	if let Err(error) = result
+	{
+		eprintln!("Error: {}", error);
+		process::exit(1);
+	}
+}
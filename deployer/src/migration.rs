@@ -0,0 +1,112 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ckb_types::H256;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// A record of where each cell was deployed, in the same format as the migrations created by `ckb-cli deploy`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Migration
+{
+	pub cell_recipes: Vec<CellRecipe>,
+	pub dep_group_recipes: Vec<DepGroupRecipe>,
+}
+
+/// The location and code hash of a deployed cell.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CellRecipe
+{
+	pub name: String,
+	pub tx_hash: H256,
+	pub index: u32,
+	pub occupied_capacity: u64,
+	pub data_hash: H256,
+	/// The hash of the Type ID script, which is used as the code hash with a hash type of "type".
+	pub type_id: Option<H256>,
+}
+
+/// The location of a deployed dep group cell.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DepGroupRecipe
+{
+	pub name: String,
+	pub tx_hash: H256,
+	pub index: u32,
+	pub occupied_capacity: u64,
+	pub data_hash: H256,
+}
+
+impl Migration
+{
+	/// Find the recipe for the cell with the specified name.
+	pub fn cell_recipe(&self, name: &str) -> Option<&CellRecipe>
+	{
+		self.cell_recipes.iter().find(|recipe| recipe.name == name)
+	}
+
+	/// Find the recipe for the dep group with the specified name.
+	pub fn dep_group_recipe(&self, name: &str) -> Option<&DepGroupRecipe>
+	{
+		self.dep_group_recipes.iter().find(|recipe| recipe.name == name)
+	}
+
+	/// Load a migration from the specified JSON file.
+	pub fn load(path: &Path) -> Result<Self, Error>
+	{
+		Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+	}
+
+	/// Load the most recent migration from the migrations directory, if there is one.
+	pub fn load_latest(dir: &Path) -> Result<Option<Self>, Error>
+	{
+		if !dir.exists()
+		{
+			return Ok(None);
+		}
+
+		// Migrations are named by their UTC timestamp, so the last file by name is the most recent.
+		let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+			.filter_map(|entry| entry.ok().map(|entry| entry.path()))
+			.filter(|path| path.extension().is_some_and(|extension| extension == "json"))
+			.collect();
+		paths.sort();
+
+		match paths.last()
+		{
+			Some(path) => Ok(Some(Self::load(path)?)),
+			None => Ok(None),
+		}
+	}
+
+	/// Save the migration to the migrations directory using the current UTC time as the file name.
+	pub fn save(&self, dir: &Path) -> Result<PathBuf, Error>
+	{
+		let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or(0);
+		let path = dir.join(format!("{}.json", format_timestamp(seconds)));
+		fs::create_dir_all(dir)?;
+		fs::write(&path, serde_json::to_string_pretty(self)?)?;
+
+		Ok(path)
+	}
+}
+
+/// Format a Unix timestamp as `YYYY-MM-DD-HHMMSS` in UTC, the same file name format used by `ckb-cli deploy`.
+pub fn format_timestamp(seconds: u64) -> String
+{
+	// Convert days since the epoch to a civil date. (Howard Hinnant's days_from_civil algorithm in reverse.)
+	let days = (seconds / 86_400) as i64 + 719_468;
+	let era = days.div_euclid(146_097);
+	let day_of_era = days.rem_euclid(146_097);
+	let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let month_index = (5 * day_of_year + 2) / 153;
+	let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+	let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+	let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+	let time = seconds % 86_400;
+	format!("{:04}-{:02}-{:02}-{:02}{:02}{:02}", year, month, day, time / 3_600, time % 3_600 / 60, time % 60)
+}
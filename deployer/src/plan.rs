@@ -0,0 +1,220 @@
+use std::fs;
+use std::path::Path;
+
+use ckb_types::packed::{CellOutput, OutPoint, OutPointVec};
+use ckb_types::prelude::*;
+use ckb_types::{bytes::Bytes, H256};
+
+use crate::chain::{Chain, LiveCell};
+use crate::deployment::{CellLocation, DeploymentConfig};
+use crate::error::Error;
+use crate::migration::{CellRecipe, DepGroupRecipe, Migration};
+use crate::transaction::{DeployOutput, TransactionGenerator, TYPE_ID_CODE_HASH};
+
+/// The planned action for a cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CellAction
+{
+	/// Deploy the cell for the first time, or again because the data changed and it does not use Type ID.
+	Deploy,
+	/// The previously deployed cell has the same data and is still live.
+	Reuse(CellRecipe),
+	/// Replace the previously deployed Type ID cell, keeping the same Type ID.
+	Upgrade(CellRecipe),
+	/// The cell already exists on-chain and is only referenced.
+	Reference(OutPoint),
+}
+
+/// The plan for a single cell.
+#[derive(Debug, Clone)]
+pub struct CellPlan
+{
+	pub name: String,
+	pub data: Bytes,
+	pub data_hash: H256,
+	pub enable_type_id: bool,
+	pub action: CellAction,
+}
+
+/// The plan for all cells and dep groups in a deployment config.
+#[derive(Debug, Clone)]
+pub struct DeploymentPlan
+{
+	pub cells: Vec<CellPlan>,
+	pub config: DeploymentConfig,
+}
+
+/// Plan the cells in the deployment config. File locations are relative to the base directory.
+/// The previous migration is used to reuse cells that have not changed and to upgrade Type ID cells.
+pub fn plan(config: &DeploymentConfig, base_dir: &Path, chain: &impl Chain, previous: Option<&Migration>) -> Result<DeploymentPlan, Error>
+{
+	let mut cells = Vec::new();
+
+	for cell in &config.cells
+	{
+		let (data, action) = match &cell.location
+		{
+			CellLocation::File { file } =>
+			{
+				let data = Bytes::from(fs::read(base_dir.join(file))?);
+				let data_hash: H256 = CellOutput::calc_data_hash(&data).unpack();
+
+				// Only a previous recipe that is still live on the chain can be reused or upgraded.
+				let recipe = previous
+					.and_then(|migration| migration.cell_recipe(&cell.name))
+					.filter(|recipe| chain.live_cell(&OutPoint::new(recipe.tx_hash.pack(), recipe.index)).is_some());
+				let action = match recipe
+				{
+					Some(recipe) if recipe.data_hash == data_hash => CellAction::Reuse(recipe.clone()),
+					Some(recipe) if cell.enable_type_id && recipe.type_id.is_some() => CellAction::Upgrade(recipe.clone()),
+					_ => CellAction::Deploy,
+				};
+
+				(data, action)
+			},
+			CellLocation::OnChain { tx_hash, index } =>
+			{
+				let out_point = OutPoint::new(tx_hash.pack(), *index);
+				let live_cell = chain.live_cell(&out_point).ok_or_else(|| Error::DeadCell(format!("{}", out_point)))?;
+
+				(live_cell.data, CellAction::Reference(out_point))
+			},
+		};
+
+		let data_hash = CellOutput::calc_data_hash(&data).unpack();
+		cells.push(CellPlan { name: cell.name.clone(), data, data_hash, enable_type_id: cell.enable_type_id, action });
+	}
+
+	Ok(DeploymentPlan { cells, config: config.clone() })
+}
+
+/// Commit a deployment plan to the chain using the generator to build and fund the transactions.
+/// Returns the new migration, or None if nothing needed to be deployed.
+pub fn execute(plan: &DeploymentPlan, chain: &mut impl Chain, generator: &TransactionGenerator, previous: Option<&Migration>) -> Result<Option<Migration>, Error>
+{
+	let mut changed = false;
+
+	// Deploy the new and upgraded code cells.
+	let mut outputs = Vec::new();
+	for cell in &plan.cells
+	{
+		let output = match &cell.action
+		{
+			CellAction::Deploy if cell.enable_type_id => generator.type_id_output(&cell.name, cell.data.clone()),
+			CellAction::Deploy => generator.output(&cell.name, None, cell.data.clone()),
+			CellAction::Upgrade(recipe) =>
+			{
+				// The previous cell is consumed and its type script is kept to preserve the Type ID.
+				let out_point = OutPoint::new(recipe.tx_hash.pack(), recipe.index);
+				let live_cell = chain.live_cell(&out_point).ok_or_else(|| Error::DeadCell(format!("{}", out_point)))?;
+				let output = generator.output(&cell.name, live_cell.output.type_().to_opt(), cell.data.clone());
+				DeployOutput { replaces: Some(live_cell), ..output }
+			},
+			CellAction::Reuse(_) | CellAction::Reference(_) => continue,
+		};
+		outputs.push(output);
+	}
+	let mut deployed = send(chain, generator, outputs)?.into_iter();
+	changed |= deployed.len() > 0;
+
+	// Create a recipe for every cell.
+	let mut cell_recipes = Vec::new();
+	for cell in &plan.cells
+	{
+		let recipe = match &cell.action
+		{
+			CellAction::Reuse(recipe) => recipe.clone(),
+			CellAction::Reference(out_point) =>
+			{
+				let live_cell = chain.live_cell(out_point).ok_or_else(|| Error::DeadCell(format!("{}", out_point)))?;
+				recipe_from_cell(&cell.name, &live_cell)
+			},
+			CellAction::Deploy | CellAction::Upgrade(_) => recipe_from_cell(&cell.name, &deployed.next().expect("deployed cell")),
+		};
+		cell_recipes.push(recipe);
+	}
+
+	// Dep groups are deployed after the code cells since they reference their out points.
+	let mut outputs = Vec::new();
+	let mut dep_group_recipes = Vec::new();
+	for dep_group in &plan.config.dep_groups
+	{
+		let mut out_points = Vec::new();
+		for name in &dep_group.cells
+		{
+			let recipe = cell_recipes.iter().find(|recipe| &recipe.name == name).ok_or_else(|| Error::UnknownCell(name.clone()))?;
+			out_points.push(OutPoint::new(recipe.tx_hash.pack(), recipe.index));
+		}
+		let data = OutPointVec::new_builder().set(out_points).build().as_bytes();
+		let data_hash: H256 = CellOutput::calc_data_hash(&data).unpack();
+
+		// Reuse the previous dep group if it is unchanged, otherwise replace it and reclaim its capacity.
+		let previous_recipe = previous.and_then(|migration| migration.dep_group_recipe(&dep_group.name));
+		let previous_cell = previous_recipe.and_then(|recipe| chain.live_cell(&OutPoint::new(recipe.tx_hash.pack(), recipe.index)));
+		if let (Some(recipe), Some(_)) = (previous_recipe, &previous_cell)
+		{
+			if recipe.data_hash == data_hash
+			{
+				dep_group_recipes.push(recipe.clone());
+				continue;
+			}
+		}
+		outputs.push(DeployOutput { replaces: previous_cell, ..generator.output(&dep_group.name, None, data) });
+	}
+	for (name, cell) in outputs.iter().map(|output| output.name.clone()).zip(send(chain, generator, outputs.clone())?)
+	{
+		dep_group_recipes.push(DepGroupRecipe
+		{
+			name,
+			tx_hash: cell.out_point.tx_hash().unpack(),
+			index: cell.out_point.index().unpack(),
+			occupied_capacity: cell.output.capacity().unpack(),
+			data_hash: CellOutput::calc_data_hash(&cell.data).unpack(),
+		});
+	}
+	changed |= !outputs.is_empty();
+
+	// Keep the dep groups in the same order as the config.
+	dep_group_recipes.sort_by_key(|recipe| plan.config.dep_groups.iter().position(|dep_group| dep_group.name == recipe.name));
+
+	match changed
+	{
+		true => Ok(Some(Migration { cell_recipes, dep_group_recipes })),
+		false => Ok(None),
+	}
+}
+
+/// Generate and send the transactions for the outputs, returning the created cells in the same order.
+fn send(chain: &mut impl Chain, generator: &TransactionGenerator, outputs: Vec<DeployOutput>) -> Result<Vec<LiveCell>, Error>
+{
+	if outputs.is_empty()
+	{
+		return Ok(Vec::new());
+	}
+
+	let funding_cells = generator.funding_cells(chain.live_cells_by_lock(&generator.lock));
+	let deployment = generator.generate(outputs, funding_cells)?;
+	for tx in &deployment.transactions
+	{
+		chain.send_transaction(tx)?;
+	}
+
+	deployment.out_points.iter()
+		.map(|out_point| chain.live_cell(out_point).ok_or_else(|| Error::DeadCell(format!("{}", out_point))))
+		.collect()
+}
+
+fn recipe_from_cell(name: &str, cell: &LiveCell) -> CellRecipe
+{
+	CellRecipe
+	{
+		name: name.to_string(),
+		tx_hash: cell.out_point.tx_hash().unpack(),
+		index: cell.out_point.index().unpack(),
+		occupied_capacity: cell.output.capacity().unpack(),
+		data_hash: CellOutput::calc_data_hash(&cell.data).unpack(),
+		type_id: cell.output.type_().to_opt()
+			.filter(|script| script.code_hash().as_slice() == TYPE_ID_CODE_HASH.as_bytes())
+			.map(|script| script.calc_script_hash().unpack()),
+	}
+}
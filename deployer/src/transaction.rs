@@ -0,0 +1,262 @@
+use ckb_hash::new_blake2b;
+use ckb_types::core::{Capacity, FeeRate, ScriptHashType, TransactionBuilder, TransactionView};
+use ckb_types::packed::{CellDep, CellInput, CellOutput, OutPoint, Script, WitnessArgs};
+use ckb_types::prelude::*;
+use ckb_types::{bytes::Bytes, h256, H256};
+
+use crate::chain::LiveCell;
+use crate::error::Error;
+
+// Constants
+/// The code hash of the built-in Type ID script.
+pub const TYPE_ID_CODE_HASH: H256 = h256!("0x545950455f4944");
+/// The default fee rate in shannons per kilobyte, which is the minimum accepted by the default tx pool config.
+pub const DEFAULT_FEE_RATE: u64 = 1_000;
+/// The default maximum transaction size accepted by the tx pool.
+pub const DEFAULT_MAX_TX_SIZE: usize = 512_000;
+/// The size of a secp256k1 signature, which is used as the size of the witness lock placeholder.
+pub const SECP256K1_SIGNATURE_SIZE: usize = 65;
+
+/// A cell to be created by a deployment transaction.
+#[derive(Debug, Clone)]
+pub struct DeployOutput
+{
+	pub name: String,
+	pub output: CellOutput,
+	pub data: Bytes,
+	/// Replace the Type ID args with a new Type ID calculated from the transaction.
+	pub new_type_id: bool,
+	/// A cell which is consumed by the same transaction, such as the previous version of an upgraded cell.
+	pub replaces: Option<LiveCell>,
+}
+
+/// The transactions created by the generator, and the out point of each deployed cell in the same order as the outputs.
+#[derive(Debug, Clone)]
+pub struct Deployment
+{
+	pub transactions: Vec<TransactionView>,
+	pub out_points: Vec<OutPoint>,
+}
+
+/// Generates unsigned deployment transactions which are funded by cells owned by a single lock.
+#[derive(Debug, Clone)]
+pub struct TransactionGenerator
+{
+	/// The lock of the deployed cells, the funding cells, and the change cells.
+	pub lock: Script,
+	/// The cell deps needed to unlock the funding cells, such as the secp256k1 dep group.
+	pub cell_deps: Vec<CellDep>,
+	pub fee_rate: FeeRate,
+	pub max_tx_size: usize,
+	/// The size of the placeholder for the signature in the first witness. Zero disables the placeholder.
+	pub witness_lock_size: usize,
+}
+
+impl TransactionGenerator
+{
+	/// Create a generator for the lock with the default fee rate and a placeholder for a secp256k1 signature.
+	pub fn new(lock: Script) -> Self
+	{
+		Self
+		{
+			lock,
+			cell_deps: Vec::new(),
+			fee_rate: FeeRate::from_u64(DEFAULT_FEE_RATE),
+			max_tx_size: DEFAULT_MAX_TX_SIZE,
+			witness_lock_size: SECP256K1_SIGNATURE_SIZE,
+		}
+	}
+
+	/// Create an output owned by the lock with exactly the capacity occupied by the lock, type, and data.
+	pub fn output(&self, name: &str, type_script: Option<Script>, data: Bytes) -> DeployOutput
+	{
+		DeployOutput { name: name.to_string(), output: occupied_output(&self.lock, type_script, &data), data, new_type_id: false, replaces: None }
+	}
+
+	/// Create an output owned by the lock with a new Type ID.
+	pub fn type_id_output(&self, name: &str, data: Bytes) -> DeployOutput
+	{
+		DeployOutput { new_type_id: true, ..self.output(name, Some(type_id_script(H256::default())), data) }
+	}
+
+	/// Filter the live cells down to those that can be used for capacity: plain cells owned by the lock.
+	pub fn funding_cells(&self, live_cells: Vec<LiveCell>) -> Vec<LiveCell>
+	{
+		live_cells.into_iter()
+			.filter(|cell| cell.output.lock().as_slice() == self.lock.as_slice() && cell.output.type_().is_none() && cell.data.is_empty())
+			.collect()
+	}
+
+	/// Generate the transactions to create the outputs, splitting them into as many transactions as needed to stay under the
+	/// maximum transaction size. Each transaction spends the change of the previous one, so they must be sent in order.
+	pub fn generate(&self, outputs: Vec<DeployOutput>, funding_cells: Vec<LiveCell>) -> Result<Deployment, Error>
+	{
+		let mut candidates = funding_cells;
+		let mut deployment = Deployment { transactions: Vec::new(), out_points: Vec::new() };
+		let mut pending: Vec<DeployOutput> = Vec::new();
+
+		for output in outputs
+		{
+			let mut trial = pending.clone();
+			trial.push(output.clone());
+			if self.fits(trial.clone(), &candidates)?
+			{
+				pending = trial;
+				continue;
+			}
+
+			// The output starts a new transaction, and it must fit on its own.
+			if !pending.is_empty()
+			{
+				self.commit(std::mem::take(&mut pending), &mut candidates, &mut deployment)?;
+			}
+			if !self.fits(vec![output.clone()], &candidates)?
+			{
+				return Err(Error::TransactionTooLarge(output.name));
+			}
+			pending.push(output);
+		}
+		if !pending.is_empty()
+		{
+			self.commit(pending, &mut candidates, &mut deployment)?;
+		}
+
+		Ok(deployment)
+	}
+
+	/// Check if a transaction for the outputs is within the maximum transaction size.
+	fn fits(&self, outputs: Vec<DeployOutput>, candidates: &[LiveCell]) -> Result<bool, Error>
+	{
+		let (tx, _) = self.build(outputs, candidates)?;
+
+		Ok(tx.data().serialized_size_in_block() <= self.max_tx_size)
+	}
+
+	/// Build the transaction for the outputs and record it in the deployment. The used candidates are removed and the change
+	/// cell becomes the first candidate for the next transaction.
+	fn commit(&self, outputs: Vec<DeployOutput>, candidates: &mut Vec<LiveCell>, deployment: &mut Deployment) -> Result<(), Error>
+	{
+		let count = outputs.len();
+		let (tx, used) = self.build(outputs, candidates)?;
+		candidates.drain(..used);
+		if tx.outputs().len() > count
+		{
+			let (output, data) = tx.output_with_data(count).expect("change output");
+			candidates.insert(0, LiveCell { out_point: OutPoint::new(tx.hash(), count as u32), output, data });
+		}
+
+		deployment.out_points.extend((0..count).map(|i| OutPoint::new(tx.hash(), i as u32)));
+		deployment.transactions.push(tx);
+
+		Ok(())
+	}
+
+	/// Build a single transaction for the outputs. Candidates are selected in order until the outputs and fee are covered
+	/// and the leftover is either zero or enough for a change cell. Returns the transaction and the number of candidates used.
+	pub fn build(&self, outputs: Vec<DeployOutput>, candidates: &[LiveCell]) -> Result<(TransactionView, usize), Error>
+	{
+		let output_capacity: u64 = outputs.iter().map(|planned| Unpack::<u64>::unpack(&planned.output.capacity())).sum();
+		let change_output = occupied_output(&self.lock, None, &Bytes::new());
+		let change_capacity: u64 = change_output.capacity().unpack();
+
+		let mut inputs: Vec<LiveCell> = outputs.iter().filter_map(|planned| planned.replaces.clone()).collect();
+		let mut used = 0;
+		loop
+		{
+			let input_capacity: u64 = inputs.iter().map(|cell| Unpack::<u64>::unpack(&cell.output.capacity())).sum();
+
+			// The fee depends on the size of the transaction, so it is calculated with and without a change cell.
+			let fee_with_change = self.fee(&self.assemble(&inputs, &outputs, Some(change_output.clone())));
+			let fee_without_change = self.fee(&self.assemble(&inputs, &outputs, None));
+			if !inputs.is_empty()
+			{
+				if input_capacity >= output_capacity + fee_with_change + change_capacity
+				{
+					let change = change_output.as_builder().capacity((input_capacity - output_capacity - fee_with_change).pack()).build();
+					return Ok((self.assemble(&inputs, &outputs, Some(change)), used));
+				}
+				if input_capacity == output_capacity + fee_without_change
+				{
+					return Ok((self.assemble(&inputs, &outputs, None), used));
+				}
+			}
+
+			match candidates.get(used)
+			{
+				Some(cell) =>
+				{
+					inputs.push(cell.clone());
+					used += 1;
+				},
+				None => return Err(Error::InsufficientCapacity((output_capacity + fee_with_change + change_capacity).saturating_sub(input_capacity))),
+			}
+		}
+	}
+
+	/// Calculate the fee for a transaction based on its size in a block.
+	pub fn fee(&self, tx: &TransactionView) -> u64
+	{
+		self.fee_rate.fee(tx.data().serialized_size_in_block()).as_u64()
+	}
+
+	fn assemble(&self, inputs: &[LiveCell], outputs: &[DeployOutput], change: Option<CellOutput>) -> TransactionView
+	{
+		// Type IDs are calculated from the first input. Without inputs the placeholder args are kept, which has the same size.
+		let first_input = inputs.first().map(|cell| CellInput::new(cell.out_point.clone(), 0));
+
+		let mut builder = TransactionBuilder::default()
+			.cell_deps(self.cell_deps.clone())
+			.inputs(inputs.iter().map(|cell| CellInput::new(cell.out_point.clone(), 0)));
+		for (i, planned) in outputs.iter().enumerate()
+		{
+			let output = match (&first_input, planned.new_type_id)
+			{
+				(Some(first_input), true) => planned.output.clone().as_builder().type_(Some(type_id_script(calculate_type_id(first_input, i as u64))).pack()).build(),
+				_ => planned.output.clone(),
+			};
+			builder = builder.output(output).output_data(planned.data.pack());
+		}
+		if let Some(change) = change
+		{
+			builder = builder.output(change).output_data(Bytes::new().pack());
+		}
+		if self.witness_lock_size > 0
+		{
+			let witness = WitnessArgs::new_builder().lock(Some(Bytes::from(vec![0u8; self.witness_lock_size])).pack()).build();
+			builder = builder.witness(witness.as_bytes().pack());
+		}
+
+		builder.build()
+	}
+}
+
+/// Calculate a Type ID from the first input of a transaction and the index of the output cell.
+pub fn calculate_type_id(first_input: &CellInput, output_index: u64) -> H256
+{
+	let mut type_id = [0u8; 32];
+	let mut blake2b = new_blake2b();
+	blake2b.update(first_input.as_slice());
+	blake2b.update(&output_index.to_le_bytes());
+	blake2b.finalize(&mut type_id);
+
+	type_id.into()
+}
+
+/// Build a Type ID type script with the specified args.
+pub fn type_id_script(type_id: H256) -> Script
+{
+	Script::new_builder()
+		.code_hash(TYPE_ID_CODE_HASH.pack())
+		.hash_type(ScriptHashType::Type.into())
+		.args(Bytes::from(type_id.as_bytes().to_vec()).pack())
+		.build()
+}
+
+/// Create an output with exactly the capacity occupied by the lock, type, and data.
+pub fn occupied_output(lock: &Script, type_script: Option<Script>, data: &Bytes) -> CellOutput
+{
+	let output = CellOutput::new_builder().lock(lock.clone()).type_(type_script.pack()).build();
+	let capacity = output.occupied_capacity(Capacity::bytes(data.len()).expect("data capacity")).expect("occupied capacity");
+
+	output.as_builder().capacity(capacity.pack()).build()
+}
@@ -0,0 +1,103 @@
+//! Fuzzing targets for the contracts in `capsule.toml`, with one target for each contract.
+//!
+//! Each target turns arbitrary input into a case for the differential harness of the `tests` crate, which builds the
+//! transaction around the contract binary. A case has any number of input and output cells which use the contract as
+//! their lock or type, and cell deps, with arbitrary args, data, since values, and witnesses. The transaction is run
+//! through the CKB VM, and any failure which is not one of the error codes declared in the `Error` enum of the contract
+//! is reported as a crash. This includes panics, which exit with -1, and exceeding the cycle limit.
+//!
+//! The contracts are loaded from `build/debug`, or from `build/release` when `CAPSULE_TEST_ENV` is `release`.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use ckb_testtool::ckb_types::bytes::Bytes;
+use libfuzzer_sys::arbitrary::{self, Arbitrary, Unstructured};
+use tests::differential::{Case, CaseCell, Harness, Position};
+
+// Constants
+const TEST_ENV_VAR: &str = "CAPSULE_TEST_ENV";
+const MAX_CELLS: usize = 16; // The maximum number of input and output cells, which keeps each run fast.
+const NEVER_ERROR_CODE: i8 = 1; // The never contract always fails with this error code, and has no Error enum.
+const POSITIONS: [Option<Position>; 4] = [None, Some(Position::Lock), Some(Position::Type), Some(Position::Proxy)];
+
+/// A case built from arbitrary input, where at least one cell uses the contract.
+#[derive(Debug, Clone)]
+pub struct Input(pub Case);
+
+fn arbitrary_cells(u: &mut Unstructured) -> arbitrary::Result<Vec<CaseCell>>
+{
+	let count = u.int_in_range(0..=MAX_CELLS)?;
+
+	(0..count).map(|_| Ok(CaseCell
+	{
+		position: *u.choose(&POSITIONS)?,
+		args: u.arbitrary()?,
+		owner: u.arbitrary()?,
+		tag: u.arbitrary()?,
+		capacity: u.arbitrary()?,
+		since: u.arbitrary()?,
+		data: u.arbitrary()?,
+	})).collect()
+}
+
+impl<'a> Arbitrary<'a> for Input
+{
+	fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self>
+	{
+		let args = u.arbitrary()?;
+		let inputs = arbitrary_cells(u)?;
+		let mut outputs = arbitrary_cells(u)?;
+		let deps = arbitrary_cells(u)?;
+		let witnesses = u.arbitrary()?;
+
+		// The contract must be used by at least one cell for it to run.
+		if !inputs.iter().chain(outputs.iter()).any(CaseCell::runs_contract)
+		{
+			match outputs.first_mut()
+			{
+				Some(cell) => cell.position = Some(Position::Type),
+				None => outputs.push(CaseCell { position: Some(Position::Type), args: None, owner: 0, tag: None, capacity: 0, since: 0, data: Vec::new() }),
+			}
+		}
+
+		Ok(Self(Case { args, inputs, outputs, deps, witnesses }))
+	}
+}
+
+/// Load a contract binary from the build directory.
+fn load_binary(name: &str) -> Bytes
+{
+	let build = match env::var(TEST_ENV_VAR).map(|value| value.to_lowercase()).as_deref()
+	{
+		Ok("release") => "release",
+		_ => "debug",
+	};
+	let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "..", "build", build, name].iter().collect();
+
+	fs::read(&path).unwrap_or_else(|err| panic!("{}: {}", path.display(), err)).into()
+}
+
+/// Determine if the error code is declared by the contract.
+pub fn is_declared(contract: &str, code: i8) -> bool
+{
+	match contract
+	{
+		"never" => code == NEVER_ERROR_CODE,
+		_ => inspector::errors(contract).is_some_and(|errors| code > 0 && (code as usize) <= errors.len()),
+	}
+}
+
+/// Run the input and panic if the contract fails with anything other than one of its declared error codes. Failures
+/// without an error code, such as exceeding the cycle limit, panic in the harness.
+pub fn check(contract: &str, input: &Input)
+{
+	if let Err(code) = Harness::with_binary(contract, load_binary(contract)).run(&input.0)
+	{
+		if !is_declared(contract, code)
+		{
+			panic!("{} returned undeclared error code {} ({})", contract, code, inspector::error_name(contract, code).unwrap_or("unknown"));
+		}
+	}
+}
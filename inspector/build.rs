@@ -0,0 +1,60 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Rerun the build script when the path changes. A path which does not exist yet is watched through its nearest existing parent.
+fn watch(path: &Path)
+{
+	let existing = path.ancestors().find(|path| path.exists()).unwrap_or(path);
+	println!("cargo:rerun-if-changed={}", existing.display());
+}
+
+/// Read the variant names of the Error enum in order. The first variant has an error code of 1 and each one after it
+/// is one more, which is the layout generated by capsule for every contract.
+fn parse_errors(source: &str) -> Vec<String>
+{
+	let body = source.split("pub enum Error").nth(1)
+		.and_then(|rest| rest.split('{').nth(1))
+		.and_then(|rest| rest.split('}').next())
+		.unwrap_or_default();
+
+	body.lines()
+		.map(|line| line.split("//").next().unwrap_or_default())
+		.flat_map(|line| line.split(','))
+		.filter_map(|variant| variant.split('=').next())
+		.map(str::trim)
+		.filter(|variant| !variant.is_empty())
+		.map(String::from)
+		.collect()
+}
+
+fn main()
+{
+	let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("manifest dir")).join("..");
+	let out_dir = PathBuf::from(env::var("OUT_DIR").expect("out dir"));
+
+	// Read the contract names from capsule.toml.
+	let capsule_path = root.join("capsule.toml");
+	watch(&capsule_path);
+	let capsule: toml::Value = toml::from_str(&fs::read_to_string(&capsule_path).expect("capsule.toml")).expect("capsule.toml");
+	let names: Vec<String> = capsule.get("contracts").and_then(|contracts| contracts.as_array()).into_iter().flatten()
+		.filter_map(|contract| contract.get("name").and_then(|name| name.as_str()).map(String::from))
+		.collect();
+
+	// Read the error names of each contract. Contracts without an error module, such as always and never, have none.
+	let mut output = String::new();
+	output.push_str("/// The contracts in capsule.toml with the names of their error codes in order, starting from 1.\n");
+	output.push_str("pub const ERRORS: &[(&str, &[&str])] = &[\n");
+	for name in &names
+	{
+		let path = root.join("contracts").join(name).join("src").join("error.rs");
+		watch(&path);
+		let errors: Vec<String> = fs::read_to_string(&path).map(|source| parse_errors(&source)).unwrap_or_default()
+			.iter()
+			.map(|error| format!("\"{}\"", error))
+			.collect();
+		output.push_str(&format!("\t(\"{}\", &[{}]),\n", name, errors.join(", ")));
+	}
+	output.push_str("];\n");
+	fs::write(out_dir.join("errors.rs"), output).expect("write errors");
+}
@@ -0,0 +1,23 @@
+use std::fmt;
+
+/// Error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error
+{
+	UnknownContract(String),
+	InvalidHex(String),
+}
+
+impl fmt::Display for Error
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			Self::UnknownContract(name) => write!(f, "unknown contract: {} is not in capsule.toml", name),
+			Self::InvalidHex(hex) => write!(f, "invalid hex: {}", hex),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
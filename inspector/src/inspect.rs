@@ -0,0 +1,710 @@
+use std::fmt;
+
+use sdk::Encoding;
+use sdk::{acplock, cellcount, chequelock, counter, daowrapper, data10, datacap, datarange, dataratio, doublecounter, escrowlock, hashlock};
+use sdk::{icclock, jsoncell, lockproxylock, nft, occlock, odcounter, oddoublecounter, oracle, oraclelock, orderlock, ratelimitlock, sudt};
+use sdk::{timelock, typeid, typeproxylock, vestinglock};
+
+use crate::{error_code, errors, Error};
+
+// Constants
+const SHANNONS_PER_CKBYTE: u64 = 100_000_000; // One CKByte is 100,000,000 Shannons.
+const SINCE_RELATIVE_FLAG: u64 = 0x8000_0000_0000_0000; // The since is relative to the block the input cell was committed in.
+const SINCE_METRIC_MASK: u64 = 0x6000_0000_0000_0000; // The metric flag bits.
+const SINCE_RESERVED_MASK: u64 = 0x1F00_0000_0000_0000; // The reserved flag bits, which must be zero.
+const SINCE_VALUE_MASK: u64 = 0x00FF_FFFF_FFFF_FFFF; // The value is the lower 56 bits of the since value.
+const SINCE_METRIC_BLOCK_NUMBER: u64 = 0x0000_0000_0000_0000;
+const SINCE_METRIC_EPOCH: u64 = 0x2000_0000_0000_0000;
+const SINCE_METRIC_TIMESTAMP: u64 = 0x4000_0000_0000_0000;
+
+/// A decoded value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field
+{
+	pub name: String,
+	pub value: String,
+}
+
+/// A rule of the contract which is broken, with the error code the contract would return.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation
+{
+	pub code: i8,
+	pub name: String,
+	pub reason: String,
+}
+
+impl fmt::Display for Violation
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "error {} ({}): {}", self.code, self.name, self.reason)
+	}
+}
+
+/// The decoded args or data of a contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section
+{
+	pub bytes: Vec<u8>,
+	pub fields: Vec<Field>,
+	pub violations: Vec<Violation>,
+	pub notes: Vec<String>,
+	contract: String,
+}
+
+impl Section
+{
+	fn new(contract: &str, bytes: &[u8]) -> Self
+	{
+		Self { bytes: bytes.to_vec(), fields: Vec::new(), violations: Vec::new(), notes: Vec::new(), contract: contract.to_string() }
+	}
+
+	fn field(&mut self, name: &str, value: impl ToString)
+	{
+		self.fields.push(Field { name: name.to_string(), value: value.to_string() });
+	}
+
+	fn note(&mut self, note: impl Into<String>)
+	{
+		self.notes.push(note.into());
+	}
+
+	/// Add a violation of the named error of the contract.
+	fn violation(&mut self, name: &str, reason: impl Into<String>)
+	{
+		let code = error_code(&self.contract, name).unwrap_or_else(|| panic!("{} has no error named {}", self.contract, name));
+		self.violations.push(Violation { code, name: name.to_string(), reason: reason.into() });
+	}
+
+	/// Describe the named error of the contract, for use in a note about a rule which depends on the transaction.
+	fn describe(&self, name: &str) -> String
+	{
+		format!("error {} ({})", error_code(&self.contract, name).unwrap_or_default(), name)
+	}
+
+	/// Decode the bytes, adding a violation of the named error if the contract would not accept them.
+	fn decode<T: Encoding>(&mut self, error: &str) -> Option<T>
+	{
+		match T::decode(&self.bytes)
+		{
+			Ok(value) =>
+			{
+				// Contracts which only read the start of the bytes ignore the rest.
+				let length = value.encode().len();
+				if length < self.bytes.len()
+				{
+					self.note(format!("The {} bytes after the first {} are ignored.", self.bytes.len() - length, length));
+				}
+				Some(value)
+			},
+			Err(err) =>
+			{
+				self.violation(error, err.to_string());
+				None
+			},
+		}
+	}
+}
+
+/// The decoded args and data of a contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report
+{
+	pub contract: String,
+	pub args: Option<Section>,
+	pub data: Option<Section>,
+}
+
+impl Report
+{
+	pub fn violations(&self) -> impl Iterator<Item = &Violation>
+	{
+		self.args.iter().chain(self.data.iter()).flat_map(|section| section.violations.iter())
+	}
+
+	/// Determine if the args and data follow every rule of the contract which can be checked without a transaction.
+	pub fn is_valid(&self) -> bool
+	{
+		self.violations().next().is_none()
+	}
+}
+
+impl fmt::Display for Report
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "Contract: {}", self.contract)?;
+		for (title, section) in [("Args", &self.args), ("Data", &self.data)]
+		{
+			let section = match section
+			{
+				Some(section) => section,
+				None => continue,
+			};
+			write!(f, "\n\n{}: 0x{}", title, hex(&section.bytes))?;
+			for field in &section.fields
+			{
+				write!(f, "\n  {}: {}", field.name, field.value)?;
+			}
+			for violation in &section.violations
+			{
+				write!(f, "\n  {}", violation)?;
+			}
+			for note in &section.notes
+			{
+				write!(f, "\n  note: {}", note)?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+fn hex(data: &[u8]) -> String
+{
+	data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn hash(data: &[u8]) -> String
+{
+	format!("0x{}", hex(data))
+}
+
+/// Format an amount of Shannons with the equivalent amount of CKBytes.
+fn shannons(amount: u64) -> String
+{
+	let fraction = format!("{:08}", amount % SHANNONS_PER_CKBYTE);
+	match fraction.trim_end_matches('0')
+	{
+		"" => format!("{} Shannons ({} CKB)", amount, amount / SHANNONS_PER_CKBYTE),
+		fraction => format!("{} Shannons ({}.{} CKB)", amount, amount / SHANNONS_PER_CKBYTE, fraction),
+	}
+}
+
+/// Determine if a since value has no reserved flags set and uses a known metric.
+fn is_valid_since(since: u64) -> bool
+{
+	let metric = since & SINCE_METRIC_MASK;
+
+	(since & SINCE_RESERVED_MASK) == 0 && (metric == SINCE_METRIC_BLOCK_NUMBER || metric == SINCE_METRIC_EPOCH || metric == SINCE_METRIC_TIMESTAMP)
+}
+
+/// Format a since value with a description of its flags and value.
+fn since(since: u64) -> String
+{
+	if !is_valid_since(since)
+	{
+		return format!("0x{:016x} (invalid)", since);
+	}
+
+	let relative = if since & SINCE_RELATIVE_FLAG != 0 { "relative" } else { "absolute" };
+	let value = since & SINCE_VALUE_MASK;
+	let description = match since & SINCE_METRIC_MASK
+	{
+		SINCE_METRIC_EPOCH =>
+		{
+			// An epoch is encoded as a number (24 bits), an index (16 bits), and a length (16 bits).
+			let (number, index, length) = (value & 0xFF_FFFF, (value >> 24) & 0xFFFF, (value >> 40) & 0xFFFF);
+			match index == 0 || length == 0
+			{
+				true => format!("epoch {}", number),
+				false => format!("epoch {} {}/{}", number, index, length),
+			}
+		},
+		SINCE_METRIC_TIMESTAMP => format!("timestamp {}", value),
+		_ => format!("block {}", value),
+	};
+
+	format!("0x{:016x} ({} {})", since, relative, description)
+}
+
+/// Inspect the args and data of a contract. Either can be omitted, but the limits of some data depend on the args.
+pub fn inspect(contract: &str, args: Option<&[u8]>, data: Option<&[u8]>) -> Result<Report, Error>
+{
+	if errors(contract).is_none()
+	{
+		return Err(Error::UnknownContract(contract.to_string()));
+	}
+
+	let args_section = args.map(|args| inspect_args(contract, args));
+	let data_section = data.map(|data| inspect_data(contract, data, args));
+
+	Ok(Report { contract: contract.to_string(), args: args_section, data: data_section })
+}
+
+fn inspect_args(contract: &str, args: &[u8]) -> Section
+{
+	let mut section = Section::new(contract, args);
+	match contract
+	{
+		"always" => section.note("The args are not used. The contract always succeeds."),
+		"never" => section.note("The args are not used. The contract always fails with error code 1."),
+		"aggcounter" | "aggdoublecounter" | "ckb500" | "counter" | "doublecounter" | "jsoncell" | "odcounter" | "oddoublecounter" =>
+		{
+			section.note("The args are not used by the contract.");
+		},
+		"acplock" =>
+		{
+			if let Some(args) = section.decode::<acplock::Args>("ArgsLength")
+			{
+				section.field("owner_lock_hash", hash(&args.owner_lock_hash));
+				section.field("minimum_ckb", args.minimum_ckb.map_or("none".to_string(), shannons));
+				section.field("minimum_sudt", args.minimum_sudt.map_or("none".to_string(), |amount| amount.to_string()));
+			}
+		},
+		"cellcount" =>
+		{
+			// The source and operator are checked before the length of the operands.
+			if args.len() >= 2 && args[0] > 3
+			{
+				section.violation("InvalidSource", format!("unknown source {}", args[0]));
+			}
+			else if args.len() >= 2 && args[1] > 6
+			{
+				section.violation("InvalidOperator", format!("unknown operator {}", args[1]));
+			}
+			else if let Some(args) = section.decode::<cellcount::Args>("ArgsLen")
+			{
+				section.field("source", format!("{:?}", args.source));
+				section.field("condition", match args.condition
+				{
+					cellcount::Condition::Equal(operand) => format!("equal to {}", operand),
+					cellcount::Condition::NotEqual(operand) => format!("not equal to {}", operand),
+					cellcount::Condition::LessThan(operand) => format!("less than {}", operand),
+					cellcount::Condition::LessThanOrEqual(operand) => format!("less than or equal to {}", operand),
+					cellcount::Condition::GreaterThan(operand) => format!("greater than {}", operand),
+					cellcount::Condition::GreaterThanOrEqual(operand) => format!("greater than or equal to {}", operand),
+					cellcount::Condition::Range(minimum, maximum) => format!("between {} and {}", minimum, maximum),
+				});
+			}
+		},
+		"chequelock" =>
+		{
+			if let Some(args) = section.decode::<chequelock::Args>("ArgsLen")
+			{
+				section.field("receiver_lock_hash_prefix", hash(&args.receiver_lock_hash_prefix));
+				section.field("sender_lock_hash_prefix", hash(&args.sender_lock_hash_prefix));
+				section.note(format!("The sender can withdraw with a relative since of at least {} epochs.", chequelock::WITHDRAW_LOCK_EPOCHS));
+			}
+		},
+		"daowrapper" =>
+		{
+			if let Some(args) = section.decode::<daowrapper::Args>("ArgsLen")
+			{
+				section.field("dao_type_hash", hash(&args.dao_type_hash));
+				section.field("pool_lock_code_hash", hash(&args.pool_lock_code_hash));
+				section.field("pool_lock_hash_type", args.pool_lock_hash_type);
+			}
+		},
+		"data10" =>
+		{
+			// Args of any length decode, since the contract ignores args which are not a limit.
+			if let Some(args) = section.decode::<data10::Args>("Encoding")
+			{
+				section.field("limit", match args.limit
+				{
+					Some(limit) => format!("{} bytes", limit),
+					None => format!("{} bytes (default)", data10::MAX_DATA_SIZE),
+				});
+			}
+		},
+		"datacap" =>
+		{
+			if let Some(args) = section.decode::<datacap::Args>("ArgsLen")
+			{
+				section.field("limit", format!("{} bytes", args.limit));
+			}
+		},
+		"datarange" =>
+		{
+			if let Some(args) = section.decode::<datarange::Args>("ArgsLen")
+			{
+				section.field("minimum", format!("{} bytes", args.minimum));
+				section.field("limit", format!("{} bytes", args.limit));
+				if args.minimum > args.limit
+				{
+					section.note("The minimum is more than the limit, so no output cell can be created.");
+				}
+			}
+		},
+		"dataratio" =>
+		{
+			// The length is checked before the mode.
+			if args.len() == 5 && args[4] > 1
+			{
+				section.violation("InvalidMode", format!("unknown mode {}", args[4]));
+			}
+			else if let Some(args) = section.decode::<dataratio::Args>("ArgsLen")
+			{
+				section.field("bytes_per_ckbyte", args.bytes_per_ckbyte);
+				section.field("mode", match args.mode
+				{
+					Some(dataratio::Mode::PerCell) => "per cell",
+					Some(dataratio::Mode::Aggregate) => "aggregate",
+					None => "per cell (default)",
+				});
+			}
+		},
+		"escrowlock" =>
+		{
+			if let Some(args) = section.decode::<escrowlock::Args>("ArgsLen")
+			{
+				section.field("buyer_lock_hash", hash(&args.buyer_lock_hash));
+				section.field("seller_lock_hash", hash(&args.seller_lock_hash));
+				section.field("arbiter_lock_hash", hash(&args.arbiter_lock_hash));
+				section.field("release_lock_hash", hash(&args.release_lock_hash));
+				section.field("timeout", since(args.timeout));
+				if !is_valid_since(args.timeout)
+				{
+					section.violation("InvalidTimeout", "the timeout has reserved flags set or an unknown metric");
+				}
+			}
+		},
+		"hashlock" =>
+		{
+			if let Some(args) = section.decode::<hashlock::Args>("ArgsLen")
+			{
+				section.field("hash", hash(&args.hash));
+			}
+		},
+		"icclock" =>
+		{
+			if let Some(args) = section.decode::<icclock::Args>("ArgsLen")
+			{
+				section.field("amount", shannons(args.amount));
+			}
+		},
+		"lockproxylock" =>
+		{
+			if let Some(args) = section.decode::<lockproxylock::Args>("ArgsLen")
+			{
+				section.field("owner_lock_hash", hash(&args.owner_lock_hash));
+			}
+		},
+		"nft" if nft::ClassArgs::decode(args).is_ok() =>
+		{
+			if let Some(args) = section.decode::<nft::ClassArgs>("ArgsLen")
+			{
+				section.field("class_id", hash(&args.class_id));
+				section.note("The args are for a class cell, which limits the number of NFTs issued with its type hash as the class.");
+			}
+		},
+		"nft" =>
+		{
+			if let Some(args) = section.decode::<nft::Args>("ArgsLen")
+			{
+				section.field("nft_id", hash(&args.nft_id));
+				section.field("class_type_hash", args.class_type_hash.map_or("none".to_string(), |class_type_hash| hash(&class_type_hash)));
+			}
+		},
+		"occlock" =>
+		{
+			if let Some(args) = section.decode::<occlock::Args>("ArgsLen")
+			{
+				section.field("amount", shannons(args.amount));
+				section.field("count", args.count);
+			}
+		},
+		"oracle" =>
+		{
+			if let Some(args) = section.decode::<oracle::Args>("ArgsLen")
+			{
+				section.field("updater_lock_hash", hash(&args.updater_lock_hash));
+			}
+		},
+		"oraclelock" =>
+		{
+			if let Some(args) = section.decode::<oraclelock::Args>("ArgsLen")
+			{
+				section.field("owner_lock_hash", hash(&args.owner_lock_hash));
+				section.field("oracle_type_hash", hash(&args.oracle_type_hash));
+				section.field("minimum_sequence", args.minimum_sequence);
+				section.field("threshold", args.threshold);
+			}
+		},
+		"orderlock" =>
+		{
+			if let Some(args) = section.decode::<orderlock::Args>("ArgsLen")
+			{
+				section.field("maker_lock_hash", hash(&args.maker_lock_hash));
+				section.field("rate_ckb", shannons(args.rate_ckb));
+				section.field("rate_sudt", args.rate_sudt);
+				if args.rate_sudt == 0
+				{
+					section.violation("InvalidExchangeRate", "the SUDT amount of the exchange rate is zero, so the order can only be cancelled by the maker");
+				}
+			}
+		},
+		"ratelimitlock" =>
+		{
+			if let Some(args) = section.decode::<ratelimitlock::Args>("ArgsLen")
+			{
+				section.field("owner_lock_hash", hash(&args.owner_lock_hash));
+				section.field("limit", format!("{} per window", shannons(args.limit)));
+			}
+		},
+		"sudt" =>
+		{
+			if let Some(args) = section.decode::<sudt::Args>("ArgsLength")
+			{
+				section.field("owner_lock_hash", hash(&args.owner_lock_hash));
+			}
+		},
+		"timelock" =>
+		{
+			if let Some(args) = section.decode::<timelock::Args>("ArgsLen")
+			{
+				section.field("delegate_lock_hash", hash(&args.delegate_lock_hash));
+				section.field("threshold", since(args.threshold));
+				if !is_valid_since(args.threshold)
+				{
+					section.violation("InvalidThreshold", "the threshold has reserved flags set or an unknown metric");
+				}
+			}
+		},
+		"typeid" =>
+		{
+			if let Some(args) = section.decode::<typeid::Args>("ArgsLen")
+			{
+				section.field("type_id", hash(&args.type_id));
+			}
+		},
+		"typeproxylock" =>
+		{
+			if let Some(args) = section.decode::<typeproxylock::Args>("ArgsLen")
+			{
+				section.field("owner_type_hash", hash(&args.owner_type_hash));
+			}
+		},
+		"vestinglock" =>
+		{
+			if let Some(args) = section.decode::<vestinglock::Args>("ArgsLen")
+			{
+				section.field("beneficiary_lock_hash", hash(&args.beneficiary_lock_hash));
+				section.field("start_epoch", args.start_epoch);
+				section.field("cliff", format!("{} epochs", args.cliff));
+				section.field("duration", format!("{} epochs", args.duration));
+				section.field("total_amount", args.total_amount);
+				section.note("The amount is the capacity in Shannons for a plain cell, or the SUDT amount for a cell with a type script.");
+			}
+		},
+		_ => section.note("The args of this contract are not known."),
+	}
+
+	section
+}
+
+fn inspect_data(contract: &str, data: &[u8], args: Option<&[u8]>) -> Section
+{
+	let mut section = Section::new(contract, data);
+	match contract
+	{
+		"counter" | "aggcounter" =>
+		{
+			if data.len() < 8
+			{
+				section.violation("Panic", "the data is shorter than the 8 bytes of the counter value");
+			}
+			else if let Some(data) = section.decode::<counter::Data>("Panic")
+			{
+				section.field("value", data.value);
+				if data.next().is_none()
+				{
+					section.note("The value cannot be incremented, so the cell cannot be transferred.");
+				}
+			}
+		},
+		"doublecounter" | "aggdoublecounter" =>
+		{
+			if data.len() < 16
+			{
+				section.violation("Panic", "the data is shorter than the 16 bytes of the counter values");
+			}
+			else if let Some(data) = section.decode::<doublecounter::Data>("Panic")
+			{
+				section.field("value_1", data.value_1);
+				section.field("value_2", data.value_2);
+				if data.next().is_none()
+				{
+					section.note("The values cannot be incremented, so the cell cannot be transferred.");
+				}
+			}
+		},
+		"odcounter" =>
+		{
+			match odcounter::Data::decode(data)
+			{
+				Ok(data) =>
+				{
+					section.field("value", data.value);
+					if data.value != 0
+					{
+						section.note("A new counter must have a value of zero, so this data can only be used in a transfer.");
+					}
+					if data.next().is_none()
+					{
+						let error = section.describe("CounterValueOverflow");
+						section.note(format!("The value cannot be incremented, so a transfer fails with {}.", error));
+					}
+				},
+				Err(err) =>
+				{
+					section.violation("InvalidInputCellData", format!("as an input, {}", err));
+					section.violation("InvalidOutputCellData", format!("as an output, {}", err));
+				},
+			}
+		},
+		"oddoublecounter" =>
+		{
+			match oddoublecounter::Data::decode(data)
+			{
+				Ok(data) =>
+				{
+					section.field("value_1", data.value_1);
+					section.field("value_2", data.value_2);
+					if data != oddoublecounter::Data::default()
+					{
+						section.note("A new counter must have both values at zero, so this data can only be used in a transfer.");
+					}
+					if data.next().is_none()
+					{
+						let error = section.describe("CounterValueOverflow");
+						section.note(format!("The values cannot be incremented, so a transfer fails with {}.", error));
+					}
+				},
+				Err(err) =>
+				{
+					section.violation("InvalidInputCellData", format!("as an input, {}", err));
+					section.violation("InvalidOutputCellData", format!("as an output, {}", err));
+				},
+			}
+		},
+		"sudt" =>
+		{
+			if let Some(data) = section.decode::<sudt::Data>("Encoding")
+			{
+				section.field("amount", data.amount);
+			}
+		},
+		"daowrapper" =>
+		{
+			if let Some(data) = section.decode::<daowrapper::Data>("Encoding")
+			{
+				section.field("amount", shannons(u64::try_from(data.amount).unwrap_or(u64::MAX)));
+			}
+		},
+		"oracle" =>
+		{
+			if let Some(data) = section.decode::<oracle::Data>("InvalidCellData")
+			{
+				section.field("value", data.value);
+				section.field("timestamp", data.timestamp);
+				section.field("sequence", data.sequence);
+			}
+		},
+		"ratelimitlock" =>
+		{
+			match ratelimitlock::Data::decode(data)
+			{
+				Ok(data) =>
+				{
+					section.field("window", data.window);
+					section.field("withdrawn", shannons(data.withdrawn));
+				},
+				Err(err) =>
+				{
+					section.violation("InvalidOutputCellData", format!("as an output, {}", err));
+					section.note("As an input, the cell is treated as a new cell which has not withdrawn anything.");
+				},
+			}
+		},
+		"jsoncell" =>
+		{
+			match jsoncell::Data::decode(data)
+			{
+				Ok(data) =>
+				{
+					match serde_json::from_str::<serde_json::Value>(&data.json)
+					{
+						Ok(_) => section.field("json", data.json),
+						Err(err) => section.violation("InvalidJson", err.to_string()),
+					}
+				},
+				Err(err) => section.violation("InvalidStringData", err.to_string()),
+			}
+		},
+		"data10" | "datacap" | "datarange" =>
+		{
+			section.field("length", format!("{} bytes", data.len()));
+			let range = match (contract, args)
+			{
+				("data10", Some(args)) => data10::Args::decode(args).ok().map(|args| (0, args.limit())),
+				("datacap", Some(args)) => datacap::Args::decode(args).ok().map(|args| (0, args.limit as usize)),
+				("datarange", Some(args)) => datarange::Args::decode(args).ok().map(|args| (args.minimum as usize, args.limit as usize)),
+				_ => None,
+			};
+			match range
+			{
+				Some((minimum, _)) if data.len() < minimum => section.violation("DataMinimumNotMet", format!("the data is less than the minimum of {} bytes", minimum)),
+				Some((_, limit)) if data.len() > limit => section.violation("DataLimitExceeded", format!("the data is more than the limit of {} bytes", limit)),
+				Some(_) => (),
+				None => section.note("The data limits depend on valid args."),
+			}
+		},
+		"dataratio" =>
+		{
+			section.field("length", format!("{} bytes", data.len()));
+			match args.and_then(|args| dataratio::Args::decode(args).ok())
+			{
+				Some(args) if args.bytes_per_ckbyte == 0 && !data.is_empty() =>
+				{
+					let error = section.describe("DataLimitExceeded");
+					section.note(format!("No data is allowed with a ratio of zero, so an output fails with {}.", error));
+				},
+				Some(args) =>
+				{
+					let bytes_per_ckbyte = (args.bytes_per_ckbyte as u64).max(1);
+					let ckbytes = (data.len() as u64 + bytes_per_ckbyte - 1) / bytes_per_ckbyte;
+					let free_capacity = ckbytes.saturating_mul(SHANNONS_PER_CKBYTE);
+					section.note(format!("The data requires at least {} of free capacity.", shannons(free_capacity)));
+				},
+				None => section.note("The data limits depend on valid args and the free capacity of the cell."),
+			}
+		},
+		"nft" if args.and_then(|args| nft::ClassArgs::decode(args).ok()).is_some() =>
+		{
+			if let Some(data) = section.decode::<nft::ClassData>("InvalidClassData")
+			{
+				section.field("max_supply", data.max_supply);
+				section.field("issued", data.issued);
+				if data.issued > data.max_supply
+				{
+					section.violation("ClassLimitExceeded", format!("the issued count exceeds the maximum supply of {}", data.max_supply));
+				}
+				section.note("The maximum supply cannot change, and the issued count must increase by the number of NFTs created.");
+			}
+		},
+		"nft" =>
+		{
+			section.field("length", format!("{} bytes", data.len()));
+			section.note("The data is the content of the NFT, which cannot change after it is created.");
+		},
+		"acplock" | "orderlock" | "vestinglock" =>
+		{
+			// These locks read the SUDT amount from the data when the cell has a type script.
+			match sudt::Data::decode(data)
+			{
+				Ok(data) => section.field("sudt_amount", data.amount),
+				Err(_) =>
+				{
+					let error = section.describe("Encoding");
+					section.note(format!("The data is too short for an SUDT amount, which fails with {} if the cell has a type script.", error));
+				},
+			}
+		},
+		_ => section.note("The data is not used by the contract."),
+	}
+
+	section
+}
@@ -0,0 +1,56 @@
+//! Decode and validate the args and cell data of the contracts in `capsule.toml`.
+//!
+//! The args and data are decoded with the sdk and checked against the rules that each contract applies to them on-chain.
+//! Any rule which is broken is reported with the error code the contract would return. The error codes are generated at
+//! build time from the `Error` enum of each contract.
+
+pub mod error;
+pub mod inspect;
+
+pub use error::Error;
+pub use inspect::{inspect, Field, Report, Section, Violation};
+
+include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+
+// Constants
+pub const PANIC_ERROR_CODE: i8 = -1; // The error code returned when a contract panics, such as reading past the end of the data.
+pub const PANIC_ERROR_NAME: &str = "Panic";
+
+/// The names of the error codes of a contract, starting from 1, or None if the contract is not in capsule.toml.
+pub fn errors(contract: &str) -> Option<&'static [&'static str]>
+{
+	ERRORS.iter().find(|(name, _)| *name == contract).map(|(_, errors)| *errors)
+}
+
+/// The name of an error code returned by a contract.
+pub fn error_name(contract: &str, code: i8) -> Option<&'static str>
+{
+	match code
+	{
+		PANIC_ERROR_CODE => Some(PANIC_ERROR_NAME),
+		code if code > 0 => errors(contract)?.get(code as usize - 1).copied(),
+		_ => None,
+	}
+}
+
+/// The error code of a named error returned by a contract.
+pub fn error_code(contract: &str, name: &str) -> Option<i8>
+{
+	match name
+	{
+		PANIC_ERROR_NAME => Some(PANIC_ERROR_CODE),
+		name => errors(contract)?.iter().position(|error| *error == name).map(|index| index as i8 + 1),
+	}
+}
+
+/// Parse a hex string, with or without a 0x prefix.
+pub fn parse_hex(hex: &str) -> Result<Vec<u8>, Error>
+{
+	let digits = hex.trim().trim_start_matches("0x");
+	if digits.len() % 2 != 0 || !digits.chars().all(|c| c.is_ascii_hexdigit())
+	{
+		return Err(Error::InvalidHex(hex.to_string()));
+	}
+
+	Ok((0..digits.len()).step_by(2).map(|i| u8::from_str_radix(&digits[i..i+2], 16).expect("hex digit")).collect())
+}
@@ -0,0 +1,70 @@
+use std::env;
+use std::process;
+
+// Constants
+const USAGE: &str = "Usage: inspector <contract> [--args <hex>] [--data <hex>] [--error <code>]";
+
+fn run(args: &[String]) -> Result<bool, String>
+{
+	let (contract, args) = args.split_first().ok_or_else(|| String::from(USAGE))?;
+	let errors = inspector::errors(contract).ok_or_else(|| inspector::Error::UnknownContract(contract.clone()).to_string())?;
+
+	let mut script_args = None;
+	let mut data = None;
+	let mut code = None;
+
+	let mut args = args.iter();
+	while let Some(flag) = args.next()
+	{
+		let value = args.next().ok_or_else(|| format!("missing value for {}", flag))?;
+		match flag.as_str()
+		{
+			"--args" => script_args = Some(inspector::parse_hex(value).map_err(|err| err.to_string())?),
+			"--data" => data = Some(inspector::parse_hex(value).map_err(|err| err.to_string())?),
+			"--error" => code = Some(value.parse::<i8>().map_err(|_| format!("invalid error code: {}", value))?),
+			other => return Err(format!("unknown option: {}", other)),
+		}
+	}
+
+	// Explain a single error code.
+	if let Some(code) = code
+	{
+		let name = inspector::error_name(contract, code).ok_or_else(|| format!("{} does not return error code {}", contract, code))?;
+		println!("{}: error {} ({})", contract, code, name);
+
+		return Ok(true);
+	}
+
+	// List the error codes when there is nothing to inspect.
+	if script_args.is_none() && data.is_none()
+	{
+		println!("Contract: {}", contract);
+		for (index, name) in errors.iter().enumerate()
+		{
+			println!("  error {} ({})", index + 1, name);
+		}
+		println!("  error {} ({})", inspector::PANIC_ERROR_CODE, inspector::PANIC_ERROR_NAME);
+
+		return Ok(true);
+	}
+
+	let report = inspector::inspect(contract, script_args.as_deref(), data.as_deref()).map_err(|err| err.to_string())?;
+	println!("{}", report);
+
+	Ok(report.is_valid())
+}
+
+fn main()
+{
+	let args: Vec<String> = env::args().skip(1).collect();
+	match run(&args)
+	{
+		Ok(true) => (),
+		Ok(false) => process::exit(1),
+		Err(message) =>
+		{
+			eprintln!("{}", message);
+			process::exit(2);
+		},
+	}
+}
@@ -0,0 +1,94 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ckb_hash::blake2b_256;
+
+// Constants
+const PROFILES: [&str; 2] = ["debug", "release"];
+
+/// A contract binary found in the build directory.
+struct Contract
+{
+	name: String,
+	data_hash: [u8; 32],
+	size: usize,
+}
+
+/// Rerun the build script when the path changes. Cargo treats a path which does not exist as changed on every build.
+fn watch(path: &Path)
+{
+	println!("cargo:rerun-if-changed={}", path.display());
+}
+
+/// Render the JSON manifest for a profile.
+fn render_json(contracts: &[Contract]) -> String
+{
+	let entries: Vec<String> = contracts.iter()
+		.map(|contract| format!("    {{ \"name\": \"{}\", \"data_hash\": \"0x{}\", \"size\": {} }}", contract.name, hex(&contract.data_hash), contract.size))
+		.collect();
+
+	match entries.is_empty()
+	{
+		true => String::from("{\n  \"contracts\": []\n}\n"),
+		false => format!("{{\n  \"contracts\": [\n{}\n  ]\n}}\n", entries.join(",\n")),
+	}
+}
+
+/// Render the Rust module for a profile, with a constant for each contract.
+fn render_module(profile: &str, contracts: &[Contract]) -> String
+{
+	let mut output = String::new();
+	output.push_str(&format!("/// The contracts in `build/{}`.\n", profile));
+	output.push_str(&format!("pub mod {}\n{{\n", profile));
+	output.push_str("\tuse super::Contract;\n\n");
+	output.push_str(&format!("\t/// The JSON manifest for `build/{}`.\n", profile));
+	output.push_str(&format!("\tpub const JSON: &str = include_str!(concat!(env!(\"OUT_DIR\"), \"/{}.json\"));\n\n", profile));
+	for contract in contracts
+	{
+		let bytes: Vec<String> = contract.data_hash.iter().map(|byte| format!("0x{:02x}", byte)).collect();
+		output.push_str(&format!("\tpub const {}: Contract = Contract {{ name: \"{}\", data_hash: [{}], size: {} }};\n", contract.name.to_uppercase(), contract.name, bytes.join(", "), contract.size));
+	}
+	let names: Vec<String> = contracts.iter().map(|contract| contract.name.to_uppercase()).collect();
+	output.push_str("\n\t/// Every contract in capsule.toml which has been built.\n");
+	output.push_str(&format!("\tpub const CONTRACTS: &[Contract] = &[{}];\n", names.join(", ")));
+	output.push_str("}\n\n");
+
+	output
+}
+
+fn hex(data: &[u8]) -> String
+{
+	data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn main()
+{
+	let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("manifest dir")).join("..");
+	let out_dir = PathBuf::from(env::var("OUT_DIR").expect("out dir"));
+
+	// Read the contract names from capsule.toml.
+	let capsule_path = root.join("capsule.toml");
+	watch(&capsule_path);
+	let capsule: toml::Value = toml::from_str(&fs::read_to_string(&capsule_path).expect("capsule.toml")).expect("capsule.toml");
+	let names: Vec<String> = capsule.get("contracts").and_then(|contracts| contracts.as_array()).into_iter().flatten()
+		.filter_map(|contract| contract.get("name").and_then(|name| name.as_str()).map(String::from))
+		.collect();
+
+	// Hash every contract that has been built for each profile.
+	let mut module = String::new();
+	for profile in PROFILES
+	{
+		// The directory is created empty until contracts are built into it, so the build script only reruns when they are.
+		let dir = root.join("build").join(profile);
+		fs::create_dir_all(&dir).expect("create build directory");
+		watch(&dir);
+		let contracts: Vec<Contract> = names.iter()
+			.filter_map(|name| fs::read(dir.join(name)).ok().map(|data| Contract { name: name.clone(), data_hash: blake2b_256(&data), size: data.len() }))
+			.collect();
+
+		fs::write(out_dir.join(format!("{}.json", profile)), render_json(&contracts)).expect("write json");
+		module.push_str(&render_module(profile, &contracts));
+	}
+	fs::write(out_dir.join("manifest.rs"), module).expect("write module");
+}
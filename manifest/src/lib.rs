@@ -0,0 +1,45 @@
+//! The data hash and size of every contract in `capsule.toml`, generated at build time from the binaries in `build/debug`
+//! and `build/release`. This allows off-chain code to reference a contract by code hash without loading the binary.
+//!
+//! Only contracts which have been built are included, so rebuild this crate after running `capsule build`.
+
+use ckb_types::core::ScriptHashType;
+use ckb_types::packed::{Byte32, Script};
+use ckb_types::prelude::*;
+use ckb_types::bytes::Bytes;
+
+/// A contract binary identified by the ckb blake2b hash of its data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contract
+{
+	pub name: &'static str,
+	pub data_hash: [u8; 32],
+	pub size: usize,
+}
+
+impl Contract
+{
+	/// The code hash used to reference the contract with a hash type of data1.
+	pub fn code_hash(&self) -> Byte32
+	{
+		self.data_hash.pack()
+	}
+
+	/// Build a script which references the contract by data hash with the specified args.
+	pub fn script(&self, args: Bytes) -> Script
+	{
+		Script::new_builder()
+			.code_hash(self.code_hash())
+			.hash_type(ScriptHashType::Data1.into())
+			.args(args.pack())
+			.build()
+	}
+}
+
+/// Find a contract by name.
+pub fn find(contracts: &'static [Contract], name: &str) -> Option<&'static Contract>
+{
+	contracts.iter().find(|contract| contract.name == name)
+}
+
+include!(concat!(env!("OUT_DIR"), "/manifest.rs"));
@@ -0,0 +1,17 @@
+use std::env;
+use std::process;
+
+// Print the JSON manifest for the debug or release build.
+fn main()
+{
+	match env::args().nth(1).as_deref()
+	{
+		Some("debug") => println!("{}", manifest::debug::JSON),
+		Some("release") | None => println!("{}", manifest::release::JSON),
+		Some(_) =>
+		{
+			eprintln!("Usage: manifest [debug|release]");
+			process::exit(2);
+		},
+	}
+}
@@ -0,0 +1,104 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, check_lock_hash, read_bytes, read_u128, read_u64, sudt, Encoding, Error};
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const MINIMUM_CKB_LEN: usize = 8; // The minimum CKB amount is a u64, which is 8 bytes.
+const MINIMUM_SUDT_LEN: usize = 16; // The minimum SUDT amount is a u128, which is 16 bytes.
+
+/// The script args, which hold the owner lock hash, optionally followed by the minimum CKB amount in Shannons,
+/// optionally followed by the minimum SUDT amount, that a payment must add to the cell.
+/// A minimum SUDT amount can only be given with a minimum CKB amount, which is encoded as zero if it is not set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub owner_lock_hash: [u8; LOCK_HASH_LEN],
+	pub minimum_ckb: Option<u64>,
+	pub minimum_sudt: Option<u128>,
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		let mut args = self.owner_lock_hash.to_vec();
+		if self.minimum_ckb.is_some() || self.minimum_sudt.is_some()
+		{
+			args.extend_from_slice(&self.minimum_ckb.unwrap_or(0).to_le_bytes());
+		}
+		if let Some(minimum_sudt) = self.minimum_sudt
+		{
+			args.extend_from_slice(&minimum_sudt.to_le_bytes());
+		}
+
+		args.into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[LOCK_HASH_LEN, LOCK_HASH_LEN + MINIMUM_CKB_LEN, LOCK_HASH_LEN + MINIMUM_CKB_LEN + MINIMUM_SUDT_LEN])?;
+		let minimum_ckb = (data.len() > LOCK_HASH_LEN).then(|| read_u64(data, LOCK_HASH_LEN));
+		let minimum_sudt = (data.len() > LOCK_HASH_LEN + MINIMUM_CKB_LEN).then(|| read_u128(data, LOCK_HASH_LEN + MINIMUM_CKB_LEN));
+
+		Ok(Self { owner_lock_hash: read_bytes(data, 0), minimum_ckb, minimum_sudt })
+	}
+}
+
+/// Build a transaction which locks the capacity with the anyone can pay lock, along with an amount of the token if a token type
+/// script is given.
+pub fn create(lock: Script, capacity: u64, token: Option<(Script, u128)>) -> TransactionView
+{
+	let output = match token
+	{
+		Some((type_script, amount)) => Output::new(capacity, lock, Some(type_script), sudt::Data { amount }.encode()),
+		None => Output::new(capacity, lock, None, Bytes::new()),
+	};
+
+	transaction::create(vec![output])
+}
+
+/// Build a transaction which pays the capacity and token amount to the cell, which is recreated with the same lock and type.
+/// The payment must meet the minimums given in the args of the lock, and only a cell with a token type can receive tokens.
+pub fn pay(cell: &Cell, capacity: u64, amount: u128) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	if capacity < args.minimum_ckb.unwrap_or(0)
+	{
+		return Err(Error::InvalidValue(format!("the payment is less than the minimum of {} Shannons", args.minimum_ckb.unwrap_or(0))));
+	}
+	let capacity = cell.capacity().checked_add(capacity).ok_or_else(|| Error::InvalidValue("capacity overflow".to_string()))?;
+	let data = match cell.output.type_().to_opt()
+	{
+		Some(_) =>
+		{
+			let minimum_sudt = args.minimum_sudt.unwrap_or(0);
+			if amount < minimum_sudt
+			{
+				return Err(Error::InsufficientAmount(minimum_sudt - amount));
+			}
+			let input_amount = sudt::Data::decode(&cell.data)?.amount;
+			sudt::Data { amount: input_amount.checked_add(amount).ok_or_else(|| Error::InvalidValue("token amount overflow".to_string()))? }.encode()
+		},
+		None if amount == 0 => cell.data.clone(),
+		None => return Err(Error::InvalidValue("the cell cannot receive tokens".to_string())),
+	};
+	let output = Output::new(capacity, cell.output.lock(), cell.output.type_().to_opt(), data);
+
+	Ok(transaction::transfer(slice::from_ref(cell), vec![output]))
+}
+
+/// Build a transaction which withdraws the cell with the owner cell, which is recreated unchanged, and transfers it to the lock.
+pub fn withdraw(cell: &Cell, owner: &Cell, lock: Script) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	check_lock_hash(owner, &args.owner_lock_hash, "owner")?;
+	let output = Output::new(cell.capacity(), lock, cell.output.type_().to_opt(), cell.data.clone());
+
+	Ok(transaction::transfer_with(slice::from_ref(cell), slice::from_ref(owner), vec![output]))
+}
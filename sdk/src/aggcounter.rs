@@ -0,0 +1,14 @@
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::Cell;
+use crate::Error;
+
+// The cell data and creation are the same as the counter, which only differs in allowing many cells per transaction.
+pub use crate::counter::{create, Data};
+
+/// Build a transaction which transfers the counter cells to the lock and increments each value.
+pub fn transfer(cells: &[Cell], lock: Script) -> Result<TransactionView, Error>
+{
+	crate::counter::transfer_all(cells, lock)
+}
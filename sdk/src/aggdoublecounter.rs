@@ -0,0 +1,14 @@
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::Cell;
+use crate::Error;
+
+// The cell data and creation are the same as the double counter, which only differs in allowing many cells per transaction.
+pub use crate::doublecounter::{create, Data};
+
+/// Build a transaction which transfers the double counter cells to the lock and increments the values of each.
+pub fn transfer(cells: &[Cell], lock: Script) -> Result<TransactionView, Error>
+{
+	crate::doublecounter::transfer_all(cells, lock)
+}
@@ -0,0 +1,169 @@
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, check_minimum_length, read_u64, Encoding, Error};
+
+// Constants
+const ARGS_LEN: usize = 10; // Source (1) + Operator (1) + Operand (8)
+const ARGS_LEN_RANGE: usize = 18; // Source (1) + Operator (1) + Minimum (8) + Maximum (8)
+
+/// The cells which are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source
+{
+	Input = 0,
+	Output = 1,
+	GroupInput = 2,
+	GroupOutput = 3,
+}
+
+/// The comparison which the cell count must satisfy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition
+{
+	Equal(u64),
+	NotEqual(u64),
+	LessThan(u64),
+	LessThanOrEqual(u64),
+	GreaterThan(u64),
+	GreaterThanOrEqual(u64),
+	/// The count must be between the minimum and maximum, inclusive.
+	Range(u64, u64),
+}
+
+impl Condition
+{
+	/// The operator byte used in the args.
+	pub fn operator(&self) -> u8
+	{
+		match self
+		{
+			Self::Equal(_) => 0,
+			Self::NotEqual(_) => 1,
+			Self::LessThan(_) => 2,
+			Self::LessThanOrEqual(_) => 3,
+			Self::GreaterThan(_) => 4,
+			Self::GreaterThanOrEqual(_) => 5,
+			Self::Range(_, _) => 6,
+		}
+	}
+
+	/// Determine if the cell count satisfies the condition.
+	pub fn is_satisfied(&self, count: u64) -> bool
+	{
+		match *self
+		{
+			Self::Equal(operand) => count == operand,
+			Self::NotEqual(operand) => count != operand,
+			Self::LessThan(operand) => count < operand,
+			Self::LessThanOrEqual(operand) => count <= operand,
+			Self::GreaterThan(operand) => count > operand,
+			Self::GreaterThanOrEqual(operand) => count >= operand,
+			Self::Range(minimum, maximum) => count >= minimum && count <= maximum,
+		}
+	}
+}
+
+/// The script args, which hold the source of the cells to count and the condition the count must satisfy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Args
+{
+	pub source: Source,
+	pub condition: Condition,
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		let mut args = vec![self.source as u8, self.condition.operator()];
+		match self.condition
+		{
+			Condition::Range(minimum, maximum) =>
+			{
+				args.extend_from_slice(&minimum.to_le_bytes());
+				args.extend_from_slice(&maximum.to_le_bytes());
+			},
+			Condition::Equal(operand) | Condition::NotEqual(operand) | Condition::LessThan(operand) | Condition::LessThanOrEqual(operand)
+				| Condition::GreaterThan(operand) | Condition::GreaterThanOrEqual(operand) => args.extend_from_slice(&operand.to_le_bytes()),
+		}
+
+		args.into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_minimum_length(data, 2)?;
+		let source = match data[0]
+		{
+			0 => Source::Input,
+			1 => Source::Output,
+			2 => Source::GroupInput,
+			3 => Source::GroupOutput,
+			source => return Err(Error::InvalidValue(format!("unknown source {}", source))),
+		};
+		if data[1] > 6
+		{
+			return Err(Error::InvalidValue(format!("unknown operator {}", data[1])));
+		}
+
+		// The length of the args depends on the operator.
+		check_length(data, &[if data[1] == 6 { ARGS_LEN_RANGE } else { ARGS_LEN }])?;
+		let operand = read_u64(data, 2);
+		let condition = match data[1]
+		{
+			0 => Condition::Equal(operand),
+			1 => Condition::NotEqual(operand),
+			2 => Condition::LessThan(operand),
+			3 => Condition::LessThanOrEqual(operand),
+			4 => Condition::GreaterThan(operand),
+			5 => Condition::GreaterThanOrEqual(operand),
+			_ => Condition::Range(operand, read_u64(data, 10)),
+		};
+
+		Ok(Self { source, condition })
+	}
+}
+
+/// Verify the condition for the cells of the group, given the number of group input and output cells. Conditions on all
+/// inputs or outputs depend on the rest of the transaction, so they are not checked.
+fn check_group_count(type_script: &Script, input_count: u64, output_count: u64) -> Result<(), Error>
+{
+	let args = Args::decode(&type_script.args().raw_data())?;
+	let count = match args.source
+	{
+		Source::GroupInput => input_count,
+		Source::GroupOutput => output_count,
+		Source::Input | Source::Output => return Ok(()),
+	};
+	if !args.condition.is_satisfied(count)
+	{
+		return Err(Error::InvalidValue(format!("a count of {} does not satisfy the condition", count)));
+	}
+
+	Ok(())
+}
+
+/// Build a transaction which creates the number of cells with the type script.
+pub fn create(lock: Script, type_script: Script, count: u64) -> Result<TransactionView, Error>
+{
+	check_group_count(&type_script, 0, count)?;
+	let outputs = (0..count).map(|_| Output::occupied(lock.clone(), Some(type_script.clone()), Bytes::new())).collect();
+
+	Ok(transaction::create(outputs))
+}
+
+/// Build a transaction which consumes the cells, which must all have the same type script.
+pub fn burn(cells: &[Cell]) -> Result<TransactionView, Error>
+{
+	let type_script = cells.first().and_then(|cell| cell.output.type_().to_opt()).ok_or_else(|| Error::InvalidValue("no cells with a type script".to_string()))?;
+	if cells.iter().any(|cell| cell.output.type_().to_opt().as_ref() != Some(&type_script))
+	{
+		return Err(Error::InvalidValue("cells have different types".to_string()));
+	}
+	check_group_count(&type_script, cells.len() as u64, 0)?;
+
+	Ok(transaction::burn(cells))
+}
@@ -0,0 +1,86 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, check_lock_hash, read_bytes, sudt, Encoding, Error};
+
+// Constants
+const LOCK_HASH_PREFIX_LEN: usize = 20; // Number of bytes of a lock hash used to identify the receiver and sender.
+pub const WITHDRAW_LOCK_EPOCHS: u64 = 6; // The number of epochs the sender must wait before withdrawing.
+const SINCE_RELATIVE_EPOCH_FLAGS: u64 = 0xA000_0000_0000_0000; // Relative flag with the epoch metric flag.
+
+/// The script args, which hold the lock hash prefixes of the receiver, who can claim at any time, and the sender,
+/// who can withdraw after the lock period.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub receiver_lock_hash_prefix: [u8; LOCK_HASH_PREFIX_LEN],
+	pub sender_lock_hash_prefix: [u8; LOCK_HASH_PREFIX_LEN],
+}
+
+impl Args
+{
+	/// Create the args from the full lock hashes of the receiver and sender.
+	pub fn new(receiver_lock_hash: &[u8; 32], sender_lock_hash: &[u8; 32]) -> Self
+	{
+		Self { receiver_lock_hash_prefix: read_bytes(receiver_lock_hash, 0), sender_lock_hash_prefix: read_bytes(sender_lock_hash, 0) }
+	}
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		[self.receiver_lock_hash_prefix, self.sender_lock_hash_prefix].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[LOCK_HASH_PREFIX_LEN * 2])?;
+
+		Ok(Self { receiver_lock_hash_prefix: read_bytes(data, 0), sender_lock_hash_prefix: read_bytes(data, LOCK_HASH_PREFIX_LEN) })
+	}
+}
+
+/// The since value the sender must use on each cheque cell to withdraw once the lock period has passed.
+pub fn withdraw_since() -> u64
+{
+	SINCE_RELATIVE_EPOCH_FLAGS | WITHDRAW_LOCK_EPOCHS
+}
+
+/// Build a transaction which locks the capacity with the cheque lock, along with an amount of the token if a token type
+/// script is given.
+pub fn create(lock: Script, capacity: u64, token: Option<(Script, u128)>) -> TransactionView
+{
+	let output = match token
+	{
+		Some((type_script, amount)) => Output::new(capacity, lock, Some(type_script), sudt::Data { amount }.encode()),
+		None => Output::new(capacity, lock, None, Bytes::new()),
+	};
+
+	transaction::create(vec![output])
+}
+
+/// Build a transaction which claims the cell with the receiver cell, which is recreated unchanged, and transfers it to the lock.
+pub fn claim(cell: &Cell, receiver: &Cell, lock: Script) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	check_lock_hash(receiver, &args.receiver_lock_hash_prefix, "receiver")?;
+	let output = Output::new(cell.capacity(), lock, cell.output.type_().to_opt(), cell.data.clone());
+
+	Ok(transaction::transfer_with(slice::from_ref(cell), slice::from_ref(receiver), vec![output]))
+}
+
+/// Build a transaction which withdraws the cell with the sender cell, which is recreated unchanged, and transfers it to
+/// the lock. The cell uses the withdraw since, so the transaction cannot be committed until the lock period has passed.
+pub fn withdraw(cell: &Cell, sender: &Cell, lock: Script) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	check_lock_hash(sender, &args.sender_lock_hash_prefix, "sender")?;
+	let output = Output::new(cell.capacity(), lock, cell.output.type_().to_opt(), cell.data.clone());
+
+	Ok(transaction::transfer_with(&[cell.clone().with_since(withdraw_since())], slice::from_ref(sender), vec![output]))
+}
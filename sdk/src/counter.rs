@@ -0,0 +1,68 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_minimum_length, read_u64, Encoding, Error};
+
+// Constants
+const DATA_LEN: usize = 8; // The counter value is a u64, which is 8 bytes.
+
+/// The cell data, which holds the counter value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Data
+{
+	pub value: u64,
+}
+
+impl Data
+{
+	/// The data required after the next transfer, or None if the value would overflow.
+	pub fn next(&self) -> Option<Self>
+	{
+		Some(Self { value: self.value.checked_add(1)? })
+	}
+}
+
+impl Encoding for Data
+{
+	fn encode(&self) -> Bytes
+	{
+		Bytes::from(self.value.to_le_bytes().to_vec())
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_minimum_length(data, DATA_LEN)?;
+
+		Ok(Self { value: read_u64(data, 0) })
+	}
+}
+
+/// Build a transaction which creates a counter cell with the specified value.
+pub fn create(lock: Script, type_script: Script, data: &Data) -> TransactionView
+{
+	transaction::create(vec![Output::occupied(lock, Some(type_script), data.encode())])
+}
+
+/// Build a transaction which transfers a counter cell to the lock and increments the value.
+pub fn transfer(cell: &Cell, lock: Script) -> Result<TransactionView, Error>
+{
+	transfer_all(slice::from_ref(cell), lock)
+}
+
+/// Build a transaction which transfers every counter cell to the lock and increments each value.
+/// Only counters that accept more than one cell in a transaction, such as aggcounter, can use this.
+pub(crate) fn transfer_all(cells: &[Cell], lock: Script) -> Result<TransactionView, Error>
+{
+	let mut outputs = Vec::new();
+	for cell in cells
+	{
+		let data = Data::decode(&cell.data)?.next().ok_or_else(|| Error::InvalidValue("counter value overflow".to_string()))?;
+		outputs.push(Output::new(cell.capacity(), lock.clone(), cell.output.type_().to_opt(), data.encode()));
+	}
+
+	Ok(transaction::transfer(cells, outputs))
+}
@@ -0,0 +1,179 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::{Capacity, HeaderView, TransactionView};
+use ckb_types::packed::{Byte, Script};
+use ckb_types::prelude::*;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, check_minimum_length, read_bytes, read_u128, read_u64, script_hash, Encoding, Error};
+
+// Constants
+const TYPE_HASH_LEN: usize = 32; // Number of bytes for a type hash. (Blake2b 256-bit 32 bytes)
+const CODE_HASH_LEN: usize = 32; // Number of bytes for a code hash. (Blake2b 256-bit 32 bytes)
+const HASH_TYPE_LEN: usize = 1; // A hash type is a single byte.
+const RECEIPT_DATA_LEN: usize = 16; // The receipt amount is a u128, which is 16 bytes, the same as SUDT.
+const DAO_DATA_LEN: usize = 8; // Nervos DAO cells hold a u64, which is zero for a deposit or the deposit block number when withdrawing.
+
+/// The script args, which hold the Nervos DAO type hash and the code hash and hash type of the pool lock that holds the deposits.
+/// Deposits are locked by the pool lock with the hash of the receipt type script as its args.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub dao_type_hash: [u8; TYPE_HASH_LEN],
+	pub pool_lock_code_hash: [u8; CODE_HASH_LEN],
+	pub pool_lock_hash_type: u8,
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		[&self.dao_type_hash[..], &self.pool_lock_code_hash, &[self.pool_lock_hash_type]].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[TYPE_HASH_LEN + CODE_HASH_LEN + HASH_TYPE_LEN])?;
+
+		Ok(Self { dao_type_hash: read_bytes(data, 0), pool_lock_code_hash: read_bytes(data, TYPE_HASH_LEN), pool_lock_hash_type: data[TYPE_HASH_LEN + CODE_HASH_LEN] })
+	}
+}
+
+/// The cell data of a receipt, which holds the amount of capacity deposited in Shannons. Any bytes after the amount are ignored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Data
+{
+	pub amount: u128,
+}
+
+impl Encoding for Data
+{
+	fn encode(&self) -> Bytes
+	{
+		Bytes::from(self.amount.to_le_bytes().to_vec())
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_minimum_length(data, RECEIPT_DATA_LEN)?;
+
+		Ok(Self { amount: read_u128(data, 0) })
+	}
+}
+
+/// The pool lock which holds the deposits for the receipt type script, which has the args of this contract.
+pub fn pool_lock(receipt_type: &Script) -> Result<Script, Error>
+{
+	let args = Args::decode(&receipt_type.args().raw_data())?;
+
+	Ok(Script::new_builder()
+		.code_hash(args.pool_lock_code_hash.pack())
+		.hash_type(Byte::new(args.pool_lock_hash_type))
+		.args(script_hash(receipt_type).to_vec().pack())
+		.build())
+}
+
+/// Read the u64 value from the data of a Nervos DAO cell, which is the deposit block number once withdrawing.
+fn dao_data(cell: &Cell) -> Result<u64, Error>
+{
+	check_length(&cell.data, &[DAO_DATA_LEN])?;
+
+	Ok(read_u64(&cell.data, 0))
+}
+
+/// Extract the accumulated rate (AR) from the DAO field of a header.
+fn accumulated_rate(header: &HeaderView) -> u64
+{
+	read_u64(header.dao().as_slice(), 8)
+}
+
+/// Build a transaction which deposits the capacity into the Nervos DAO under the pool lock, and mints receipts for the
+/// capacity to the lock in the second output.
+pub fn deposit(receipt_type: Script, dao_type: Script, lock: Script, capacity: u64) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&receipt_type.args().raw_data())?;
+	if script_hash(&dao_type) != args.dao_type_hash
+	{
+		return Err(Error::InvalidValue("the DAO type does not match the args".to_string()));
+	}
+	let deposit = Output::new(capacity, pool_lock(&receipt_type)?, Some(dao_type), Bytes::from(vec![0u8; DAO_DATA_LEN]));
+	let receipt = Output::occupied(lock, Some(receipt_type), Data { amount: capacity as u128 }.encode());
+
+	Ok(transaction::create(vec![deposit, receipt]))
+}
+
+/// Build a transaction which starts the withdrawal of the deposit, the first phase of a Nervos DAO withdrawal, with the
+/// receipt cell, which is recreated unchanged to unlock the pool lock. The deposit header is added as a header dep.
+pub fn prepare_withdraw(cell: &Cell, receipt: &Cell, deposit_header: &HeaderView) -> Result<TransactionView, Error>
+{
+	if dao_data(cell)? != 0
+	{
+		return Err(Error::InvalidValue("the cell is not a deposit".to_string()));
+	}
+	let data = Bytes::from(deposit_header.number().to_le_bytes().to_vec());
+	let output = Output::new(cell.capacity(), cell.output.lock(), cell.output.type_().to_opt(), data);
+
+	Ok(transaction::transfer_with(slice::from_ref(cell), slice::from_ref(receipt), vec![output])
+		.as_advanced_builder()
+		.header_dep(deposit_header.hash())
+		.build())
+}
+
+/// Build a transaction which withdraws the withdrawing cell with interest to the lock in the first output, burning
+/// receipts for the principal. Any receipts left over are returned to the lock in the second output. The withdraw header
+/// is the header of the block the withdrawing cell was created in, and both headers are added as header deps.
+pub fn withdraw(cell: &Cell, receipts: &[Cell], deposit_header: &HeaderView, withdraw_header: &HeaderView, lock: Script) -> Result<TransactionView, Error>
+{
+	if dao_data(cell)? != deposit_header.number()
+	{
+		return Err(Error::InvalidValue("the deposit header does not match the cell".to_string()));
+	}
+
+	// Interest is earned on the capacity that is not occupied, using the ratio of the accumulated rates.
+	let occupied_capacity = cell.output.occupied_capacity(Capacity::bytes(cell.data.len()).expect("data capacity")).expect("occupied capacity").as_u64();
+	let deposit_ar = accumulated_rate(deposit_header) as u128;
+	let withdraw_ar = accumulated_rate(withdraw_header) as u128;
+	if deposit_ar == 0
+	{
+		return Err(Error::InvalidValue("the deposit header has no accumulated rate".to_string()));
+	}
+	let counted_capacity = cell.capacity().saturating_sub(occupied_capacity) as u128;
+	let withdraw_capacity = counted_capacity * withdraw_ar / deposit_ar + occupied_capacity as u128;
+
+	// The receipts must be for the pool lock of the cell and cover the principal.
+	let pool_lock_args = cell.output.lock().args().raw_data();
+	let mut receipt_amount = 0u128;
+	let mut receipt_capacity = 0u128;
+	let mut receipt_type = None;
+	for receipt in receipts
+	{
+		let type_script = receipt.output.type_().to_opt().filter(|type_script| script_hash(type_script)[..] == pool_lock_args[..])
+			.ok_or_else(|| Error::InvalidValue("the receipt type does not match the pool lock".to_string()))?;
+		receipt_amount = receipt_amount.checked_add(Data::decode(&receipt.data)?.amount).ok_or_else(|| Error::InvalidValue("receipt amount overflow".to_string()))?;
+		receipt_capacity += receipt.capacity() as u128;
+		receipt_type = Some(type_script);
+	}
+	let principal = cell.capacity() as u128;
+	if receipt_amount < principal
+	{
+		return Err(Error::InsufficientAmount(principal - receipt_amount));
+	}
+
+	// Receipts left over are returned, so the payout holds the receipt capacity which is not needed to hold them.
+	let returned = receipt_type.filter(|_| receipt_amount > principal)
+		.map(|type_script| Output::occupied(lock.clone(), Some(type_script), Data { amount: receipt_amount - principal }.encode()));
+	let returned_capacity = returned.as_ref().map_or(0, |output| output.capacity() as u128);
+	let capacity = (withdraw_capacity + receipt_capacity).checked_sub(returned_capacity)
+		.and_then(|capacity| u64::try_from(capacity).ok())
+		.ok_or_else(|| Error::InvalidValue("the receipts cannot hold the returned receipts".to_string()))?;
+	let mut outputs = vec![Output::new(capacity, lock, None, Bytes::new())];
+	outputs.extend(returned);
+	let inputs: Vec<Cell> = std::iter::once(cell).chain(receipts).cloned().collect();
+
+	Ok(transaction::transfer(&inputs, outputs)
+		.as_advanced_builder()
+		.header_dep(deposit_header.hash())
+		.header_dep(withdraw_header.hash())
+		.build())
+}
@@ -0,0 +1,87 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{read_u32, Encoding, Error};
+
+// Constants
+pub const MAX_DATA_SIZE: usize = 10; // The data size limit when no args are given.
+
+/// The script args, which optionally override the data size limit with a u32 value in the same format as datacap. Args
+/// of any other length are ignored by the contract, so they decode to the default limit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub limit: Option<u32>,
+}
+
+impl Args
+{
+	/// The maximum number of bytes of data allowed in each cell.
+	pub fn limit(&self) -> usize
+	{
+		self.limit.map_or(MAX_DATA_SIZE, |limit| limit as usize)
+	}
+
+	/// Determine if the contract accepts the data in an output cell.
+	pub fn accepts(&self, data: &[u8]) -> bool
+	{
+		data.len() <= self.limit()
+	}
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		match self.limit
+		{
+			Some(limit) => Bytes::from(limit.to_le_bytes().to_vec()),
+			None => Bytes::new(),
+		}
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		Ok(Self { limit: (data.len() == 4).then(|| read_u32(data, 0)) })
+	}
+}
+
+/// Verify that the data is within the limit of the type script, which is the default limit unless the args override it.
+fn check_data(type_script: &Script, data: &[u8]) -> Result<(), Error>
+{
+	let args = Args::decode(&type_script.args().raw_data())?;
+	if !args.accepts(data)
+	{
+		return Err(Error::InvalidLength(data.len()));
+	}
+
+	Ok(())
+}
+
+/// Build a transaction which creates a cell with the data, which must be within the limit of the type script.
+pub fn create(lock: Script, type_script: Script, data: Bytes) -> Result<TransactionView, Error>
+{
+	check_data(&type_script, &data)?;
+
+	Ok(transaction::create(vec![Output::occupied(lock, Some(type_script), data)]))
+}
+
+/// Build a transaction which transfers a cell to the lock and replaces the data, which must be within the limit.
+pub fn transfer(cell: &Cell, lock: Script, data: Bytes) -> Result<TransactionView, Error>
+{
+	let type_script = cell.output.type_().to_opt().ok_or_else(|| Error::InvalidValue("cell has no type script".to_string()))?;
+	check_data(&type_script, &data)?;
+	let output = Output::new(cell.capacity(), lock, Some(type_script), data);
+
+	Ok(transaction::transfer(slice::from_ref(cell), vec![output]))
+}
+
+/// Build a transaction which consumes a cell. Data limits only apply to output cells.
+pub fn burn(cell: &Cell) -> TransactionView
+{
+	transaction::burn(slice::from_ref(cell))
+}
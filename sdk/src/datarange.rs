@@ -0,0 +1,79 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, read_u32, Encoding, Error};
+
+// Constants
+const ARGS_LEN: usize = 8; // The minimum and the limit are u32 values, which are 4 bytes each.
+
+/// The script args, which hold the minimum and maximum number of bytes of data allowed in each cell.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub minimum: u32,
+	pub limit: u32,
+}
+
+impl Args
+{
+	/// Determine if the contract accepts the data in an output cell.
+	pub fn accepts(&self, data: &[u8]) -> bool
+	{
+		data.len() as u64 >= self.minimum as u64 && data.len() as u64 <= self.limit as u64
+	}
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		[self.minimum.to_le_bytes(), self.limit.to_le_bytes()].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[ARGS_LEN])?;
+
+		Ok(Self { minimum: read_u32(data, 0), limit: read_u32(data, 4) })
+	}
+}
+
+/// Verify that the data is within the range given in the args of the type script.
+fn check_data(type_script: &Script, data: &[u8]) -> Result<(), Error>
+{
+	let args = Args::decode(&type_script.args().raw_data())?;
+	if !args.accepts(data)
+	{
+		return Err(Error::InvalidLength(data.len()));
+	}
+
+	Ok(())
+}
+
+/// Build a transaction which creates a cell with the data, which must be within the range given in the args of the type script.
+pub fn create(lock: Script, type_script: Script, data: Bytes) -> Result<TransactionView, Error>
+{
+	check_data(&type_script, &data)?;
+
+	Ok(transaction::create(vec![Output::occupied(lock, Some(type_script), data)]))
+}
+
+/// Build a transaction which transfers a cell to the lock and replaces the data, which must be within the range.
+pub fn transfer(cell: &Cell, lock: Script, data: Bytes) -> Result<TransactionView, Error>
+{
+	let type_script = cell.output.type_().to_opt().ok_or_else(|| Error::InvalidValue("cell has no type script".to_string()))?;
+	check_data(&type_script, &data)?;
+	let output = Output::new(cell.capacity(), lock, Some(type_script), data);
+
+	Ok(transaction::transfer(slice::from_ref(cell), vec![output]))
+}
+
+/// Build a transaction which consumes a cell. Data limits only apply to output cells.
+pub fn burn(cell: &Cell) -> TransactionView
+{
+	transaction::burn(slice::from_ref(cell))
+}
@@ -0,0 +1,126 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, read_u32, Encoding, Error};
+
+// Constants
+const SHANNONS_PER_CKBYTE: u64 = 100_000_000; // One CKByte is 100,000,000 Shannons.
+const RATIO_LEN: usize = 4; // The bytes per CKByte value is a u32, which is 4 bytes.
+const MODE_LEN: usize = 1; // The optional mode is a single byte.
+
+/// How the data is limited by the free capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode
+{
+	/// Each cell is limited by its own free capacity.
+	PerCell = 0,
+	/// The total data of the group is limited by the total free capacity of the group.
+	Aggregate = 1,
+}
+
+/// The script args, which hold the number of bytes allowed per CKByte of free capacity, optionally followed by the mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Args
+{
+	pub bytes_per_ckbyte: u32,
+	pub mode: Option<Mode>,
+}
+
+impl Args
+{
+	/// The mode used by the contract, which is per cell by default.
+	pub fn mode(&self) -> Mode
+	{
+		self.mode.unwrap_or(Mode::PerCell)
+	}
+
+	/// The maximum number of bytes of data allowed for the specified free capacity in Shannons.
+	pub fn limit(&self, free_capacity: u64) -> u64
+	{
+		(free_capacity / SHANNONS_PER_CKBYTE).saturating_mul(self.bytes_per_ckbyte as u64)
+	}
+
+	/// The minimum free capacity in Shannons required for the number of bytes of data, rounded up to whole CKBytes.
+	/// Returns None if no data is allowed or the capacity would overflow.
+	pub fn free_capacity(&self, data_len: usize) -> Option<u64>
+	{
+		let data_len = data_len as u64;
+		let bytes_per_ckbyte = self.bytes_per_ckbyte as u64;
+		if data_len == 0
+		{
+			return Some(0);
+		}
+		if bytes_per_ckbyte == 0
+		{
+			return None;
+		}
+
+		((data_len + bytes_per_ckbyte - 1) / bytes_per_ckbyte).checked_mul(SHANNONS_PER_CKBYTE)
+	}
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		let mut args = self.bytes_per_ckbyte.to_le_bytes().to_vec();
+		if let Some(mode) = self.mode
+		{
+			args.push(mode as u8);
+		}
+
+		args.into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[RATIO_LEN, RATIO_LEN + MODE_LEN])?;
+		let mode = match data.get(RATIO_LEN)
+		{
+			None => None,
+			Some(0) => Some(Mode::PerCell),
+			Some(1) => Some(Mode::Aggregate),
+			Some(mode) => return Err(Error::InvalidValue(format!("unknown mode {}", mode))),
+		};
+
+		Ok(Self { bytes_per_ckbyte: read_u32(data, 0), mode })
+	}
+}
+
+/// Build a transaction which creates a cell with the data, with enough free capacity for the data under the ratio given
+/// in the args of the type script. A cell with enough free capacity on its own also meets the aggregate limit.
+pub fn create(lock: Script, type_script: Script, data: Bytes) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&type_script.args().raw_data())?;
+	let free_capacity = args.free_capacity(data.len()).ok_or(Error::InvalidLength(data.len()))?;
+	let occupied = Output::occupied(lock.clone(), Some(type_script.clone()), data.clone()).capacity();
+	let capacity = occupied.checked_add(free_capacity).ok_or_else(|| Error::InvalidValue("capacity overflow".to_string()))?;
+
+	Ok(transaction::create(vec![Output::new(capacity, lock, Some(type_script), data)]))
+}
+
+/// Build a transaction which transfers a cell to the lock and replaces the data, which must fit in the free capacity of
+/// the cell under the ratio.
+pub fn transfer(cell: &Cell, lock: Script, data: Bytes) -> Result<TransactionView, Error>
+{
+	let type_script = cell.output.type_().to_opt().ok_or_else(|| Error::InvalidValue("cell has no type script".to_string()))?;
+	let args = Args::decode(&type_script.args().raw_data())?;
+	let occupied = Output::occupied(lock.clone(), Some(type_script.clone()), data.clone()).capacity();
+	if data.len() as u64 > args.limit(cell.capacity().saturating_sub(occupied))
+	{
+		return Err(Error::InvalidLength(data.len()));
+	}
+	let output = Output::new(cell.capacity(), lock, Some(type_script), data);
+
+	Ok(transaction::transfer(slice::from_ref(cell), vec![output]))
+}
+
+/// Build a transaction which consumes a cell. Data limits only apply to output cells.
+pub fn burn(cell: &Cell) -> TransactionView
+{
+	transaction::burn(slice::from_ref(cell))
+}
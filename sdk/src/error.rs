@@ -0,0 +1,25 @@
+use std::fmt;
+
+/// Error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error
+{
+	InvalidLength(usize),
+	InvalidValue(String),
+	InsufficientAmount(u128),
+}
+
+impl fmt::Display for Error
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			Self::InvalidLength(length) => write!(f, "invalid length: {} bytes", length),
+			Self::InvalidValue(message) => write!(f, "invalid value: {}", message),
+			Self::InsufficientAmount(required) => write!(f, "insufficient amount: {} more tokens are required", required),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
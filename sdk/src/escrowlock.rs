@@ -0,0 +1,104 @@
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, read_bytes, read_u64, script_hash, Encoding, Error};
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const SINCE_LEN: usize = 8; // A since value is a u64, which is 8 bytes.
+const ARGS_LEN: usize = LOCK_HASH_LEN * 4 + SINCE_LEN; // The buyer, seller, arbiter, and release lock hashes followed by the timeout since.
+
+/// The script args. Any two of the buyer, seller, and arbiter can release the funds to the release lock, otherwise
+/// the funds can only be refunded to the buyer with a since that meets the timeout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub buyer_lock_hash: [u8; LOCK_HASH_LEN],
+	pub seller_lock_hash: [u8; LOCK_HASH_LEN],
+	pub arbiter_lock_hash: [u8; LOCK_HASH_LEN],
+	pub release_lock_hash: [u8; LOCK_HASH_LEN],
+	pub timeout: u64,
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		[&self.buyer_lock_hash[..], &self.seller_lock_hash, &self.arbiter_lock_hash, &self.release_lock_hash, &self.timeout.to_le_bytes()].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[ARGS_LEN])?;
+
+		Ok(Self
+		{
+			buyer_lock_hash: read_bytes(data, 0),
+			seller_lock_hash: read_bytes(data, LOCK_HASH_LEN),
+			arbiter_lock_hash: read_bytes(data, LOCK_HASH_LEN * 2),
+			release_lock_hash: read_bytes(data, LOCK_HASH_LEN * 3),
+			timeout: read_u64(data, LOCK_HASH_LEN * 4),
+		})
+	}
+}
+
+/// Build a transaction which locks the capacity with the escrow lock.
+pub fn create(lock: Script, capacity: u64) -> TransactionView
+{
+	transaction::create(vec![Output::new(capacity, lock, None, Bytes::new())])
+}
+
+/// Create the payout of each escrow cell to the lock, which must match the lock hash selected from the args of the cell.
+/// Each payout is at the same index as its escrow cell, which is where the contract looks for it.
+fn payouts(cells: &[Cell], lock: &Script, lock_hash: impl Fn(&Args) -> [u8; 32]) -> Result<Vec<Output>, Error>
+{
+	let mut outputs = Vec::new();
+	for cell in cells
+	{
+		let args = Args::decode(&cell.output.lock().args().raw_data())?;
+		if script_hash(lock) != lock_hash(&args)
+		{
+			return Err(Error::InvalidValue("the payout lock does not match the args".to_string()));
+		}
+		outputs.push(Output::new(cell.capacity(), lock.clone(), None, Bytes::new()));
+	}
+
+	Ok(outputs)
+}
+
+/// Build a transaction which releases the escrow cells to the release lock. The cells of at least two of the buyer, seller,
+/// and arbiter must approve, and are recreated unchanged.
+pub fn release(cells: &[Cell], approvers: &[Cell], lock: Script) -> Result<TransactionView, Error>
+{
+	for cell in cells
+	{
+		let args = Args::decode(&cell.output.lock().args().raw_data())?;
+		let approvals = [args.buyer_lock_hash, args.seller_lock_hash, args.arbiter_lock_hash].iter()
+			.filter(|lock_hash| approvers.iter().any(|approver| script_hash(&approver.output.lock()) == **lock_hash))
+			.count();
+		if approvals < 2
+		{
+			return Err(Error::InvalidValue("at least two parties must approve".to_string()));
+		}
+	}
+	let outputs = payouts(cells, &lock, |args| args.release_lock_hash)?;
+
+	Ok(transaction::transfer_with(cells, approvers, outputs))
+}
+
+/// Build a transaction which refunds the escrow cells to the buyer lock. Each cell uses the timeout from its args as its
+/// since, so the transaction cannot be committed before the timeout.
+pub fn refund(cells: &[Cell], lock: Script) -> Result<TransactionView, Error>
+{
+	let outputs = payouts(cells, &lock, |args| args.buyer_lock_hash)?;
+	let mut inputs = Vec::new();
+	for cell in cells
+	{
+		let args = Args::decode(&cell.output.lock().args().raw_data())?;
+		inputs.push(cell.clone().with_since(args.timeout));
+	}
+
+	Ok(transaction::transfer(&inputs, outputs))
+}
@@ -0,0 +1,69 @@
+use std::slice;
+
+use blake2b_ref::Blake2bBuilder;
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+use ckb_types::prelude::*;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_minimum_length, read_bytes, Encoding, Error};
+
+// Constants
+const HASH_LEN: usize = 32; // Number of bytes for a Blake2b 256-bit hash.
+pub const WITNESS_SIZE_LIMIT: usize = 1024; // The largest preimage the contract can load from the witness.
+
+/// The script args, which hold the hash of the preimage required to unlock. Any bytes after the hash are ignored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub hash: [u8; HASH_LEN],
+}
+
+impl Args
+{
+	/// Create the args for a preimage. The contract uses Blake2b without the CKB personalization.
+	pub fn from_preimage(preimage: &[u8]) -> Self
+	{
+		let mut hash = [0u8; HASH_LEN];
+		let mut blake2b = Blake2bBuilder::new(HASH_LEN).build();
+		blake2b.update(preimage);
+		blake2b.finalize(&mut hash);
+
+		Self { hash }
+	}
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		Bytes::from(self.hash.to_vec())
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_minimum_length(data, HASH_LEN)?;
+
+		Ok(Self { hash: read_bytes(data, 0) })
+	}
+}
+
+/// Build a transaction which locks the capacity with the hash lock.
+pub fn create(lock: Script, capacity: u64) -> TransactionView
+{
+	transaction::create(vec![Output::new(capacity, lock, None, Bytes::new())])
+}
+
+/// Build a transaction which unlocks the cell with the preimage and transfers it to the lock.
+/// The preimage is placed in the first witness, which is where the contract reads it from.
+pub fn transfer(cell: &Cell, lock: Script, preimage: Bytes) -> Result<TransactionView, Error>
+{
+	if preimage.len() > WITNESS_SIZE_LIMIT
+	{
+		return Err(Error::InvalidLength(preimage.len()));
+	}
+	let output = Output::new(cell.capacity(), lock, cell.output.type_().to_opt(), cell.data.clone());
+
+	Ok(transaction::transfer(slice::from_ref(cell), vec![output]).as_advanced_builder().witness(preimage.pack()).build())
+}
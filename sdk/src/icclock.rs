@@ -0,0 +1,53 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, read_u64, Encoding, Error};
+
+// Constants
+const ARGS_LEN: usize = 8; // The capacity amount is a u64, which is 8 bytes.
+
+/// The script args, which hold the exact capacity in Shannons that an input cell must have to unlock.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub amount: u64,
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		Bytes::from(self.amount.to_le_bytes().to_vec())
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[ARGS_LEN])?;
+
+		Ok(Self { amount: read_u64(data, 0) })
+	}
+}
+
+/// Build a transaction which locks the capacity with the input capacity count lock.
+pub fn create(lock: Script, capacity: u64) -> TransactionView
+{
+	transaction::create(vec![Output::new(capacity, lock, None, Bytes::new())])
+}
+
+/// Build a transaction which unlocks the cell and transfers it to the lock. An input with exactly the capacity given in
+/// the args is required, which is the cell itself if it has that capacity, otherwise the key cell, which is recreated unchanged.
+pub fn transfer(cell: &Cell, key: Option<&Cell>, lock: Script) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	let output = Output::new(cell.capacity(), lock, cell.output.type_().to_opt(), cell.data.clone());
+	match key
+	{
+		None if cell.capacity() == args.amount => Ok(transaction::transfer(slice::from_ref(cell), vec![output])),
+		Some(key) if key.capacity() == args.amount => Ok(transaction::transfer_with(slice::from_ref(cell), slice::from_ref(key), vec![output])),
+		_ => Err(Error::InvalidValue(format!("an input with a capacity of {} is required", args.amount))),
+	}
+}
@@ -0,0 +1,51 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{Encoding, Error};
+
+/// The cell data, which must be a UTF-8 JSON document.
+/// Decoding only checks the UTF-8 encoding. The JSON itself is validated by the contract.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Data
+{
+	pub json: String,
+}
+
+impl Encoding for Data
+{
+	fn encode(&self) -> Bytes
+	{
+		Bytes::from(self.json.clone().into_bytes())
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		let json = String::from_utf8(data.to_vec()).map_err(|err| Error::InvalidValue(err.to_string()))?;
+
+		Ok(Self { json })
+	}
+}
+
+/// Build a transaction which creates a cell with the JSON document.
+pub fn create(lock: Script, type_script: Script, data: &Data) -> TransactionView
+{
+	transaction::create(vec![Output::occupied(lock, Some(type_script), data.encode())])
+}
+
+/// Build a transaction which transfers a cell to the lock and replaces the JSON document.
+pub fn transfer(cell: &Cell, lock: Script, data: &Data) -> TransactionView
+{
+	let output = Output::new(cell.capacity(), lock, cell.output.type_().to_opt(), data.encode());
+
+	transaction::transfer(slice::from_ref(cell), vec![output])
+}
+
+/// Build a transaction which consumes a cell. Only output cells must hold a JSON document.
+pub fn burn(cell: &Cell) -> TransactionView
+{
+	transaction::burn(slice::from_ref(cell))
+}
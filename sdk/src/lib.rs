@@ -0,0 +1,130 @@
+//! Typed args and cell data for the example contracts, with builders for the transactions which use them.
+//!
+//! Each contract with args or cell data has a module with `Args` and `Data` structs that encode to and decode from the
+//! same bytes the contract reads on-chain. Decoding applies the same length rules as the contract. Contracts that have
+//! neither, such as `always`, `never`, and `ckb500`, are used with the builders in `transaction`.
+//!
+//! Each module also has builders for the transactions the contract accepts. The builders check the same rules as the
+//! contract and return an error rather than build a transaction which would be rejected. Cells of parties which authorize
+//! a transaction, such as the owner of a proxy lock, are passed to the builder and recreated unchanged after the other
+//! outputs, using `transaction::transfer_with`.
+//!
+//! The transactions returned by the builders are not balanced. Inputs to pay for the outputs and the fee, and the cell
+//! deps of the scripts, must be added before the transaction is signed.
+
+use ckb_types::bytes::Bytes;
+use ckb_types::packed::Script;
+use ckb_types::prelude::*;
+use ckb_types::H256;
+
+pub mod error;
+pub mod transaction;
+
+pub mod acplock;
+pub mod aggcounter;
+pub mod aggdoublecounter;
+pub mod cellcount;
+pub mod chequelock;
+pub mod counter;
+pub mod daowrapper;
+pub mod data10;
+pub mod datacap;
+pub mod datarange;
+pub mod dataratio;
+pub mod doublecounter;
+pub mod escrowlock;
+pub mod hashlock;
+pub mod icclock;
+pub mod jsoncell;
+pub mod lockproxylock;
+pub mod nft;
+pub mod occlock;
+pub mod odcounter;
+pub mod oddoublecounter;
+pub mod oracle;
+pub mod oraclelock;
+pub mod orderlock;
+pub mod ratelimitlock;
+pub mod sudt;
+pub mod timelock;
+pub mod typeid;
+pub mod typeproxylock;
+pub mod vestinglock;
+
+pub use error::Error;
+pub use transaction::{Cell, Output};
+
+/// A value which is stored in script args or cell data.
+pub trait Encoding: Sized
+{
+	/// Encode the value to the bytes read by the contract.
+	fn encode(&self) -> Bytes;
+
+	/// Decode the value, returning an error if the contract would not accept the bytes.
+	fn decode(data: &[u8]) -> Result<Self, Error>;
+}
+
+/// The hash of a script, as used in the args of contracts which reference another lock or type.
+pub fn script_hash(script: &Script) -> [u8; 32]
+{
+	let hash: H256 = script.calc_script_hash().unpack();
+
+	hash.0
+}
+
+/// Verify that the lock hash of the cell starts with the lock hash, or the lock hash prefix, from the args of a contract.
+pub(crate) fn check_lock_hash(cell: &Cell, lock_hash: &[u8], party: &str) -> Result<(), Error>
+{
+	match script_hash(&cell.output.lock()).starts_with(lock_hash)
+	{
+		true => Ok(()),
+		false => Err(Error::InvalidValue(format!("the {} lock does not match the args", party))),
+	}
+}
+
+/// Verify that the length of the data is one of the lengths accepted by the contract.
+pub(crate) fn check_length(data: &[u8], lengths: &[usize]) -> Result<(), Error>
+{
+	match lengths.contains(&data.len())
+	{
+		true => Ok(()),
+		false => Err(Error::InvalidLength(data.len())),
+	}
+}
+
+/// Verify that the data is at least the minimum length read by the contract. Any bytes after it are ignored.
+pub(crate) fn check_minimum_length(data: &[u8], minimum: usize) -> Result<(), Error>
+{
+	match data.len() >= minimum
+	{
+		true => Ok(()),
+		false => Err(Error::InvalidLength(data.len())),
+	}
+}
+
+/// Read a u32 LE value from the data at the specified offset.
+pub(crate) fn read_u32(data: &[u8], offset: usize) -> u32
+{
+	u32::from_le_bytes(read_bytes(data, offset))
+}
+
+/// Read a u64 LE value from the data at the specified offset.
+pub(crate) fn read_u64(data: &[u8], offset: usize) -> u64
+{
+	u64::from_le_bytes(read_bytes(data, offset))
+}
+
+/// Read a u128 LE value from the data at the specified offset.
+pub(crate) fn read_u128(data: &[u8], offset: usize) -> u128
+{
+	u128::from_le_bytes(read_bytes(data, offset))
+}
+
+/// Read a fixed number of bytes, such as a hash, from the data at the specified offset.
+pub(crate) fn read_bytes<const N: usize>(data: &[u8], offset: usize) -> [u8; N]
+{
+	let mut buffer = [0u8; N];
+	buffer.copy_from_slice(&data[offset..offset+N]);
+
+	buffer
+}
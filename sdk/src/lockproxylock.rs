@@ -0,0 +1,49 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, check_lock_hash, read_bytes, Encoding, Error};
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+
+/// The script args, which hold the hash of the owner lock that must be present in the inputs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub owner_lock_hash: [u8; LOCK_HASH_LEN],
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		Bytes::from(self.owner_lock_hash.to_vec())
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[LOCK_HASH_LEN])?;
+
+		Ok(Self { owner_lock_hash: read_bytes(data, 0) })
+	}
+}
+
+/// Build a transaction which locks the capacity with the lock proxy lock.
+pub fn create(lock: Script, capacity: u64) -> TransactionView
+{
+	transaction::create(vec![Output::new(capacity, lock, None, Bytes::new())])
+}
+
+/// Build a transaction which unlocks the cell with the owner cell, which is recreated unchanged, and transfers it to the lock.
+pub fn transfer(cell: &Cell, owner: &Cell, lock: Script) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	check_lock_hash(owner, &args.owner_lock_hash, "owner")?;
+	let output = Output::new(cell.capacity(), lock, cell.output.type_().to_opt(), cell.data.clone());
+
+	Ok(transaction::transfer_with(slice::from_ref(cell), slice::from_ref(owner), vec![output]))
+}
@@ -0,0 +1,193 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::{CellInput, Script};
+use ckb_types::prelude::*;
+
+use crate::transaction::{self, Cell, Output};
+use crate::typeid::calculate_id;
+use crate::{check_length, read_bytes, read_u64, script_hash, Encoding, Error};
+
+// Constants
+const NFT_ID_LEN: usize = 32; // Number of bytes for an NFT ID. (Blake2b 256-bit 32 bytes)
+const CLASS_TYPE_HASH_LEN: usize = 32; // Number of bytes for the type hash of a class cell.
+const CLASS_ID_LEN: usize = 32; // Number of bytes for a class ID. (Blake2b 256-bit 32 bytes)
+const CLASS_DATA_LEN: usize = 16; // A class cell holds two u64 values: the maximum supply and the issued count.
+pub const CLASS_MODE: u8 = 1; // The last byte of the args of a class cell.
+
+/// The script args, which hold the NFT ID, optionally followed by the type hash of the class cell that limits the supply.
+/// The cell data is the content of the NFT, which can be anything but cannot change after it is created.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub nft_id: [u8; NFT_ID_LEN],
+	pub class_type_hash: Option<[u8; CLASS_TYPE_HASH_LEN]>,
+}
+
+impl Args
+{
+	/// Create the args for a new NFT from the first input of the transaction and the index of the output cell.
+	/// The ID is calculated in the same way as a Type ID.
+	pub fn new(first_input: &CellInput, output_index: u64, class_type_hash: Option<[u8; CLASS_TYPE_HASH_LEN]>) -> Self
+	{
+		Self { nft_id: calculate_id(first_input, output_index), class_type_hash }
+	}
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		let mut args = self.nft_id.to_vec();
+		if let Some(class_type_hash) = self.class_type_hash
+		{
+			args.extend_from_slice(&class_type_hash);
+		}
+
+		args.into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[NFT_ID_LEN, NFT_ID_LEN + CLASS_TYPE_HASH_LEN])?;
+		let class_type_hash = (data.len() > NFT_ID_LEN).then(|| read_bytes(data, NFT_ID_LEN));
+
+		Ok(Self { nft_id: read_bytes(data, 0), class_type_hash })
+	}
+}
+
+/// The script args of a class cell, which hold the class ID followed by the class mode byte. A class cell uses the same
+/// contract as its NFTs, and its type hash is the class type hash in their args.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClassArgs
+{
+	pub class_id: [u8; CLASS_ID_LEN],
+}
+
+impl ClassArgs
+{
+	/// Create the args for a new class cell from the first input of the transaction and the index of the output cell.
+	/// The ID is calculated in the same way as a Type ID.
+	pub fn new(first_input: &CellInput, output_index: u64) -> Self
+	{
+		Self { class_id: calculate_id(first_input, output_index) }
+	}
+}
+
+impl Encoding for ClassArgs
+{
+	fn encode(&self) -> Bytes
+	{
+		[&self.class_id[..], &[CLASS_MODE]].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[CLASS_ID_LEN + 1])?;
+		if data[CLASS_ID_LEN] != CLASS_MODE
+		{
+			return Err(Error::InvalidValue(format!("unknown class mode {}", data[CLASS_ID_LEN])));
+		}
+
+		Ok(Self { class_id: read_bytes(data, 0) })
+	}
+}
+
+/// The cell data of a class cell, which holds the maximum supply and the number of NFTs issued.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClassData
+{
+	pub max_supply: u64,
+	pub issued: u64,
+}
+
+impl ClassData
+{
+	/// The class data after issuing the number of NFTs, or None if the maximum supply would be exceeded.
+	pub fn issue(&self, count: u64) -> Option<Self>
+	{
+		let issued = self.issued.checked_add(count).filter(|issued| *issued <= self.max_supply)?;
+
+		Some(Self { issued, ..*self })
+	}
+}
+
+impl Encoding for ClassData
+{
+	fn encode(&self) -> Bytes
+	{
+		[self.max_supply.to_le_bytes(), self.issued.to_le_bytes()].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[CLASS_DATA_LEN])?;
+
+		Ok(Self { max_supply: read_u64(data, 0), issued: read_u64(data, 8) })
+	}
+}
+
+/// Build a transaction which creates a class cell with the maximum supply, using the first cell as the first input, which
+/// is recreated unchanged. The code is a script with the code hash and hash type of the contract, and its args are
+/// replaced with the args of the class.
+pub fn create_class(code: &Script, first: &Cell, lock: Script, max_supply: u64) -> TransactionView
+{
+	let type_script = code.clone().as_builder().args(ClassArgs::new(&first.input(), 0).encode().pack()).build();
+	let data = ClassData { max_supply, issued: 0 };
+
+	transaction::transfer_with(&[], slice::from_ref(first), vec![Output::occupied(lock, Some(type_script), data.encode())])
+}
+
+/// Build a transaction which creates an NFT for each of the contents, using the first cell as the first input, which is
+/// recreated unchanged. The code is a script with the code hash and hash type of the contract, and its args are replaced
+/// with the args of each NFT. If a class cell is given then the NFTs belong to the class, and the class cell is updated
+/// after the NFTs with the number issued. The first cell cannot be the class cell.
+pub fn create(code: &Script, first: &Cell, lock: Script, contents: &[Bytes], class: Option<&Cell>) -> Result<TransactionView, Error>
+{
+	let first_input = first.input();
+	let class_type_hash = match class
+	{
+		Some(class) =>
+		{
+			let type_script = class.output.type_().to_opt().ok_or_else(|| Error::InvalidValue("class cell has no type script".to_string()))?;
+			ClassArgs::decode(&type_script.args().raw_data())?;
+			Some(script_hash(&type_script))
+		},
+		None => None,
+	};
+
+	let mut outputs: Vec<Output> = contents.iter().enumerate().map(|(i, content)|
+	{
+		let type_script = code.clone().as_builder().args(Args::new(&first_input, i as u64, class_type_hash).encode().pack()).build();
+		Output::occupied(lock.clone(), Some(type_script), content.clone())
+	}).collect();
+
+	// The class cell is consumed after the first cell and updated after the NFTs, and the first cell is recreated last.
+	let mut inputs = vec![first.clone()];
+	if let Some(class) = class
+	{
+		let data = ClassData::decode(&class.data)?.issue(contents.len() as u64)
+			.ok_or_else(|| Error::InvalidValue("the maximum supply of the class would be exceeded".to_string()))?;
+		outputs.push(Output::new(class.capacity(), class.output.lock(), class.output.type_().to_opt(), data.encode()));
+		inputs.push(class.clone());
+	}
+	outputs.push(Output::from_cell(first));
+
+	Ok(transaction::transfer(&inputs, outputs))
+}
+
+/// Build a transaction which transfers the NFT to the lock. The content cannot change.
+pub fn transfer(cell: &Cell, lock: Script) -> Result<TransactionView, Error>
+{
+	let type_script = cell.output.type_().to_opt().ok_or_else(|| Error::InvalidValue("cell has no type script".to_string()))?;
+	Args::decode(&type_script.args().raw_data())?;
+
+	Ok(transaction::transfer(slice::from_ref(cell), vec![Output::new(cell.capacity(), lock, Some(type_script), cell.data.clone())]))
+}
+
+/// Build a transaction which burns the NFT or class cell.
+pub fn burn(cell: &Cell) -> TransactionView
+{
+	transaction::burn(slice::from_ref(cell))
+}
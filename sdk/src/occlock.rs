@@ -0,0 +1,51 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_minimum_length, read_u64, Encoding, Error};
+
+// Constants
+const ARGS_LEN: usize = 16; // The capacity amount and the count are u64 values, which are 8 bytes each.
+
+/// The script args, which hold the capacity in Shannons and the number of output cells that must have exactly
+/// that capacity to unlock. Any bytes after the count are ignored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub amount: u64,
+	pub count: u64,
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		[self.amount.to_le_bytes(), self.count.to_le_bytes()].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_minimum_length(data, ARGS_LEN)?;
+
+		Ok(Self { amount: read_u64(data, 0), count: read_u64(data, 8) })
+	}
+}
+
+/// Build a transaction which locks the capacity with the output capacity count lock.
+pub fn create(lock: Script, capacity: u64) -> TransactionView
+{
+	transaction::create(vec![Output::new(capacity, lock, None, Bytes::new())])
+}
+
+/// Build a transaction which unlocks the cell by creating the required number of outputs for the lock,
+/// each with exactly the capacity given in the args of the lock of the cell.
+pub fn transfer(cell: &Cell, lock: Script) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	let outputs = (0..args.count).map(|_| Output::new(args.amount, lock.clone(), None, Bytes::new())).collect();
+
+	Ok(transaction::transfer(slice::from_ref(cell), outputs))
+}
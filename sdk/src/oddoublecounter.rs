@@ -0,0 +1,65 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, read_u64, Encoding, Error};
+
+// Constants
+const DATA_LEN: usize = 16; // Two u64 counter values, which are 8 bytes each.
+
+/// The cell data, which holds the pair of counter values. A new counter always starts with both values at zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Data
+{
+	pub value_1: u64,
+	pub value_2: u64,
+}
+
+impl Data
+{
+	/// The data required after the next transfer, or None if either value would overflow.
+	/// The first value increases by one and the second value increases by two.
+	pub fn next(&self) -> Option<Self>
+	{
+		Some(Self { value_1: self.value_1.checked_add(1)?, value_2: self.value_2.checked_add(2)? })
+	}
+}
+
+impl Encoding for Data
+{
+	fn encode(&self) -> Bytes
+	{
+		[self.value_1.to_le_bytes(), self.value_2.to_le_bytes()].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[DATA_LEN])?;
+
+		Ok(Self { value_1: read_u64(data, 0), value_2: read_u64(data, 8) })
+	}
+}
+
+/// Build a transaction which creates a double counter cell with both values at zero.
+pub fn create(lock: Script, type_script: Script) -> TransactionView
+{
+	transaction::create(vec![Output::occupied(lock, Some(type_script), Data::default().encode())])
+}
+
+/// Build a transaction which transfers a double counter cell to the lock and increments the values.
+pub fn transfer(cell: &Cell, lock: Script) -> Result<TransactionView, Error>
+{
+	let data = Data::decode(&cell.data)?.next().ok_or_else(|| Error::InvalidValue("counter value overflow".to_string()))?;
+	let output = Output::new(cell.capacity(), lock, cell.output.type_().to_opt(), data.encode());
+
+	Ok(transaction::transfer(slice::from_ref(cell), vec![output]))
+}
+
+/// Build a transaction which consumes a double counter cell.
+pub fn burn(cell: &Cell) -> TransactionView
+{
+	transaction::burn(slice::from_ref(cell))
+}
@@ -0,0 +1,99 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, check_lock_hash, read_bytes, read_u64, Encoding, Error};
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const ORACLE_DATA_LEN: usize = 24; // The oracle data holds three u64 values: the value, the timestamp, and the sequence.
+
+/// The script args, which hold the hash of the updater lock that must be present to create, update, or burn the oracle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub updater_lock_hash: [u8; LOCK_HASH_LEN],
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		Bytes::from(self.updater_lock_hash.to_vec())
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[LOCK_HASH_LEN])?;
+
+		Ok(Self { updater_lock_hash: read_bytes(data, 0) })
+	}
+}
+
+/// The cell data, which holds the oracle record. Every update must increase the sequence and cannot decrease the timestamp.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Data
+{
+	pub value: u64,
+	pub timestamp: u64,
+	pub sequence: u64,
+}
+
+impl Encoding for Data
+{
+	fn encode(&self) -> Bytes
+	{
+		[self.value.to_le_bytes(), self.timestamp.to_le_bytes(), self.sequence.to_le_bytes()].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[ORACLE_DATA_LEN])?;
+
+		Ok(Self { value: read_u64(data, 0), timestamp: read_u64(data, 8), sequence: read_u64(data, 16) })
+	}
+}
+
+/// Verify that the updater cell has the updater lock from the args of the type script.
+fn check_updater(type_script: &Script, updater: &Cell) -> Result<(), Error>
+{
+	let args = Args::decode(&type_script.args().raw_data())?;
+
+	check_lock_hash(updater, &args.updater_lock_hash, "updater")
+}
+
+/// Build a transaction which creates an oracle cell with the record, with the updater cell, which is recreated unchanged.
+pub fn create(lock: Script, type_script: Script, updater: &Cell, data: &Data) -> Result<TransactionView, Error>
+{
+	check_updater(&type_script, updater)?;
+
+	Ok(transaction::transfer_with(&[], slice::from_ref(updater), vec![Output::occupied(lock, Some(type_script), data.encode())]))
+}
+
+/// Build a transaction which updates the record in the oracle cell, with the updater cell, which is recreated unchanged.
+/// The record must have a higher sequence and cannot have an earlier timestamp.
+pub fn update(cell: &Cell, updater: &Cell, data: &Data) -> Result<TransactionView, Error>
+{
+	let type_script = cell.output.type_().to_opt().ok_or_else(|| Error::InvalidValue("cell has no type script".to_string()))?;
+	check_updater(&type_script, updater)?;
+	let previous = Data::decode(&cell.data)?;
+	if data.sequence <= previous.sequence || data.timestamp < previous.timestamp
+	{
+		return Err(Error::InvalidValue("the record is older than the current record".to_string()));
+	}
+	let output = Output::new(cell.capacity(), cell.output.lock(), Some(type_script), data.encode());
+
+	Ok(transaction::transfer_with(slice::from_ref(cell), slice::from_ref(updater), vec![output]))
+}
+
+/// Build a transaction which burns the oracle cell, with the updater cell, which is recreated unchanged.
+pub fn burn(cell: &Cell, updater: &Cell) -> Result<TransactionView, Error>
+{
+	let type_script = cell.output.type_().to_opt().ok_or_else(|| Error::InvalidValue("cell has no type script".to_string()))?;
+	check_updater(&type_script, updater)?;
+
+	Ok(transaction::transfer_with(slice::from_ref(cell), slice::from_ref(updater), Vec::new()))
+}
@@ -0,0 +1,78 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::{CellDep, Script};
+use ckb_types::prelude::*;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, check_lock_hash, oracle, read_bytes, read_u64, script_hash, Encoding, Error};
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const TYPE_HASH_LEN: usize = 32; // Number of bytes for a type hash. (Blake2b 256-bit 32 bytes)
+const SEQUENCE_LEN: usize = 8; // The minimum sequence is a u64, which is 8 bytes.
+const THRESHOLD_LEN: usize = 8; // The threshold is a u64 oracle value, which is 8 bytes.
+
+/// The script args, which hold the owner lock hash, the type hash of the oracle cell read through a cell dep, and the
+/// minimum sequence and value the oracle record must have for the cell to be unlocked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub owner_lock_hash: [u8; LOCK_HASH_LEN],
+	pub oracle_type_hash: [u8; TYPE_HASH_LEN],
+	pub minimum_sequence: u64,
+	pub threshold: u64,
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		[&self.owner_lock_hash[..], &self.oracle_type_hash[..], &self.minimum_sequence.to_le_bytes(), &self.threshold.to_le_bytes()].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[LOCK_HASH_LEN + TYPE_HASH_LEN + SEQUENCE_LEN + THRESHOLD_LEN])?;
+
+		Ok(Self
+		{
+			owner_lock_hash: read_bytes(data, 0),
+			oracle_type_hash: read_bytes(data, LOCK_HASH_LEN),
+			minimum_sequence: read_u64(data, LOCK_HASH_LEN + TYPE_HASH_LEN),
+			threshold: read_u64(data, LOCK_HASH_LEN + TYPE_HASH_LEN + SEQUENCE_LEN),
+		})
+	}
+}
+
+/// Build a transaction which locks the capacity with the oracle lock.
+pub fn create(lock: Script, capacity: u64) -> TransactionView
+{
+	transaction::create(vec![Output::new(capacity, lock, None, Bytes::new())])
+}
+
+/// Build a transaction which unlocks the cell with the owner cell, which is recreated unchanged, and transfers it to the
+/// lock. The oracle cell is added as a cell dep, and its record must meet the minimum sequence and the threshold.
+pub fn transfer(cell: &Cell, owner: &Cell, oracle: &Cell, lock: Script) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	check_lock_hash(owner, &args.owner_lock_hash, "owner")?;
+	if oracle.output.type_().to_opt().map(|type_script| script_hash(&type_script)) != Some(args.oracle_type_hash)
+	{
+		return Err(Error::InvalidValue("the oracle type does not match the args".to_string()));
+	}
+	let record = oracle::Data::decode(&oracle.data)?;
+	if record.sequence < args.minimum_sequence
+	{
+		return Err(Error::InvalidValue(format!("the oracle record is older than sequence {}", args.minimum_sequence)));
+	}
+	if record.value < args.threshold
+	{
+		return Err(Error::InvalidValue(format!("the oracle value is below the threshold of {}", args.threshold)));
+	}
+	let output = Output::new(cell.capacity(), lock, cell.output.type_().to_opt(), cell.data.clone());
+	let tx = transaction::transfer_with(slice::from_ref(cell), slice::from_ref(owner), vec![output]);
+
+	Ok(tx.as_advanced_builder().cell_dep(CellDep::new_builder().out_point(oracle.out_point.clone()).build()).build())
+}
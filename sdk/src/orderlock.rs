@@ -0,0 +1,106 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, check_lock_hash, read_bytes, read_u128, read_u64, script_hash, sudt, Encoding, Error};
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const CKB_AMOUNT_LEN: usize = 8; // The CKB amount of the exchange rate is a u64, which is 8 bytes.
+const SUDT_AMOUNT_LEN: usize = 16; // The SUDT amount of the exchange rate is a u128, which is 16 bytes.
+
+/// The script args, which hold the maker lock hash and the exchange rate as a CKB amount in Shannons for an SUDT amount.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub maker_lock_hash: [u8; LOCK_HASH_LEN],
+	pub rate_ckb: u64,
+	pub rate_sudt: u128,
+}
+
+impl Args
+{
+	/// The capacity in Shannons the maker must receive for the amount of tokens sold, rounded up. The payment is made by
+	/// the output at the same index as the order cell.
+	/// Returns None if the exchange rate is invalid or the payment would overflow.
+	pub fn payment(&self, sold_amount: u128) -> Option<u128>
+	{
+		if self.rate_sudt == 0
+		{
+			return None;
+		}
+		let value = sold_amount.checked_mul(self.rate_ckb as u128)?;
+
+		Some(value / self.rate_sudt + if value % self.rate_sudt != 0 { 1 } else { 0 })
+	}
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		[&self.maker_lock_hash[..], &self.rate_ckb.to_le_bytes(), &self.rate_sudt.to_le_bytes()].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[LOCK_HASH_LEN + CKB_AMOUNT_LEN + SUDT_AMOUNT_LEN])?;
+
+		Ok(Self { maker_lock_hash: read_bytes(data, 0), rate_ckb: read_u64(data, LOCK_HASH_LEN), rate_sudt: read_u128(data, LOCK_HASH_LEN + CKB_AMOUNT_LEN) })
+	}
+}
+
+/// Build a transaction which creates an order cell selling the amount of the token.
+pub fn create(lock: Script, type_script: Script, capacity: u64, amount: u128) -> TransactionView
+{
+	transaction::create(vec![Output::new(capacity, lock, Some(type_script), sudt::Data { amount }.encode())])
+}
+
+/// Build a transaction which buys the amount of tokens from the order cell for the taker lock. The maker lock is paid at
+/// the same index as the order cell, along with the capacity of the order cell for a full fill. A partial fill leaves a
+/// residual order cell with the rest of the tokens.
+pub fn fill(cell: &Cell, amount: u128, maker_lock: Script, taker_lock: Script) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	if script_hash(&maker_lock) != args.maker_lock_hash
+	{
+		return Err(Error::InvalidValue("the maker lock does not match the args".to_string()));
+	}
+	let type_script = cell.output.type_().to_opt().ok_or_else(|| Error::InvalidValue("the order cell has no token type".to_string()))?;
+	let order_amount = sudt::Data::decode(&cell.data)?.amount;
+	if amount > order_amount
+	{
+		return Err(Error::InsufficientAmount(amount - order_amount));
+	}
+	if amount == 0
+	{
+		return Err(Error::InvalidValue("no tokens are bought".to_string()));
+	}
+
+	// The payment is the first output, since the order cell is the first input.
+	let payment = args.payment(amount).ok_or_else(|| Error::InvalidValue("invalid exchange rate".to_string()))?;
+	let returned_capacity = if amount == order_amount { cell.capacity() as u128 } else { 0 };
+	let payment = payment.checked_add(returned_capacity).and_then(|payment| u64::try_from(payment).ok()).ok_or_else(|| Error::InvalidValue("payment overflow".to_string()))?;
+	let mut outputs = vec![Output::new(payment, maker_lock, None, Bytes::new())];
+	if amount < order_amount
+	{
+		outputs.push(Output::new(cell.capacity(), cell.output.lock(), Some(type_script.clone()), sudt::Data { amount: order_amount - amount }.encode()));
+	}
+	outputs.push(sudt::token_output(taker_lock, &type_script, amount));
+
+	Ok(transaction::transfer(slice::from_ref(cell), outputs))
+}
+
+/// Build a transaction which cancels the order with the maker cell, which is recreated unchanged, and transfers the order
+/// cell to the lock.
+pub fn cancel(cell: &Cell, maker: &Cell, lock: Script) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	check_lock_hash(maker, &args.maker_lock_hash, "maker")?;
+	let output = Output::new(cell.capacity(), lock, cell.output.type_().to_opt(), cell.data.clone());
+
+	Ok(transaction::transfer_with(slice::from_ref(cell), slice::from_ref(maker), vec![output]))
+}
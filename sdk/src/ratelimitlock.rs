@@ -0,0 +1,101 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, check_lock_hash, read_bytes, read_u64, Encoding, Error};
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const LIMIT_LEN: usize = 8; // The per-window limit is a u64 amount of Shannons, which is 8 bytes.
+const DATA_LEN: usize = 16; // The cell data holds two u64 values: the current window number and the amount withdrawn in it.
+pub const NEW_WINDOW_SINCE: u64 = 0xA000_0000_0000_0001; // A relative since of one epoch, which starts a new window.
+
+/// The script args, which hold the owner lock hash and the maximum capacity in Shannons that can be withdrawn per epoch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub owner_lock_hash: [u8; LOCK_HASH_LEN],
+	pub limit: u64,
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		[&self.owner_lock_hash[..], &self.limit.to_le_bytes()].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[LOCK_HASH_LEN + LIMIT_LEN])?;
+
+		Ok(Self { owner_lock_hash: read_bytes(data, 0), limit: read_u64(data, LOCK_HASH_LEN) })
+	}
+}
+
+/// The cell data, which holds the number of the current window and the capacity in Shannons withdrawn in it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Data
+{
+	pub window: u64,
+	pub withdrawn: u64,
+}
+
+impl Data
+{
+	/// The data after withdrawing the amount, or None if the limit would be exceeded. A new window, which requires an input
+	/// since of at least one epoch relative to the cell, advances the window by one and resets the amount withdrawn.
+	pub fn withdraw(&self, new_window: bool, amount: u64, limit: u64) -> Option<Self>
+	{
+		let (window, previous_withdrawn) = if new_window { (self.window.checked_add(1)?, 0) } else { (self.window, self.withdrawn) };
+		let withdrawn = previous_withdrawn.checked_add(amount).filter(|withdrawn| *withdrawn <= limit)?;
+
+		Some(Self { window, withdrawn })
+	}
+}
+
+impl Encoding for Data
+{
+	fn encode(&self) -> Bytes
+	{
+		[self.window.to_le_bytes(), self.withdrawn.to_le_bytes()].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[DATA_LEN])?;
+
+		Ok(Self { window: read_u64(data, 0), withdrawn: read_u64(data, 8) })
+	}
+}
+
+/// Build a transaction which locks the capacity with the rate limit lock, starting in window zero with nothing withdrawn.
+pub fn create(lock: Script, capacity: u64) -> TransactionView
+{
+	transaction::create(vec![Output::new(capacity, lock, None, Data::default().encode())])
+}
+
+/// Build a transaction which withdraws the amount to the lock with the owner cell, which is recreated unchanged. The cell
+/// is recreated first with the rest of the capacity and the updated accounting, unless all of it is withdrawn. A new window
+/// uses the new window since on the cell. Data the contract cannot parse is treated as a new cell, the same as the contract.
+pub fn withdraw(cell: &Cell, owner: &Cell, amount: u64, new_window: bool, lock: Script) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	check_lock_hash(owner, &args.owner_lock_hash, "owner")?;
+	let remaining_capacity = cell.capacity().checked_sub(amount).ok_or_else(|| Error::InvalidValue(format!("the cell holds less than {} Shannons", amount)))?;
+	let data = Data::decode(&cell.data).unwrap_or_default().withdraw(new_window, amount, args.limit)
+		.ok_or_else(|| Error::InvalidValue(format!("the limit of {} Shannons per window would be exceeded", args.limit)))?;
+
+	let mut outputs = Vec::new();
+	if remaining_capacity > 0
+	{
+		outputs.push(Output::new(remaining_capacity, cell.output.lock(), cell.output.type_().to_opt(), data.encode()));
+	}
+	outputs.push(Output::new(amount, lock, None, Bytes::new()));
+	let cell = if new_window { cell.clone().with_since(NEW_WINDOW_SINCE) } else { cell.clone() };
+
+	Ok(transaction::transfer_with(slice::from_ref(&cell), slice::from_ref(owner), outputs))
+}
@@ -0,0 +1,127 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, check_minimum_length, read_bytes, read_u128, Encoding, Error};
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const SUDT_DATA_LEN: usize = 16; // SUDT uses a u128, which is 16 bytes.
+
+/// The script args, which hold the hash of the owner lock that is allowed to mint tokens.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub owner_lock_hash: [u8; LOCK_HASH_LEN],
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		Bytes::from(self.owner_lock_hash.to_vec())
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[LOCK_HASH_LEN])?;
+
+		Ok(Self { owner_lock_hash: read_bytes(data, 0) })
+	}
+}
+
+/// The cell data, which holds the token amount. Any bytes after the amount are ignored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Data
+{
+	pub amount: u128,
+}
+
+impl Encoding for Data
+{
+	fn encode(&self) -> Bytes
+	{
+		Bytes::from(self.amount.to_le_bytes().to_vec())
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_minimum_length(data, SUDT_DATA_LEN)?;
+
+		Ok(Self { amount: read_u128(data, 0) })
+	}
+}
+
+/// Create a token cell for the lock with the amount.
+pub(crate) fn token_output(lock: Script, type_script: &Script, amount: u128) -> Output
+{
+	Output::occupied(lock, Some(type_script.clone()), Data { amount }.encode())
+}
+
+/// Determine the type script and the total amount of the token cells, which must all be the same token.
+fn total_amount(cells: &[Cell]) -> Result<(Script, u128), Error>
+{
+	let type_script = cells.first().and_then(|cell| cell.output.type_().to_opt()).ok_or_else(|| Error::InvalidValue("no token cells".to_string()))?;
+	let mut total = 0u128;
+	for cell in cells
+	{
+		if cell.output.type_().to_opt().as_ref() != Some(&type_script)
+		{
+			return Err(Error::InvalidValue("token cells have different types".to_string()));
+		}
+		total = total.checked_add(Data::decode(&cell.data)?.amount).ok_or_else(|| Error::InvalidValue("token amount overflow".to_string()))?;
+	}
+
+	Ok((type_script, total))
+}
+
+/// Build a transaction which mints tokens to each lock. The owner cell is consumed to enable owner mode and is
+/// recreated unchanged, so the lock of the owner cell must match the owner lock hash in the args of the type script.
+pub fn mint(owner: &Cell, type_script: Script, outputs: Vec<(Script, u128)>) -> TransactionView
+{
+	let outputs: Vec<Output> = outputs.into_iter().map(|(lock, amount)| token_output(lock, &type_script, amount)).collect();
+
+	transaction::transfer_with(&[], slice::from_ref(owner), outputs)
+}
+
+/// Build a transaction which transfers tokens from the cells to each lock.
+/// Any tokens which are not transferred are returned to the lock of the first cell.
+pub fn transfer(cells: &[Cell], outputs: Vec<(Script, u128)>) -> Result<TransactionView, Error>
+{
+	let (type_script, input_amount) = total_amount(cells)?;
+	let output_amount = outputs.iter().try_fold(0u128, |total, (_, amount)| total.checked_add(*amount)).ok_or_else(|| Error::InvalidValue("token amount overflow".to_string()))?;
+	if output_amount > input_amount
+	{
+		return Err(Error::InsufficientAmount(output_amount - input_amount));
+	}
+
+	let mut outputs: Vec<Output> = outputs.into_iter().map(|(lock, amount)| token_output(lock, &type_script, amount)).collect();
+	if input_amount > output_amount
+	{
+		outputs.push(token_output(cells[0].output.lock(), &type_script, input_amount - output_amount));
+	}
+
+	Ok(transaction::transfer(cells, outputs))
+}
+
+/// Build a transaction which burns the amount of tokens from the cells.
+/// Any tokens which are not burned are returned to the lock of the first cell.
+pub fn burn(cells: &[Cell], amount: u128) -> Result<TransactionView, Error>
+{
+	let (type_script, input_amount) = total_amount(cells)?;
+	if amount > input_amount
+	{
+		return Err(Error::InsufficientAmount(amount - input_amount));
+	}
+
+	let mut outputs = Vec::new();
+	if input_amount > amount
+	{
+		outputs.push(token_output(cells[0].output.lock(), &type_script, input_amount - amount));
+	}
+
+	Ok(transaction::transfer(cells, outputs))
+}
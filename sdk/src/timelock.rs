@@ -0,0 +1,53 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, check_lock_hash, read_bytes, read_u64, Encoding, Error};
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const SINCE_LEN: usize = 8; // A since value is a u64, which is 8 bytes.
+
+/// The script args, which hold the delegate lock hash that must be present in the inputs, and the since threshold
+/// that each cell using the lock must meet.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub delegate_lock_hash: [u8; LOCK_HASH_LEN],
+	pub threshold: u64,
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		[&self.delegate_lock_hash[..], &self.threshold.to_le_bytes()].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[LOCK_HASH_LEN + SINCE_LEN])?;
+
+		Ok(Self { delegate_lock_hash: read_bytes(data, 0), threshold: read_u64(data, LOCK_HASH_LEN) })
+	}
+}
+
+/// Build a transaction which locks the capacity with the time lock.
+pub fn create(lock: Script, capacity: u64) -> TransactionView
+{
+	transaction::create(vec![Output::new(capacity, lock, None, Bytes::new())])
+}
+
+/// Build a transaction which unlocks the cell with the delegate cell, which is recreated unchanged, and transfers it to the
+/// lock. The cell uses the threshold from the args as its since, so the transaction cannot be committed before it.
+pub fn transfer(cell: &Cell, delegate: &Cell, lock: Script) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	check_lock_hash(delegate, &args.delegate_lock_hash, "delegate")?;
+	let output = Output::new(cell.capacity(), lock, cell.output.type_().to_opt(), cell.data.clone());
+
+	Ok(transaction::transfer_with(&[cell.clone().with_since(args.threshold)], slice::from_ref(delegate), vec![output]))
+}
@@ -0,0 +1,113 @@
+use ckb_types::bytes::Bytes;
+use ckb_types::core::{Capacity, TransactionBuilder, TransactionView};
+use ckb_types::packed::{CellInput, CellOutput, OutPoint, Script};
+use ckb_types::prelude::*;
+
+/// A live cell which can be consumed as an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell
+{
+	pub out_point: OutPoint,
+	pub output: CellOutput,
+	pub data: Bytes,
+	pub since: u64,
+}
+
+impl Cell
+{
+	pub fn new(out_point: OutPoint, output: CellOutput, data: Bytes) -> Self
+	{
+		Self { out_point, output, data, since: 0 }
+	}
+
+	/// Set the since value used when the cell is consumed.
+	pub fn with_since(self, since: u64) -> Self
+	{
+		Self { since, ..self }
+	}
+
+	/// The input which consumes the cell.
+	pub fn input(&self) -> CellInput
+	{
+		CellInput::new(self.out_point.clone(), self.since)
+	}
+
+	pub fn capacity(&self) -> u64
+	{
+		self.output.capacity().unpack()
+	}
+}
+
+/// A cell to create, with its data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output
+{
+	pub output: CellOutput,
+	pub data: Bytes,
+}
+
+impl Output
+{
+	/// Create an output with the specified capacity.
+	pub fn new(capacity: u64, lock: Script, type_script: Option<Script>, data: Bytes) -> Self
+	{
+		let output = CellOutput::new_builder().capacity(capacity.pack()).lock(lock).type_(type_script.pack()).build();
+
+		Self { output, data }
+	}
+
+	/// Create an output with exactly the capacity occupied by the lock, type, and data.
+	pub fn occupied(lock: Script, type_script: Option<Script>, data: Bytes) -> Self
+	{
+		let output = CellOutput::new_builder().lock(lock).type_(type_script.pack()).build();
+		let capacity = output.occupied_capacity(Capacity::bytes(data.len()).expect("data capacity")).expect("occupied capacity");
+
+		Self { output: output.as_builder().capacity(capacity.pack()).build(), data }
+	}
+
+	/// An output which recreates the cell unchanged.
+	pub fn from_cell(cell: &Cell) -> Self
+	{
+		Self { output: cell.output.clone(), data: cell.data.clone() }
+	}
+
+	pub fn capacity(&self) -> u64
+	{
+		self.output.capacity().unpack()
+	}
+}
+
+/// Build a transaction which creates the outputs.
+pub fn create(outputs: Vec<Output>) -> TransactionView
+{
+	transfer(&[], outputs)
+}
+
+/// Build a transaction which consumes the cells and creates the outputs.
+pub fn transfer(cells: &[Cell], outputs: Vec<Output>) -> TransactionView
+{
+	let (outputs, outputs_data): (Vec<CellOutput>, Vec<Bytes>) = outputs.into_iter().map(|output| (output.output, output.data)).unzip();
+
+	TransactionBuilder::default()
+		.inputs(cells.iter().map(Cell::input))
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.build()
+}
+
+/// Build a transaction which consumes the cells along with the cells of the parties whose locks authorize them, such as
+/// the owner of a proxy lock. The cells are the first inputs, so their indexes match the outputs, and the cells of the
+/// parties are recreated unchanged after the outputs.
+pub fn transfer_with(cells: &[Cell], parties: &[Cell], outputs: Vec<Output>) -> TransactionView
+{
+	let inputs: Vec<Cell> = cells.iter().chain(parties).cloned().collect();
+	let outputs: Vec<Output> = outputs.into_iter().chain(parties.iter().map(Output::from_cell)).collect();
+
+	transfer(&inputs, outputs)
+}
+
+/// Build a transaction which consumes the cells without creating any outputs.
+pub fn burn(cells: &[Cell]) -> TransactionView
+{
+	transfer(cells, Vec::new())
+}
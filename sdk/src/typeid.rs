@@ -0,0 +1,81 @@
+use std::slice;
+
+use ckb_hash::new_blake2b;
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::{CellInput, Script};
+use ckb_types::prelude::*;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, read_bytes, Encoding, Error};
+
+// Constants
+const TYPE_ID_LEN: usize = 32; // Number of bytes for a Type ID. (Blake2b 256-bit 32 bytes)
+
+/// Calculate a unique ID from the first input of the transaction and the index of the output cell it is created in.
+pub fn calculate_id(first_input: &CellInput, output_index: u64) -> [u8; TYPE_ID_LEN]
+{
+	let mut id = [0u8; TYPE_ID_LEN];
+	let mut blake2b = new_blake2b();
+	blake2b.update(first_input.as_slice());
+	blake2b.update(&output_index.to_le_bytes());
+	blake2b.finalize(&mut id);
+
+	id
+}
+
+/// The script args, which hold the Type ID.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub type_id: [u8; TYPE_ID_LEN],
+}
+
+impl Args
+{
+	/// Create the args for a new cell from the first input of the transaction and the index of the output cell.
+	pub fn new(first_input: &CellInput, output_index: u64) -> Self
+	{
+		Self { type_id: calculate_id(first_input, output_index) }
+	}
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		Bytes::from(self.type_id.to_vec())
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[TYPE_ID_LEN])?;
+
+		Ok(Self { type_id: read_bytes(data, 0) })
+	}
+}
+
+/// Build a transaction which creates a cell with a new Type ID, using the first cell as the first input, which is
+/// recreated unchanged. The code is a script with the code hash and hash type of the contract, and its args are replaced
+/// with the Type ID of the first output.
+pub fn create(code: &Script, first: &Cell, lock: Script, data: Bytes) -> TransactionView
+{
+	let type_script = code.clone().as_builder().args(Args::new(&first.input(), 0).encode().pack()).build();
+
+	transaction::transfer_with(&[], slice::from_ref(first), vec![Output::occupied(lock, Some(type_script), data)])
+}
+
+/// Build a transaction which transfers the cell to the lock and replaces its data, such as to upgrade the code it holds.
+pub fn transfer(cell: &Cell, lock: Script, data: Bytes) -> Result<TransactionView, Error>
+{
+	let type_script = cell.output.type_().to_opt().ok_or_else(|| Error::InvalidValue("cell has no type script".to_string()))?;
+	Args::decode(&type_script.args().raw_data())?;
+
+	Ok(transaction::transfer(slice::from_ref(cell), vec![Output::occupied(lock, Some(type_script), data)]))
+}
+
+/// Build a transaction which burns the cell.
+pub fn burn(cell: &Cell) -> TransactionView
+{
+	transaction::burn(slice::from_ref(cell))
+}
@@ -0,0 +1,53 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, read_bytes, script_hash, Encoding, Error};
+
+// Constants
+const TYPE_HASH_LEN: usize = 32; // Number of bytes for a type hash. (Blake2b 256-bit 32 bytes)
+
+/// The script args, which hold the hash of the owner type that must be present in the inputs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub owner_type_hash: [u8; TYPE_HASH_LEN],
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		Bytes::from(self.owner_type_hash.to_vec())
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[TYPE_HASH_LEN])?;
+
+		Ok(Self { owner_type_hash: read_bytes(data, 0) })
+	}
+}
+
+/// Build a transaction which locks the capacity with the type proxy lock.
+pub fn create(lock: Script, capacity: u64) -> TransactionView
+{
+	transaction::create(vec![Output::new(capacity, lock, None, Bytes::new())])
+}
+
+/// Build a transaction which unlocks the cell with the owner cell, which is recreated unchanged, and transfers it to the
+/// lock. The owner cell must have the type script from the args, which validates the owner cell being recreated.
+pub fn transfer(cell: &Cell, owner: &Cell, lock: Script) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	if owner.output.type_().to_opt().map(|type_script| script_hash(&type_script)) != Some(args.owner_type_hash)
+	{
+		return Err(Error::InvalidValue("the owner type does not match the args".to_string()));
+	}
+	let output = Output::new(cell.capacity(), lock, cell.output.type_().to_opt(), cell.data.clone());
+
+	Ok(transaction::transfer_with(slice::from_ref(cell), slice::from_ref(owner), vec![output]))
+}
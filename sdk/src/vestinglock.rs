@@ -0,0 +1,136 @@
+use std::slice;
+
+use ckb_types::bytes::Bytes;
+use ckb_types::core::TransactionView;
+use ckb_types::packed::Script;
+
+use crate::transaction::{self, Cell, Output};
+use crate::{check_length, check_lock_hash, read_bytes, read_u128, read_u64, sudt, Encoding, Error};
+
+// Constants
+const LOCK_HASH_LEN: usize = 32; // Number of bytes for a lock hash. (Blake2b 256-bit 32 bytes)
+const ARGS_LEN: usize = LOCK_HASH_LEN + 8 + 8 + 8 + 16; // Beneficiary lock hash, start epoch, cliff, duration, total amount.
+const SINCE_ABSOLUTE_EPOCH_FLAGS: u64 = 0x2000_0000_0000_0000; // Absolute flag with the epoch metric flag.
+const SINCE_EPOCH_NUMBER_MASK: u64 = 0x0000_0000_00FF_FFFF; // The epoch number is the lowest 24 bits of the since value.
+
+/// The script args, which hold the vesting schedule. The amount is the capacity in Shannons for a plain cell,
+/// or the SUDT amount for a cell with a type script.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Args
+{
+	pub beneficiary_lock_hash: [u8; LOCK_HASH_LEN],
+	pub start_epoch: u64,
+	pub cliff: u64,
+	pub duration: u64,
+	pub total_amount: u128,
+}
+
+impl Args
+{
+	/// Calculate the amount which has vested by the epoch, or None if the calculation would overflow.
+	pub fn vested_amount(&self, epoch: u64) -> Option<u128>
+	{
+		// Nothing is vested until the cliff has passed.
+		if epoch < self.start_epoch.saturating_add(self.cliff)
+		{
+			return Some(0);
+		}
+
+		// Everything is vested once the duration has passed, otherwise the amount vests linearly.
+		let elapsed = epoch - self.start_epoch;
+		if elapsed >= self.duration
+		{
+			return Some(self.total_amount);
+		}
+
+		Some(self.total_amount.checked_mul(elapsed as u128)? / self.duration as u128)
+	}
+}
+
+impl Encoding for Args
+{
+	fn encode(&self) -> Bytes
+	{
+		[&self.beneficiary_lock_hash[..], &self.start_epoch.to_le_bytes(), &self.cliff.to_le_bytes(), &self.duration.to_le_bytes(), &self.total_amount.to_le_bytes()].concat().into()
+	}
+
+	fn decode(data: &[u8]) -> Result<Self, Error>
+	{
+		check_length(data, &[ARGS_LEN])?;
+
+		Ok(Self
+		{
+			beneficiary_lock_hash: read_bytes(data, 0),
+			start_epoch: read_u64(data, 32),
+			cliff: read_u64(data, 40),
+			duration: read_u64(data, 48),
+			total_amount: read_u128(data, 56),
+		})
+	}
+}
+
+/// Build a transaction which locks the capacity with the vesting lock, along with an amount of the token if a token type
+/// script is given.
+pub fn create(lock: Script, capacity: u64, token: Option<(Script, u128)>) -> TransactionView
+{
+	let output = match token
+	{
+		Some((type_script, amount)) => Output::new(capacity, lock, Some(type_script), sudt::Data { amount }.encode()),
+		None => Output::new(capacity, lock, None, Bytes::new()),
+	};
+
+	transaction::create(vec![output])
+}
+
+/// Build a transaction which withdraws the amount vested by the epoch to the lock, with the beneficiary cell, which is
+/// recreated unchanged. The cell uses the epoch as an absolute since, so the transaction cannot be committed before it.
+/// The amount which has not vested remains under the vesting lock in the first output.
+pub fn withdraw(cell: &Cell, beneficiary: &Cell, epoch: u64, lock: Script) -> Result<TransactionView, Error>
+{
+	let args = Args::decode(&cell.output.lock().args().raw_data())?;
+	check_lock_hash(beneficiary, &args.beneficiary_lock_hash, "beneficiary")?;
+	if epoch > SINCE_EPOCH_NUMBER_MASK
+	{
+		return Err(Error::InvalidValue(format!("epoch {} cannot be used as a since", epoch)));
+	}
+	let vested_amount = args.vested_amount(epoch).ok_or_else(|| Error::InvalidValue("vested amount overflow".to_string()))?;
+	let type_script = cell.output.type_().to_opt();
+	let input_amount = match type_script
+	{
+		Some(_) => sudt::Data::decode(&cell.data)?.amount,
+		None => cell.capacity() as u128,
+	};
+	let remaining_amount = (args.total_amount - vested_amount).min(input_amount);
+	let withdrawn_amount = input_amount - remaining_amount;
+
+	// A token cell keeps its capacity under the vesting lock, while a plain cell splits its capacity.
+	let mut outputs = Vec::new();
+	match type_script
+	{
+		Some(type_script) =>
+		{
+			if remaining_amount > 0
+			{
+				outputs.push(Output::new(cell.capacity(), cell.output.lock(), Some(type_script.clone()), sudt::Data { amount: remaining_amount }.encode()));
+			}
+			if withdrawn_amount > 0
+			{
+				outputs.push(sudt::token_output(lock, &type_script, withdrawn_amount));
+			}
+		},
+		None =>
+		{
+			if remaining_amount > 0
+			{
+				outputs.push(Output::new(remaining_amount as u64, cell.output.lock(), None, Bytes::new()));
+			}
+			if withdrawn_amount > 0
+			{
+				outputs.push(Output::new(withdrawn_amount as u64, lock, None, Bytes::new()));
+			}
+		},
+	}
+	let cell = cell.clone().with_since(SINCE_ABSOLUTE_EPOCH_FLAGS | epoch);
+
+	Ok(transaction::transfer_with(slice::from_ref(&cell), slice::from_ref(beneficiary), outputs))
+}
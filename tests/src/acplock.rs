@@ -0,0 +1,1038 @@
+use super::*;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::{TransactionBuilder};
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+
+// Error Codes
+const ERROR_ACPLOCK_ARGS_LENGTH: i8 = 5;
+const ERROR_ACPLOCK_OUTPUT_NOT_FOUND: i8 = 6;
+const ERROR_ACPLOCK_INVALID_TRANSACTION_STRUCTURE: i8 = 7;
+const ERROR_ACPLOCK_INSUFFICIENT_CAPACITY: i8 = 8;
+const ERROR_ACPLOCK_INSUFFICIENT_AMOUNT: i8 = 9;
+
+#[test]
+fn test_acplock_owner_mode()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(110_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_acplock_owner_mode_sudt()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+	let sudt_args: Bytes = [0u8; 32].to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_acplock_ckb_payment()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(110_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(40_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_acplock_ckb_payment_no_minimum()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let acplock_args: Bytes = owner_lock_hash.into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_100_000_000_u64.pack()).lock(acplock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(49_900_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_acplock_ckb_payment_below_minimum()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(109_900_000_000_u64.pack()).lock(acplock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(40_100_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ACPLOCK_INSUFFICIENT_CAPACITY);
+}
+
+#[test]
+fn test_acplock_ckb_withdraw()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(90_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ACPLOCK_INSUFFICIENT_CAPACITY);
+}
+
+#[test]
+fn test_acplock_sudt_payment()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+	let sudt_args: Bytes = [0u8; 32].to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(500u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_100u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(40_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_acplock_sudt_payment_no_minimum()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let acplock_args: Bytes = owner_lock_hash.into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+	let sudt_args: Bytes = [0u8; 32].to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(500u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_001u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(499u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_acplock_sudt_payment_below_minimum()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+	let sudt_args: Bytes = [0u8; 32].to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(500u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_099u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(401u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(40_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ACPLOCK_INSUFFICIENT_AMOUNT);
+}
+
+#[test]
+fn test_acplock_sudt_payment_without_capacity()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+	let sudt_args: Bytes = [0u8; 32].to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(500u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_100u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ACPLOCK_INSUFFICIENT_CAPACITY);
+}
+
+#[test]
+fn test_acplock_sudt_withdraw()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+	let sudt_args: Bytes = [0u8; 32].to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(900u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ACPLOCK_INSUFFICIENT_AMOUNT);
+}
+
+#[test]
+fn test_acplock_multiple_payments()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+	let sudt_args: Bytes = [0u8; 32].to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(500u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(110_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_100u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_acplock_type_changed()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+	let sudt_args: Bytes = [0u8; 32].to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ACPLOCK_OUTPUT_NOT_FOUND);
+}
+
+#[test]
+fn test_acplock_no_output()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ACPLOCK_OUTPUT_NOT_FOUND);
+}
+
+#[test]
+fn test_acplock_merge_inputs()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(210_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(40_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ACPLOCK_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_acplock_split_outputs()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let minimum_ckb = 10_000_000_000u64.to_le_bytes().to_vec();
+	let minimum_sudt = 100u128.to_le_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, minimum_ckb, minimum_sudt].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(110_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ACPLOCK_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_acplock_args_length()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_acplock = context.deploy_cell(Loader::default().load_binary("acplock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let acplock_dep = CellDep::new_builder().out_point(out_point_acplock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let acplock_args: Bytes = [owner_lock_hash, vec![0u8]].concat().into();
+	let acplock_lock_script = context.build_script(&out_point_acplock, acplock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(110_000_000_000_u64.pack()).lock(acplock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(40_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(acplock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ACPLOCK_ARGS_LENGTH);
+}
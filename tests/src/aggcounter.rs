@@ -1,5 +1,5 @@
 use super::*;
-use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
 use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
 use ckb_testtool::ckb_types::core::{TransactionBuilder};
 
@@ -15,7 +15,7 @@ const ERROR_AGGCOUNTER_INVALID_COUNTER_VALUE: i8 = 6;
 fn test_aggcounter_burn()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -61,7 +61,7 @@ fn test_aggcounter_burn()
 fn test_aggcounter_burn_multiple()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -107,7 +107,7 @@ fn test_aggcounter_burn_multiple()
 fn test_aggcounter_create()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -157,7 +157,7 @@ fn test_aggcounter_create()
 fn test_aggcounter_create_multiple()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -213,7 +213,7 @@ fn test_aggcounter_create_multiple()
 fn test_aggcounter_create_no_output_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -262,7 +262,7 @@ fn test_aggcounter_create_no_output_data()
 fn test_aggcounter_create_invalid_output_data_value()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -312,7 +312,7 @@ fn test_aggcounter_create_invalid_output_data_value()
 fn test_aggcounter_create_invalid_output_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -362,7 +362,7 @@ fn test_aggcounter_create_invalid_output_data()
 fn test_aggcounter_transfer()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -412,7 +412,7 @@ fn test_aggcounter_transfer()
 fn test_aggcounter_transfer_high_value()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -462,7 +462,7 @@ fn test_aggcounter_transfer_high_value()
 fn test_aggcounter_transfer_multiple()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -526,7 +526,7 @@ fn test_aggcounter_transfer_multiple()
 fn test_aggcounter_transfer_multiple_mismatch_cell_count_too_few_outputs()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -587,7 +587,7 @@ fn test_aggcounter_transfer_multiple_mismatch_cell_count_too_few_outputs()
 fn test_aggcounter_transfer_multiple_mismatch_cell_count_too_many_outputs()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -654,7 +654,7 @@ fn test_aggcounter_transfer_multiple_mismatch_cell_count_too_many_outputs()
 fn test_aggcounter_transfer_plus_2()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -704,7 +704,7 @@ fn test_aggcounter_transfer_plus_2()
 fn test_aggcounter_transfer_plus_9000()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -754,7 +754,7 @@ fn test_aggcounter_transfer_plus_9000()
 fn test_aggcounter_transfer_minus_1()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -804,7 +804,7 @@ fn test_aggcounter_transfer_minus_1()
 fn test_aggcounter_transfer_overflow_panic_expected()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -854,7 +854,7 @@ fn test_aggcounter_transfer_overflow_panic_expected()
 fn test_aggcounter_transfer_invalid_input_data_panic_expected()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -904,7 +904,7 @@ fn test_aggcounter_transfer_invalid_input_data_panic_expected()
 fn test_aggcounter_transfer_invalid_output_data_panic_expected()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -1,5 +1,5 @@
 use super::*;
-use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
 use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
 use ckb_testtool::ckb_types::core::{TransactionBuilder};
 
@@ -15,7 +15,7 @@ const ERROR_AGGDOUBLECOUNTER_INVALID_COUNTER_VALUE: i8 = 6;
 fn test_aggdoublecounter_burn() // Expected failure. (Not implemented.)
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -63,7 +63,7 @@ fn test_aggdoublecounter_burn() // Expected failure. (Not implemented.)
 fn test_aggdoublecounter_burn_multiple() // Expected failure. (Not implemented.)
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -111,7 +111,7 @@ fn test_aggdoublecounter_burn_multiple() // Expected failure. (Not implemented.)
 fn test_aggdoublecounter_create()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -163,7 +163,7 @@ fn test_aggdoublecounter_create()
 fn test_aggdoublecounter_create_multiple()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -221,7 +221,7 @@ fn test_aggdoublecounter_create_multiple()
 fn test_aggdoublecounter_create_no_output_data() // Expected success. (Not implemented.)
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -270,7 +270,7 @@ fn test_aggdoublecounter_create_no_output_data() // Expected success. (Not imple
 fn test_aggdoublecounter_create_invalid_output_data_value() // Expected success. (Not implemented.)
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -320,7 +320,7 @@ fn test_aggdoublecounter_create_invalid_output_data_value() // Expected success.
 fn test_aggdoublecounter_transfer()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -374,7 +374,7 @@ fn test_aggdoublecounter_transfer()
 fn test_aggdoublecounter_transfer_high_value()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -428,7 +428,7 @@ fn test_aggdoublecounter_transfer_high_value()
 fn test_aggdoublecounter_transfer_multiple()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -504,7 +504,7 @@ fn test_aggdoublecounter_transfer_multiple()
 fn test_aggdoublecounter_transfer_multiple_mismatch_cell_count_too_few_outputs()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -562,7 +562,7 @@ fn test_aggdoublecounter_transfer_multiple_mismatch_cell_count_too_few_outputs()
 fn test_aggdoublecounter_transfer_multiple_mismatch_cell_count_too_many_outputs()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -624,7 +624,7 @@ fn test_aggdoublecounter_transfer_multiple_mismatch_cell_count_too_many_outputs(
 fn test_aggdoublecounter_transfer_value_1_plus_2()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -678,7 +678,7 @@ fn test_aggdoublecounter_transfer_value_1_plus_2()
 fn test_aggdoublecounter_transfer_value_2_plus_9000()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -732,7 +732,7 @@ fn test_aggdoublecounter_transfer_value_2_plus_9000()
 fn test_aggdoublecounter_transfer_overflow_panic_expected()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -786,7 +786,7 @@ fn test_aggdoublecounter_transfer_overflow_panic_expected()
 fn test_aggdoublecounter_transfer_invalid_input_data_panic_expected()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -840,7 +840,7 @@ fn test_aggdoublecounter_transfer_invalid_input_data_panic_expected()
 fn test_aggdoublecounter_transfer_invalid_output_data_panic_expected()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -0,0 +1,1443 @@
+use super::*;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::{TransactionBuilder};
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+
+// Sources
+const SOURCE_INPUT: u8 = 0;
+const SOURCE_OUTPUT: u8 = 1;
+const SOURCE_GROUP_INPUT: u8 = 2;
+const SOURCE_GROUP_OUTPUT: u8 = 3;
+
+// Operators
+const OPERATOR_EQUAL: u8 = 0;
+const OPERATOR_NOT_EQUAL: u8 = 1;
+const OPERATOR_LESS_THAN: u8 = 2;
+const OPERATOR_LESS_THAN_OR_EQUAL: u8 = 3;
+const OPERATOR_GREATER_THAN: u8 = 4;
+const OPERATOR_GREATER_THAN_OR_EQUAL: u8 = 5;
+const OPERATOR_RANGE: u8 = 6;
+
+// Error Codes
+const ERROR_CELLCOUNT_ARGSLEN: i8 = 5;
+const ERROR_CELLCOUNT_INVALID_SOURCE: i8 = 6;
+const ERROR_CELLCOUNT_INVALID_OPERATOR: i8 = 7;
+const ERROR_CELLCOUNT_UNAUTHORIZED: i8 = 8;
+
+#[test]
+fn test_cellcount_input_equal_valid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_EQUAL], 3u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..3
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_cellcount_input_equal_invalid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_EQUAL], 3u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..2
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_UNAUTHORIZED);
+}
+
+#[test]
+fn test_cellcount_input_not_equal_valid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_NOT_EQUAL], 3u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..2
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_cellcount_input_not_equal_invalid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_NOT_EQUAL], 3u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..3
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_UNAUTHORIZED);
+}
+
+#[test]
+fn test_cellcount_input_less_than_valid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_LESS_THAN], 3u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..2
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_cellcount_input_less_than_invalid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_LESS_THAN], 3u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..3
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_UNAUTHORIZED);
+}
+
+#[test]
+fn test_cellcount_input_less_than_or_equal_valid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_LESS_THAN_OR_EQUAL], 3u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..3
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_cellcount_input_less_than_or_equal_invalid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_LESS_THAN_OR_EQUAL], 3u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..4
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_UNAUTHORIZED);
+}
+
+#[test]
+fn test_cellcount_input_greater_than_valid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_GREATER_THAN], 3u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..4
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_cellcount_input_greater_than_invalid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_GREATER_THAN], 3u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..3
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_UNAUTHORIZED);
+}
+
+#[test]
+fn test_cellcount_input_greater_than_or_equal_valid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_GREATER_THAN_OR_EQUAL], 3u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..3
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_cellcount_input_greater_than_or_equal_invalid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_GREATER_THAN_OR_EQUAL], 3u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..2
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_UNAUTHORIZED);
+}
+
+#[test]
+fn test_cellcount_input_range_minimum_valid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_RANGE], 2u64.to_le_bytes().to_vec(), 4u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..2
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_cellcount_input_range_maximum_valid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_RANGE], 2u64.to_le_bytes().to_vec(), 4u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..4
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_cellcount_input_range_too_few()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_RANGE], 2u64.to_le_bytes().to_vec(), 4u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_UNAUTHORIZED);
+}
+
+#[test]
+fn test_cellcount_input_range_too_many()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_RANGE], 2u64.to_le_bytes().to_vec(), 4u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..5
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_UNAUTHORIZED);
+}
+
+#[test]
+fn test_cellcount_output_equal_valid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_OUTPUT, OPERATOR_EQUAL], 5u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	for _ in 0..4
+	{
+		let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).build();
+		outputs.push(output);
+		outputs_data.push(Bytes::new());
+	}
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_cellcount_output_equal_invalid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_OUTPUT, OPERATOR_EQUAL], 5u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	for _ in 0..3
+	{
+		let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).build();
+		outputs.push(output);
+		outputs_data.push(Bytes::new());
+	}
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_UNAUTHORIZED);
+}
+
+#[test]
+fn test_cellcount_group_input_equal_valid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_GROUP_INPUT, OPERATOR_EQUAL], 2u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..2
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+	for _ in 0..3
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_cellcount_group_input_equal_invalid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_GROUP_INPUT, OPERATOR_EQUAL], 2u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	for _ in 0..3
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_UNAUTHORIZED);
+}
+
+#[test]
+fn test_cellcount_group_output_equal_valid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_GROUP_OUTPUT, OPERATOR_EQUAL], 2u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..3
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	for _ in 0..2
+	{
+		let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+		outputs.push(output);
+		outputs_data.push(Bytes::new());
+	}
+	for _ in 0..3
+	{
+		let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).build();
+		outputs.push(output);
+		outputs_data.push(Bytes::new());
+	}
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_cellcount_group_output_equal_invalid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_GROUP_OUTPUT, OPERATOR_EQUAL], 2u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	for _ in 0..3
+	{
+		let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+		let input = CellInput::new_builder().previous_output(input_out_point).build();
+		inputs.push(input);
+	}
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	for _ in 0..3
+	{
+		let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).build();
+		outputs.push(output);
+		outputs_data.push(Bytes::new());
+	}
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_UNAUTHORIZED);
+}
+
+#[test]
+fn test_cellcount_no_args()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let type_script = context.build_script(&out_point_cellcount, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_ARGSLEN);
+}
+
+#[test]
+fn test_cellcount_args_len_invalid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = vec![SOURCE_INPUT, OPERATOR_EQUAL];
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_ARGSLEN);
+}
+
+#[test]
+fn test_cellcount_range_args_len_invalid()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, OPERATOR_RANGE], 1u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_ARGSLEN);
+}
+
+#[test]
+fn test_cellcount_invalid_source()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![4, OPERATOR_EQUAL], 1u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_INVALID_SOURCE);
+}
+
+#[test]
+fn test_cellcount_invalid_operator()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_cellcount = context.deploy_cell(Loader::default().load_binary("cellcount"));
+
+	// Prepare Cell Deps
+	let mut cell_deps = vec![];
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	cell_deps.push(always_success_dep);
+	let cellcount_dep = CellDep::new_builder().out_point(out_point_cellcount.clone()).build();
+	cell_deps.push(cellcount_dep);
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [vec![SOURCE_INPUT, 7], 1u64.to_le_bytes().to_vec()].concat();
+	let type_script = context.build_script(&out_point_cellcount, Bytes::from(args)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_deps(cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CELLCOUNT_INVALID_OPERATOR);
+}
@@ -0,0 +1,779 @@
+use super::*;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::{TransactionBuilder};
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+const SINCE_RELATIVE_EPOCH_FLAGS: u64 = 0xA000_0000_0000_0000;
+const SINCE_ABSOLUTE_EPOCH_FLAGS: u64 = 0x2000_0000_0000_0000;
+const SINCE_RELATIVE_BLOCK_NUMBER_FLAGS: u64 = 0x8000_0000_0000_0000;
+
+// Error Codes
+const ERROR_CHEQUELOCK_ARGSLEN: i8 = 5;
+const ERROR_CHEQUELOCK_UNAUTHORIZED: i8 = 6;
+const ERROR_CHEQUELOCK_INVALID_SINCE: i8 = 7;
+
+#[test]
+fn test_chequelock_send()
+{
+	// The cheque lock does not execute when cells are sent to it.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_chequelock = context.deploy_cell(Loader::default().load_binary("chequelock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let chequelock_dep = CellDep::new_builder().out_point(out_point_chequelock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let sender_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let receiver_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let sender_lock_hash = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let receiver_lock_hash = receiver_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let chequelock_args: Bytes = [&receiver_lock_hash[0..20], &sender_lock_hash[0..20]].concat().into();
+	let chequelock_lock_script = context.build_script(&out_point_chequelock, chequelock_args).expect("script");
+	let sudt_args: Bytes = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(sender_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(80_000_000_000_u64.pack()).lock(sender_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(600u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(chequelock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_chequelock_claim()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_chequelock = context.deploy_cell(Loader::default().load_binary("chequelock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let chequelock_dep = CellDep::new_builder().out_point(out_point_chequelock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let sender_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let receiver_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let sender_lock_hash = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let receiver_lock_hash = receiver_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let chequelock_args: Bytes = [&receiver_lock_hash[0..20], &sender_lock_hash[0..20]].concat().into();
+	let chequelock_lock_script = context.build_script(&out_point_chequelock, chequelock_args).expect("script");
+	let sudt_args: Bytes = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(400u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(receiver_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(receiver_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(chequelock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_chequelock_claim_multiple()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_chequelock = context.deploy_cell(Loader::default().load_binary("chequelock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let chequelock_dep = CellDep::new_builder().out_point(out_point_chequelock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let sender_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let receiver_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let sender_lock_hash = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let receiver_lock_hash = receiver_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let chequelock_args: Bytes = [&receiver_lock_hash[0..20], &sender_lock_hash[0..20]].concat().into();
+	let chequelock_lock_script = context.build_script(&out_point_chequelock, chequelock_args).expect("script");
+	let sudt_args: Bytes = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(400u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(500u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(receiver_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(receiver_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(900u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(chequelock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_chequelock_claim_no_receiver()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_chequelock = context.deploy_cell(Loader::default().load_binary("chequelock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let chequelock_dep = CellDep::new_builder().out_point(out_point_chequelock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let sender_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let receiver_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let other_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let sender_lock_hash = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let receiver_lock_hash = receiver_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let chequelock_args: Bytes = [&receiver_lock_hash[0..20], &sender_lock_hash[0..20]].concat().into();
+	let chequelock_lock_script = context.build_script(&out_point_chequelock, chequelock_args).expect("script");
+	let sudt_args: Bytes = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(400u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(other_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(other_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(chequelock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CHEQUELOCK_UNAUTHORIZED);
+}
+
+#[test]
+fn test_chequelock_withdraw()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_chequelock = context.deploy_cell(Loader::default().load_binary("chequelock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let chequelock_dep = CellDep::new_builder().out_point(out_point_chequelock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let sender_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let receiver_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let sender_lock_hash = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let receiver_lock_hash = receiver_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let chequelock_args: Bytes = [&receiver_lock_hash[0..20], &sender_lock_hash[0..20]].concat().into();
+	let chequelock_lock_script = context.build_script(&out_point_chequelock, chequelock_args).expect("script");
+	let sudt_args: Bytes = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(400u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_RELATIVE_EPOCH_FLAGS | 6).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(sender_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(sender_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(chequelock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_chequelock_withdraw_fractional_epoch()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_chequelock = context.deploy_cell(Loader::default().load_binary("chequelock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let chequelock_dep = CellDep::new_builder().out_point(out_point_chequelock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let sender_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let receiver_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let sender_lock_hash = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let receiver_lock_hash = receiver_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let chequelock_args: Bytes = [&receiver_lock_hash[0..20], &sender_lock_hash[0..20]].concat().into();
+	let chequelock_lock_script = context.build_script(&out_point_chequelock, chequelock_args).expect("script");
+	let sudt_args: Bytes = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(400u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_RELATIVE_EPOCH_FLAGS | (10 << 40) | (5 << 24) | 6).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(sender_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(sender_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(chequelock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_chequelock_withdraw_too_early()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_chequelock = context.deploy_cell(Loader::default().load_binary("chequelock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let chequelock_dep = CellDep::new_builder().out_point(out_point_chequelock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let sender_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let receiver_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let sender_lock_hash = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let receiver_lock_hash = receiver_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let chequelock_args: Bytes = [&receiver_lock_hash[0..20], &sender_lock_hash[0..20]].concat().into();
+	let chequelock_lock_script = context.build_script(&out_point_chequelock, chequelock_args).expect("script");
+	let sudt_args: Bytes = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(400u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_RELATIVE_EPOCH_FLAGS | 5).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(sender_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(sender_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(chequelock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CHEQUELOCK_INVALID_SINCE);
+}
+
+#[test]
+fn test_chequelock_withdraw_no_since()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_chequelock = context.deploy_cell(Loader::default().load_binary("chequelock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let chequelock_dep = CellDep::new_builder().out_point(out_point_chequelock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let sender_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let receiver_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let sender_lock_hash = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let receiver_lock_hash = receiver_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let chequelock_args: Bytes = [&receiver_lock_hash[0..20], &sender_lock_hash[0..20]].concat().into();
+	let chequelock_lock_script = context.build_script(&out_point_chequelock, chequelock_args).expect("script");
+	let sudt_args: Bytes = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(400u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(sender_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(sender_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(chequelock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CHEQUELOCK_INVALID_SINCE);
+}
+
+#[test]
+fn test_chequelock_withdraw_absolute_since()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_chequelock = context.deploy_cell(Loader::default().load_binary("chequelock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let chequelock_dep = CellDep::new_builder().out_point(out_point_chequelock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let sender_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let receiver_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let sender_lock_hash = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let receiver_lock_hash = receiver_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let chequelock_args: Bytes = [&receiver_lock_hash[0..20], &sender_lock_hash[0..20]].concat().into();
+	let chequelock_lock_script = context.build_script(&out_point_chequelock, chequelock_args).expect("script");
+	let sudt_args: Bytes = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(400u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH_FLAGS | 6).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(sender_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(sender_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(chequelock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CHEQUELOCK_INVALID_SINCE);
+}
+
+#[test]
+fn test_chequelock_withdraw_block_number_since()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_chequelock = context.deploy_cell(Loader::default().load_binary("chequelock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let chequelock_dep = CellDep::new_builder().out_point(out_point_chequelock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let sender_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let receiver_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let sender_lock_hash = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let receiver_lock_hash = receiver_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let chequelock_args: Bytes = [&receiver_lock_hash[0..20], &sender_lock_hash[0..20]].concat().into();
+	let chequelock_lock_script = context.build_script(&out_point_chequelock, chequelock_args).expect("script");
+	let sudt_args: Bytes = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(400u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_RELATIVE_BLOCK_NUMBER_FLAGS | 10_000).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(sender_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(sender_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(chequelock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CHEQUELOCK_INVALID_SINCE);
+}
+
+#[test]
+fn test_chequelock_withdraw_multiple_one_too_early()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_chequelock = context.deploy_cell(Loader::default().load_binary("chequelock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let chequelock_dep = CellDep::new_builder().out_point(out_point_chequelock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let sender_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let receiver_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let sender_lock_hash = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let receiver_lock_hash = receiver_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let chequelock_args: Bytes = [&receiver_lock_hash[0..20], &sender_lock_hash[0..20]].concat().into();
+	let chequelock_lock_script = context.build_script(&out_point_chequelock, chequelock_args).expect("script");
+	let sudt_args: Bytes = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(400u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_RELATIVE_EPOCH_FLAGS | 6).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(500u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_RELATIVE_EPOCH_FLAGS | 5).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(sender_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(sender_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(900u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(chequelock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CHEQUELOCK_INVALID_SINCE);
+}
+
+#[test]
+fn test_chequelock_withdraw_no_sender()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_chequelock = context.deploy_cell(Loader::default().load_binary("chequelock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let chequelock_dep = CellDep::new_builder().out_point(out_point_chequelock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let sender_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let receiver_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let other_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let sender_lock_hash = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let receiver_lock_hash = receiver_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let chequelock_args: Bytes = [&receiver_lock_hash[0..20], &sender_lock_hash[0..20]].concat().into();
+	let chequelock_lock_script = context.build_script(&out_point_chequelock, chequelock_args).expect("script");
+	let sudt_args: Bytes = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(400u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_RELATIVE_EPOCH_FLAGS | 6).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(other_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(other_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(chequelock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CHEQUELOCK_UNAUTHORIZED);
+}
+
+#[test]
+fn test_chequelock_args_len()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_chequelock = context.deploy_cell(Loader::default().load_binary("chequelock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let chequelock_dep = CellDep::new_builder().out_point(out_point_chequelock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let sender_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let receiver_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let sender_lock_hash = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let receiver_lock_hash = receiver_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let chequelock_args: Bytes = [receiver_lock_hash, sender_lock_hash].concat().into();
+	let chequelock_lock_script = context.build_script(&out_point_chequelock, chequelock_args).expect("script");
+	let sudt_args: Bytes = sender_lock_script.calc_script_hash().unpack().as_bytes().to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(chequelock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(400u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(receiver_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(receiver_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(chequelock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_CHEQUELOCK_ARGSLEN);
+}
@@ -1,6 +1,5 @@
 use super::*;
 // use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
-use ckb_testtool::{context::Context};
 use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
 use ckb_testtool::ckb_types::core::{TransactionBuilder};
 
@@ -14,7 +13,7 @@ const ERROR_CKB500_UNAUTHORIZED: i8 = 5;
 fn test_ckb500_minimum_capacity()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("ckb500");
@@ -42,7 +41,7 @@ fn test_ckb500_minimum_capacity()
 fn test_ckb500_exact_capacity()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("ckb500");
@@ -71,7 +70,7 @@ fn test_ckb500_exact_capacity()
 fn test_ckb500_multi_cell_exact_capacity()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("ckb500");
@@ -100,7 +99,7 @@ fn test_ckb500_multi_cell_exact_capacity()
 fn test_ckb500_over_capacity()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("ckb500");
@@ -0,0 +1,307 @@
+use super::*;
+use std::collections::HashMap;
+use std::process;
+use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::{DepType, EpochNumberWithFraction, HeaderBuilder, TransactionBuilder};
+use ::compat::{recording, Outcome};
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+
+/// Create an empty temporary directory which is unique to the test.
+fn temp_dir(name: &str) -> PathBuf
+{
+	let dir = env::temp_dir().join(format!("compat-{}-{}", name, process::id()));
+	let _ = fs::remove_dir_all(&dir);
+	fs::create_dir_all(&dir).expect("temp dir");
+
+	dir
+}
+
+/// Build a counter transaction that updates the value, verify it, and record it to the directory.
+fn record_counter_tx(dir: &Path, name: &str, input_value: u64, output_value: u64) -> Outcome
+{
+	// Create Context
+	let mut context = Context::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_counter = context.deploy_cell(Loader::default().load_binary("counter"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let counter_dep = CellDep::new_builder().out_point(out_point_counter.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let type_script = context.build_script(&out_point_counter, Default::default()).expect("script");
+
+	// Prepare Cells
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build(), Bytes::from(input_value.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.input(input)
+		.output(output)
+		.output_data(Bytes::from(output_value.to_le_bytes().to_vec()).pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(counter_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Record
+	let result = context.verify_tx(&tx, MAX_CYCLES);
+	::compat::record(dir, name, &context, &tx, &result).expect("record");
+
+	Outcome::from_result(&result)
+}
+
+#[test]
+fn test_compat_replay()
+{
+	// A recording replays with the same outcome and cycles as the original verification.
+
+	let dir = temp_dir("replay");
+	let outcome = record_counter_tx(&dir, "counter_increment", 1, 2);
+	assert!(matches!(outcome, Outcome::Pass { .. }));
+
+	let recordings = recording::load_corpus(&dir).expect("corpus");
+	assert_eq!(recordings.len(), 1);
+	assert_eq!(recordings[0].name, "counter_increment");
+	assert_eq!(recordings[0].outcome, outcome);
+	assert_eq!(::compat::replay(&recordings[0], &dir, &HashMap::new(), MAX_CYCLES).expect("replay"), outcome);
+}
+
+#[test]
+fn test_compat_replay_failure()
+{
+	// A recording of a failed transaction replays with the same error.
+
+	let dir = temp_dir("replay-failure");
+	let outcome = record_counter_tx(&dir, "counter_invalid_increment", 1, 3);
+	assert!(matches!(&outcome, Outcome::Fail { code: Some(6), .. }));
+
+	let recordings = recording::load_corpus(&dir).expect("corpus");
+	assert_eq!(::compat::replay(&recordings[0], &dir, &HashMap::new(), MAX_CYCLES).expect("replay"), outcome);
+}
+
+#[test]
+fn test_compat_outcome_error_code()
+{
+	// Failures are compared by error code, while failures without one are compared by message.
+
+	let fail = |code: Option<i8>, error: &str| Outcome::Fail { code, error: String::from(error) };
+	assert!(fail(Some(6), "first").is_compatible(&fail(Some(6), "second")));
+	assert!(!fail(Some(6), "first").is_compatible(&fail(Some(7), "first")));
+	assert!(!fail(Some(6), "first").is_compatible(&fail(None, "first")));
+	assert!(fail(None, "first").is_compatible(&fail(None, "first")));
+	assert!(!fail(None, "first").is_compatible(&fail(None, "second")));
+	assert!(!fail(None, "first").is_compatible(&Outcome::Pass { cycles: 0 }));
+}
+
+#[test]
+fn test_compat_replay_dep_group()
+{
+	// The cells of a dep group are recorded, and the group is resolved to them when replayed.
+
+	// Create Context
+	let mut context = Context::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_counter = context.deploy_cell(Loader::default().load_binary("counter"));
+	let dep_group_data = OutPointVec::new_builder().push(out_point_always_success.clone()).push(out_point_counter.clone()).build();
+	let out_point_dep_group = context.deploy_cell(dep_group_data.as_bytes());
+
+	// Prepare Cell Deps
+	let dep_group_dep = CellDep::new_builder().out_point(out_point_dep_group.clone()).dep_type(DepType::DepGroup.into()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let type_script = context.build_script(&out_point_counter, Default::default()).expect("script");
+
+	// Prepare Cells
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build(), Bytes::from(1u64.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.input(input)
+		.output(output)
+		.output_data(Bytes::from(2u64.to_le_bytes().to_vec()).pack())
+		.cell_dep(dep_group_dep)
+		.build();
+
+	// Record
+	let dir = temp_dir("replay-dep-group");
+	let result = context.verify_tx(&tx, MAX_CYCLES);
+	::compat::record(&dir, "counter_dep_group", &context, &tx, &result).expect("record");
+
+	// Replay
+	// The test context does not resolve dep groups, so the transaction only passes when replayed.
+	let recordings = recording::load_corpus(&dir).expect("corpus");
+	assert_eq!(recordings[0].cells.len(), 4);
+	assert!(matches!(::compat::replay(&recordings[0], &dir, &HashMap::new(), MAX_CYCLES).expect("replay"), Outcome::Pass { .. }));
+
+	// The contract is replaced when it is a member of a dep group.
+	let counter = Loader::default().load_binary("counter");
+	let doublecounter = Loader::default().load_binary("doublecounter");
+	let report = ::compat::check(&dir, &counter, &doublecounter, MAX_CYCLES).expect("check");
+	assert_eq!(report.replayed, 1);
+	assert_eq!(report.divergences.len(), 1);
+}
+
+#[test]
+fn test_compat_replay_headers()
+{
+	// Header deps and cells linked with a block are recorded and replayed.
+
+	// Create Context
+	let mut context = Context::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Header Deps
+	let header = HeaderBuilder::default().number(1_000u64.pack()).epoch(EpochNumberWithFraction::new(150, 0, 1).pack()).build();
+	context.insert_header(header.clone());
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	context.link_cell_with_block(input_out_point.clone(), header.hash(), 0);
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(60_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.header_dep(header.hash())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Record
+	let dir = temp_dir("replay-headers");
+	let result = context.verify_tx(&tx, MAX_CYCLES);
+	assert!(result.is_ok());
+	::compat::record(&dir, "vestinglock_header_dep", &context, &tx, &result).expect("record");
+
+	// Replay
+	let recordings = recording::load_corpus(&dir).expect("corpus");
+	assert_eq!(recordings[0].headers.len(), 1);
+	assert!(recordings[0].cells.iter().any(|cell| cell.block.is_some()));
+	let (replay_context, replay_tx) = ::compat::replay::context(&recordings[0], &dir).expect("context");
+	assert_eq!(replay_tx.hash(), tx.hash());
+	assert_eq!(replay_context.transaction_infos.len(), 1);
+	assert_eq!(::compat::replay(&recordings[0], &dir, &HashMap::new(), MAX_CYCLES).expect("replay"), Outcome::from_result(&result));
+}
+
+#[test]
+fn test_compat_blobs()
+{
+	// Contract binaries are stored once in the blobs directory and shared between recordings.
+
+	let dir = temp_dir("blobs");
+	record_counter_tx(&dir, "first", 1, 2);
+	record_counter_tx(&dir, "second", 2, 3);
+
+	let counter = Loader::default().load_binary("counter");
+	let blobs: Vec<PathBuf> = fs::read_dir(dir.join("blobs")).unwrap().map(|entry| entry.unwrap().path()).collect();
+	let data_hash = CellOutput::calc_data_hash(&counter);
+	assert!(blobs.iter().any(|path| path.file_name().unwrap().to_str().unwrap() == format!("{:x}", data_hash)));
+	assert_eq!(recording::load_corpus(&dir).expect("corpus").len(), 2);
+}
+
+#[test]
+fn test_compat_check_same_binary()
+{
+	// The same binary is always compatible with itself.
+
+	let dir = temp_dir("check-same-binary");
+	record_counter_tx(&dir, "counter_increment", 1, 2);
+	record_counter_tx(&dir, "counter_invalid_increment", 1, 3);
+
+	let counter = Loader::default().load_binary("counter");
+	let report = ::compat::check(&dir, &counter, &counter, MAX_CYCLES).expect("check");
+	assert!(report.is_compatible());
+	assert_eq!(report.replayed, 2);
+	assert_eq!(report.skipped, 0);
+	assert_eq!(report.old_cycles, report.new_cycles);
+}
+
+#[test]
+fn test_compat_check_divergence()
+{
+	// A binary with different rules is reported as a divergence for each transaction it handles differently.
+
+	let dir = temp_dir("check-divergence");
+	record_counter_tx(&dir, "counter_increment", 1, 2);
+	record_counter_tx(&dir, "counter_invalid_increment", 1, 3);
+
+	let counter = Loader::default().load_binary("counter");
+	let doublecounter = Loader::default().load_binary("doublecounter");
+	let report = ::compat::check(&dir, &counter, &doublecounter, MAX_CYCLES).expect("check");
+	assert!(!report.is_compatible());
+	assert_eq!(report.replayed, 2);
+	let divergence = report.divergences.iter().find(|divergence| divergence.name == "counter_increment").expect("divergence");
+	assert!(matches!(divergence.old, Outcome::Pass { .. }));
+	assert!(matches!(divergence.new, Outcome::Fail { .. }));
+
+	// The report is shown as a diff of the outcomes.
+	let output = report.to_string();
+	assert!(output.starts_with("--- old\n+++ new\n@@ counter_increment @@\n-pass ("));
+	assert!(output.ends_with(&format!("Replayed 2 transactions, {} diverged, 0 skipped. Cycles of passing transactions: 0 old, 0 new.", report.divergences.len())));
+}
+
+#[test]
+fn test_compat_check_skipped()
+{
+	// Recordings which do not use the contract are skipped, and a contract which is never exercised is not compatible.
+
+	let dir = temp_dir("check-skipped");
+	record_counter_tx(&dir, "counter_increment", 1, 2);
+
+	let never = Loader::default().load_binary("never");
+	let always = Loader::default().load_binary("always");
+	let report = ::compat::check(&dir, &never, &always, MAX_CYCLES).expect("check");
+	assert!(!report.is_compatible());
+	assert_eq!(report.replayed, 0);
+	assert_eq!(report.skipped, 1);
+	assert!(report.to_string().ends_with("\nThe contract was never exercised by a transaction in the corpus, so its compatibility was not checked."));
+}
@@ -1,5 +1,5 @@
 use super::*;
-use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
 use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
 use ckb_testtool::ckb_types::core::{TransactionBuilder};
 
@@ -15,7 +15,7 @@ const ERROR_COUNTER_INVALID_COUNTER_VALUE: i8 = 6;
 fn test_counter_burn()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -54,7 +54,7 @@ fn test_counter_burn()
 fn test_counter_burn_multiple()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -94,7 +94,7 @@ fn test_counter_burn_multiple()
 fn test_counter_create()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -133,7 +133,7 @@ fn test_counter_create()
 fn test_counter_create_no_output_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -172,7 +172,7 @@ fn test_counter_create_no_output_data()
 fn test_counter_create_invalid_output_data_value()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -211,7 +211,7 @@ fn test_counter_create_invalid_output_data_value()
 fn test_counter_create_invalid_output_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -250,7 +250,7 @@ fn test_counter_create_invalid_output_data()
 fn test_counter_transfer()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -294,7 +294,7 @@ fn test_counter_transfer()
 fn test_counter_transfer_high_value()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -338,7 +338,7 @@ fn test_counter_transfer_high_value()
 fn test_counter_transfer_plus_2()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -382,7 +382,7 @@ fn test_counter_transfer_plus_2()
 fn test_counter_transfer_plus_9000()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -426,7 +426,7 @@ fn test_counter_transfer_plus_9000()
 fn test_counter_transfer_minus_1()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -470,7 +470,7 @@ fn test_counter_transfer_minus_1()
 fn test_counter_transfer_overflow_panic_expected()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -514,7 +514,7 @@ fn test_counter_transfer_overflow_panic_expected()
 fn test_counter_transfer_invalid_input_data_panic_expected()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -558,7 +558,7 @@ fn test_counter_transfer_invalid_input_data_panic_expected()
 fn test_counter_transfer_invalid_output_data_panic_expected()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
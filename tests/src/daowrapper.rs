@@ -0,0 +1,1377 @@
+use super::*;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::{Capacity, HeaderBuilder, HeaderView, ScriptHashType, TransactionBuilder};
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+
+// Error Codes
+const ERROR_DAOWRAPPER_ARGSLEN: i8 = 5;
+const ERROR_DAOWRAPPER_INVALID_RECEIPT_AMOUNT: i8 = 6;
+const ERROR_DAOWRAPPER_MISSING_HEADER: i8 = 7;
+const ERROR_DAOWRAPPER_EXCESSIVE_WITHDRAWAL: i8 = 8;
+const ERROR_TYPEPROXYLOCK_UNAUTHORIZED: i8 = 6;
+
+/// Build a header with the specified block number and Nervos DAO accumulated rate (AR).
+/// The DAO field holds four u64 values: C, AR, S, and U. Only AR is needed to calculate interest.
+fn dao_header(number: u64, accumulated_rate: u64) -> HeaderView
+{
+	let mut dao = [0u8; 32];
+	dao[8..16].copy_from_slice(&accumulated_rate.to_le_bytes());
+
+	HeaderBuilder::default().number(number.pack()).dao(dao.pack()).build()
+}
+
+#[test]
+fn test_daowrapper_deposit()
+{
+	// Deposit 1,000 CKBytes and mint 1,000 CKBytes (in Shannons) of receipts.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(120_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(0u64.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_daowrapper_deposit_multiple()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(120_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(0u64.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(0u64.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_daowrapper_deposit_split_receipts()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(140_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(0u64.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(60_000_000_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(40_000_000_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_daowrapper_deposit_excess_receipts()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(120_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(0u64.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100_000_000_001u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DAOWRAPPER_INVALID_RECEIPT_AMOUNT);
+}
+
+#[test]
+fn test_daowrapper_deposit_insufficient_receipts()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(120_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(0u64.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(99_999_999_999u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DAOWRAPPER_INVALID_RECEIPT_AMOUNT);
+}
+
+#[test]
+fn test_daowrapper_deposit_unwrapped()
+{
+	// A deposit that is not locked by the pool lock does not count towards the receipts.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(120_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(0u64.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DAOWRAPPER_INVALID_RECEIPT_AMOUNT);
+}
+
+#[test]
+fn test_daowrapper_deposit_wrong_hash_type()
+{
+	// A deposit locked by a lock with the pool lock code hash and a different hash type does not count towards the receipts.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+	let pool_lock_script = pool_lock_script.as_builder().hash_type(ScriptHashType::Type.into()).build();
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(120_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(0u64.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DAOWRAPPER_INVALID_RECEIPT_AMOUNT);
+}
+
+#[test]
+fn test_daowrapper_deposit_not_dao()
+{
+	// A cell locked by the pool lock without the DAO type does not count towards the receipts.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(120_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DAOWRAPPER_INVALID_RECEIPT_AMOUNT);
+}
+
+#[test]
+fn test_daowrapper_transfer()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build(), Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_daowrapper_transfer_split()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(40_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build(), Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(30_000_000_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(70_000_000_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_daowrapper_transfer_amount_changed()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build(), Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100_100_000_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DAOWRAPPER_INVALID_RECEIPT_AMOUNT);
+}
+
+#[test]
+fn test_daowrapper_withdraw_phase_1()
+{
+	// The deposit cell is converted to a withdrawing cell, which still holds the principal, so no receipts are burned.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build(), Bytes::from(0u64.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build(), Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100u64.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_daowrapper_withdraw_phase_1_burn()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build(), Bytes::from(0u64.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build(), Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100u64.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DAOWRAPPER_INVALID_RECEIPT_AMOUNT);
+}
+
+#[test]
+fn test_daowrapper_withdraw_phase_1_without_receipts()
+{
+	// The pool lock cannot be unlocked without a receipt cell in the inputs.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build(), Bytes::from(0u64.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100u64.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_TYPEPROXYLOCK_UNAUTHORIZED);
+}
+
+#[test]
+fn test_daowrapper_withdraw()
+{
+	// Withdraw the deposit with interest and burn the receipts for the principal.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Calculate Withdraw Capacity
+	// The accumulated rate increases by 10% between the deposit and withdraw headers.
+	let withdrawing_output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	let occupied_capacity = withdrawing_output.occupied_capacity(Capacity::bytes(8).unwrap()).unwrap().as_u64();
+	let withdraw_capacity = occupied_capacity + (100_000_000_000 - occupied_capacity) * 11 / 10;
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build(), Bytes::from(100u64.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build(), Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity((withdraw_capacity + 20_000_000_000).pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Header Deps
+	let deposit_header = dao_header(100, 10_000_000_000_000_000);
+	let withdraw_header = dao_header(200, 11_000_000_000_000_000);
+	context.insert_header(deposit_header.clone());
+	context.insert_header(withdraw_header.clone());
+	context.link_cell_with_block(inputs[0].previous_output(), withdraw_header.hash(), 0);
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.header_dep(deposit_header.hash())
+		.header_dep(withdraw_header.hash())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_daowrapper_withdraw_excessive_capacity()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Calculate Withdraw Capacity
+	// The accumulated rate increases by 10% between the deposit and withdraw headers.
+	let withdrawing_output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	let occupied_capacity = withdrawing_output.occupied_capacity(Capacity::bytes(8).unwrap()).unwrap().as_u64();
+	let withdraw_capacity = occupied_capacity + (100_000_000_000 - occupied_capacity) * 11 / 10;
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build(), Bytes::from(100u64.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build(), Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity((withdraw_capacity + 20_000_000_001).pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Header Deps
+	let deposit_header = dao_header(100, 10_000_000_000_000_000);
+	let withdraw_header = dao_header(200, 11_000_000_000_000_000);
+	context.insert_header(deposit_header.clone());
+	context.insert_header(withdraw_header.clone());
+	context.link_cell_with_block(inputs[0].previous_output(), withdraw_header.hash(), 0);
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.header_dep(deposit_header.hash())
+		.header_dep(withdraw_header.hash())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DAOWRAPPER_EXCESSIVE_WITHDRAWAL);
+}
+
+#[test]
+fn test_daowrapper_withdraw_receipts_not_burned()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Calculate Withdraw Capacity
+	// The accumulated rate increases by 10% between the deposit and withdraw headers.
+	let withdrawing_output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	let occupied_capacity = withdrawing_output.occupied_capacity(Capacity::bytes(8).unwrap()).unwrap().as_u64();
+	let withdraw_capacity = occupied_capacity + (100_000_000_000 - occupied_capacity) * 11 / 10;
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build(), Bytes::from(100u64.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build(), Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(withdraw_capacity.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+
+	// Prepare Header Deps
+	let deposit_header = dao_header(100, 10_000_000_000_000_000);
+	let withdraw_header = dao_header(200, 11_000_000_000_000_000);
+	context.insert_header(deposit_header.clone());
+	context.insert_header(withdraw_header.clone());
+	context.link_cell_with_block(inputs[0].previous_output(), withdraw_header.hash(), 0);
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.header_dep(deposit_header.hash())
+		.header_dep(withdraw_header.hash())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DAOWRAPPER_INVALID_RECEIPT_AMOUNT);
+}
+
+#[test]
+fn test_daowrapper_withdraw_partial_burn()
+{
+	// Only the receipts for the principal are burned, and the remainder is kept.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Calculate Withdraw Capacity
+	// The accumulated rate increases by 10% between the deposit and withdraw headers.
+	let withdrawing_output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	let occupied_capacity = withdrawing_output.occupied_capacity(Capacity::bytes(8).unwrap()).unwrap().as_u64();
+	let withdraw_capacity = occupied_capacity + (100_000_000_000 - occupied_capacity) * 11 / 10;
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build(), Bytes::from(100u64.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build(), Bytes::from(150_000_000_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(withdraw_capacity.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(50_000_000_000u128.to_le_bytes().to_vec()));
+
+	// Prepare Header Deps
+	let deposit_header = dao_header(100, 10_000_000_000_000_000);
+	let withdraw_header = dao_header(200, 11_000_000_000_000_000);
+	context.insert_header(deposit_header.clone());
+	context.insert_header(withdraw_header.clone());
+	context.link_cell_with_block(inputs[0].previous_output(), withdraw_header.hash(), 0);
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.header_dep(deposit_header.hash())
+		.header_dep(withdraw_header.hash())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_daowrapper_withdraw_missing_deposit_header()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Calculate Withdraw Capacity
+	// The accumulated rate increases by 10% between the deposit and withdraw headers.
+	let withdrawing_output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	let occupied_capacity = withdrawing_output.occupied_capacity(Capacity::bytes(8).unwrap()).unwrap().as_u64();
+	let withdraw_capacity = occupied_capacity + (100_000_000_000 - occupied_capacity) * 11 / 10;
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build(), Bytes::from(100u64.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build(), Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity((withdraw_capacity + 20_000_000_000).pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Header Deps
+	let deposit_header = dao_header(100, 10_000_000_000_000_000);
+	let withdraw_header = dao_header(200, 11_000_000_000_000_000);
+	context.insert_header(deposit_header.clone());
+	context.insert_header(withdraw_header.clone());
+	context.link_cell_with_block(inputs[0].previous_output(), withdraw_header.hash(), 0);
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.header_dep(withdraw_header.hash())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DAOWRAPPER_MISSING_HEADER);
+}
+
+#[test]
+fn test_daowrapper_withdraw_missing_withdraw_header()
+{
+	// The withdrawing cell is linked to the withdraw block, but the withdraw header is not included in the header deps.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let pool_lock_code_hash = pool_lock_template.code_hash().as_bytes();
+	let pool_lock_hash_type = Bytes::from(vec![pool_lock_template.hash_type().into()]);
+	let daowrapper_args: Bytes = [dao_type_hash, pool_lock_code_hash, pool_lock_hash_type].concat().into();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, daowrapper_args).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Calculate Withdraw Capacity
+	// The accumulated rate increases by 10% between the deposit and withdraw headers.
+	let withdrawing_output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	let occupied_capacity = withdrawing_output.occupied_capacity(Capacity::bytes(8).unwrap()).unwrap().as_u64();
+	let withdraw_capacity = occupied_capacity + (100_000_000_000 - occupied_capacity) * 11 / 10;
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build(), Bytes::from(100u64.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build(), Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity((withdraw_capacity + 20_000_000_000).pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Header Deps
+	let deposit_header = dao_header(100, 10_000_000_000_000_000);
+	let withdraw_header = dao_header(200, 11_000_000_000_000_000);
+	context.insert_header(deposit_header.clone());
+	context.insert_header(withdraw_header.clone());
+	context.link_cell_with_block(inputs[0].previous_output(), withdraw_header.hash(), 0);
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.header_dep(deposit_header.hash())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DAOWRAPPER_MISSING_HEADER);
+}
+
+#[test]
+fn test_daowrapper_args_len()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_daowrapper = context.deploy_cell(Loader::default().load_binary("daowrapper"));
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let daowrapper_dep = CellDep::new_builder().out_point(out_point_daowrapper.clone()).build();
+	let typeproxylock_dep = CellDep::new_builder().out_point(out_point_typeproxylock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let dao_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let dao_type_hash = dao_type_script.calc_script_hash().as_bytes();
+	let daowrapper_type_script = context.build_script(&out_point_daowrapper, dao_type_hash).expect("script");
+	let pool_lock_script = context.build_script(&out_point_typeproxylock, daowrapper_type_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(120_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(pool_lock_script.clone()).type_(Some(dao_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(0u64.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(daowrapper_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(100_000_000_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(daowrapper_dep)
+		.cell_dep(typeproxylock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DAOWRAPPER_ARGSLEN);
+}
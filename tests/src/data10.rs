@@ -1,10 +1,11 @@
 use super::*;
-use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
 use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
-use ckb_testtool::ckb_types::core::{TransactionBuilder};
+use ckb_testtool::ckb_types::core::{Cycle, TransactionBuilder};
 
 // Constants
 const MAX_CYCLES: u64 = 100_000_000;
+const BURN_MAX_CYCLES: u64 = 1_000_000; // A budget for the burn of a data10 cell in a large transaction.
 
 // Error Codes
 const ERROR_DATA10_DATA_LIMIT_EXCEEDED: i8 = 5;
@@ -13,7 +14,7 @@ const ERROR_DATA10_DATA_LIMIT_EXCEEDED: i8 = 5;
 fn test_data10_valid_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -58,7 +59,7 @@ fn test_data10_valid_data()
 fn test_data10_empty_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -103,7 +104,7 @@ fn test_data10_empty_data()
 fn test_data10_data_limit_exceeded()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -148,7 +149,7 @@ fn test_data10_data_limit_exceeded()
 fn test_data10_burn()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -181,3 +182,218 @@ fn test_data10_burn()
 	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
 	// println!("consume cycles: {}", cycles);
 }
+
+#[test]
+fn test_data10_args_valid_data()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_data10 = context.deploy_cell(Loader::default().load_binary("data10"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let data10_dep = CellDep::new_builder().out_point(out_point_data10.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = 16u32.to_le_bytes().to_vec();
+	let type_script = context.build_script(&out_point_data10, Bytes::from(args)).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Hello World!".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(data10_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_data10_args_data_limit_exceeded()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_data10 = context.deploy_cell(Loader::default().load_binary("data10"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let data10_dep = CellDep::new_builder().out_point(out_point_data10.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = 8u32.to_le_bytes().to_vec();
+	let type_script = context.build_script(&out_point_data10, Bytes::from(args)).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("HelloWorld".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(data10_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DATA10_DATA_LIMIT_EXCEEDED);
+}
+
+
+#[test]
+fn test_data10_args_other_length()
+{
+	// Args which are not 4 bytes are ignored, so the default limit of 10 bytes applies.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_data10 = context.deploy_cell(Loader::default().load_binary("data10"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let data10_dep = CellDep::new_builder().out_point(out_point_data10.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = 16u16.to_le_bytes().to_vec();
+	let type_script = context.build_script(&out_point_data10, Bytes::from(args)).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Hello World!".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(data10_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DATA10_DATA_LIMIT_EXCEEDED);
+}
+
+/// Verify a transaction which burns a data10 cell of the binary, with a number of unrelated output cells, and return the cycles.
+fn burn_cycles(binary: Bytes, unrelated_outputs: usize, max_cycles: u64) -> Result<Cycle, Error>
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_data10 = context.deploy_cell(binary);
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let data10_dep = CellDep::new_builder().out_point(out_point_data10.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let type_script = context.build_script(&out_point_data10, Default::default()).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	for _ in 0..unrelated_outputs
+	{
+		let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+		outputs.push(output);
+		outputs_data.push(Bytes::from("Hello World!".as_bytes().to_vec()));
+	}
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(data10_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	context.verify_tx(&tx, max_cycles)
+}
+
+#[test]
+fn test_data10_burn_with_unrelated_outputs()
+{
+	// The baseline data10 loaded every output of the transaction to find its own, so a burn with many unrelated outputs
+	// could run out of cycles. Only the group outputs are loaded now, so the burn fits within the same budget.
+	let baseline = Bytes::from(&include_bytes!("../fixtures/data10_baseline")[..]);
+	let data10 = Loader::default().load_binary("data10");
+
+	let err = burn_cycles(baseline, 1_000, BURN_MAX_CYCLES).unwrap_err();
+	assert!(err.to_string().contains("ExceededMaximumCycles"), "{}", err);
+	burn_cycles(data10, 1_000, BURN_MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_data10_cycles_large_transaction()
+{
+	// The baseline binary is the data10 contract before it was scoped to its group outputs. See fixtures/README.md.
+	let baseline = Bytes::from(&include_bytes!("../fixtures/data10_baseline")[..]);
+	let data10 = Loader::default().load_binary("data10");
+
+	let baseline_small = burn_cycles(baseline.clone(), 0, MAX_CYCLES).expect("pass verification");
+	let baseline_large = burn_cycles(baseline, 500, MAX_CYCLES).expect("pass verification");
+	let cycles_small = burn_cycles(data10.clone(), 0, MAX_CYCLES).expect("pass verification");
+	let cycles_large = burn_cycles(data10, 500, MAX_CYCLES).expect("pass verification");
+
+	// The cycles of the baseline grow with the number of outputs, and the cycles of data10 do not.
+	assert!(baseline_large > baseline_small);
+	assert_eq!(cycles_small, cycles_large);
+	assert!(cycles_large < baseline_large);
+}
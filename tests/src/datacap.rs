@@ -1,5 +1,5 @@
 use super::*;
-use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
 use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
 use ckb_testtool::ckb_types::core::{TransactionBuilder};
 
@@ -14,7 +14,7 @@ const ERROR_DATACAP_DATA_LIMIT_EXCEEDED: i8 = 6;
 fn test_datacap_valid_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -59,7 +59,7 @@ fn test_datacap_valid_data()
 fn test_datacap_empty_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -104,7 +104,7 @@ fn test_datacap_empty_data()
 fn test_datacap_empty_args()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -148,7 +148,7 @@ fn test_datacap_empty_args()
 fn test_datacap_data_limit_exceeded()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -193,7 +193,7 @@ fn test_datacap_data_limit_exceeded()
 fn test_datacap_burn()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
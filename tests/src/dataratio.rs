@@ -0,0 +1,523 @@
+use super::*;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::{TransactionBuilder};
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+const SHANNONS_PER_CKBYTE: u64 = 100_000_000;
+
+// Error Codes
+const ERROR_DATARATIO_ARGSLEN: i8 = 5;
+const ERROR_DATARATIO_DATA_LIMIT_EXCEEDED: i8 = 6;
+const ERROR_DATARATIO_INVALID_MODE: i8 = 7;
+
+#[test]
+fn test_dataratio_valid_data()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_dataratio = context.deploy_cell(Loader::default().load_binary("dataratio"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let dataratio_dep = CellDep::new_builder().out_point(out_point_dataratio.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = 1u32.to_le_bytes().to_vec();
+	let type_script = context.build_script(&out_point_dataratio, Bytes::from(args)).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(1_000_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	// Occupied capacity is 78 CKBytes for the cell structure plus 12 CKBytes for the data, leaving 12 CKBytes free.
+	let output = CellOutput::new_builder().capacity((102 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Hello World!".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(dataratio_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_dataratio_empty_data()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_dataratio = context.deploy_cell(Loader::default().load_binary("dataratio"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let dataratio_dep = CellDep::new_builder().out_point(out_point_dataratio.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = 1u32.to_le_bytes().to_vec();
+	let type_script = context.build_script(&out_point_dataratio, Bytes::from(args)).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(1_000_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	// Occupied capacity is 78 CKBytes for the cell structure, leaving no CKBytes free.
+	let output = CellOutput::new_builder().capacity((78 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(dataratio_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_dataratio_empty_args()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_dataratio = context.deploy_cell(Loader::default().load_binary("dataratio"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let dataratio_dep = CellDep::new_builder().out_point(out_point_dataratio.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let type_script = context.build_script(&out_point_dataratio, Default::default()).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(1_000_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let output = CellOutput::new_builder().capacity((78 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(dataratio_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DATARATIO_ARGSLEN);
+}
+
+#[test]
+fn test_dataratio_invalid_mode()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_dataratio = context.deploy_cell(Loader::default().load_binary("dataratio"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let dataratio_dep = CellDep::new_builder().out_point(out_point_dataratio.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [1u32.to_le_bytes().to_vec(), vec![2u8]].concat();
+	let type_script = context.build_script(&out_point_dataratio, Bytes::from(args)).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(1_000_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let output = CellOutput::new_builder().capacity((79 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(dataratio_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DATARATIO_INVALID_MODE);
+}
+
+#[test]
+fn test_dataratio_data_limit_exceeded()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_dataratio = context.deploy_cell(Loader::default().load_binary("dataratio"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let dataratio_dep = CellDep::new_builder().out_point(out_point_dataratio.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = 1u32.to_le_bytes().to_vec();
+	let type_script = context.build_script(&out_point_dataratio, Bytes::from(args)).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(1_000_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	// Occupied capacity is 78 CKBytes for the cell structure plus 12 CKBytes for the data, leaving 11 CKBytes free.
+	let output = CellOutput::new_builder().capacity((101 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Hello World!".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(dataratio_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DATARATIO_DATA_LIMIT_EXCEEDED);
+}
+
+#[test]
+fn test_dataratio_multiple()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_dataratio = context.deploy_cell(Loader::default().load_binary("dataratio"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let dataratio_dep = CellDep::new_builder().out_point(out_point_dataratio.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = 4u32.to_le_bytes().to_vec();
+	let type_script = context.build_script(&out_point_dataratio, Bytes::from(args)).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(1_000_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	// Occupied capacity is 90 CKBytes for each cell. At 4 bytes per CKByte the first cell allows 12 bytes and so does the second.
+	let output = CellOutput::new_builder().capacity((93 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Hello World!".as_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity((93 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Hello World!".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(dataratio_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_dataratio_multiple_data_limit_exceeded()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_dataratio = context.deploy_cell(Loader::default().load_binary("dataratio"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let dataratio_dep = CellDep::new_builder().out_point(out_point_dataratio.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = 4u32.to_le_bytes().to_vec();
+	let type_script = context.build_script(&out_point_dataratio, Bytes::from(args)).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(1_000_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	// Occupied capacity is 90 CKBytes for each cell. At 4 bytes per CKByte the first cell allows 12 bytes and the second allows none.
+	let output = CellOutput::new_builder().capacity((93 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Hello World!".as_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity((90 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Hello World!".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(dataratio_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DATARATIO_DATA_LIMIT_EXCEEDED);
+}
+
+#[test]
+fn test_dataratio_aggregate()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_dataratio = context.deploy_cell(Loader::default().load_binary("dataratio"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let dataratio_dep = CellDep::new_builder().out_point(out_point_dataratio.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [1u32.to_le_bytes().to_vec(), vec![1u8]].concat();
+	let type_script = context.build_script(&out_point_dataratio, Bytes::from(args)).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(1_000_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	// Occupied capacity is 79 CKBytes for the cell structure plus the data. The first cell has none free and the second has 25 free.
+	let output = CellOutput::new_builder().capacity((104 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Hello World! Hello World!".as_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity((104 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(dataratio_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_dataratio_aggregate_data_limit_exceeded()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_dataratio = context.deploy_cell(Loader::default().load_binary("dataratio"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let dataratio_dep = CellDep::new_builder().out_point(out_point_dataratio.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = [1u32.to_le_bytes().to_vec(), vec![1u8]].concat();
+	let type_script = context.build_script(&out_point_dataratio, Bytes::from(args)).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(1_000_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	// Occupied capacity is 79 CKBytes for the cell structure plus the data. The cells have 24 CKBytes free between them.
+	let output = CellOutput::new_builder().capacity((104 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Hello World! Hello World!".as_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity((103 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(dataratio_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DATARATIO_DATA_LIMIT_EXCEEDED);
+}
+
+#[test]
+fn test_dataratio_per_cell_rejects_aggregate()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_dataratio = context.deploy_cell(Loader::default().load_binary("dataratio"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let dataratio_dep = CellDep::new_builder().out_point(out_point_dataratio.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = 1u32.to_le_bytes().to_vec();
+	let type_script = context.build_script(&out_point_dataratio, Bytes::from(args)).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(1_000_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	// Occupied capacity is 78 CKBytes for the cell structure plus the data. The first cell has none free and the second has 25 free.
+	let output = CellOutput::new_builder().capacity((103 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Hello World! Hello World!".as_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity((103 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(dataratio_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DATARATIO_DATA_LIMIT_EXCEEDED);
+}
+
+#[test]
+fn test_dataratio_burn()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_dataratio = context.deploy_cell(Loader::default().load_binary("dataratio"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let dataratio_dep = CellDep::new_builder().out_point(out_point_dataratio.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let args = 1u32.to_le_bytes().to_vec();
+	let type_script = context.build_script(&out_point_dataratio, Bytes::from(args)).expect("script");
+
+	// Prepare Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity((90 * SHANNONS_PER_CKBYTE).pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build(), Bytes::from("Hello World!".as_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.cell_dep(always_success_dep)
+		.cell_dep(dataratio_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
@@ -0,0 +1,686 @@
+use super::*;
+use std::path::Path;
+use std::process;
+use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_testtool::ckb_types::{bytes::Bytes, core::{Capacity, FeeRate, ScriptHashType, TransactionView}, packed::*, prelude::*, H256};
+use ::deployer::capsule::CapsuleConfig;
+use ::deployer::chain::{self, Chain, LiveCell, MockChain};
+use ::deployer::deployment::{CellConfig, CellLocation, DepGroupConfig, DeploymentConfig};
+use ::deployer::migration::{format_timestamp, Migration};
+use ::deployer::plan::{self, CellAction};
+use ::deployer::transaction::{self, DeployOutput, TransactionGenerator};
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+const CAPACITY: u64 = 10_000_000 * 100_000_000;
+const FEE_RATE: u64 = 1_000;
+
+// Error Codes
+const ERROR_TYPE_ID_INVALID_INPUT_HASH: i8 = -3;
+
+/// Create an empty temporary directory which is unique to the test.
+fn temp_dir(name: &str) -> PathBuf
+{
+	let dir = env::temp_dir().join(format!("deployer-{}-{}", name, process::id()));
+	let _ = fs::remove_dir_all(&dir);
+	fs::create_dir_all(&dir).expect("temp dir");
+
+	dir
+}
+
+/// Create the lock used to own the deployed cells.
+fn deploy_lock() -> Script
+{
+	Script::new_builder()
+		.code_hash([1u8; 32].pack())
+		.hash_type(ScriptHashType::Type.into())
+		.args(Bytes::from(vec![2u8; 20]).pack())
+		.build()
+}
+
+/// Create a generator for the deploy lock. The mock chain does not charge fees, so the fee rate is zero.
+fn mock_generator() -> TransactionGenerator
+{
+	TransactionGenerator { fee_rate: FeeRate::zero(), ..TransactionGenerator::new(deploy_lock()) }
+}
+
+/// Create a cell config which deploys the specified file.
+fn file_cell(name: &str, enable_type_id: bool) -> CellConfig
+{
+	CellConfig { name: name.to_string(), enable_type_id, location: CellLocation::File { file: name.to_string() } }
+}
+
+/// Create a mock chain with a single cell owned by the deploy lock.
+fn funded_chain(capacity: u64) -> MockChain
+{
+	let mut chain = MockChain::default();
+	chain.issue_cell(deploy_lock(), capacity);
+
+	chain
+}
+
+/// Plan and execute a deployment config using the files in the specified directory.
+fn deploy(config: &DeploymentConfig, dir: &Path, chain: &mut MockChain, previous: Option<&Migration>) -> Option<Migration>
+{
+	let plan = plan::plan(config, dir, chain, previous).expect("plan");
+	plan::execute(&plan, chain, &mock_generator(), previous).expect("execute")
+}
+
+/// Convert a recipe location to an out point.
+fn out_point(tx_hash: &H256, index: u32) -> OutPoint
+{
+	OutPoint::new(tx_hash.pack(), index)
+}
+
+#[test]
+fn test_deployer_capsule_contracts()
+{
+	// Every contract in capsule.toml is included in the generated deployment config.
+
+	let capsule = CapsuleConfig::load(Path::new("../capsule.toml")).expect("capsule.toml");
+	let names = capsule.contract_names();
+	assert!(names.contains(&String::from("always")));
+	assert!(names.contains(&String::from("sudt")));
+
+	let config = DeploymentConfig::from_contracts(&names, "build/release", None);
+	let parsed: DeploymentConfig = toml::from_str(&config.to_toml()).expect("toml");
+	assert_eq!(parsed.cells.len(), names.len());
+	for (cell, name) in parsed.cells.iter().zip(names.iter())
+	{
+		assert_eq!(&cell.name, name);
+		assert!(!cell.enable_type_id);
+		assert!(matches!(&cell.location, CellLocation::File { file } if file == &format!("build/release/{}", name)));
+	}
+}
+
+#[test]
+fn test_deployer_deployment_toml()
+{
+	// The deployment.toml in the repo deploys every contract in capsule.toml.
+
+	let capsule = CapsuleConfig::load(Path::new("../capsule.toml")).expect("capsule.toml");
+	let config = DeploymentConfig::load(Path::new("../deployment.toml")).expect("deployment.toml");
+	let names: Vec<String> = config.cells.iter().map(|cell| cell.name.clone()).collect();
+	assert_eq!(names, capsule.contract_names());
+	assert!(config.lock.expect("lock").to_script().is_ok());
+}
+
+#[test]
+fn test_deployer_data_hashes()
+{
+	// The planned data hashes match the binaries loaded by the tests.
+
+	let loader = Loader::default();
+	let capsule = CapsuleConfig::load(Path::new("../capsule.toml")).expect("capsule.toml");
+	let names = capsule.contract_names();
+	let config = DeploymentConfig::from_contracts(&names, loader.0.to_str().expect("build dir"), None);
+	let chain = MockChain::default();
+
+	let plan = plan::plan(&config, Path::new(".."), &chain, None).expect("plan");
+	assert_eq!(plan.cells.len(), names.len());
+	for cell in &plan.cells
+	{
+		let binary = loader.load_binary(&cell.name);
+		let data_hash: H256 = CellOutput::calc_data_hash(&binary).unpack();
+		assert_eq!(cell.data_hash, data_hash, "{}", cell.name);
+		assert_eq!(cell.action, CellAction::Deploy);
+	}
+}
+
+#[test]
+fn test_deployer_deploy()
+{
+	// Two code cells are deployed in a single transaction and change is returned to the lock.
+
+	let dir = temp_dir("deploy");
+	fs::write(dir.join("a"), [1u8; 100]).unwrap();
+	fs::write(dir.join("b"), [2u8; 200]).unwrap();
+	let config = DeploymentConfig { cells: vec![file_cell("a", false), file_cell("b", false)], ..Default::default() };
+	let mut chain = funded_chain(CAPACITY);
+
+	let migration = deploy(&config, &dir, &mut chain, None).expect("migration");
+	assert_eq!(migration.cell_recipes.len(), 2);
+	for (recipe, size) in migration.cell_recipes.iter().zip([100u64, 200])
+	{
+		let cell = chain.live_cell(&out_point(&recipe.tx_hash, recipe.index)).expect("live cell");
+		let data_hash: H256 = CellOutput::calc_data_hash(&cell.data).unpack();
+		assert_eq!(recipe.data_hash, data_hash);
+		assert_eq!(recipe.occupied_capacity, (8 + 53 + size) * 100_000_000);
+		assert_eq!(recipe.type_id, None);
+		assert_eq!(cell.output.lock(), deploy_lock());
+	}
+	assert_eq!(migration.cell_recipes[0].tx_hash, migration.cell_recipes[1].tx_hash);
+
+	// The code cells and the change cell are live and no capacity is lost.
+	assert_eq!(chain.live_cell_count(), 3);
+	let total: u64 = chain.live_cells_by_lock(&deploy_lock()).iter().map(|cell| Unpack::<u64>::unpack(&cell.output.capacity())).sum();
+	assert_eq!(total, CAPACITY);
+}
+
+#[test]
+fn test_deployer_redeploy_unchanged()
+{
+	// Redeploying unchanged cells reuses the existing cells and creates no migration.
+
+	let dir = temp_dir("redeploy-unchanged");
+	fs::write(dir.join("a"), [1u8; 100]).unwrap();
+	let config = DeploymentConfig { cells: vec![file_cell("a", false)], ..Default::default() };
+	let mut chain = funded_chain(CAPACITY);
+	let migration = deploy(&config, &dir, &mut chain, None).expect("migration");
+
+	let plan = plan::plan(&config, &dir, &chain, Some(&migration)).expect("plan");
+	assert_eq!(plan.cells[0].action, CellAction::Reuse(migration.cell_recipes[0].clone()));
+	assert!(plan::execute(&plan, &mut chain, &mock_generator(), Some(&migration)).expect("execute").is_none());
+	assert_eq!(chain.live_cell_count(), 2);
+}
+
+#[test]
+fn test_deployer_redeploy_changed()
+{
+	// A changed cell without a Type ID is deployed to a new cell and the old cell is left in place.
+
+	let dir = temp_dir("redeploy-changed");
+	fs::write(dir.join("a"), [1u8; 100]).unwrap();
+	fs::write(dir.join("b"), [2u8; 100]).unwrap();
+	let config = DeploymentConfig { cells: vec![file_cell("a", false), file_cell("b", false)], ..Default::default() };
+	let mut chain = funded_chain(CAPACITY);
+	let first = deploy(&config, &dir, &mut chain, None).expect("migration");
+
+	fs::write(dir.join("b"), [3u8; 100]).unwrap();
+	let second = deploy(&config, &dir, &mut chain, Some(&first)).expect("migration");
+	assert_eq!(second.cell_recipes[0], first.cell_recipes[0]);
+	assert_ne!(second.cell_recipes[1].data_hash, first.cell_recipes[1].data_hash);
+	assert!(chain.live_cell(&out_point(&first.cell_recipes[1].tx_hash, first.cell_recipes[1].index)).is_some());
+	assert!(chain.live_cell(&out_point(&second.cell_recipes[1].tx_hash, second.cell_recipes[1].index)).is_some());
+}
+
+#[test]
+fn test_deployer_redeploy_dead_cell()
+{
+	// A cell which is no longer live is deployed again even though the data is unchanged.
+
+	let dir = temp_dir("redeploy-dead");
+	fs::write(dir.join("a"), [1u8; 100]).unwrap();
+	let config = DeploymentConfig { cells: vec![file_cell("a", false)], ..Default::default() };
+	let mut chain = funded_chain(CAPACITY);
+	let first = deploy(&config, &dir, &mut chain, None).expect("migration");
+
+	let plan = plan::plan(&config, &dir, &funded_chain(CAPACITY), Some(&first)).expect("plan");
+	assert_eq!(plan.cells[0].action, CellAction::Deploy);
+}
+
+#[test]
+fn test_deployer_type_id()
+{
+	// A cell with Type ID enabled gets a Type ID script with args calculated from the first input.
+
+	let dir = temp_dir("type-id");
+	fs::write(dir.join("a"), [1u8; 100]).unwrap();
+	let config = DeploymentConfig { cells: vec![file_cell("a", true)], ..Default::default() };
+	let mut chain = funded_chain(CAPACITY);
+	let funding = chain.live_cells_by_lock(&deploy_lock())[0].out_point.clone();
+
+	let migration = deploy(&config, &dir, &mut chain, None).expect("migration");
+	let recipe = &migration.cell_recipes[0];
+	let cell = chain.live_cell(&out_point(&recipe.tx_hash, recipe.index)).expect("live cell");
+	let type_script = cell.output.type_().to_opt().expect("type script");
+	let type_id = transaction::calculate_type_id(&CellInput::new(funding, 0), 0);
+	assert_eq!(type_script, transaction::type_id_script(type_id));
+	assert_eq!(recipe.type_id, Some(type_script.calc_script_hash().unpack()));
+	assert_eq!(recipe.occupied_capacity, (8 + 53 + 65 + 100) * 100_000_000);
+}
+
+#[test]
+fn test_deployer_type_id_upgrade()
+{
+	// Upgrading a Type ID cell consumes the old cell and keeps the same Type ID.
+
+	let dir = temp_dir("type-id-upgrade");
+	fs::write(dir.join("a"), [1u8; 100]).unwrap();
+	let config = DeploymentConfig { cells: vec![file_cell("a", true)], ..Default::default() };
+	let mut chain = funded_chain(CAPACITY);
+	let first = deploy(&config, &dir, &mut chain, None).expect("migration");
+
+	fs::write(dir.join("a"), [2u8; 150]).unwrap();
+	let plan = plan::plan(&config, &dir, &chain, Some(&first)).expect("plan");
+	assert_eq!(plan.cells[0].action, CellAction::Upgrade(first.cell_recipes[0].clone()));
+	let second = plan::execute(&plan, &mut chain, &mock_generator(), Some(&first)).expect("execute").expect("migration");
+
+	assert_eq!(second.cell_recipes[0].type_id, first.cell_recipes[0].type_id);
+	assert_ne!(second.cell_recipes[0].data_hash, first.cell_recipes[0].data_hash);
+	assert!(chain.live_cell(&out_point(&first.cell_recipes[0].tx_hash, first.cell_recipes[0].index)).is_none());
+	assert!(chain.live_cell(&out_point(&second.cell_recipes[0].tx_hash, second.cell_recipes[0].index)).is_some());
+}
+
+#[test]
+fn test_deployer_reference()
+{
+	// An on-chain cell is referenced by out point without being deployed again.
+
+	let dir = temp_dir("reference");
+	let mut chain = funded_chain(CAPACITY);
+	let existing = chain.issue_cell(deploy_lock(), 1_000 * 100_000_000);
+	let location = CellLocation::OnChain { tx_hash: existing.tx_hash().unpack(), index: existing.index().unpack() };
+	let config = DeploymentConfig { cells: vec![CellConfig { name: String::from("existing"), enable_type_id: false, location }], ..Default::default() };
+
+	let plan = plan::plan(&config, &dir, &chain, None).expect("plan");
+	assert_eq!(plan.cells[0].action, CellAction::Reference(existing.clone()));
+	assert!(plan::execute(&plan, &mut chain, &mock_generator(), None).expect("execute").is_none());
+	assert_eq!(chain.live_cell_count(), 2);
+}
+
+#[test]
+fn test_deployer_reference_dead_cell()
+{
+	// An on-chain cell which is not live cannot be referenced.
+
+	let dir = temp_dir("reference-dead");
+	let chain = funded_chain(CAPACITY);
+	let location = CellLocation::OnChain { tx_hash: H256::default(), index: 0 };
+	let config = DeploymentConfig { cells: vec![CellConfig { name: String::from("missing"), enable_type_id: false, location }], ..Default::default() };
+
+	let result = plan::plan(&config, &dir, &chain, None);
+	assert!(matches!(result, Err(::deployer::Error::DeadCell(_))));
+}
+
+#[test]
+fn test_deployer_dep_group()
+{
+	// A dep group cell contains the out points of its cells in order.
+
+	let dir = temp_dir("dep-group");
+	fs::write(dir.join("a"), [1u8; 100]).unwrap();
+	fs::write(dir.join("b"), [2u8; 100]).unwrap();
+	let dep_group = DepGroupConfig { name: String::from("group"), cells: vec![String::from("b"), String::from("a")] };
+	let config = DeploymentConfig { cells: vec![file_cell("a", false), file_cell("b", false)], dep_groups: vec![dep_group], lock: None };
+	let mut chain = funded_chain(CAPACITY);
+
+	let migration = deploy(&config, &dir, &mut chain, None).expect("migration");
+	let recipe = &migration.dep_group_recipes[0];
+	assert_eq!(recipe.name, "group");
+	assert_ne!(recipe.tx_hash, migration.cell_recipes[0].tx_hash);
+	let cell = chain.live_cell(&out_point(&recipe.tx_hash, recipe.index)).expect("live cell");
+	let out_points = OutPointVec::from_slice(&cell.data).expect("out point vec");
+	let expected: Vec<OutPoint> = [1, 0].iter().map(|&i| out_point(&migration.cell_recipes[i].tx_hash, migration.cell_recipes[i].index)).collect();
+	assert_eq!(out_points.into_iter().collect::<Vec<OutPoint>>(), expected);
+	assert_eq!(recipe.occupied_capacity, (8 + 53 + 4 + 2 * 36) * 100_000_000);
+}
+
+#[test]
+fn test_deployer_dep_group_redeploy()
+{
+	// A dep group is reused while its cells are unchanged, and replaced when one of its cells changes.
+
+	let dir = temp_dir("dep-group-redeploy");
+	fs::write(dir.join("a"), [1u8; 100]).unwrap();
+	let dep_group = DepGroupConfig { name: String::from("group"), cells: vec![String::from("a")] };
+	let config = DeploymentConfig { cells: vec![file_cell("a", false)], dep_groups: vec![dep_group], lock: None };
+	let mut chain = funded_chain(CAPACITY);
+	let first = deploy(&config, &dir, &mut chain, None).expect("migration");
+	assert!(deploy(&config, &dir, &mut chain, Some(&first)).is_none());
+
+	fs::write(dir.join("a"), [2u8; 100]).unwrap();
+	let second = deploy(&config, &dir, &mut chain, Some(&first)).expect("migration");
+	let old = &first.dep_group_recipes[0];
+	let new = &second.dep_group_recipes[0];
+	assert!(chain.live_cell(&out_point(&old.tx_hash, old.index)).is_none());
+	let cell = chain.live_cell(&out_point(&new.tx_hash, new.index)).expect("live cell");
+	let out_points = OutPointVec::from_slice(&cell.data).expect("out point vec");
+	assert_eq!(out_points.get(0), Some(out_point(&second.cell_recipes[0].tx_hash, second.cell_recipes[0].index)));
+}
+
+#[test]
+fn test_deployer_dep_group_unknown_cell()
+{
+	// A dep group cannot reference a cell which is not in the config.
+
+	let dir = temp_dir("dep-group-unknown");
+	fs::write(dir.join("a"), [1u8; 100]).unwrap();
+	let dep_group = DepGroupConfig { name: String::from("group"), cells: vec![String::from("missing")] };
+	let config = DeploymentConfig { cells: vec![file_cell("a", false)], dep_groups: vec![dep_group], lock: None };
+	let mut chain = funded_chain(CAPACITY);
+
+	let plan = plan::plan(&config, &dir, &chain, None).expect("plan");
+	let result = plan::execute(&plan, &mut chain, &mock_generator(), None);
+	assert!(matches!(result, Err(::deployer::Error::UnknownCell(name)) if name == "missing"));
+}
+
+#[test]
+fn test_deployer_insufficient_capacity()
+{
+	// The deployment fails when the lock does not own enough capacity for the cells and change.
+
+	let dir = temp_dir("insufficient-capacity");
+	fs::write(dir.join("a"), [1u8; 100]).unwrap();
+	let config = DeploymentConfig { cells: vec![file_cell("a", false)], ..Default::default() };
+	let mut chain = funded_chain(200 * 100_000_000);
+
+	let plan = plan::plan(&config, &dir, &chain, None).expect("plan");
+	let result = plan::execute(&plan, &mut chain, &mock_generator(), None);
+	assert!(matches!(result, Err(::deployer::Error::InsufficientCapacity(capacity)) if capacity == 22 * 100_000_000));
+	assert_eq!(chain.live_cell_count(), 1);
+}
+
+#[test]
+fn test_deployer_exact_capacity()
+{
+	// No change cell is created when the lock owns exactly the capacity needed.
+
+	let dir = temp_dir("exact-capacity");
+	fs::write(dir.join("a"), [1u8; 100]).unwrap();
+	let config = DeploymentConfig { cells: vec![file_cell("a", false)], ..Default::default() };
+	let mut chain = funded_chain(161 * 100_000_000);
+
+	assert!(deploy(&config, &dir, &mut chain, None).is_some());
+	assert_eq!(chain.live_cell_count(), 1);
+}
+
+#[test]
+fn test_deployer_snapshot()
+{
+	// A snapshot of the mock chain can be saved and loaded to continue a deployment later.
+
+	let dir = temp_dir("snapshot");
+	fs::write(dir.join("a"), [1u8; 100]).unwrap();
+	let config = DeploymentConfig { cells: vec![file_cell("a", true)], ..Default::default() };
+	let mut chain = funded_chain(CAPACITY);
+	let migration = deploy(&config, &dir, &mut chain, None).expect("migration");
+
+	chain.save_snapshot(&dir.join("snapshot.json")).expect("save snapshot");
+	let mut loaded = MockChain::load_snapshot(&dir.join("snapshot.json")).expect("load snapshot");
+	assert_eq!(loaded.live_cell_count(), chain.live_cell_count());
+	let recipe = &migration.cell_recipes[0];
+	let original = chain.live_cell(&out_point(&recipe.tx_hash, recipe.index)).expect("live cell");
+	let restored = loaded.live_cell(&out_point(&recipe.tx_hash, recipe.index)).expect("live cell");
+	assert_eq!(restored.output, original.output);
+	assert_eq!(restored.data, original.data);
+
+	// Newly issued cells do not collide with cells issued before the snapshot.
+	assert_ne!(loaded.issue_cell(deploy_lock(), 100), chain.issue_cell(deploy_lock(), 200));
+	assert!(deploy(&config, &dir, &mut loaded, Some(&migration)).is_none());
+}
+
+#[test]
+fn test_deployer_migration_files()
+{
+	// Migrations are saved with a timestamp file name and the latest one is loaded.
+
+	let dir = temp_dir("migration-files");
+	assert!(Migration::load_latest(&dir.join("missing")).expect("load").is_none());
+	assert!(Migration::load_latest(&dir).expect("load").is_none());
+
+	let older = Migration::default();
+	fs::write(dir.join("2022-01-01-000000.json"), serde_json::to_string(&older).unwrap()).unwrap();
+	fs::write(dir.join(".gitkeep"), "").unwrap();
+	let dir_a = temp_dir("migration-files-a");
+	fs::write(dir_a.join("a"), [1u8; 100]).unwrap();
+	let config = DeploymentConfig { cells: vec![file_cell("a", false)], ..Default::default() };
+	let migration = deploy(&config, &dir_a, &mut funded_chain(CAPACITY), None).expect("migration");
+	let path = migration.save(&dir).expect("save");
+
+	assert_eq!(path.extension().and_then(|extension| extension.to_str()), Some("json"));
+	assert_eq!(Migration::load_latest(&dir).expect("load"), Some(migration));
+	assert_eq!(format_timestamp(0), "1970-01-01-000000");
+	assert_eq!(format_timestamp(1_666_051_199), "2022-10-17-235959");
+	assert_eq!(format_timestamp(951_825_600), "2000-02-29-120000");
+}
+
+/// Create a generator which uses the always success lock in the context.
+fn context_generator(context: &mut Context) -> TransactionGenerator
+{
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success).build();
+
+	TransactionGenerator { cell_deps: vec![always_success_dep], fee_rate: FeeRate::from_u64(FEE_RATE), ..TransactionGenerator::new(lock_script) }
+}
+
+/// Create funding cells in the context with the specified capacities.
+fn context_funding_cells(context: &mut Context, lock: &Script, capacities: &[u64]) -> Vec<LiveCell>
+{
+	capacities.iter()
+		.map(|&capacity|
+		{
+			let output = CellOutput::new_builder().capacity(capacity.pack()).lock(lock.clone()).build();
+			let out_point = context.create_cell(output.clone(), Bytes::new());
+			LiveCell { out_point, output, data: Bytes::new() }
+		})
+		.collect()
+}
+
+/// Verify a generated transaction and check its capacities, then add its outputs to the context so that later
+/// transactions can spend them. Returns the fee.
+fn verify_deploy_tx(context: &mut Context, tx: &TransactionView) -> u64
+{
+	let _cycles = context.verify_tx(tx, MAX_CYCLES).expect("pass verification");
+
+	// Every output must hold at least its occupied capacity.
+	for (output, data) in tx.outputs_with_data_iter()
+	{
+		assert!(!output.is_lack_of_capacity(Capacity::bytes(data.len()).unwrap()).unwrap());
+	}
+
+	// The fee is the difference between the inputs and outputs, and matches the size of the transaction.
+	let input_capacity: u64 = tx.input_pts_iter().map(|out_point| Unpack::<u64>::unpack(&context.get_cell(&out_point).expect("input cell").0.capacity())).sum();
+	let output_capacity = tx.outputs_capacity().unwrap().as_u64();
+	let fee = input_capacity - output_capacity;
+	assert_eq!(fee, FeeRate::from_u64(FEE_RATE).fee(tx.data().serialized_size_in_block()).as_u64());
+
+	for (i, (output, data)) in tx.outputs_with_data_iter().enumerate()
+	{
+		context.create_cell_with_out_point(OutPoint::new(tx.hash(), i as u32), output, data);
+	}
+
+	fee
+}
+
+#[test]
+fn test_deployer_generate()
+{
+	// Two code cells are funded from two cells, with the fee deducted from the change.
+
+	let mut context = Context::default();
+	let generator = context_generator(&mut context);
+	let funding_cells = context_funding_cells(&mut context, &generator.lock, &[200 * 100_000_000, 1_000 * 100_000_000]);
+	let outputs = vec![generator.output("a", None, Bytes::from(vec![1u8; 100])), generator.output("b", None, Bytes::from(vec![2u8; 200]))];
+
+	let deployment = generator.generate(outputs, funding_cells).expect("generate");
+	assert_eq!(deployment.transactions.len(), 1);
+	let tx = &deployment.transactions[0];
+	let fee = verify_deploy_tx(&mut context, tx);
+	assert!(fee > 0);
+	assert_eq!(tx.inputs().len(), 2);
+	assert_eq!(tx.outputs().len(), 3);
+
+	// The code cells hold exactly their occupied capacity and the rest is returned as change.
+	assert_eq!(Unpack::<u64>::unpack(&tx.outputs().get(0).unwrap().capacity()), (8 + 32 + 1 + 100) * 100_000_000);
+	assert_eq!(Unpack::<u64>::unpack(&tx.outputs().get(1).unwrap().capacity()), (8 + 32 + 1 + 200) * 100_000_000);
+	assert_eq!(Unpack::<u64>::unpack(&tx.outputs().get(2).unwrap().capacity()), (1_200 - 141 - 241) * 100_000_000 - fee);
+	assert_eq!(deployment.out_points, vec![OutPoint::new(tx.hash(), 0), OutPoint::new(tx.hash(), 1)]);
+
+	// The first witness has a placeholder for a secp256k1 signature.
+	let witness = WitnessArgs::from_slice(&tx.witnesses().get(0).unwrap().raw_data()).expect("witness args");
+	assert_eq!(witness.lock().to_opt().unwrap().raw_data(), Bytes::from(vec![0u8; 65]));
+}
+
+#[test]
+fn test_deployer_generate_type_id()
+{
+	// A cell deployed with a new Type ID passes the Type ID system script, and so does an upgrade of it.
+
+	let mut context = Context::default();
+	let generator = context_generator(&mut context);
+	let funding_cells = context_funding_cells(&mut context, &generator.lock, &[1_000 * 100_000_000]);
+	let outputs = vec![generator.output("a", None, Bytes::from(vec![1u8; 100])), generator.type_id_output("b", Bytes::from(vec![2u8; 100]))];
+
+	let deployment = generator.generate(outputs, funding_cells).expect("generate");
+	let tx = &deployment.transactions[0];
+	verify_deploy_tx(&mut context, tx);
+	let type_script = tx.outputs().get(1).unwrap().type_().to_opt().expect("type script");
+	let first_input = tx.inputs().get(0).unwrap();
+	assert_eq!(type_script, transaction::type_id_script(transaction::calculate_type_id(&first_input, 1)));
+
+	// Upgrade the Type ID cell using the change from the first transaction.
+	let (output, data) = tx.output_with_data(1).unwrap();
+	let replaces = LiveCell { out_point: OutPoint::new(tx.hash(), 1), output, data };
+	let (change, change_data) = tx.output_with_data(2).unwrap();
+	let funding_cells = vec![LiveCell { out_point: OutPoint::new(tx.hash(), 2), output: change, data: change_data }];
+	let upgrade = DeployOutput { replaces: Some(replaces), ..generator.output("b", Some(type_script.clone()), Bytes::from(vec![3u8; 150])) };
+
+	let deployment = generator.generate(vec![upgrade], funding_cells).expect("generate");
+	let tx = &deployment.transactions[0];
+	verify_deploy_tx(&mut context, tx);
+	assert_eq!(tx.inputs().len(), 2);
+	assert_eq!(tx.outputs().get(0).unwrap().type_().to_opt(), Some(type_script));
+}
+
+#[test]
+fn test_deployer_generate_invalid_type_id()
+{
+	// A Type ID which was not calculated from the first input is rejected by the Type ID system script.
+
+	let mut context = Context::default();
+	let generator = context_generator(&mut context);
+	let funding_cells = context_funding_cells(&mut context, &generator.lock, &[1_000 * 100_000_000]);
+	let outputs = vec![generator.type_id_output("a", Bytes::from(vec![1u8; 100]))];
+
+	let deployment = generator.generate(outputs, funding_cells).expect("generate");
+	let tx = &deployment.transactions[0];
+	let output = tx.outputs().get(0).unwrap();
+	let output = output.as_builder().type_(Some(transaction::type_id_script(H256::default())).pack()).build();
+	let tx = tx.as_advanced_builder().set_outputs(vec![output]).output(tx.outputs().get(1).unwrap()).build();
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_TYPE_ID_INVALID_INPUT_HASH);
+}
+
+#[test]
+fn test_deployer_generate_split()
+{
+	// Outputs which do not fit in a single transaction are split, and each transaction spends the change of the previous one.
+
+	let mut context = Context::default();
+	let generator = TransactionGenerator { max_tx_size: 3_000, ..context_generator(&mut context) };
+	let funding_cells = context_funding_cells(&mut context, &generator.lock, &[10_000 * 100_000_000]);
+	let outputs = (0..3u8).map(|i| generator.output(&format!("cell{}", i), None, Bytes::from(vec![i; 1_000]))).collect();
+
+	let deployment = generator.generate(outputs, funding_cells).expect("generate");
+	assert_eq!(deployment.transactions.len(), 2);
+	let mut fees = 0;
+	for tx in &deployment.transactions
+	{
+		assert!(tx.data().serialized_size_in_block() <= 3_000);
+		fees += verify_deploy_tx(&mut context, tx);
+	}
+	let first = &deployment.transactions[0];
+	let second = &deployment.transactions[1];
+	assert_eq!(second.inputs().get(0).unwrap().previous_output(), OutPoint::new(first.hash(), 2));
+
+	// The out points are in the same order as the outputs.
+	for (i, out_point) in deployment.out_points.iter().enumerate()
+	{
+		assert_eq!(context.get_cell(out_point).expect("cell").1, Bytes::from(vec![i as u8; 1_000]));
+	}
+
+	// All capacity is accounted for by the code cells, the final change, and the fees.
+	let code_capacity = 3 * (8 + 32 + 1 + 1_000) * 100_000_000;
+	let change: u64 = second.outputs().get(1).unwrap().capacity().unpack();
+	assert_eq!(code_capacity + change + fees, 10_000 * 100_000_000);
+}
+
+#[test]
+fn test_deployer_generate_too_large()
+{
+	// An output which does not fit in a transaction on its own cannot be deployed.
+
+	let mut context = Context::default();
+	let generator = TransactionGenerator { max_tx_size: 2_500, ..context_generator(&mut context) };
+	let funding_cells = context_funding_cells(&mut context, &generator.lock, &[10_000 * 100_000_000]);
+	let outputs = vec![generator.output("small", None, Bytes::from(vec![1u8; 1_000])), generator.output("large", None, Bytes::from(vec![2u8; 3_000]))];
+
+	let result = generator.generate(outputs, funding_cells);
+	assert!(matches!(result, Err(::deployer::Error::TransactionTooLarge(name)) if name == "large"));
+}
+
+#[test]
+fn test_deployer_generate_insufficient_capacity()
+{
+	// The funding cells must cover the outputs, the fee, and a change cell.
+
+	let mut context = Context::default();
+	let generator = context_generator(&mut context);
+	let funding_cells = context_funding_cells(&mut context, &generator.lock, &[100 * 100_000_000, 80 * 100_000_000]);
+	let outputs = vec![generator.output("a", None, Bytes::from(vec![1u8; 100]))];
+
+	let result = generator.generate(outputs, funding_cells);
+	assert!(matches!(result, Err(::deployer::Error::InsufficientCapacity(capacity)) if capacity > 2 * 100_000_000 && capacity < 3 * 100_000_000));
+}
+
+#[test]
+fn test_deployer_generate_exact_capacity()
+{
+	// No change cell is created when the funding cell holds exactly the outputs and the fee.
+
+	let mut context = Context::default();
+	let generator = context_generator(&mut context);
+	let outputs = vec![generator.output("a", None, Bytes::from(vec![1u8; 100]))];
+
+	// Find the fee of the transaction without change by removing the change from a transaction with change.
+	let funding_cells = context_funding_cells(&mut context, &generator.lock, &[1_000 * 100_000_000]);
+	let (tx, _) = generator.build(outputs.clone(), &funding_cells).expect("build");
+	let tx = tx.as_advanced_builder().set_outputs(vec![tx.outputs().get(0).unwrap()]).set_outputs_data(vec![tx.outputs_data().get(0).unwrap()]).build();
+	let fee = generator.fee(&tx);
+
+	let funding_cells = context_funding_cells(&mut context, &generator.lock, &[141 * 100_000_000 + fee]);
+	let deployment = generator.generate(outputs, funding_cells).expect("generate");
+	let tx = &deployment.transactions[0];
+	assert_eq!(tx.outputs().len(), 1);
+	assert_eq!(verify_deploy_tx(&mut context, tx), fee);
+}
+
+#[test]
+fn test_deployer_generate_json()
+{
+	// The unsigned transactions can be converted to JSON and back without changing the hash.
+
+	let mut context = Context::default();
+	let generator = context_generator(&mut context);
+	let funding_cells = context_funding_cells(&mut context, &generator.lock, &[1_000 * 100_000_000]);
+	let outputs = vec![generator.type_id_output("a", Bytes::from(vec![1u8; 100]))];
+	let deployment = generator.generate(outputs, funding_cells).expect("generate");
+
+	let json: Vec<ckb_jsonrpc_types::TransactionView> = deployment.transactions.iter().cloned().map(Into::into).collect();
+	let parsed: Vec<ckb_jsonrpc_types::TransactionView> = serde_json::from_str(&serde_json::to_string(&json).unwrap()).unwrap();
+	let tx: Transaction = parsed[0].inner.clone().into();
+	assert_eq!(tx.calc_tx_hash(), deployment.transactions[0].hash());
+	assert_eq!(parsed[0].hash, deployment.transactions[0].hash().unpack());
+}
+
+#[test]
+fn test_deployer_live_cells()
+{
+	// Live cells can be loaded from an array, a snapshot, or an indexer result, and only plain cells owned by the lock are used for funding.
+
+	let dir = temp_dir("live-cells");
+	let mut chain = funded_chain(CAPACITY);
+	chain.issue_cell(Script::default(), 1_000 * 100_000_000);
+	chain.save_snapshot(&dir.join("snapshot.json")).expect("save snapshot");
+	let snapshot: serde_json::Value = serde_json::from_str(&fs::read_to_string(dir.join("snapshot.json")).unwrap()).unwrap();
+	let mut cells = snapshot["cells"].clone();
+	fs::write(dir.join("cells.json"), cells.to_string()).unwrap();
+	for cell in cells.as_array_mut().unwrap()
+	{
+		let data = cell.as_object_mut().unwrap().remove("data").unwrap();
+		cell["output_data"] = data;
+		cell["block_number"] = serde_json::json!("0x1");
+	}
+	fs::write(dir.join("indexer.json"), serde_json::json!({ "objects": cells, "last_cursor": "0x" }).to_string()).unwrap();
+
+	for file in ["snapshot.json", "cells.json", "indexer.json"]
+	{
+		let live_cells = chain::load_live_cells(&dir.join(file)).expect("live cells");
+		assert_eq!(live_cells.len(), 2, "{}", file);
+		let funding_cells = mock_generator().funding_cells(live_cells);
+		assert_eq!(funding_cells.len(), 1, "{}", file);
+		assert_eq!(Unpack::<u64>::unpack(&funding_cells[0].output.capacity()), CAPACITY);
+	}
+}
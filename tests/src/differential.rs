@@ -0,0 +1,651 @@
+use std::fmt;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_error::Error as ScriptError;
+use ckb_testtool::ckb_hash::blake2b_256;
+use ckb_testtool::ckb_types::bytes::Bytes;
+use ckb_testtool::ckb_types::core::{Capacity, TransactionBuilder};
+use ckb_testtool::ckb_types::packed::{CellDep, CellInput, CellOutput, OutPoint, Script};
+use ckb_testtool::ckb_types::prelude::*;
+use ckb_testtool::context::Context;
+use crate::Loader;
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+const CASES_ENV_VAR: &str = "DIFFERENTIAL_CASES"; // Overrides the number of cases generated for each contract.
+const SEED_ENV_VAR: &str = "DIFFERENTIAL_SEED"; // Overrides the seed of the first case, to explore other cases.
+
+/// The verdict of a contract on a transaction: success, or the error code the contract exits with.
+pub type Verdict = Result<(), i8>;
+
+/// A small deterministic random number generator (SplitMix64), so that every case can be reproduced from its seed.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng
+{
+	pub fn new(seed: u64) -> Self
+	{
+		Self(seed)
+	}
+
+	pub fn next_u64(&mut self) -> u64
+	{
+		self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+		z ^ (z >> 31)
+	}
+
+	/// A random number in the range [0, n).
+	pub fn below(&mut self, n: u64) -> u64
+	{
+		self.next_u64() % n
+	}
+
+	/// True one time in n on average.
+	pub fn one_in(&mut self, n: u64) -> bool
+	{
+		self.below(n) == 0
+	}
+
+	pub fn choose<T: Copy>(&mut self, items: &[T]) -> T
+	{
+		items[self.below(items.len() as u64) as usize]
+	}
+
+	pub fn bytes(&mut self, len: usize) -> Vec<u8>
+	{
+		(0..len).map(|_| self.next_u64() as u8).collect()
+	}
+}
+
+/// Where a cell uses the contract being tested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position
+{
+	Lock,
+	Type,
+	/// The cell does not run the contract, but is locked by the always success lock with the hash of the contract
+	/// script as the args, like a pool lock which holds cells on behalf of a type script.
+	Proxy,
+}
+
+/// A cell in a case. Cells which do not use the contract as their lock use the always success lock with the owner
+/// byte as the args, and cells which do not use the contract as their type use the always success lock as their type
+/// with the tag byte as the args, if there is a tag. This gives cells different lock and type hashes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaseCell
+{
+	pub position: Option<Position>,
+	/// The args of the contract script used by the cell, when they are not the args of the case. This puts the cell
+	/// in a second script group of the contract.
+	pub args: Option<Vec<u8>>,
+	pub owner: u8,
+	pub tag: Option<u8>,
+	pub capacity: u64,
+	/// The since value of the cell when it is an input.
+	pub since: u64,
+	pub data: Vec<u8>,
+}
+
+/// A transaction built around the contract being tested, with the args of the contract script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case
+{
+	pub args: Vec<u8>,
+	pub inputs: Vec<CaseCell>,
+	pub outputs: Vec<CaseCell>,
+	/// Cells which are added as cell deps, for contracts which read other cells, such as an oracle record.
+	pub deps: Vec<CaseCell>,
+	pub witnesses: Vec<Vec<u8>>,
+}
+
+fn hex_vec(bytes: &[u8]) -> String
+{
+	let items: Vec<String> = bytes.iter().map(|byte| format!("0x{:02x}", byte)).collect();
+
+	format!("vec![{}]", items.join(", "))
+}
+
+impl CaseCell
+{
+	/// Determine if the cell runs the contract, as its lock or its type.
+	pub fn runs_contract(&self) -> bool
+	{
+		matches!(self.position, Some(Position::Lock | Position::Type))
+	}
+
+	fn to_rust(&self) -> String
+	{
+		let position = match self.position
+		{
+			Some(position) => format!("Some(Position::{:?})", position),
+			None => String::from("None"),
+		};
+		let args = match &self.args
+		{
+			Some(args) => format!("Some({})", hex_vec(args)),
+			None => String::from("None"),
+		};
+
+		format!("CaseCell {{ position: {}, args: {}, owner: {}, tag: {:?}, capacity: {}, since: {}, data: {} }}", position, args, self.owner, self.tag, self.capacity, self.since, hex_vec(&self.data))
+	}
+}
+
+impl Case
+{
+	/// The case as a Rust expression, which can be pasted into a test to reproduce it.
+	pub fn to_rust(&self) -> String
+	{
+		let list = |items: Vec<String>| match items.is_empty()
+		{
+			true => String::from("vec![]"),
+			false => format!("vec!\n\t[\n{}\t]", items.iter().map(|item| format!("\t\t{},\n", item)).collect::<String>()),
+		};
+		let inputs = list(self.inputs.iter().map(CaseCell::to_rust).collect());
+		let outputs = list(self.outputs.iter().map(CaseCell::to_rust).collect());
+		let deps = list(self.deps.iter().map(CaseCell::to_rust).collect());
+		let witnesses = list(self.witnesses.iter().map(|witness| hex_vec(witness)).collect());
+
+		format!("Case\n{{\n\targs: {},\n\tinputs: {},\n\toutputs: {},\n\tdeps: {},\n\twitnesses: {},\n}}", hex_vec(&self.args), inputs, outputs, deps, witnesses)
+	}
+
+	/// Every case which is one step simpler than this case, from the largest step to the smallest.
+	fn simplifications(&self) -> Vec<Case>
+	{
+		let mut candidates = Vec::new();
+
+		// Remove a cell or a witness.
+		for i in 0..self.inputs.len()
+		{
+			let mut case = self.clone();
+			case.inputs.remove(i);
+			candidates.push(case);
+		}
+		for i in 0..self.outputs.len()
+		{
+			let mut case = self.clone();
+			case.outputs.remove(i);
+			candidates.push(case);
+		}
+		for i in 0..self.deps.len()
+		{
+			let mut case = self.clone();
+			case.deps.remove(i);
+			candidates.push(case);
+		}
+		for i in 0..self.witnesses.len()
+		{
+			let mut case = self.clone();
+			case.witnesses.remove(i);
+			candidates.push(case);
+		}
+
+		// Shorten or clear the args, the data, and the witnesses.
+		for bytes in shorter(&self.args)
+		{
+			candidates.push(Case { args: bytes, ..self.clone() });
+		}
+		for (i, witness) in self.witnesses.iter().enumerate()
+		{
+			for bytes in shorter(witness)
+			{
+				let mut case = self.clone();
+				case.witnesses[i] = bytes;
+				candidates.push(case);
+			}
+		}
+		for (kind, cells) in [&self.inputs, &self.outputs, &self.deps].into_iter().enumerate()
+		{
+			for (i, cell) in cells.iter().enumerate()
+			{
+				for cell in cell.simplifications()
+				{
+					let mut case = self.clone();
+					match kind
+					{
+						0 => case.inputs[i] = cell,
+						1 => case.outputs[i] = cell,
+						_ => case.deps[i] = cell,
+					}
+					candidates.push(case);
+				}
+			}
+		}
+
+		candidates
+	}
+}
+
+impl CaseCell
+{
+	fn simplifications(&self) -> Vec<CaseCell>
+	{
+		let mut candidates = Vec::new();
+		if let Some(args) = &self.args
+		{
+			candidates.push(CaseCell { args: None, ..self.clone() });
+			for bytes in shorter(args)
+			{
+				candidates.push(CaseCell { args: Some(bytes), ..self.clone() });
+			}
+		}
+		for data in shorter(&self.data)
+		{
+			candidates.push(CaseCell { data, ..self.clone() });
+		}
+		if self.owner != 0
+		{
+			candidates.push(CaseCell { owner: 0, ..self.clone() });
+		}
+		if self.tag.is_some()
+		{
+			candidates.push(CaseCell { tag: None, ..self.clone() });
+		}
+		if self.capacity != 0
+		{
+			candidates.push(CaseCell { capacity: 0, ..self.clone() });
+		}
+		if self.since != 0
+		{
+			candidates.push(CaseCell { since: 0, ..self.clone() });
+		}
+
+		candidates
+	}
+}
+
+/// Shorter and zeroed versions of the bytes, from the largest change to the smallest.
+fn shorter(bytes: &[u8]) -> Vec<Vec<u8>>
+{
+	let mut candidates = Vec::new();
+	if !bytes.is_empty()
+	{
+		candidates.push(Vec::new());
+		candidates.push(bytes[..bytes.len() / 2].to_vec());
+		candidates.push(bytes[..bytes.len() - 1].to_vec());
+	}
+	if bytes.iter().any(|byte| *byte != 0)
+	{
+		candidates.push(vec![0; bytes.len()]);
+		for (i, byte) in bytes.iter().enumerate()
+		{
+			if *byte != 0
+			{
+				let mut zeroed = bytes.to_vec();
+				zeroed[i] = 0;
+				candidates.push(zeroed);
+			}
+		}
+	}
+	candidates.dedup();
+
+	candidates
+}
+
+/// A script group of the contract, as seen by a model. A lock group has the inputs which use the contract as their
+/// lock with the args of the group and no outputs, and a type group has the inputs and outputs which use the contract
+/// as their type with the args of the group.
+pub struct Group<'a>
+{
+	pub position: Position,
+	pub args: &'a [u8],
+	pub case: &'a Case,
+	/// The index in the transaction of each group input.
+	pub inputs: Vec<usize>,
+	/// The index in the transaction of each group output.
+	pub outputs: Vec<usize>,
+	harness: &'a Harness,
+}
+
+impl<'a> Group<'a>
+{
+	pub fn group_inputs(&self) -> Vec<&'a CaseCell>
+	{
+		self.inputs.iter().map(|i| &self.case.inputs[*i]).collect()
+	}
+
+	pub fn group_outputs(&self) -> Vec<&'a CaseCell>
+	{
+		self.outputs.iter().map(|i| &self.case.outputs[*i]).collect()
+	}
+
+	/// The hash of the contract script of the group.
+	pub fn script_hash(&self) -> [u8; 32]
+	{
+		self.harness.script_hash(self.args)
+	}
+
+	/// The args of the contract script used by the cell, which is only meaningful for cells which use the contract.
+	pub fn contract_args(&self, cell: &'a CaseCell) -> &'a [u8]
+	{
+		cell_args(self.case, cell)
+	}
+
+	pub fn lock_script(&self, cell: &CaseCell) -> Script
+	{
+		self.harness.lock_script(self.case, cell)
+	}
+
+	pub fn lock_hash(&self, cell: &CaseCell) -> [u8; 32]
+	{
+		script_hash(&self.lock_script(cell))
+	}
+
+	pub fn type_hash(&self, cell: &CaseCell) -> Option<[u8; 32]>
+	{
+		self.harness.type_script(self.case, cell).map(|script| script_hash(&script))
+	}
+
+	/// The input of the transaction at the index, as loaded by the contract.
+	pub fn cell_input(&self, index: usize) -> CellInput
+	{
+		self.harness.cell_input(self.case, index)
+	}
+
+	/// The capacity in shannons needed by the cell for its capacity field, lock, type, and data.
+	pub fn occupied_capacity(&self, cell: &CaseCell) -> u64
+	{
+		let data_capacity = Capacity::bytes(cell.data.len()).expect("capacity");
+
+		self.harness.cell_output(self.case, cell).occupied_capacity(data_capacity).expect("capacity").as_u64()
+	}
+}
+
+/// A case where the verdict of the contract does not match the verdict of the model.
+#[derive(Debug, Clone)]
+pub struct Mismatch
+{
+	pub contract: String,
+	/// The seed the case was generated from, if it was generated.
+	pub seed: Option<u64>,
+	pub case: Case,
+	pub actual: Verdict,
+	pub expected: Verdict,
+}
+
+fn describe(contract: &str, verdict: Verdict) -> String
+{
+	match verdict
+	{
+		Ok(()) => String::from("success"),
+		Err(code) => format!("error {} ({})", code, ::inspector::error_name(contract, code).unwrap_or("unknown")),
+	}
+}
+
+impl fmt::Display for Mismatch
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		write!(f, "{} returned {} but the model expected {}", self.contract, describe(&self.contract, self.actual), describe(&self.contract, self.expected))?;
+		match self.seed
+		{
+			Some(seed) => writeln!(f, " for the case generated from seed {}.", seed)?,
+			None => writeln!(f, ".")?,
+		}
+		writeln!(f, "The minimized case can be reproduced with Harness::new({:?}).assert_case(&case, model), where case is:", self.contract)?;
+		write!(f, "{}", self.case.to_rust())
+	}
+}
+
+/// Runs cases through the CKB VM and compares the verdict of the contract with the verdict of a model.
+pub struct Harness
+{
+	contract: String,
+	binary: Bytes,
+	always_success: Script,
+	script: Script,
+}
+
+impl Harness
+{
+	pub fn new(contract: &str) -> Self
+	{
+		Self::with_binary(contract, Loader::default().load_binary(contract))
+	}
+
+	/// A harness for a contract binary which is not loaded from the build directory of the tests.
+	pub fn with_binary(contract: &str, binary: Bytes) -> Self
+	{
+		// The scripts use the data hash of the binaries, so they are the same in every context they are deployed to.
+		let mut context = Context::default();
+		let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+		let out_point_contract = context.deploy_cell(binary.clone());
+		let always_success = context.build_script(&out_point_always_success, Bytes::new()).expect("script");
+		let script = context.build_script(&out_point_contract, Bytes::new()).expect("script");
+
+		Self { contract: contract.to_string(), binary, always_success, script }
+	}
+
+	/// The lock hash of a cell which does not use the contract as its lock.
+	pub fn owner_lock_hash(&self, owner: u8) -> [u8; 32]
+	{
+		self.always_success.clone().as_builder().args(Bytes::from(vec![owner]).pack()).build().calc_script_hash().as_slice().try_into().expect("hash")
+	}
+
+	/// The type hash of a cell which does not use the contract as its type.
+	pub fn tag_type_hash(&self, tag: u8) -> [u8; 32]
+	{
+		self.owner_lock_hash(tag)
+	}
+
+	/// The always success script, without args.
+	pub fn always_success(&self) -> &Script
+	{
+		&self.always_success
+	}
+
+	/// The hash of the contract script with the args.
+	pub fn script_hash(&self, args: &[u8]) -> [u8; 32]
+	{
+		script_hash(&self.contract_script(args))
+	}
+
+	fn contract_script(&self, args: &[u8]) -> Script
+	{
+		self.script.clone().as_builder().args(Bytes::from(args.to_vec()).pack()).build()
+	}
+
+	fn lock_script(&self, case: &Case, cell: &CaseCell) -> Script
+	{
+		match cell.position
+		{
+			Some(Position::Lock) => self.contract_script(cell_args(case, cell)),
+			Some(Position::Proxy) => self.always_success.clone().as_builder().args(Bytes::from(self.script_hash(cell_args(case, cell)).to_vec()).pack()).build(),
+			_ => self.always_success.clone().as_builder().args(Bytes::from(vec![cell.owner]).pack()).build(),
+		}
+	}
+
+	fn type_script(&self, case: &Case, cell: &CaseCell) -> Option<Script>
+	{
+		match (cell.position, cell.tag)
+		{
+			(Some(Position::Type), _) => Some(self.contract_script(cell_args(case, cell))),
+			(_, Some(tag)) => Some(self.always_success.clone().as_builder().args(Bytes::from(vec![tag]).pack()).build()),
+			_ => None,
+		}
+	}
+
+	fn cell_output(&self, case: &Case, cell: &CaseCell) -> CellOutput
+	{
+		CellOutput::new_builder().capacity(cell.capacity.pack()).lock(self.lock_script(case, cell)).type_(self.type_script(case, cell).pack()).build()
+	}
+
+	/// The input at the index, with an out point which is unique to the index.
+	pub fn cell_input(&self, case: &Case, index: usize) -> CellInput
+	{
+		let out_point = OutPoint::new(blake2b_256((index as u64).to_le_bytes()).pack(), 0);
+
+		CellInput::new(out_point, case.inputs[index].since)
+	}
+
+	/// The cell dep at the index, with an out point which is unique to the index and different from every input.
+	fn cell_dep(&self, index: usize) -> CellDep
+	{
+		let out_point = OutPoint::new(blake2b_256((index as u64).to_le_bytes()).pack(), 1);
+
+		CellDep::new_builder().out_point(out_point).build()
+	}
+
+	/// Build the case into a transaction and run it through the CKB VM.
+	pub fn run(&self, case: &Case) -> Verdict
+	{
+		let mut context = Context::default();
+		context.deploy_cell(ALWAYS_SUCCESS.clone());
+		context.deploy_cell(self.binary.clone());
+
+		let mut cell_inputs = Vec::new();
+		for (i, cell) in case.inputs.iter().enumerate()
+		{
+			let cell_input = self.cell_input(case, i);
+			context.create_cell_with_out_point(cell_input.previous_output(), self.cell_output(case, cell), Bytes::from(cell.data.clone()));
+			cell_inputs.push(cell_input);
+		}
+		let mut cell_deps = Vec::new();
+		for (i, cell) in case.deps.iter().enumerate()
+		{
+			let cell_dep = self.cell_dep(i);
+			context.create_cell_with_out_point(cell_dep.out_point(), self.cell_output(case, cell), Bytes::from(cell.data.clone()));
+			cell_deps.push(cell_dep);
+		}
+
+		let tx = TransactionBuilder::default()
+			.inputs(cell_inputs)
+			.outputs(case.outputs.iter().map(|cell| self.cell_output(case, cell)))
+			.outputs_data(case.outputs.iter().map(|cell| Bytes::from(cell.data.clone()).pack()))
+			.cell_deps(cell_deps)
+			.witnesses(case.witnesses.iter().map(|witness| Bytes::from(witness.clone()).pack()))
+			.build();
+		let tx = context.complete_tx(tx);
+
+		context.verify_tx(&tx, MAX_CYCLES).map(|_| ()).map_err(|err| error_code(&err).unwrap_or_else(|| panic!("{} failed without an error code: {}", self.contract, err)))
+	}
+
+	/// The script groups of the contract in the order the CKB VM runs them: the lock groups and then the type groups,
+	/// each ordered by script hash.
+	pub fn groups<'a>(&'a self, case: &'a Case) -> Vec<Group<'a>>
+	{
+		let mut groups = Vec::new();
+		for position in [Position::Lock, Position::Type]
+		{
+			let outputs: &[CaseCell] = match position
+			{
+				Position::Type => &case.outputs,
+				_ => &[],
+			};
+			let mut scripts: Vec<([u8; 32], &[u8])> = case.inputs.iter().chain(outputs.iter())
+				.filter(|cell| cell.position == Some(position))
+				.map(|cell| (self.script_hash(cell_args(case, cell)), cell_args(case, cell)))
+				.collect();
+			scripts.sort();
+			scripts.dedup();
+
+			for (_, args) in scripts
+			{
+				let indices = |cells: &[CaseCell]| -> Vec<usize>
+				{
+					cells.iter().enumerate().filter(|(_, cell)| cell.position == Some(position) && cell_args(case, cell) == args).map(|(i, _)| i).collect()
+				};
+				groups.push(Group { position, args, case, inputs: indices(&case.inputs), outputs: indices(outputs), harness: self });
+			}
+		}
+
+		groups
+	}
+
+	/// The verdict of the model on the case. The model is run on each script group of the contract, in the same order
+	/// as the CKB VM, and the first failure is the verdict.
+	pub fn expect<M>(&self, case: &Case, model: M) -> Verdict
+		where M: Fn(&Group) -> Verdict
+	{
+		self.groups(case).iter().try_for_each(&model)
+	}
+
+	fn compare<M>(&self, seed: Option<u64>, case: Case, model: &M) -> Option<Mismatch>
+		where M: Fn(&Group) -> Verdict
+	{
+		let actual = self.run(&case);
+		let expected = self.expect(&case, model);
+
+		(actual != expected).then(|| Mismatch { contract: self.contract.clone(), seed, case, actual, expected })
+	}
+
+	/// Simplify a mismatched case one step at a time, for as long as the simpler case is still a mismatch.
+	fn minimize<M>(&self, mut mismatch: Mismatch, model: &M) -> Mismatch
+		where M: Fn(&Group) -> Verdict
+	{
+		while let Some(simpler) = mismatch.case.simplifications().into_iter().find_map(|case| self.compare(mismatch.seed, case, model))
+		{
+			mismatch = simpler;
+		}
+
+		mismatch
+	}
+
+	/// Generate cases from consecutive seeds and return the first mismatch, minimized.
+	pub fn find_mismatch<G, M>(&self, seed: u64, cases: u64, generate: G, model: M) -> Option<Mismatch>
+		where G: Fn(&mut Rng) -> Case, M: Fn(&Group) -> Verdict
+	{
+		(seed..seed.wrapping_add(cases))
+			.find_map(|seed| self.compare(Some(seed), generate(&mut Rng::new(seed)), &model))
+			.map(|mismatch| self.minimize(mismatch, &model))
+	}
+
+	/// Generate cases and panic with a reproducible test case if the contract and the model disagree on any of them.
+	/// The seed and the number of cases can be overridden with `DIFFERENTIAL_SEED` and `DIFFERENTIAL_CASES`.
+	pub fn check<G, M>(&self, seed: u64, cases: u64, generate: G, model: M)
+		where G: Fn(&mut Rng) -> Case, M: Fn(&Group) -> Verdict
+	{
+		let seed = env_u64(SEED_ENV_VAR).unwrap_or(seed);
+		let cases = env_u64(CASES_ENV_VAR).unwrap_or(cases);
+
+		if let Some(mismatch) = self.find_mismatch(seed, cases, generate, model)
+		{
+			panic!("{}", mismatch);
+		}
+	}
+
+	/// Panic if the contract and the model disagree on the case.
+	pub fn assert_case<M>(&self, case: &Case, model: M)
+		where M: Fn(&Group) -> Verdict
+	{
+		if let Some(mismatch) = self.compare(None, case.clone(), &model)
+		{
+			panic!("{}", mismatch);
+		}
+	}
+}
+
+/// The args of the contract script used by the cell.
+fn cell_args<'a>(case: &'a Case, cell: &'a CaseCell) -> &'a [u8]
+{
+	cell.args.as_deref().unwrap_or(&case.args)
+}
+
+fn script_hash(script: &Script) -> [u8; 32]
+{
+	script.calc_script_hash().as_slice().try_into().expect("hash")
+}
+
+fn env_u64(name: &str) -> Option<u64>
+{
+	std::env::var(name).ok().map(|value| value.parse().unwrap_or_else(|_| panic!("{} must be a number", name)))
+}
+
+/// The error code returned by a script, or None if the transaction failed for another reason.
+fn error_code(err: &ScriptError) -> Option<i8>
+{
+	let message = err.to_string();
+	let code = message.split("error code ").nth(1)?;
+	let end = code.find(|c: char| c != '-' && !c.is_ascii_digit()).unwrap_or(code.len());
+
+	code[..end].parse().ok()
+}
+
+/// The error code of a named error returned by a contract, for use in models.
+pub fn error(contract: &str, name: &str) -> i8
+{
+	::inspector::error_code(contract, name).unwrap_or_else(|| panic!("{} does not have an error named {}", contract, name))
+}
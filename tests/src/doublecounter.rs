@@ -1,5 +1,5 @@
 use super::*;
-use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
 use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
 use ckb_testtool::ckb_types::core::{TransactionBuilder};
 
@@ -16,7 +16,7 @@ const ERROR_DOUBLECOUNTER_INVALID_COUNTER_VALUE_2: i8 = 7;
 fn test_doublecounter_burn()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -55,7 +55,7 @@ fn test_doublecounter_burn()
 fn test_doublecounter_burn_multiple()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -95,7 +95,7 @@ fn test_doublecounter_burn_multiple()
 fn test_doublecounter_create()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -134,7 +134,7 @@ fn test_doublecounter_create()
 fn test_doublecounter_create_no_output_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -173,7 +173,7 @@ fn test_doublecounter_create_no_output_data()
 fn test_doublecounter_create_invalid_output_data_value()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -212,7 +212,7 @@ fn test_doublecounter_create_invalid_output_data_value()
 fn test_doublecounter_create_invalid_output_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -251,7 +251,7 @@ fn test_doublecounter_create_invalid_output_data()
 fn test_doublecounter_transfer()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -305,7 +305,7 @@ fn test_doublecounter_transfer()
 fn test_doublecounter_transfer_high_value()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -359,7 +359,7 @@ fn test_doublecounter_transfer_high_value()
 fn test_doublecounter_invalid_transfer_plus_2_value_1()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -413,7 +413,7 @@ fn test_doublecounter_invalid_transfer_plus_2_value_1()
 fn test_doublecounter_invalid_transfer_plus_1_value_2()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -467,7 +467,7 @@ fn test_doublecounter_invalid_transfer_plus_1_value_2()
 fn test_doublecounter_invalid_transfer_plus_9000_value_1()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -521,7 +521,7 @@ fn test_doublecounter_invalid_transfer_plus_9000_value_1()
 fn test_doublecounter_invalid_transfer_minus_1_value_1()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -575,7 +575,7 @@ fn test_doublecounter_invalid_transfer_minus_1_value_1()
 fn test_doublecounter_transfer_overflow_panic_expected()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -629,7 +629,7 @@ fn test_doublecounter_transfer_overflow_panic_expected()
 fn test_doublecounter_transfer_invalid_input_data_panic_expected()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -683,7 +683,7 @@ fn test_doublecounter_transfer_invalid_input_data_panic_expected()
 fn test_doublecounter_transfer_invalid_output_data_panic_expected()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -0,0 +1,1974 @@
+use super::*;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::TransactionBuilder;
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+const SINCE_ABSOLUTE_BLOCK_NUMBER: u64 = 0x0000_0000_0000_0000;
+const SINCE_ABSOLUTE_EPOCH: u64 = 0x2000_0000_0000_0000;
+const SINCE_ABSOLUTE_TIMESTAMP: u64 = 0x4000_0000_0000_0000;
+
+// Error Codes
+const ERROR_ESCROWLOCK_ARGSLEN: i8 = 5;
+const ERROR_ESCROWLOCK_INVALID_TIMEOUT: i8 = 6;
+const ERROR_ESCROWLOCK_UNAUTHORIZED: i8 = 7;
+const ERROR_ESCROWLOCK_INVALID_SINCE: i8 = 8;
+const ERROR_ESCROWLOCK_INSUFFICIENT_CAPACITY: i8 = 9;
+
+#[test]
+fn test_escrowlock_release_buyer_seller()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_escrowlock_release_arbiter_buyer()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(arbiter_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(arbiter_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_escrowlock_release_arbiter_seller()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(arbiter_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(arbiter_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_escrowlock_release_all_parties()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(arbiter_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_escrowlock_release_buyer_only()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_UNAUTHORIZED);
+}
+
+#[test]
+fn test_escrowlock_release_seller_only()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_UNAUTHORIZED);
+}
+
+#[test]
+fn test_escrowlock_release_arbiter_only()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(arbiter_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(arbiter_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_UNAUTHORIZED);
+}
+
+#[test]
+fn test_escrowlock_release_no_approvals()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_UNAUTHORIZED);
+}
+
+#[test]
+fn test_escrowlock_release_approvals_in_outputs()
+{
+	// Approvals only count when the lock is used by an input cell.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(80_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_UNAUTHORIZED);
+}
+
+#[test]
+fn test_escrowlock_release_split_outputs()
+{
+	// Each escrow cell must be paid out by the output at the same index, so the payout cannot be split.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(60_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(40_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_INSUFFICIENT_CAPACITY);
+}
+
+#[test]
+fn test_escrowlock_release_extra_capacity()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(110_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_escrowlock_release_multiple_cells()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_escrowlock_release_insufficient_capacity()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(99_900_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_100_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_INSUFFICIENT_CAPACITY);
+}
+
+#[test]
+fn test_escrowlock_release_to_buyer()
+{
+	// Approved funds can only be released to the release lock.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(arbiter_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_INSUFFICIENT_CAPACITY);
+}
+
+#[test]
+fn test_escrowlock_refund_timeout()
+{
+	// No approvals are needed since the funds can only go to the buyer.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).pack()).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_escrowlock_refund_timeout_later()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_BLOCK_NUMBER | 5_000).pack()).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_escrowlock_refund_timeout_with_buyer()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(110_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_escrowlock_refund_timeout_multiple_cells()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).pack()).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_escrowlock_refund_timeout_too_early()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_BLOCK_NUMBER | 999).pack()).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_INVALID_SINCE);
+}
+
+#[test]
+fn test_escrowlock_refund_timeout_metric_mismatch()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_TIMESTAMP | 1_000).pack()).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_INVALID_SINCE);
+}
+
+#[test]
+fn test_escrowlock_refund_timeout_one_without_since()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(150_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_UNAUTHORIZED);
+}
+
+#[test]
+fn test_escrowlock_refund_timeout_to_seller()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).pack()).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_INSUFFICIENT_CAPACITY);
+}
+
+#[test]
+fn test_escrowlock_refund_timeout_insufficient_capacity()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).pack()).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(90_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_INSUFFICIENT_CAPACITY);
+}
+
+#[test]
+fn test_escrowlock_refund_timeout_epoch()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_EPOCH | (2 << 40) | (1 << 24) | 10).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | (1 << 40) | 11).pack()).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_escrowlock_refund_timeout_epoch_too_early()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_EPOCH | (2 << 40) | (1 << 24) | 10).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | (4 << 40) | (1 << 24) | 10).pack()).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_INVALID_SINCE);
+}
+
+#[test]
+fn test_escrowlock_release_after_timeout()
+{
+	// Approved releases are still possible after the timeout.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_escrowlock_release_two_escrows()
+{
+	// Two escrow cells with different args are each paid out by the output at the same index.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash.clone(), seller_lock_hash.clone(), arbiter_lock_hash.clone(), release_lock_hash.clone(), timeout].concat().into();
+	let escrowlock_lock_script_1 = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 2_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script_2 = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script_1.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script_2.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_escrowlock_release_two_escrows_one_output()
+{
+	// Two escrow cells with different args cannot both be paid out by a single output.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash.clone(), seller_lock_hash.clone(), arbiter_lock_hash.clone(), release_lock_hash.clone(), timeout].concat().into();
+	let escrowlock_lock_script_1 = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 2_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script_2 = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script_1.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script_2.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(120_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_INSUFFICIENT_CAPACITY);
+}
+
+#[test]
+fn test_escrowlock_refund_timeout_two_escrows_one_output()
+{
+	// Two escrow cells with different args cannot both be refunded by a single output.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash.clone(), seller_lock_hash.clone(), arbiter_lock_hash.clone(), release_lock_hash.clone(), timeout].concat().into();
+	let escrowlock_lock_script_1 = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+	let timeout = Bytes::from((SINCE_ABSOLUTE_BLOCK_NUMBER | 2_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script_2 = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script_1.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_BLOCK_NUMBER | 2_000).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script_2.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_BLOCK_NUMBER | 2_000).pack()).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_INSUFFICIENT_CAPACITY);
+}
+
+#[test]
+fn test_escrowlock_args_len()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_ARGSLEN);
+}
+
+#[test]
+fn test_escrowlock_invalid_timeout()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_escrowlock = context.deploy_cell(Loader::default().load_binary("escrowlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let escrowlock_dep = CellDep::new_builder().out_point(out_point_escrowlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let buyer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let seller_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let arbiter_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let release_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![4u8])).expect("script");
+	let buyer_lock_hash = buyer_lock_script.calc_script_hash().as_bytes();
+	let seller_lock_hash = seller_lock_script.calc_script_hash().as_bytes();
+	let arbiter_lock_hash = arbiter_lock_script.calc_script_hash().as_bytes();
+	let release_lock_hash = release_lock_script.calc_script_hash().as_bytes();
+	let timeout = Bytes::from((0x6000_0000_0000_0000u64 | 1_000).to_le_bytes().to_vec());
+	let escrowlock_args: Bytes = [buyer_lock_hash, seller_lock_hash, arbiter_lock_hash, release_lock_hash, timeout].concat().into();
+	let escrowlock_lock_script = context.build_script(&out_point_escrowlock, escrowlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(escrowlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(buyer_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(seller_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(release_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(escrowlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ESCROWLOCK_INVALID_TIMEOUT);
+}
@@ -1,5 +1,4 @@
 use super::*;
-use ckb_testtool::{context::Context};
 use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
 use ckb_testtool::ckb_types::core::{TransactionBuilder};
 use blake2b_ref::Blake2bBuilder;
@@ -23,7 +22,7 @@ fn test_hashlock_valid()
 	blake2b.finalize(&mut hash);
 
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("hashlock");
@@ -63,7 +62,7 @@ fn test_hashlock_no_witness()
 	blake2b.finalize(&mut hash);
 
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("hashlock");
@@ -100,7 +99,7 @@ fn test_hashlock_no_args()
 	blake2b.finalize(&mut hash);
 
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("hashlock");
@@ -139,7 +138,7 @@ fn test_hashlock_incorrect()
 	blake2b.finalize(&mut hash);
 
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("hashlock");
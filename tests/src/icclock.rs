@@ -1,5 +1,4 @@
 use super::*;
-use ckb_testtool::{context::Context};
 use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
 use ckb_testtool::ckb_types::core::{TransactionBuilder};
 
@@ -14,7 +13,7 @@ const ERROR_ICCLOCK_ARGSLEN: i8 = 6;
 fn test_icclock_minimum_capacity()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("icclock");
@@ -42,7 +41,7 @@ fn test_icclock_minimum_capacity()
 fn test_icclock_exact_capacity()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("icclock");
@@ -72,7 +71,7 @@ fn test_icclock_exact_capacity()
 fn test_icclock_multi_cell_exact_capacity()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("icclock");
@@ -102,7 +101,7 @@ fn test_icclock_multi_cell_exact_capacity()
 fn test_icclock_over_capacity()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("icclock");
@@ -132,7 +131,7 @@ fn test_icclock_over_capacity()
 fn test_icclock_no_args()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("icclock");
@@ -162,7 +161,7 @@ fn test_icclock_no_args()
 fn test_icclock_wrong_args()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("icclock");
@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+use ckb_testtool::ckb_types::bytes::Bytes;
+use ckb_testtool::ckb_types::packed::{Byte32, CellOutput, OutPoint, Script};
+use ckb_testtool::ckb_types::prelude::*;
+use serde_json::{json, Map, Value};
+use ::sdk::{sudt, Cell, Encoding};
+use crate::simulator::Simulator;
+
+// Constants
+const SCRIPT_FIXED_LEN: u64 = 33; // The code hash (32) and hash type (1) of a script, which are followed by the args.
+
+/// The script of a cell which is matched by a search key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptType
+{
+	Lock,
+	Type,
+}
+
+/// The order of the results, by block number, transaction index, and output index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order
+{
+	Asc,
+	Desc,
+}
+
+/// Additional conditions on the cells matched by a search key. Each range includes the start and excludes the end.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchKeyFilter
+{
+	/// The other script of the cell, which is the type script when searching by lock and the lock when searching by type.
+	pub script: Option<Script>,
+	/// The length of the other script, where a cell without a type script has a length of zero.
+	pub script_len_range: Option<[u64; 2]>,
+	pub output_data_len_range: Option<[u64; 2]>,
+	pub output_capacity_range: Option<[u64; 2]>,
+	pub block_range: Option<[u64; 2]>,
+}
+
+/// A query for live cells with the same semantics as the `get_cells` RPC of the CKB indexer.
+/// The code hash and hash type of the script must match exactly, and the args are matched as a prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchKey
+{
+	pub script: Script,
+	pub script_type: ScriptType,
+	pub filter: Option<SearchKeyFilter>,
+}
+
+impl SearchKey
+{
+	pub fn lock(script: Script) -> Self
+	{
+		Self { script, script_type: ScriptType::Lock, filter: None }
+	}
+
+	pub fn type_script(script: Script) -> Self
+	{
+		Self { script, script_type: ScriptType::Type, filter: None }
+	}
+
+	pub fn with_filter(self, filter: SearchKeyFilter) -> Self
+	{
+		Self { filter: Some(filter), ..self }
+	}
+
+	/// The search key as the JSON parameter of the indexer RPC, so the same query can be sent to a node.
+	pub fn to_json(&self) -> Value
+	{
+		let range = |range: &[u64; 2]| json!([format!("{:#x}", range[0]), format!("{:#x}", range[1])]);
+
+		let mut search_key = Map::new();
+		search_key.insert(String::from("script"), script_json(&self.script));
+		search_key.insert(String::from("script_type"), json!(match self.script_type { ScriptType::Lock => "lock", ScriptType::Type => "type" }));
+		if let Some(filter) = &self.filter
+		{
+			let mut json = Map::new();
+			if let Some(script) = &filter.script
+			{
+				json.insert(String::from("script"), script_json(script));
+			}
+			for (name, value) in [("script_len_range", &filter.script_len_range), ("output_data_len_range", &filter.output_data_len_range), ("output_capacity_range", &filter.output_capacity_range), ("block_range", &filter.block_range)]
+			{
+				if let Some(value) = value
+				{
+					json.insert(String::from(name), range(value));
+				}
+			}
+			search_key.insert(String::from("filter"), Value::Object(json));
+		}
+
+		Value::Object(search_key)
+	}
+
+	fn matches(&self, cell: &IndexerCell) -> bool
+	{
+		let (script, other) = match self.script_type
+		{
+			ScriptType::Lock => (Some(cell.output.lock()), cell.output.type_().to_opt()),
+			ScriptType::Type => (cell.output.type_().to_opt(), Some(cell.output.lock())),
+		};
+		if !script.is_some_and(|script| is_prefix(&self.script, &script))
+		{
+			return false;
+		}
+
+		let filter = match &self.filter
+		{
+			Some(filter) => filter,
+			None => return true,
+		};
+		let capacity: u64 = cell.output.capacity().unpack();
+		let other_len = other.as_ref().map_or(0, |script| SCRIPT_FIXED_LEN + script.args().raw_data().len() as u64);
+
+		filter.script.as_ref().map_or(true, |script| other.as_ref().is_some_and(|other| is_prefix(script, other)))
+			&& in_range(&filter.script_len_range, other_len)
+			&& in_range(&filter.output_data_len_range, cell.output_data.len() as u64)
+			&& in_range(&filter.output_capacity_range, capacity)
+			&& in_range(&filter.block_range, cell.block_number)
+	}
+}
+
+/// A live cell with the block it was committed in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexerCell
+{
+	pub output: CellOutput,
+	pub output_data: Bytes,
+	pub out_point: OutPoint,
+	pub block_number: u64,
+	pub tx_index: u32,
+}
+
+impl IndexerCell
+{
+	/// The cell for use as an input with the sdk.
+	pub fn cell(&self) -> Cell
+	{
+		Cell::new(self.out_point.clone(), self.output.clone(), self.output_data.clone())
+	}
+
+	/// The position of the cell in the chain, which orders the results and is used as the cursor.
+	fn cursor(&self) -> Bytes
+	{
+		let index: u32 = self.out_point.index().unpack();
+		let mut cursor = Vec::new();
+		cursor.extend_from_slice(&self.block_number.to_be_bytes());
+		cursor.extend_from_slice(&self.tx_index.to_be_bytes());
+		cursor.extend_from_slice(&index.to_be_bytes());
+		cursor.extend_from_slice(self.out_point.tx_hash().as_slice());
+
+		cursor.into()
+	}
+}
+
+/// A page of results, with the cursor to pass as `after_cursor` to get the next page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pagination<T>
+{
+	pub objects: Vec<T>,
+	pub last_cursor: Bytes,
+}
+
+/// The total capacity of the cells matched by a search key, at the tip block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellsCapacity
+{
+	pub capacity: u64,
+	pub block_hash: Byte32,
+	pub block_number: u64,
+}
+
+/// An indexer over the live cells of a simulator, which answers the same queries as the CKB indexer RPC.
+pub struct Indexer<'a>
+{
+	simulator: &'a Simulator,
+}
+
+impl<'a> Indexer<'a>
+{
+	pub fn new(simulator: &'a Simulator) -> Self
+	{
+		Self { simulator }
+	}
+
+	/// Find live cells which match the search key, up to the limit, starting after the cursor.
+	pub fn get_cells(&self, search_key: &SearchKey, order: Order, limit: usize, after_cursor: Option<&Bytes>) -> Pagination<IndexerCell>
+	{
+		let mut cells: Vec<(Bytes, IndexerCell)> = self.cells().into_iter().filter(|cell| search_key.matches(cell)).map(|cell| (cell.cursor(), cell)).collect();
+		cells.sort_by(|(a, _), (b, _)| a.cmp(b));
+		if order == Order::Desc
+		{
+			cells.reverse();
+		}
+
+		let objects: Vec<(Bytes, IndexerCell)> = cells.into_iter()
+			.filter(|(cursor, _)| match (after_cursor, order)
+			{
+				(None, _) => true,
+				(Some(after), Order::Asc) => cursor > after,
+				(Some(after), Order::Desc) => cursor < after,
+			})
+			.take(limit)
+			.collect();
+		let last_cursor = objects.last().map(|(cursor, _)| cursor.clone()).unwrap_or_default();
+
+		Pagination { objects: objects.into_iter().map(|(_, cell)| cell).collect(), last_cursor }
+	}
+
+	/// The total capacity of the live cells which match the search key.
+	pub fn get_cells_capacity(&self, search_key: &SearchKey) -> CellsCapacity
+	{
+		let capacity = self.cells().iter().filter(|cell| search_key.matches(cell)).map(|cell| Unpack::<u64>::unpack(&cell.output.capacity())).sum();
+		let tip = self.simulator.tip();
+
+		CellsCapacity { capacity, block_hash: tip.hash(), block_number: tip.number() }
+	}
+
+	/// The total amount of the sudt in the live cells of each lock, in the order the locks first received a cell.
+	/// The total is None when it does not fit in a u128, which the cells of a lock can exceed between them.
+	pub fn sudt_balances(&self, type_script: &Script) -> Vec<(Script, Option<u128>)>
+	{
+		let search_key = SearchKey::type_script(type_script.clone());
+
+		let mut balances: Vec<(Script, Option<u128>)> = Vec::new();
+		let mut positions = HashMap::new();
+		for cell in self.get_cells(&search_key, Order::Asc, usize::MAX, None).objects
+		{
+			// The args of the type script must match exactly, since a prefix could match a different token.
+			let amount = match sudt::Data::decode(&cell.output_data)
+			{
+				Ok(data) if cell.output.type_().to_opt().as_ref() == Some(type_script) => data.amount,
+				_ => continue,
+			};
+			let position = *positions.entry(cell.output.lock()).or_insert_with(||
+			{
+				balances.push((cell.output.lock(), Some(0)));
+				balances.len() - 1
+			});
+			balances[position].1 = balances[position].1.and_then(|balance| balance.checked_add(amount));
+		}
+
+		balances
+	}
+
+	/// The total amount of the sudt in the live cells of the lock, or None when it does not fit in a u128.
+	pub fn sudt_balance(&self, lock: &Script, type_script: &Script) -> Option<u128>
+	{
+		self.sudt_balances(type_script).into_iter().find(|(script, _)| script == lock).map_or(Some(0), |(_, amount)| amount)
+	}
+
+	fn cells(&self) -> Vec<IndexerCell>
+	{
+		self.simulator.live_cells().into_iter().map(|cell|
+		{
+			let info = self.simulator.context().transaction_infos.get(&cell.out_point).expect("live cell block");
+			IndexerCell { output: cell.output, output_data: cell.data, out_point: cell.out_point, block_number: info.block_number, tx_index: info.index as u32 }
+		})
+		.collect()
+	}
+}
+
+fn script_json(script: &Script) -> Value
+{
+	serde_json::to_value(ckb_jsonrpc_types::Script::from(script.clone())).expect("script json")
+}
+
+/// Determine if the script matches the code hash and hash type of the search script, and its args start with the search args.
+fn is_prefix(search: &Script, script: &Script) -> bool
+{
+	search.code_hash() == script.code_hash() && search.hash_type() == script.hash_type() && script.args().raw_data().starts_with(&search.args().raw_data())
+}
+
+fn in_range(range: &Option<[u64; 2]>, value: u64) -> bool
+{
+	range.map_or(true, |[start, end]| start <= value && value < end)
+}
@@ -0,0 +1,159 @@
+use super::*;
+use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_testtool::ckb_types::bytes::Bytes;
+use ::sdk::{cellcount, datarange, oracle, timelock, Encoding};
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+
+/// Create an output with the contract as its type script and return the error code from verification, if any.
+fn verify_output(name: &str, args: &[u8], data: &[u8]) -> Option<i8>
+{
+	let mut context = Context::default();
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_contract = context.deploy_cell(Loader::default().load_binary(name));
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let type_script = context.build_script(&out_point_contract, Bytes::from(args.to_vec())).expect("script");
+
+	let tx = ::sdk::transaction::create(vec![::sdk::Output::new(10_000_000_000_000, lock_script, Some(type_script), Bytes::from(data.to_vec()))]);
+	let tx = context.complete_tx(tx);
+	context.verify_tx(&tx, MAX_CYCLES).err().map(|err|
+	{
+		let error = err.to_string();
+		let code = error.split("error code ").nth(1).and_then(|code| code.split(' ').next()).expect("error code");
+		code.parse().expect("error code")
+	})
+}
+
+#[test]
+fn test_inspector_errors()
+{
+	// The error codes are generated from the Error enum of each contract.
+
+	assert_eq!(::inspector::error_name("datarange", 5), Some("ArgsLen"));
+	assert_eq!(::inspector::error_name("datarange", 7), Some("DataMinimumNotMet"));
+	assert_eq!(::inspector::error_name("sudt", 6), Some("ArgsLength"));
+	assert_eq!(::inspector::error_name("sudt", 7), None);
+	assert_eq!(::inspector::error_name("sudt", -1), Some("Panic"));
+	assert_eq!(::inspector::error_code("hashlock", "Unauthorized"), Some(5));
+	assert_eq!(::inspector::error_code("odcounter", "InvalidOutputCellData"), Some(8));
+	assert_eq!(::inspector::errors("always"), Some(&[][..]));
+	assert_eq!(::inspector::errors("nosuchcontract"), None);
+	assert_eq!(::inspector::inspect("nosuchcontract", None, None).unwrap_err(), ::inspector::Error::UnknownContract(String::from("nosuchcontract")));
+}
+
+#[test]
+fn test_inspector_parse_hex()
+{
+	assert_eq!(::inspector::parse_hex("0x00ff10").unwrap(), vec![0x00, 0xff, 0x10]);
+	assert_eq!(::inspector::parse_hex("00FF10").unwrap(), vec![0x00, 0xff, 0x10]);
+	assert_eq!(::inspector::parse_hex("0x").unwrap(), Vec::<u8>::new());
+	assert_eq!(::inspector::parse_hex("0x123").unwrap_err(), ::inspector::Error::InvalidHex(String::from("0x123")));
+	assert_eq!(::inspector::parse_hex("zz").unwrap_err(), ::inspector::Error::InvalidHex(String::from("zz")));
+}
+
+#[test]
+fn test_inspector_fields()
+{
+	// Valid args and data are decoded into named fields.
+
+	let args = oracle::Args { updater_lock_hash: [0x11; 32] };
+	let data = oracle::Data { value: 42, timestamp: 1_700_000_000, sequence: 7 };
+	let report = ::inspector::inspect("oracle", Some(&args.encode()), Some(&data.encode())).unwrap();
+	assert!(report.is_valid());
+
+	let fields: Vec<(&str, &str)> = report.data.as_ref().unwrap().fields.iter().map(|field| (field.name.as_str(), field.value.as_str())).collect();
+	assert_eq!(fields, vec![("value", "42"), ("timestamp", "1700000000"), ("sequence", "7")]);
+	assert_eq!(report.args.as_ref().unwrap().fields[0].value, format!("0x{}", "11".repeat(32)));
+}
+
+#[test]
+fn test_inspector_violations()
+{
+	// Each broken rule is reported with the error code the contract would return.
+
+	let codes = |contract: &str, args: Option<&[u8]>, data: Option<&[u8]>| -> Vec<i8>
+	{
+		::inspector::inspect(contract, args, data).unwrap().violations().map(|violation| violation.code).collect()
+	};
+
+	assert_eq!(codes("datarange", Some(&[0u8; 4]), None), vec![5]);
+	assert_eq!(codes("datarange", Some(&datarange::Args { minimum: 2, limit: 4 }.encode()), Some(&[0u8; 5])), vec![6]);
+	assert_eq!(codes("cellcount", Some(&[9, 0]), None), vec![6]);
+	assert_eq!(codes("cellcount", Some(&[0, 9]), None), vec![7]);
+	assert_eq!(codes("timelock", Some(&timelock::Args { delegate_lock_hash: [0; 32], threshold: 0x6000_0000_0000_0000 }.encode()), None), vec![6]);
+	assert_eq!(codes("counter", None, Some(&[0u8; 4])), vec![-1]);
+	assert_eq!(codes("odcounter", None, Some(&[0u8; 4])), vec![7, 8]);
+	assert_eq!(codes("jsoncell", None, Some(&[0xff])), vec![6]);
+	assert_eq!(codes("jsoncell", None, Some(b"{\"a\":")), vec![5]);
+	assert_eq!(codes("sudt", Some(&[0u8; 32]), Some(&[0u8; 8])), vec![4]);
+	assert_eq!(codes("nft", Some(&[[0u8; 32].as_slice(), &[1u8]].concat()), Some(&[0u8; 8])), vec![10]);
+	assert_eq!(codes("nft", Some(&[[0u8; 32].as_slice(), &[1u8]].concat()), Some(&[2u64.to_le_bytes(), 3u64.to_le_bytes()].concat())), vec![11]);
+	assert!(codes("always", Some(&[1, 2, 3]), Some(&[4, 5, 6])).is_empty());
+}
+
+#[test]
+fn test_inspector_notes()
+{
+	// Rules which depend on the rest of the transaction are explained in notes.
+
+	let report = ::inspector::inspect("counter", None, Some(&u64::MAX.to_le_bytes())).unwrap();
+	assert!(report.is_valid());
+	assert_eq!(report.data.as_ref().unwrap().notes.len(), 1);
+
+	let report = ::inspector::inspect("counter", None, Some(&[0u8; 10])).unwrap();
+	assert_eq!(report.data.as_ref().unwrap().notes, vec![String::from("The 2 bytes after the first 8 are ignored.")]);
+
+	let report = ::inspector::inspect("cellcount", Some(&cellcount::Args { source: cellcount::Source::Output, condition: cellcount::Condition::Equal(1) }.encode()), Some(&[])).unwrap();
+	assert_eq!(report.data.as_ref().unwrap().notes, vec![String::from("The data is not used by the contract.")]);
+}
+
+#[test]
+fn test_inspector_display()
+{
+	let args = datarange::Args { minimum: 2, limit: 4 };
+	let report = ::inspector::inspect("datarange", Some(&args.encode()), Some(&[1])).unwrap();
+	let expected = "Contract: datarange\n\nArgs: 0x0200000004000000\n  minimum: 2 bytes\n  limit: 4 bytes\n\nData: 0x01\n  length: 1 bytes\n  error 7 (DataMinimumNotMet): the data is less than the minimum of 2 bytes";
+	assert_eq!(report.to_string(), expected);
+}
+
+#[test]
+fn test_inspector_matches_contract()
+{
+	// The inspector reports the same error code as the contract for the same args and data.
+
+	let range = datarange::Args { minimum: 2, limit: 4 }.encode();
+	let cases: Vec<(&str, Vec<u8>, Vec<u8>)> = vec!
+	[
+		("datarange", range.to_vec(), vec![1u8; 3]),
+		("datarange", range.to_vec(), vec![1u8; 1]),
+		("datarange", range.to_vec(), vec![1u8; 5]),
+		("datarange", vec![0u8; 4], vec![1u8; 3]),
+		("datacap", 4u32.to_le_bytes().to_vec(), vec![1u8; 5]),
+		("datacap", vec![0u8; 3], vec![]),
+		("data10", vec![], vec![1u8; 11]),
+		("data10", 12u32.to_le_bytes().to_vec(), vec![1u8; 11]),
+		("data10", 16u64.to_le_bytes().to_vec(), vec![1u8; 11]),
+		("cellcount", vec![9, 0], vec![]),
+		("cellcount", vec![1, 9], vec![]),
+		("cellcount", vec![1, 0, 1], vec![]),
+		("jsoncell", vec![], b"{\"a\":1}".to_vec()),
+		("jsoncell", vec![], b"{\"a\":".to_vec()),
+		("jsoncell", vec![], vec![0xff]),
+		("dataratio", vec![1, 0, 0, 0, 2], vec![]),
+		("dataratio", vec![1, 0, 0], vec![]),
+		("sudt", vec![0u8; 32], vec![0u8; 8]),
+		("sudt", vec![0u8; 31], vec![0u8; 16]),
+	];
+	for (contract, args, data) in cases
+	{
+		let report = ::inspector::inspect(contract, Some(&args), Some(&data)).unwrap();
+		let expected = report.violations().next().map(|violation| violation.code);
+		assert_eq!(verify_output(contract, &args, &data), expected, "{} args 0x{} data 0x{}", contract, hex(&args), hex(&data));
+	}
+}
+
+fn hex(data: &[u8]) -> String
+{
+	data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
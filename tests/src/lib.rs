@@ -1,38 +1,66 @@
 use ckb_testtool::ckb_types::bytes::Bytes;
+use ckb_testtool::ckb_types::core::{Cycle, TransactionView};
 use ckb_testtool::ckb_error::Error;
+use std::cell::Cell;
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::thread;
 
+pub mod differential;
+pub mod indexer;
+pub mod simulator;
+
+#[cfg(test)]
+mod acplock;
 #[cfg(test)]
 mod aggcounter;
 #[cfg(test)]
 mod aggdoublecounter;
 #[cfg(test)]
+mod cellcount;
+#[cfg(test)]
+mod chequelock;
+#[cfg(test)]
 mod ckb500;
 #[cfg(test)]
+mod compat;
+#[cfg(test)]
 mod counter;
 #[cfg(test)]
+mod daowrapper;
+#[cfg(test)]
 mod data10;
 #[cfg(test)]
 mod datacap;
 #[cfg(test)]
 mod datarange;
 #[cfg(test)]
+mod dataratio;
+#[cfg(test)]
+mod deployer;
+#[cfg(test)]
 mod doublecounter;
 #[cfg(test)]
-mod hashlock;
+mod escrowlock;
 #[cfg(test)]
-mod ic3type;
+mod hashlock;
 #[cfg(test)]
 mod icclock;
 #[cfg(test)]
-mod icctype;
+mod inspector;
 #[cfg(test)]
 mod jsoncell;
 #[cfg(test)]
-mod oc5type;
+mod lockproxylock;
+#[cfg(test)]
+mod manifest;
+#[cfg(test)]
+mod models;
+#[cfg(test)]
+mod nft;
 #[cfg(test)]
 mod occlock;
 #[cfg(test)]
@@ -40,9 +68,32 @@ mod odcounter;
 #[cfg(test)]
 mod oddoublecounter;
 #[cfg(test)]
+mod oracle;
+#[cfg(test)]
+mod oraclelock;
+#[cfg(test)]
+mod orderlock;
+#[cfg(test)]
+mod queries;
+#[cfg(test)]
+mod ratelimitlock;
+#[cfg(test)]
+mod scenarios;
+#[cfg(test)]
+mod sdk;
+#[cfg(test)]
 mod sudt;
+#[cfg(test)]
+mod timelock;
+#[cfg(test)]
+mod typeid;
+#[cfg(test)]
+mod typeproxylock;
+#[cfg(test)]
+mod vestinglock;
 
 const TEST_ENV_VAR: &str = "CAPSULE_TEST_ENV";
+const RECORD_DIR_ENV_VAR: &str = "CAPSULE_RECORD_DIR";
 
 pub enum TestEnv {
     Debug,
@@ -104,3 +155,40 @@ pub fn assert_script_error(err: Error, err_code: i8) {
         err_code
     );
 }
+
+thread_local! {
+    static RECORD_INDEX: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A verification context which records every verified transaction to the directory in `CAPSULE_RECORD_DIR`.
+/// Tests opt in to recording by using it in place of the ckb-testtool context, and the recordings can be replayed
+/// against another build of a contract with the compat tool.
+#[derive(Default)]
+pub struct RecordingContext(ckb_testtool::context::Context);
+
+impl Deref for RecordingContext {
+    type Target = ckb_testtool::context::Context;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for RecordingContext {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl RecordingContext {
+    pub fn verify_tx(&self, tx: &TransactionView, max_cycles: u64) -> Result<Cycle, Error> {
+        let result = self.0.verify_tx(tx, max_cycles);
+        if let Ok(dir) = env::var(RECORD_DIR_ENV_VAR) {
+            // Each test runs in a thread named after the test, and may verify more than one transaction.
+            let index = RECORD_INDEX.with(|index| index.replace(index.get() + 1));
+            let name = format!("{}-{}", thread::current().name().unwrap_or("unknown"), index);
+            ::compat::record(Path::new(&dir), &name, &self.0, tx, &result).expect("record transaction");
+        }
+        result
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+use std::path::Path;
+use ckb_testtool::context::Context;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*, H256};
+use ::deployer::capsule::CapsuleConfig;
+use ::manifest::Contract;
+
+/// The manifest for the same build that the Loader uses.
+fn contracts() -> &'static [Contract]
+{
+	match env::var(TEST_ENV_VAR).ok().and_then(|value| value.parse().ok())
+	{
+		Some(TestEnv::Release) => ::manifest::release::CONTRACTS,
+		_ => ::manifest::debug::CONTRACTS,
+	}
+}
+
+/// The JSON manifest for the same build that the Loader uses.
+fn contracts_json() -> &'static str
+{
+	match env::var(TEST_ENV_VAR).ok().and_then(|value| value.parse().ok())
+	{
+		Some(TestEnv::Release) => ::manifest::release::JSON,
+		_ => ::manifest::debug::JSON,
+	}
+}
+
+#[test]
+fn test_manifest_contracts()
+{
+	// Every contract in capsule.toml is in the manifest, in the same order.
+
+	let capsule = CapsuleConfig::load(Path::new("../capsule.toml")).expect("capsule.toml");
+	let names: Vec<&str> = contracts().iter().map(|contract| contract.name).collect();
+	assert_eq!(names, capsule.contract_names());
+}
+
+#[test]
+fn test_manifest_data_hashes()
+{
+	// The data hash and size of every contract match the binary loaded by the tests.
+
+	let loader = Loader::default();
+	for contract in contracts()
+	{
+		let binary = loader.load_binary(contract.name);
+		assert_eq!(contract.code_hash(), CellOutput::calc_data_hash(&binary), "{}", contract.name);
+		assert_eq!(contract.size, binary.len(), "{}", contract.name);
+	}
+}
+
+#[test]
+fn test_manifest_script()
+{
+	// A script built from the manifest is the same as one built by the context from the deployed binary.
+
+	let mut context = Context::default();
+	let contract = ::manifest::find(contracts(), "sudt").expect("sudt");
+	let out_point = context.deploy_cell(Loader::default().load_binary("sudt"));
+	let args = Bytes::from(vec![1u8; 32]);
+	let script = context.build_script(&out_point, args.clone()).expect("script");
+	assert_eq!(contract.script(args), script);
+	assert!(::manifest::find(contracts(), "missing").is_none());
+}
+
+#[test]
+fn test_manifest_json()
+{
+	// The JSON manifest has the same entries as the Rust module.
+
+	let json: serde_json::Value = serde_json::from_str(contracts_json()).expect("json");
+	let entries = json["contracts"].as_array().expect("contracts");
+	assert_eq!(entries.len(), contracts().len());
+	for (entry, contract) in entries.iter().zip(contracts())
+	{
+		let data_hash: H256 = contract.code_hash().unpack();
+		assert_eq!(entry["name"], contract.name);
+		assert_eq!(entry["data_hash"], format!("{:#x}", data_hash));
+		assert_eq!(entry["size"], contract.size);
+	}
+}
@@ -0,0 +1,1881 @@
+use super::*;
+use blake2b_ref::Blake2bBuilder;
+use ::inspector::PANIC_ERROR_CODE;
+use ckb_testtool::ckb_types::packed::CellInput;
+use ckb_testtool::ckb_types::prelude::*;
+use differential::{error, Case, CaseCell, Group, Harness, Position, Rng, Verdict};
+
+// Constants
+const SEED: u64 = 0x5EED;
+const CASES: u64 = 200;
+const CKB: u64 = 100_000_000; // One CKByte is 100,000,000 Shannons.
+const CAPACITIES: [u64; 6] = [0, 61 * CKB, 250 * CKB, 500 * CKB, 1_000 * CKB, u64::MAX]; // Capacities which add up to the amounts used by the contracts, and overflow.
+const COUNTER_VALUES: [u64; 8] = [0, 1, 2, 3, 4, u64::MAX - 2, u64::MAX - 1, u64::MAX];
+const NEVER_ERROR_CODE: i8 = 1; // The never contract always fails with this error code, and has no Error enum.
+const WITNESS_SIZE_LIMIT: usize = 1_024; // The largest witness the hashlock contract can load.
+const LOCK_POSITIONS: [Option<Position>; 3] = [Some(Position::Lock), Some(Position::Lock), None];
+const TYPE_POSITIONS: [Option<Position>; 3] = [Some(Position::Type), Some(Position::Type), None];
+const SUDT_AMOUNTS: [u128; 5] = [0, 1, 2, 5, u128::MAX];
+const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash"; // The Blake2b personalization used by CKB.
+const NFT_CLASS_MODE: u8 = 1; // The last byte of the args of an NFT class cell.
+const CHEQUE_WITHDRAW_EPOCHS: u64 = 6; // The number of epochs the sender of a cheque must wait before withdrawing.
+const SINCE_FLAGS_MASK: u64 = 0xFF00_0000_0000_0000;
+const SINCE_VALUE_MASK: u64 = 0x00FF_FFFF_FFFF_FFFF;
+const SINCE_METRIC_MASK: u64 = 0x6000_0000_0000_0000;
+const SINCE_RESERVED_MASK: u64 = 0x1F00_0000_0000_0000;
+const SINCE_METRIC_BLOCK_NUMBER: u64 = 0x0000_0000_0000_0000;
+const SINCE_METRIC_EPOCH: u64 = 0x2000_0000_0000_0000;
+const SINCE_METRIC_TIMESTAMP: u64 = 0x4000_0000_0000_0000;
+const SINCE_EPOCH_NUMBER_MASK: u64 = 0x0000_0000_00FF_FFFF;
+const SINCE_ABSOLUTE_EPOCH_FLAGS: u64 = 0x2000_0000_0000_0000;
+const SINCE_RELATIVE_EPOCH_FLAGS: u64 = 0xA000_0000_0000_0000;
+const SINCE_THRESHOLDS: [u64; 8] = [100, 0x2000_0000_0000_000A, 0x2000_0A00_0500_000A, 0x4000_0000_0000_03E8, 0x8000_0000_0000_0064, 0xA000_0000_0000_0006, 0x6000_0000_0000_0000, 0x0100_0000_0000_0064]; // Block, epoch, epoch with a fraction, timestamp, relative, and invalid thresholds.
+const SINCE_SAMPLES: [u64; 5] = [0x2000_0400_0200_000A, 0x2000_0400_0100_000A, 0x2000_0000_0000_000B, 0xA000_0000_0000_0001, 0x4000_0000_0000_0000]; // Epochs with other fractions, and since values with other flags.
+
+fn u32_at(bytes: &[u8], offset: usize) -> Result<u32, i8>
+{
+	bytes.get(offset..offset + 4).map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap())).ok_or(PANIC_ERROR_CODE)
+}
+
+fn u64_at(bytes: &[u8], offset: usize) -> Result<u64, i8>
+{
+	bytes.get(offset..offset + 8).map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap())).ok_or(PANIC_ERROR_CODE)
+}
+
+fn u128_at(bytes: &[u8], offset: usize) -> Result<u128, i8>
+{
+	bytes.get(offset..offset + 16).map(|bytes| u128::from_le_bytes(bytes.try_into().unwrap())).ok_or(PANIC_ERROR_CODE)
+}
+
+fn blake2b(data: &[u8]) -> [u8; 32]
+{
+	let mut hash = [0u8; 32];
+	let mut blake2b = Blake2bBuilder::new(32).build();
+	blake2b.update(data);
+	blake2b.finalize(&mut hash);
+
+	hash
+}
+
+/// The unique ID of a Type ID or NFT cell, which is derived from the first input and the index of the output.
+fn unique_id(first_input: &CellInput, index: usize) -> [u8; 32]
+{
+	let mut hash = [0u8; 32];
+	let mut blake2b = Blake2bBuilder::new(32).personal(CKB_HASH_PERSONALIZATION).build();
+	blake2b.update(first_input.as_slice());
+	blake2b.update(&(index as u64).to_le_bytes());
+	blake2b.finalize(&mut hash);
+
+	hash
+}
+
+/// Determine if a since threshold has no reserved flags and a known metric.
+fn is_valid_since_threshold(threshold: u64) -> bool
+{
+	let metric = threshold & SINCE_METRIC_MASK;
+
+	threshold & SINCE_RESERVED_MASK == 0 && (metric == SINCE_METRIC_BLOCK_NUMBER || metric == SINCE_METRIC_EPOCH || metric == SINCE_METRIC_TIMESTAMP)
+}
+
+/// Determine if the value of a since has reached the value of a threshold, comparing epochs with their fractions.
+fn is_since_reached(since: u64, threshold: u64) -> bool
+{
+	let (since, threshold_value) = (since & SINCE_VALUE_MASK, threshold & SINCE_VALUE_MASK);
+	if threshold & SINCE_METRIC_MASK != SINCE_METRIC_EPOCH
+	{
+		return since >= threshold_value;
+	}
+	let number = |epoch: u64| epoch & 0xFF_FFFF;
+	let fraction = |epoch: u64| match (epoch >> 40) & 0xFFFF
+	{
+		0 => (0, 1),
+		length => ((epoch >> 24) & 0xFFFF, length),
+	};
+	if number(since) != number(threshold_value)
+	{
+		return number(since) > number(threshold_value);
+	}
+	let ((since_index, since_length), (threshold_index, threshold_length)) = (fraction(since), fraction(threshold_value));
+
+	since_index * threshold_length >= threshold_index * since_length
+}
+
+/// Random bytes of a random length up to the maximum.
+fn random_bytes(rng: &mut Rng, max_len: u64) -> Vec<u8>
+{
+	let len = rng.below(max_len + 1) as usize;
+
+	rng.bytes(len)
+}
+
+/// Args which are usually valid, and sometimes random bytes of a similar length.
+fn random_args(rng: &mut Rng, valid: Vec<u8>) -> Vec<u8>
+{
+	match rng.one_in(5)
+	{
+		true => random_bytes(rng, valid.len() as u64 + 2),
+		false => valid,
+	}
+}
+
+/// Data which is usually made of counter values, and sometimes zeroed or random bytes of a similar length.
+fn counter_data(rng: &mut Rng, values: usize) -> Vec<u8>
+{
+	match rng.below(8)
+	{
+		0 => random_bytes(rng, values as u64 * 8 + 1),
+		1 => vec![0; values * 8],
+		_ => (0..values).flat_map(|_| rng.choose(&COUNTER_VALUES).to_le_bytes()).collect(),
+	}
+}
+
+/// Data which is usually an SUDT amount, and sometimes random bytes of a similar length.
+fn sudt_data(rng: &mut Rng) -> Vec<u8>
+{
+	match rng.one_in(5)
+	{
+		true => random_bytes(rng, 17),
+		false => rng.choose(&SUDT_AMOUNTS).to_le_bytes().to_vec(),
+	}
+}
+
+/// A since value around the threshold, or one of the samples with other flags and epoch fractions.
+fn random_since(rng: &mut Rng, threshold: u64) -> u64
+{
+	match rng.below(6)
+	{
+		0 => 0,
+		1 => threshold.wrapping_sub(1),
+		2 => threshold.wrapping_add(1),
+		3 => rng.choose(&SINCE_SAMPLES),
+		_ => threshold,
+	}
+}
+
+/// Give some of the cells of the contract in the position other args, to put them in a second script group.
+fn split_groups<A>(rng: &mut Rng, case: &mut Case, position: Position, args: A)
+	where A: Fn(&mut Rng) -> Vec<u8>
+{
+	let other_args = args(rng);
+	for cell in case.inputs.iter_mut().chain(case.outputs.iter_mut()).filter(|cell| cell.position == Some(position))
+	{
+		if rng.one_in(4)
+		{
+			cell.args = Some(other_args.clone());
+		}
+	}
+}
+
+fn random_cell(rng: &mut Rng, position: Option<Position>, data: Vec<u8>) -> CaseCell
+{
+	let owner = rng.below(3) as u8;
+	let tag = match rng.one_in(3)
+	{
+		true => Some(rng.below(3) as u8),
+		false => None,
+	};
+
+	CaseCell { position, args: None, owner, tag, capacity: rng.choose(&CAPACITIES), since: 0, data }
+}
+
+/// Generate a case with up to the maximum number of inputs and outputs, where each cell uses the contract in one of
+/// the positions, and up to two short witnesses. At least one cell uses the contract.
+fn generate<A, D>(rng: &mut Rng, args: A, max_cells: u64, positions: &[Option<Position>], data: D) -> Case
+	where A: Fn(&mut Rng) -> Vec<u8>, D: Fn(&mut Rng) -> Vec<u8>
+{
+	let args = args(rng);
+	let cells = |rng: &mut Rng| -> Vec<CaseCell>
+	{
+		let count = rng.below(max_cells + 1);
+		(0..count).map(|_|
+		{
+			let position = rng.choose(positions);
+			let data = data(rng);
+			random_cell(rng, position, data)
+		}).collect()
+	};
+	let mut inputs = cells(rng);
+	let outputs = cells(rng);
+
+	// The contract must be used by at least one cell for it to run.
+	if !inputs.iter().chain(outputs.iter()).any(CaseCell::runs_contract)
+	{
+		let data = data(rng);
+		inputs.push(random_cell(rng, positions[0], data));
+	}
+	let count = rng.below(3);
+	let witnesses = (0..count).map(|_| random_bytes(rng, 2)).collect();
+
+	Case { args, inputs, outputs, deps: Vec::new(), witnesses }
+}
+
+fn model_always(_group: &Group) -> Verdict
+{
+	Ok(())
+}
+
+fn model_never(_group: &Group) -> Verdict
+{
+	Err(NEVER_ERROR_CODE)
+}
+
+fn model_ckb500(group: &Group) -> Verdict
+{
+	let total = group.case.inputs.iter().try_fold(0u64, |total, cell| total.checked_add(cell.capacity)).ok_or(PANIC_ERROR_CODE)?;
+	if total != 50_000_000_000
+	{
+		return Err(error("ckb500", "Unauthorized"));
+	}
+
+	Ok(())
+}
+
+fn model_data10(group: &Group) -> Verdict
+{
+	// Args which are not a 4 byte limit are ignored.
+	let limit = match group.args.len()
+	{
+		4 => u32_at(group.args, 0)? as usize,
+		_ => 10,
+	};
+	if group.group_outputs().iter().any(|cell| cell.data.len() > limit)
+	{
+		return Err(error("data10", "DataLimitExceeded"));
+	}
+
+	Ok(())
+}
+
+fn model_datacap(group: &Group) -> Verdict
+{
+	if group.args.len() != 4
+	{
+		return Err(error("datacap", "ArgsLen"));
+	}
+	let limit = u32_at(group.args, 0)? as usize;
+	if group.group_outputs().iter().any(|cell| cell.data.len() > limit)
+	{
+		return Err(error("datacap", "DataLimitExceeded"));
+	}
+
+	Ok(())
+}
+
+fn model_datarange(group: &Group) -> Verdict
+{
+	if group.args.len() != 8
+	{
+		return Err(error("datarange", "ArgsLen"));
+	}
+	let minimum = u32_at(group.args, 0)? as usize;
+	let limit = u32_at(group.args, 4)? as usize;
+	for cell in group.group_outputs()
+	{
+		if cell.data.len() < minimum
+		{
+			return Err(error("datarange", "DataMinimumNotMet"));
+		}
+		if cell.data.len() > limit
+		{
+			return Err(error("datarange", "DataLimitExceeded"));
+		}
+	}
+
+	Ok(())
+}
+
+fn model_dataratio(group: &Group) -> Verdict
+{
+	if group.args.len() != 4 && group.args.len() != 5
+	{
+		return Err(error("dataratio", "ArgsLen"));
+	}
+	let ratio = u32_at(group.args, 0)? as u64;
+	let free_capacity = |cell: &CaseCell| cell.capacity.saturating_sub(group.occupied_capacity(cell));
+	match group.args.get(4).copied().unwrap_or(0)
+	{
+		0 =>
+		{
+			if group.group_outputs().iter().any(|cell| cell.data.len() as u64 > (free_capacity(cell) / CKB).saturating_mul(ratio))
+			{
+				return Err(error("dataratio", "DataLimitExceeded"));
+			}
+		},
+		1 =>
+		{
+			let outputs = group.group_outputs();
+			let data_len: u64 = outputs.iter().map(|cell| cell.data.len() as u64).sum();
+			let capacity = outputs.iter().try_fold(0u64, |total, cell| total.checked_add(free_capacity(cell))).ok_or(PANIC_ERROR_CODE)?;
+			if data_len > (capacity / CKB).saturating_mul(ratio)
+			{
+				return Err(error("dataratio", "DataLimitExceeded"));
+			}
+		},
+		_ => return Err(error("dataratio", "InvalidMode")),
+	}
+
+	Ok(())
+}
+
+fn model_counter(group: &Group) -> Verdict
+{
+	let (inputs, outputs) = (group.group_inputs(), group.group_outputs());
+	if inputs.is_empty()
+	{
+		return Ok(());
+	}
+	if inputs.len() != 1 || outputs.len() != 1
+	{
+		return Err(error("counter", "InvalidTransactionStructure"));
+	}
+	let input = u64_at(&inputs[0].data, 0)?;
+	let output = u64_at(&outputs[0].data, 0)?;
+	if input.checked_add(1).ok_or(PANIC_ERROR_CODE)? != output
+	{
+		return Err(error("counter", "InvalidCounterValue"));
+	}
+
+	Ok(())
+}
+
+fn model_doublecounter(group: &Group) -> Verdict
+{
+	let (inputs, outputs) = (group.group_inputs(), group.group_outputs());
+	if inputs.is_empty()
+	{
+		return Ok(());
+	}
+	if inputs.len() != 1 || outputs.len() != 1
+	{
+		return Err(error("doublecounter", "InvalidTransactionStructure"));
+	}
+	let (input_1, input_2) = (u64_at(&inputs[0].data, 0)?, u64_at(&inputs[0].data, 8)?);
+	let (output_1, output_2) = (u64_at(&outputs[0].data, 0)?, u64_at(&outputs[0].data, 8)?);
+	if input_1.checked_add(1).ok_or(PANIC_ERROR_CODE)? != output_1
+	{
+		return Err(error("doublecounter", "InvalidCounterValue1"));
+	}
+	if input_2.checked_add(2).ok_or(PANIC_ERROR_CODE)? != output_2
+	{
+		return Err(error("doublecounter", "InvalidCounterValue2"));
+	}
+
+	Ok(())
+}
+
+fn model_aggcounter(group: &Group) -> Verdict
+{
+	let (inputs, outputs) = (group.group_inputs(), group.group_outputs());
+	if inputs.is_empty()
+	{
+		return Ok(());
+	}
+	if inputs.len() != outputs.len()
+	{
+		return Err(error("aggcounter", "InvalidTransactionStructure"));
+	}
+	for (input, output) in inputs.iter().zip(outputs.iter())
+	{
+		let (input, output) = (u64_at(&input.data, 0)?, u64_at(&output.data, 0)?);
+		if input.checked_add(1).ok_or(PANIC_ERROR_CODE)? != output
+		{
+			return Err(error("aggcounter", "InvalidCounterValue"));
+		}
+	}
+
+	Ok(())
+}
+
+fn model_aggdoublecounter(group: &Group) -> Verdict
+{
+	let (inputs, outputs) = (group.group_inputs(), group.group_outputs());
+	if inputs.is_empty()
+	{
+		return Ok(());
+	}
+	if inputs.len() != outputs.len()
+	{
+		return Err(error("aggdoublecounter", "InvalidTransactionStructure"));
+	}
+	for (input, output) in inputs.iter().zip(outputs.iter())
+	{
+		let (input_1, input_2) = (u64_at(&input.data, 0)?, u64_at(&input.data, 8)?);
+		let (output_1, output_2) = (u64_at(&output.data, 0)?, u64_at(&output.data, 8)?);
+
+		// The second value is only checked when the first value is valid.
+		if input_1.checked_add(1).ok_or(PANIC_ERROR_CODE)? != output_1 || input_2.checked_add(2).ok_or(PANIC_ERROR_CODE)? != output_2
+		{
+			return Err(error("aggdoublecounter", "InvalidCounterValue"));
+		}
+	}
+
+	Ok(())
+}
+
+fn model_odcounter(group: &Group) -> Verdict
+{
+	match (group.group_inputs().as_slice(), group.group_outputs().as_slice())
+	{
+		([_], []) => Ok(()),
+		([], [output]) =>
+		{
+			if output.data != [0; 8]
+			{
+				return Err(error("odcounter", "InvalidOutputCellData"));
+			}
+			Ok(())
+		},
+		([input], [output]) =>
+		{
+			if input.data.len() != 8
+			{
+				return Err(error("odcounter", "InvalidInputCellData"));
+			}
+			if output.data.len() != 8
+			{
+				return Err(error("odcounter", "InvalidOutputCellData"));
+			}
+			let (input, output) = (u64_at(&input.data, 0)?, u64_at(&output.data, 0)?);
+			if input == u64::MAX
+			{
+				return Err(error("odcounter", "CounterValueOverflow"));
+			}
+			if input + 1 != output
+			{
+				return Err(error("odcounter", "InvalidCounterValue"));
+			}
+			Ok(())
+		},
+		_ => Err(error("odcounter", "InvalidTransactionStructure")),
+	}
+}
+
+fn model_oddoublecounter(group: &Group) -> Verdict
+{
+	match (group.group_inputs().as_slice(), group.group_outputs().as_slice())
+	{
+		([_], []) => Ok(()),
+		([], [output]) =>
+		{
+			if output.data != [0; 16]
+			{
+				return Err(error("oddoublecounter", "InvalidOutputCellData"));
+			}
+			Ok(())
+		},
+		([input], [output]) =>
+		{
+			if input.data.len() != 16
+			{
+				return Err(error("oddoublecounter", "InvalidInputCellData"));
+			}
+			if output.data.len() != 16
+			{
+				return Err(error("oddoublecounter", "InvalidOutputCellData"));
+			}
+			let (input_1, input_2) = (u64_at(&input.data, 0)?, u64_at(&input.data, 8)?);
+			let (output_1, output_2) = (u64_at(&output.data, 0)?, u64_at(&output.data, 8)?);
+			if input_1 == u64::MAX || input_2 >= u64::MAX - 1
+			{
+				return Err(error("oddoublecounter", "CounterValueOverflow"));
+			}
+			if input_1 + 1 != output_1
+			{
+				return Err(error("oddoublecounter", "InvalidCounterValue1"));
+			}
+			if input_2 + 2 != output_2
+			{
+				return Err(error("oddoublecounter", "InvalidCounterValue2"));
+			}
+			Ok(())
+		},
+		_ => Err(error("oddoublecounter", "InvalidTransactionStructure")),
+	}
+}
+
+fn model_cellcount(group: &Group) -> Verdict
+{
+	if group.args.len() < 2
+	{
+		return Err(error("cellcount", "ArgsLen"));
+	}
+	let count = match group.args[0]
+	{
+		0 => group.case.inputs.len(),
+		1 => group.case.outputs.len(),
+		2 => group.inputs.len(),
+		3 => group.outputs.len(),
+		_ => return Err(error("cellcount", "InvalidSource")),
+	} as u64;
+	let operator = group.args[1];
+	if operator > 6
+	{
+		return Err(error("cellcount", "InvalidOperator"));
+	}
+	if group.args.len() != if operator == 6 { 18 } else { 10 }
+	{
+		return Err(error("cellcount", "ArgsLen"));
+	}
+	let operand = u64_at(group.args, 2)?;
+	let valid = match operator
+	{
+		0 => count == operand,
+		1 => count != operand,
+		2 => count < operand,
+		3 => count <= operand,
+		4 => count > operand,
+		5 => count >= operand,
+		_ => count >= operand && count <= u64_at(group.args, 10)?,
+	};
+	if !valid
+	{
+		return Err(error("cellcount", "Unauthorized"));
+	}
+
+	Ok(())
+}
+
+fn model_sudt(group: &Group) -> Verdict
+{
+	if group.args.len() != 32
+	{
+		return Err(error("sudt", "ArgsLength"));
+	}
+	if group.case.inputs.iter().any(|cell| group.lock_hash(cell)[..] == group.args[..])
+	{
+		return Ok(());
+	}
+	let amount = |cells: Vec<&CaseCell>| -> Result<u128, i8>
+	{
+		cells.iter().try_fold(0u128, |total, cell|
+		{
+			let amount = u128_at(&cell.data, 0).map_err(|_| error("sudt", "Encoding"))?;
+			total.checked_add(amount).ok_or(PANIC_ERROR_CODE)
+		})
+	};
+	let input_amount = amount(group.group_inputs())?;
+	let output_amount = amount(group.group_outputs())?;
+	if input_amount < output_amount
+	{
+		return Err(error("sudt", "Amount"));
+	}
+
+	Ok(())
+}
+
+fn model_occlock(group: &Group) -> Verdict
+{
+	if group.args.len() < 16
+	{
+		return Err(error("occlock", "ArgsLen"));
+	}
+	let amount = u64_at(group.args, 0)?;
+	let count = u64_at(group.args, 8)?;
+
+	// A count of zero still needs one matching output.
+	let found = group.case.outputs.iter().filter(|cell| cell.capacity == amount).count() as u64;
+	if found == 0 || found < count
+	{
+		return Err(error("occlock", "Unauthorized"));
+	}
+
+	Ok(())
+}
+
+fn model_icclock(group: &Group) -> Verdict
+{
+	if group.args.len() != 8
+	{
+		return Err(error("icclock", "ArgsLen"));
+	}
+	let amount = u64_at(group.args, 0)?;
+	if !group.case.inputs.iter().any(|cell| cell.capacity == amount)
+	{
+		return Err(error("icclock", "Unauthorized"));
+	}
+
+	Ok(())
+}
+
+fn model_hashlock(group: &Group) -> Verdict
+{
+	if group.args.len() < 32
+	{
+		return Err(error("hashlock", "ArgsLen"));
+	}
+
+	// The witness of the first group input is loaded into a fixed size buffer.
+	let witness = group.inputs.first()
+		.and_then(|i| group.case.witnesses.get(*i))
+		.filter(|witness| witness.len() <= WITNESS_SIZE_LIMIT)
+		.ok_or_else(|| error("hashlock", "WitnessLoadFail"))?;
+	if blake2b(witness)[..] != group.args[0..32]
+	{
+		return Err(error("hashlock", "Unauthorized"));
+	}
+
+	Ok(())
+}
+
+fn model_lockproxylock(group: &Group) -> Verdict
+{
+	if group.args.len() != 32
+	{
+		return Err(error("lockproxylock", "ArgsLen"));
+	}
+	if !group.case.inputs.iter().any(|cell| group.lock_hash(cell)[..] == group.args[..])
+	{
+		return Err(error("lockproxylock", "Unauthorized"));
+	}
+
+	Ok(())
+}
+
+fn model_typeproxylock(group: &Group) -> Verdict
+{
+	if group.args.len() != 32
+	{
+		return Err(error("typeproxylock", "ArgsLen"));
+	}
+	if !group.case.inputs.iter().any(|cell| group.type_hash(cell).is_some_and(|hash| hash[..] == group.args[..]))
+	{
+		return Err(error("typeproxylock", "Unauthorized"));
+	}
+
+	Ok(())
+}
+
+fn model_jsoncell(group: &Group) -> Verdict
+{
+	for cell in group.group_outputs()
+	{
+		let json = std::str::from_utf8(&cell.data).map_err(|_| error("jsoncell", "InvalidStringData"))?;
+		serde_json::from_str::<serde_json::Value>(json).map_err(|_| error("jsoncell", "InvalidJson"))?;
+	}
+
+	Ok(())
+}
+
+fn model_acplock(group: &Group) -> Verdict
+{
+	let args_len = group.args.len();
+	if args_len != 32 && args_len != 40 && args_len != 56
+	{
+		return Err(error("acplock", "ArgsLength"));
+	}
+	if group.case.inputs.iter().any(|cell| group.lock_hash(cell)[..] == group.args[0..32])
+	{
+		return Ok(());
+	}
+	let minimum_ckb = if args_len >= 40 { u64_at(group.args, 32)? } else { 0 };
+	let minimum_sudt = if args_len == 56 { u128_at(group.args, 40)? } else { 0 };
+	let amount = |cell: &CaseCell| u128_at(&cell.data, 0).map_err(|_| error("acplock", "Encoding"));
+
+	// Each input must be matched with exactly one output of the contract with the same type, which is not matched by
+	// another input.
+	let script_hash = group.script_hash();
+	let outputs: Vec<usize> = (0..group.case.outputs.len()).filter(|i| group.lock_hash(&group.case.outputs[*i]) == script_hash).collect();
+	let mut matched = Vec::new();
+	for input in group.group_inputs()
+	{
+		let type_hash = group.type_hash(input);
+		let mut found = outputs.iter().filter(|i| group.type_hash(&group.case.outputs[**i]) == type_hash);
+		let index = *found.next().ok_or_else(|| error("acplock", "OutputNotFound"))?;
+		if found.next().is_some() || matched.contains(&index)
+		{
+			return Err(error("acplock", "InvalidTransactionStructure"));
+		}
+		matched.push(index);
+		let output = &group.case.outputs[index];
+		if output.capacity < input.capacity.saturating_add(minimum_ckb)
+		{
+			return Err(error("acplock", "InsufficientCapacity"));
+		}
+		if type_hash.is_some() && amount(output)? < amount(input)?.saturating_add(minimum_sudt)
+		{
+			return Err(error("acplock", "InsufficientAmount"));
+		}
+	}
+
+	Ok(())
+}
+
+fn model_chequelock(group: &Group) -> Verdict
+{
+	if group.args.len() != 40
+	{
+		return Err(error("chequelock", "ArgsLen"));
+	}
+	let is_present = |prefix: &[u8]| group.case.inputs.iter().any(|cell| group.lock_hash(cell)[0..20] == *prefix);
+	if is_present(&group.args[0..20])
+	{
+		return Ok(());
+	}
+	if !is_present(&group.args[20..40])
+	{
+		return Err(error("chequelock", "Unauthorized"));
+	}
+
+	// The sender can only withdraw after the relative lock period.
+	for cell in group.group_inputs()
+	{
+		if cell.since & SINCE_FLAGS_MASK != SINCE_RELATIVE_EPOCH_FLAGS || cell.since & SINCE_EPOCH_NUMBER_MASK < CHEQUE_WITHDRAW_EPOCHS
+		{
+			return Err(error("chequelock", "InvalidSince"));
+		}
+	}
+
+	Ok(())
+}
+
+fn model_daowrapper(group: &Group) -> Verdict
+{
+	if group.args.len() != 65
+	{
+		return Err(error("daowrapper", "ArgsLen"));
+	}
+
+	// A wrapped DAO cell has the DAO type and the pool lock, with the hash of the receipt type as the lock args.
+	let script_hash = group.script_hash();
+	let is_wrapped = |cell: &CaseCell|
+	{
+		let lock = group.lock_script(cell);
+		group.type_hash(cell).is_some_and(|hash| hash[..] == group.args[0..32])
+			&& lock.code_hash().as_slice() == &group.args[32..64]
+			&& lock.hash_type().as_slice() == &group.args[64..65]
+			&& lock.args().raw_data()[..] == script_hash[..]
+	};
+
+	// There are no header deps, so a wrapped cell which is being withdrawn cannot find its deposit header.
+	let mut input_principal = 0u128;
+	let mut input_capacity = 0u128;
+	for cell in &group.case.inputs
+	{
+		if is_wrapped(cell)
+		{
+			input_principal += cell.capacity as u128;
+			if cell.data.len() != 8
+			{
+				return Err(error("daowrapper", "Encoding"));
+			}
+			if cell.data != [0; 8]
+			{
+				return Err(error("daowrapper", "MissingHeader"));
+			}
+		}
+		input_capacity += cell.capacity as u128;
+	}
+	let output_principal: u128 = group.case.outputs.iter().filter(|cell| is_wrapped(cell)).map(|cell| cell.capacity as u128).sum();
+	let output_capacity: u128 = group.case.outputs.iter().map(|cell| cell.capacity as u128).sum();
+	if output_capacity > input_capacity
+	{
+		return Err(error("daowrapper", "ExcessiveWithdrawal"));
+	}
+
+	let receipts = |cells: Vec<&CaseCell>| -> Result<u128, i8>
+	{
+		cells.iter().try_fold(0u128, |total, cell|
+		{
+			let amount = u128_at(&cell.data, 0).map_err(|_| error("daowrapper", "Encoding"))?;
+			total.checked_add(amount).ok_or_else(|| error("daowrapper", "InvalidReceiptAmount"))
+		})
+	};
+	let input_receipts = receipts(group.group_inputs())?;
+	let output_receipts = receipts(group.group_outputs())?;
+	if input_receipts.checked_add(output_principal) != output_receipts.checked_add(input_principal)
+	{
+		return Err(error("daowrapper", "InvalidReceiptAmount"));
+	}
+
+	Ok(())
+}
+
+fn model_escrowlock(group: &Group) -> Verdict
+{
+	if group.args.len() != 136
+	{
+		return Err(error("escrowlock", "ArgsLen"));
+	}
+	let timeout = u64_at(group.args, 128)?;
+	if !is_valid_since_threshold(timeout)
+	{
+		return Err(error("escrowlock", "InvalidTimeout"));
+	}
+
+	// Two of the buyer, seller, and arbiter can release the funds, and otherwise they return to the buyer after the
+	// timeout.
+	let is_present = |lock_hash: &[u8]| group.case.inputs.iter().any(|cell| group.lock_hash(cell)[..] == *lock_hash);
+	let approvals = [0, 32, 64].iter().filter(|start| is_present(&group.args[**start..**start + 32])).count();
+	let payout_lock_hash = match approvals >= 2
+	{
+		true => &group.args[96..128],
+		false =>
+		{
+			for cell in group.group_inputs()
+			{
+				if cell.since == 0
+				{
+					return Err(error("escrowlock", "Unauthorized"));
+				}
+				if cell.since & SINCE_FLAGS_MASK != timeout & SINCE_FLAGS_MASK || !is_since_reached(cell.since, timeout)
+				{
+					return Err(error("escrowlock", "InvalidSince"));
+				}
+			}
+			&group.args[0..32]
+		},
+	};
+
+	// Each escrow cell is paid out by the output at the same index.
+	for i in &group.inputs
+	{
+		match group.case.outputs.get(*i)
+		{
+			Some(output) if group.lock_hash(output)[..] == *payout_lock_hash && output.capacity >= group.case.inputs[*i].capacity => (),
+			_ => return Err(error("escrowlock", "InsufficientCapacity")),
+		}
+	}
+
+	Ok(())
+}
+
+fn model_nft(group: &Group) -> Verdict
+{
+	let is_class_args = |args: &[u8]| args.len() == 33 && args[32] == NFT_CLASS_MODE;
+	let is_class = is_class_args(group.args);
+	if !is_class && group.args.len() != 32 && group.args.len() != 64
+	{
+		return Err(error("nft", "ArgsLen"));
+	}
+	let class_data = |cell: &CaseCell| match cell.data.len()
+	{
+		16 => Ok((u64_at(&cell.data, 0)?, u64_at(&cell.data, 8)?)),
+		_ => Err(error("nft", "InvalidClassData")),
+	};
+
+	match (group.group_inputs().as_slice(), group.group_outputs().as_slice())
+	{
+		([_], []) => Ok(()),
+		([], [output]) =>
+		{
+			// The ID is derived from the first input and the index of the output.
+			if group.case.inputs.is_empty()
+			{
+				return Err(error("nft", "IndexOutOfBound"));
+			}
+			if unique_id(&group.cell_input(0), group.outputs[0])[..] != group.args[0..32]
+			{
+				return Err(error("nft", "InvalidNftId"));
+			}
+			if is_class
+			{
+				if class_data(output)?.1 != 0
+				{
+					return Err(error("nft", "InvalidClassData"));
+				}
+				return Ok(());
+			}
+			if group.args.len() == 32
+			{
+				return Ok(());
+			}
+
+			// An NFT of a class must be issued by a class cell of the contract which is updated in the transaction.
+			let class_type_hash = &group.args[32..64];
+			let is_class_cell = |cell: &&CaseCell| group.type_hash(cell).is_some_and(|hash| hash[..] == *class_type_hash);
+			let class = group.case.inputs.iter().find(is_class_cell).ok_or_else(|| error("nft", "ClassNotFound"))?;
+			if class.position != Some(Position::Type) || !is_class_args(group.contract_args(class))
+			{
+				return Err(error("nft", "ClassNotFound"));
+			}
+			if !group.case.outputs.iter().any(|cell| is_class_cell(&cell))
+			{
+				return Err(error("nft", "ClassNotFound"));
+			}
+			Ok(())
+		},
+		([input], [output]) if is_class =>
+		{
+			let (input_max_supply, input_issued) = class_data(input)?;
+			let (output_max_supply, output_issued) = class_data(output)?;
+
+			// NFTs of the class which are in the outputs but not the inputs are being created.
+			let script_hash = group.script_hash();
+			let input_type_hashes: Vec<Option<[u8; 32]>> = group.case.inputs.iter().map(|cell| group.type_hash(cell)).collect();
+			let created = group.case.outputs.iter()
+				.filter(|cell| cell.position == Some(Position::Type))
+				.filter(|cell| group.contract_args(cell).len() == 64 && group.contract_args(cell)[32..64] == script_hash[..])
+				.filter(|cell| !input_type_hashes.contains(&group.type_hash(cell)))
+				.count() as u64;
+			if output_max_supply != input_max_supply || Some(output_issued) != input_issued.checked_add(created)
+			{
+				return Err(error("nft", "InvalidClassData"));
+			}
+			if output_issued > output_max_supply
+			{
+				return Err(error("nft", "ClassLimitExceeded"));
+			}
+			Ok(())
+		},
+		([input], [output]) =>
+		{
+			if input.data != output.data
+			{
+				return Err(error("nft", "ContentChanged"));
+			}
+			Ok(())
+		},
+		_ => Err(error("nft", "InvalidTransactionStructure")),
+	}
+}
+
+fn model_oracle(group: &Group) -> Verdict
+{
+	if group.args.len() != 32
+	{
+		return Err(error("oracle", "ArgsLen"));
+	}
+	if !group.case.inputs.iter().any(|cell| group.lock_hash(cell)[..] == group.args[..])
+	{
+		return Err(error("oracle", "Unauthorized"));
+	}
+
+	// The record is the value, the timestamp, and the sequence.
+	let record = |cell: &CaseCell| match cell.data.len()
+	{
+		24 => Ok((u64_at(&cell.data, 8)?, u64_at(&cell.data, 16)?)),
+		_ => Err(error("oracle", "InvalidCellData")),
+	};
+	match (group.group_inputs().as_slice(), group.group_outputs().as_slice())
+	{
+		([_], []) => Ok(()),
+		([], [output]) => record(output).map(|_| ()),
+		([input], [output]) =>
+		{
+			let (input_timestamp, input_sequence) = record(input)?;
+			let (output_timestamp, output_sequence) = record(output)?;
+			if output_sequence <= input_sequence
+			{
+				return Err(error("oracle", "StaleSequence"));
+			}
+			if output_timestamp < input_timestamp
+			{
+				return Err(error("oracle", "StaleTimestamp"));
+			}
+			Ok(())
+		},
+		_ => Err(error("oracle", "InvalidTransactionStructure")),
+	}
+}
+
+fn model_orderlock(group: &Group) -> Verdict
+{
+	if group.args.len() != 56
+	{
+		return Err(error("orderlock", "ArgsLen"));
+	}
+	let maker_lock_hash = &group.args[0..32];
+	if group.case.inputs.iter().any(|cell| group.lock_hash(cell)[..] == *maker_lock_hash)
+	{
+		return Ok(());
+	}
+	let rate_ckb = u64_at(group.args, 32)? as u128;
+	let rate_sudt = u128_at(group.args, 40)?;
+	if rate_sudt == 0
+	{
+		return Err(error("orderlock", "InvalidExchangeRate"));
+	}
+	let script_hash = group.script_hash();
+	let residuals: Vec<&CaseCell> = group.case.outputs.iter().filter(|cell| group.lock_hash(cell) == script_hash).collect();
+	if group.inputs.len() != 1 || residuals.len() > 1
+	{
+		return Err(error("orderlock", "InvalidTransactionStructure"));
+	}
+	let order = group.group_inputs()[0];
+	let order_type_hash = group.type_hash(order);
+	if order_type_hash.is_none()
+	{
+		return Err(error("orderlock", "InvalidOrderCell"));
+	}
+	let amount = |cell: &CaseCell| u128_at(&cell.data, 0).map_err(|_| error("orderlock", "Encoding"));
+	let order_amount = amount(order)?;
+
+	// A partial fill leaves a residual order with the same capacity, and the maker is only paid for the amount sold.
+	let (sold_amount, returned_capacity) = match residuals.first()
+	{
+		Some(residual) =>
+		{
+			if group.type_hash(residual) != order_type_hash || residual.capacity < order.capacity
+			{
+				return Err(error("orderlock", "InvalidResidual"));
+			}
+			let residual_amount = amount(residual)?;
+			if residual_amount >= order_amount
+			{
+				return Err(error("orderlock", "InvalidResidual"));
+			}
+			(order_amount - residual_amount, 0)
+		},
+		None => (order_amount, order.capacity),
+	};
+	let value = sold_amount.checked_mul(rate_ckb).ok_or_else(|| error("orderlock", "InsufficientPayment"))?;
+	let payment = value / rate_sudt + u128::from(value % rate_sudt != 0);
+	let required_capacity = payment.checked_add(returned_capacity as u128).ok_or_else(|| error("orderlock", "InsufficientPayment"))?;
+
+	// The payment is the output at the same index as the order.
+	match group.case.outputs.get(group.inputs[0])
+	{
+		Some(payment) if group.lock_hash(payment)[..] == *maker_lock_hash && payment.capacity as u128 >= required_capacity => Ok(()),
+		_ => Err(error("orderlock", "InsufficientPayment")),
+	}
+}
+
+fn model_oraclelock(group: &Group) -> Verdict
+{
+	if group.args.len() != 80
+	{
+		return Err(error("oraclelock", "ArgsLen"));
+	}
+	if !group.case.inputs.iter().any(|cell| group.lock_hash(cell)[..] == group.args[0..32])
+	{
+		return Err(error("oraclelock", "Unauthorized"));
+	}
+	let (minimum_sequence, threshold) = (u64_at(group.args, 64)?, u64_at(group.args, 72)?);
+
+	// Exactly one cell dep must have the oracle type, and hold a record which is recent enough and meets the threshold.
+	let oracles: Vec<&CaseCell> = group.case.deps.iter().filter(|cell| group.type_hash(cell).is_some_and(|type_hash| type_hash[..] == group.args[32..64])).collect();
+	let oracle = match oracles.as_slice()
+	{
+		[] => return Err(error("oraclelock", "OracleNotFound")),
+		[oracle] => *oracle,
+		_ => return Err(error("oraclelock", "MultipleOracles")),
+	};
+	if oracle.data.len() != 24
+	{
+		return Err(error("oraclelock", "InvalidOracleData"));
+	}
+	if u64_at(&oracle.data, 16)? < minimum_sequence
+	{
+		return Err(error("oraclelock", "StaleRecord"));
+	}
+	if u64_at(&oracle.data, 0)? < threshold
+	{
+		return Err(error("oraclelock", "ThresholdNotMet"));
+	}
+
+	Ok(())
+}
+
+fn model_ratelimitlock(group: &Group) -> Verdict
+{
+	if group.args.len() != 40
+	{
+		return Err(error("ratelimitlock", "ArgsLen"));
+	}
+	if !group.case.inputs.iter().any(|cell| group.lock_hash(cell)[..] == group.args[0..32])
+	{
+		return Err(error("ratelimitlock", "Unauthorized"));
+	}
+	let limit = u64_at(group.args, 32)?;
+	let script_hash = group.script_hash();
+	let outputs: Vec<&CaseCell> = group.case.outputs.iter().filter(|cell| group.lock_hash(cell) == script_hash).collect();
+	let output = match (group.inputs.len(), outputs.as_slice())
+	{
+		(1, []) => None,
+		(1, [output]) => Some(*output),
+		_ => return Err(error("ratelimitlock", "InvalidTransactionStructure")),
+	};
+	let input = group.group_inputs()[0];
+	let parse = |data: &[u8]| -> Option<(u64, u64)>
+	{
+		match data.len()
+		{
+			16 => Some((u64_at(data, 0).ok()?, u64_at(data, 8).ok()?)),
+			_ => None,
+		}
+	};
+
+	// Malformed input data starts from the first window, and a since of at least one epoch starts a new window.
+	let (input_window, input_withdrawn) = parse(&input.data).unwrap_or((0, 0));
+	let (window, withdrawn) = if input.since == 0
+	{
+		(input_window, input_withdrawn)
+	}
+	else if input.since & SINCE_FLAGS_MASK == SINCE_RELATIVE_EPOCH_FLAGS && input.since & SINCE_EPOCH_NUMBER_MASK >= 1
+	{
+		(input_window.checked_add(1).ok_or_else(|| error("ratelimitlock", "InvalidInputCellData"))?, 0)
+	}
+	else
+	{
+		return Err(error("ratelimitlock", "InvalidSince"));
+	};
+	let amount = match output
+	{
+		Some(output) => input.capacity.saturating_sub(output.capacity),
+		None => input.capacity,
+	};
+	let total = withdrawn.checked_add(amount).filter(|total| *total <= limit).ok_or_else(|| error("ratelimitlock", "LimitExceeded"))?;
+	if let Some(output) = output
+	{
+		if parse(&output.data) != Some((window, total))
+		{
+			return Err(error("ratelimitlock", "InvalidOutputCellData"));
+		}
+	}
+
+	Ok(())
+}
+
+fn model_timelock(group: &Group) -> Verdict
+{
+	if group.args.len() != 40
+	{
+		return Err(error("timelock", "ArgsLen"));
+	}
+	let threshold = u64_at(group.args, 32)?;
+	if !is_valid_since_threshold(threshold)
+	{
+		return Err(error("timelock", "InvalidThreshold"));
+	}
+	if !group.case.inputs.iter().any(|cell| group.lock_hash(cell)[..] == group.args[0..32])
+	{
+		return Err(error("timelock", "Unauthorized"));
+	}
+	for cell in group.group_inputs()
+	{
+		if cell.since & SINCE_FLAGS_MASK != threshold & SINCE_FLAGS_MASK || !is_since_reached(cell.since, threshold)
+		{
+			return Err(error("timelock", "InvalidSince"));
+		}
+	}
+
+	Ok(())
+}
+
+fn model_typeid(group: &Group) -> Verdict
+{
+	if group.args.len() != 32
+	{
+		return Err(error("typeid", "ArgsLen"));
+	}
+	match (group.inputs.len(), group.outputs.len())
+	{
+		(1, 0) | (1, 1) => Ok(()),
+		(0, 1) =>
+		{
+			// The Type ID is derived from the first input and the index of the output.
+			if group.case.inputs.is_empty()
+			{
+				return Err(error("typeid", "IndexOutOfBound"));
+			}
+			if unique_id(&group.cell_input(0), group.outputs[0])[..] != *group.args
+			{
+				return Err(error("typeid", "InvalidTypeId"));
+			}
+			Ok(())
+		},
+		_ => Err(error("typeid", "InvalidTransactionStructure")),
+	}
+}
+
+fn model_vestinglock(group: &Group) -> Verdict
+{
+	if group.args.len() != 72
+	{
+		return Err(error("vestinglock", "ArgsLen"));
+	}
+	let start_epoch = u64_at(group.args, 32)?;
+	let cliff = u64_at(group.args, 40)?;
+	let duration = u64_at(group.args, 48)?;
+	let total_amount = u128_at(group.args, 56)?;
+	if group.inputs.len() != 1
+	{
+		return Err(error("vestinglock", "InvalidTransactionStructure"));
+	}
+	if !group.case.inputs.iter().any(|cell| group.lock_hash(cell)[..] == group.args[0..32])
+	{
+		return Err(error("vestinglock", "Unauthorized"));
+	}
+
+	// There are no header deps, so the current epoch can only come from an absolute epoch since.
+	let input = group.group_inputs()[0];
+	if input.since & SINCE_FLAGS_MASK != SINCE_ABSOLUTE_EPOCH_FLAGS
+	{
+		return Err(error("vestinglock", "MissingEpoch"));
+	}
+	let epoch = input.since & SINCE_EPOCH_NUMBER_MASK;
+	let vested_amount = if epoch < start_epoch.saturating_add(cliff)
+	{
+		0
+	}
+	else if epoch - start_epoch >= duration
+	{
+		total_amount
+	}
+	else
+	{
+		total_amount.checked_mul((epoch - start_epoch) as u128).ok_or_else(|| error("vestinglock", "AmountOverflow"))? / duration as u128
+	};
+	let locked_amount = total_amount - vested_amount;
+
+	// The amount is the capacity of a cell without a type, and the SUDT amount of a cell with a type.
+	let amount = |cell: &CaseCell| match group.type_hash(cell)
+	{
+		None => Ok(cell.capacity as u128),
+		Some(_) => u128_at(&cell.data, 0).map_err(|_| error("vestinglock", "Encoding")),
+	};
+	let script_hash = group.script_hash();
+	let type_hash = group.type_hash(input);
+	let mut remaining_amount = 0u128;
+	for cell in group.case.outputs.iter().filter(|cell| group.lock_hash(cell) == script_hash && group.type_hash(cell) == type_hash)
+	{
+		remaining_amount = remaining_amount.checked_add(amount(cell)?).ok_or_else(|| error("vestinglock", "AmountOverflow"))?;
+	}
+	if remaining_amount < locked_amount.min(amount(input)?)
+	{
+		return Err(error("vestinglock", "InsufficientRemainder"));
+	}
+
+	Ok(())
+}
+
+#[test]
+fn test_models_always()
+{
+	Harness::new("always").check(SEED, CASES, |rng| generate(rng, |rng| random_bytes(rng, 2), 3, &TYPE_POSITIONS, |rng| random_bytes(rng, 2)), model_always);
+}
+
+#[test]
+fn test_models_never()
+{
+	Harness::new("never").check(SEED, CASES, |rng| generate(rng, |rng| random_bytes(rng, 2), 3, &LOCK_POSITIONS, |rng| random_bytes(rng, 2)), model_never);
+}
+
+#[test]
+fn test_models_ckb500()
+{
+	Harness::new("ckb500").check(SEED, CASES, |rng| generate(rng, |_| Vec::new(), 3, &LOCK_POSITIONS, |rng| random_bytes(rng, 2)), model_ckb500);
+}
+
+fn generate_data10(rng: &mut Rng) -> Case
+{
+	let args = |rng: &mut Rng| match rng.below(3)
+	{
+		0 => Vec::new(),
+		_ =>
+		{
+			let valid = (rng.below(16) as u32).to_le_bytes().to_vec();
+			random_args(rng, valid)
+		},
+	};
+
+	generate(rng, args, 3, &TYPE_POSITIONS, |rng| random_bytes(rng, 16))
+}
+
+#[test]
+fn test_models_data10()
+{
+	Harness::new("data10").check(SEED, CASES, generate_data10, model_data10);
+}
+
+fn generate_datacap(rng: &mut Rng) -> Case
+{
+	let args = |rng: &mut Rng|
+	{
+		let valid = (rng.below(16) as u32).to_le_bytes().to_vec();
+		random_args(rng, valid)
+	};
+
+	generate(rng, args, 3, &TYPE_POSITIONS, |rng| random_bytes(rng, 16))
+}
+
+#[test]
+fn test_models_datacap()
+{
+	Harness::new("datacap").check(SEED, CASES, generate_datacap, model_datacap);
+}
+
+#[test]
+fn test_models_datarange()
+{
+	let args = |rng: &mut Rng|
+	{
+		let valid = [(rng.below(8) as u32).to_le_bytes(), (rng.below(16) as u32).to_le_bytes()].concat();
+		random_args(rng, valid)
+	};
+	Harness::new("datarange").check(SEED, CASES, |rng| generate(rng, args, 3, &TYPE_POSITIONS, |rng| random_bytes(rng, 16)), model_datarange);
+}
+
+#[test]
+fn test_models_dataratio()
+{
+	let args = |rng: &mut Rng|
+	{
+		let mut valid = rng.choose(&[0u32, 1, 10, u32::MAX]).to_le_bytes().to_vec();
+		if !rng.one_in(3)
+		{
+			valid.push(rng.below(3) as u8);
+		}
+		random_args(rng, valid)
+	};
+	Harness::new("dataratio").check(SEED, CASES, |rng| generate(rng, args, 3, &TYPE_POSITIONS, |rng| random_bytes(rng, 1_000)), model_dataratio);
+}
+
+#[test]
+fn test_models_counter()
+{
+	Harness::new("counter").check(SEED, CASES, |rng| generate(rng, |_| Vec::new(), 2, &TYPE_POSITIONS, |rng| counter_data(rng, 1)), model_counter);
+}
+
+#[test]
+fn test_models_doublecounter()
+{
+	Harness::new("doublecounter").check(SEED, CASES, |rng| generate(rng, |_| Vec::new(), 2, &TYPE_POSITIONS, |rng| counter_data(rng, 2)), model_doublecounter);
+}
+
+#[test]
+fn test_models_aggcounter()
+{
+	Harness::new("aggcounter").check(SEED, CASES, |rng| generate(rng, |_| Vec::new(), 3, &TYPE_POSITIONS, |rng| counter_data(rng, 1)), model_aggcounter);
+}
+
+#[test]
+fn test_models_aggdoublecounter()
+{
+	Harness::new("aggdoublecounter").check(SEED, CASES, |rng| generate(rng, |_| Vec::new(), 3, &TYPE_POSITIONS, |rng| counter_data(rng, 2)), model_aggdoublecounter);
+}
+
+#[test]
+fn test_models_odcounter()
+{
+	Harness::new("odcounter").check(SEED, CASES, |rng| generate(rng, |_| Vec::new(), 2, &TYPE_POSITIONS, |rng| counter_data(rng, 1)), model_odcounter);
+}
+
+#[test]
+fn test_models_oddoublecounter()
+{
+	Harness::new("oddoublecounter").check(SEED, CASES, |rng| generate(rng, |_| Vec::new(), 2, &TYPE_POSITIONS, |rng| counter_data(rng, 2)), model_oddoublecounter);
+}
+
+#[test]
+fn test_models_cellcount()
+{
+	let args = |rng: &mut Rng|
+	{
+		let operator = rng.below(8) as u8;
+		let mut valid = vec![rng.below(5) as u8, operator];
+		valid.extend_from_slice(&rng.below(4).to_le_bytes());
+		if operator == 6
+		{
+			valid.extend_from_slice(&rng.below(4).to_le_bytes());
+		}
+		random_args(rng, valid)
+	};
+	Harness::new("cellcount").check(SEED, CASES, |rng| generate(rng, args, 3, &TYPE_POSITIONS, |rng| random_bytes(rng, 2)), model_cellcount);
+}
+
+#[test]
+fn test_models_sudt()
+{
+	let harness = Harness::new("sudt");
+	let args = |rng: &mut Rng|
+	{
+		let valid = harness.owner_lock_hash(rng.below(4) as u8).to_vec();
+		random_args(rng, valid)
+	};
+	harness.check(SEED, CASES, |rng| generate(rng, args, 3, &TYPE_POSITIONS, sudt_data), model_sudt);
+}
+
+#[test]
+fn test_models_occlock()
+{
+	let args = |rng: &mut Rng|
+	{
+		let valid = [rng.choose(&CAPACITIES).to_le_bytes(), rng.below(3).to_le_bytes()].concat();
+		random_args(rng, valid)
+	};
+	Harness::new("occlock").check(SEED, CASES, |rng| generate(rng, args, 3, &LOCK_POSITIONS, |rng| random_bytes(rng, 2)), model_occlock);
+}
+
+#[test]
+fn test_models_icclock()
+{
+	let args = |rng: &mut Rng|
+	{
+		let valid = rng.choose(&CAPACITIES).to_le_bytes().to_vec();
+		random_args(rng, valid)
+	};
+	Harness::new("icclock").check(SEED, CASES, |rng| generate(rng, args, 3, &LOCK_POSITIONS, |rng| random_bytes(rng, 2)), model_icclock);
+}
+
+#[test]
+fn test_models_hashlock()
+{
+	let witness = |rng: &mut Rng| match rng.below(5)
+	{
+		0 => Vec::new(),
+		1 => vec![1],
+		2 => vec![1, 2, 3],
+		3 => vec![7; WITNESS_SIZE_LIMIT],
+		_ => vec![7; WITNESS_SIZE_LIMIT + 1],
+	};
+	let generate_case = |rng: &mut Rng|
+	{
+		let mut valid = blake2b(&witness(rng)).to_vec();
+		if rng.one_in(4)
+		{
+			valid.push(0);
+		}
+		let args = random_args(rng, valid);
+		let mut case = generate(rng, |_| args.clone(), 3, &LOCK_POSITIONS, |rng| random_bytes(rng, 2));
+		let count = rng.below(4);
+		case.witnesses = (0..count).map(|_| witness(rng)).collect();
+		case
+	};
+	Harness::new("hashlock").check(SEED, CASES, generate_case, model_hashlock);
+}
+
+#[test]
+fn test_models_lockproxylock()
+{
+	let harness = Harness::new("lockproxylock");
+	let args = |rng: &mut Rng|
+	{
+		let valid = harness.owner_lock_hash(rng.below(4) as u8).to_vec();
+		random_args(rng, valid)
+	};
+	harness.check(SEED, CASES, |rng| generate(rng, args, 3, &LOCK_POSITIONS, |rng| random_bytes(rng, 2)), model_lockproxylock);
+}
+
+#[test]
+fn test_models_typeproxylock()
+{
+	let harness = Harness::new("typeproxylock");
+	let args = |rng: &mut Rng|
+	{
+		let valid = harness.tag_type_hash(rng.below(4) as u8).to_vec();
+		random_args(rng, valid)
+	};
+	harness.check(SEED, CASES, |rng| generate(rng, args, 3, &LOCK_POSITIONS, |rng| random_bytes(rng, 2)), model_typeproxylock);
+}
+
+#[test]
+fn test_models_jsoncell()
+{
+	const DOCUMENTS: [&str; 16] = ["{}", "[]", "{\"a\":1}", "[1,2,3]", "\"text\"", "1", "true", "null", " { \"a\" : [ true , false , null ] } ", "{\"a\":{\"b\":\"c\"}}", "", "{", "{\"a\":}", "[1,]", "tru", "{'a':1}"];
+	let data = |rng: &mut Rng| match rng.one_in(5)
+	{
+		true => random_bytes(rng, 4),
+		false => rng.choose(&DOCUMENTS).as_bytes().to_vec(),
+	};
+	Harness::new("jsoncell").check(SEED, CASES, |rng| generate(rng, |_| Vec::new(), 3, &TYPE_POSITIONS, data), model_jsoncell);
+}
+
+#[test]
+fn test_models_acplock()
+{
+	let harness = Harness::new("acplock");
+	let args = |rng: &mut Rng|
+	{
+		let mut valid = harness.owner_lock_hash(rng.below(4) as u8).to_vec();
+		if !rng.one_in(3)
+		{
+			valid.extend_from_slice(&rng.choose(&[0, 1, 61 * CKB]).to_le_bytes());
+			if rng.one_in(2)
+			{
+				valid.extend_from_slice(&rng.choose(&SUDT_AMOUNTS).to_le_bytes());
+			}
+		}
+		random_args(rng, valid)
+	};
+	harness.check(SEED, CASES, |rng| generate(rng, args, 3, &LOCK_POSITIONS, sudt_data), model_acplock);
+}
+
+#[test]
+fn test_models_chequelock()
+{
+	let harness = Harness::new("chequelock");
+	let generate_case = |rng: &mut Rng|
+	{
+		let valid = [&harness.owner_lock_hash(rng.below(4) as u8)[0..20], &harness.owner_lock_hash(rng.below(4) as u8)[0..20]].concat();
+		let args = random_args(rng, valid);
+		let mut case = generate(rng, |_| args.clone(), 3, &LOCK_POSITIONS, |rng| random_bytes(rng, 2));
+		for cell in &mut case.inputs
+		{
+			cell.since = random_since(rng, SINCE_RELATIVE_EPOCH_FLAGS | CHEQUE_WITHDRAW_EPOCHS);
+		}
+		case
+	};
+	harness.check(SEED, CASES, generate_case, model_chequelock);
+}
+
+#[test]
+fn test_models_daowrapper()
+{
+	let harness = Harness::new("daowrapper");
+	let dao_type_hash = harness.tag_type_hash(1);
+	let pool_lock = harness.always_success();
+	let args = |rng: &mut Rng|
+	{
+		let hash_type = match rng.one_in(4)
+		{
+			true => vec![rng.below(3) as u8],
+			false => pool_lock.hash_type().as_slice().to_vec(),
+		};
+		let valid = [&dao_type_hash[..], pool_lock.code_hash().as_slice(), &hash_type].concat();
+		random_args(rng, valid)
+	};
+	let generate_case = |rng: &mut Rng|
+	{
+		// Receipts hold amounts which can match the capacities of the DAO cells held by the pool lock.
+		let mut case = generate(rng, args, 3, &[Some(Position::Type), Some(Position::Type), Some(Position::Proxy), None], |_| Vec::new());
+		for cell in case.inputs.iter_mut().chain(case.outputs.iter_mut())
+		{
+			match cell.position
+			{
+				Some(Position::Type) => cell.data = match rng.one_in(5)
+				{
+					true => random_bytes(rng, 17),
+					false => (rng.choose(&CAPACITIES) as u128).to_le_bytes().to_vec(),
+				},
+				Some(Position::Proxy) =>
+				{
+					if !rng.one_in(4)
+					{
+						cell.tag = Some(1);
+					}
+					cell.data = match rng.below(5)
+					{
+						0 => random_bytes(rng, 9),
+						1 => 1u64.to_le_bytes().to_vec(),
+						_ => vec![0; 8],
+					};
+				},
+				_ => (),
+			}
+		}
+		case
+	};
+	harness.check(SEED, CASES, generate_case, model_daowrapper);
+}
+
+#[test]
+fn test_models_escrowlock()
+{
+	let harness = Harness::new("escrowlock");
+	let args = |rng: &mut Rng|
+	{
+		let mut valid: Vec<u8> = (0..4).flat_map(|_| harness.owner_lock_hash(rng.below(4) as u8)).collect();
+		valid.extend_from_slice(&rng.choose(&SINCE_THRESHOLDS).to_le_bytes());
+		random_args(rng, valid)
+	};
+	let generate_case = |rng: &mut Rng|
+	{
+		let mut case = generate(rng, args, 3, &LOCK_POSITIONS, |rng| random_bytes(rng, 2));
+		let timeout = u64_at(&case.args, 128).unwrap_or(0);
+		for cell in &mut case.inputs
+		{
+			cell.since = random_since(rng, timeout);
+		}
+		split_groups(rng, &mut case, Position::Lock, args);
+		case
+	};
+	harness.check(SEED, CASES, generate_case, model_escrowlock);
+}
+
+#[test]
+fn test_models_nft()
+{
+	let harness = Harness::new("nft");
+	let class_data = |rng: &mut Rng| match rng.one_in(6)
+	{
+		true => random_bytes(rng, 17),
+		false => [rng.choose(&[0, 1, 2, u64::MAX]).to_le_bytes(), rng.below(3).to_le_bytes()].concat(),
+	};
+	let generate_case = |rng: &mut Rng|
+	{
+		// Each cell of the contract is either a class cell with the args of the case, or an NFT with its own args.
+		let mut case = generate(rng, |_| Vec::new(), 3, &TYPE_POSITIONS, |_| Vec::new());
+		let ids: Vec<[u8; 32]> = (0..3).map(|index| match case.inputs.first()
+		{
+			Some(_) => unique_id(&harness.cell_input(&case, 0), index),
+			None => [0; 32],
+		}).collect();
+		for cell in case.inputs.iter_mut().chain(case.outputs.iter_mut()).filter(|cell| cell.position == Some(Position::Type))
+		{
+			match rng.one_in(3)
+			{
+				true => cell.data = class_data(rng),
+				false =>
+				{
+					cell.args = Some(Vec::new());
+					cell.data = vec![1; rng.below(3) as usize];
+				},
+			}
+		}
+
+		// The class ID is usually valid for the first class cell in the outputs.
+		let class_index = case.outputs.iter().position(|cell| cell.position == Some(Position::Type) && cell.args.is_none()).unwrap_or(0);
+		case.args = random_args(rng, [&ids[class_index.min(2)][..], &[NFT_CLASS_MODE]].concat());
+		let class_type_hash = harness.script_hash(&case.args);
+
+		// NFTs in the outputs usually have a valid ID, and NFTs in the inputs can have the ID of an output.
+		for (outputs, cells) in [(false, &mut case.inputs), (true, &mut case.outputs)]
+		{
+			for (i, cell) in cells.iter_mut().enumerate().filter(|(_, cell)| cell.args.is_some())
+			{
+				let id = match outputs && i < ids.len() && !rng.one_in(6)
+				{
+					true => ids[i],
+					false => ids[rng.below(3) as usize],
+				};
+				let valid = match rng.one_in(4)
+				{
+					true => id.to_vec(),
+					false => [id, class_type_hash].concat(),
+				};
+				cell.args = Some(random_args(rng, valid));
+			}
+		}
+		case
+	};
+	harness.check(SEED, CASES, generate_case, model_nft);
+}
+
+#[test]
+fn test_models_oracle()
+{
+	let harness = Harness::new("oracle");
+	let args = |rng: &mut Rng|
+	{
+		let valid = harness.owner_lock_hash(rng.below(4) as u8).to_vec();
+		random_args(rng, valid)
+	};
+	let data = |rng: &mut Rng| match rng.one_in(5)
+	{
+		true => random_bytes(rng, 25),
+		false => [rng.next_u64(), rng.below(3), rng.choose(&[0, 1, 2, u64::MAX])].iter().flat_map(|value| value.to_le_bytes()).collect(),
+	};
+	harness.check(SEED, CASES, |rng| generate(rng, args, 2, &TYPE_POSITIONS, data), model_oracle);
+}
+
+#[test]
+fn test_models_oraclelock()
+{
+	let harness = Harness::new("oraclelock");
+	let args = |rng: &mut Rng|
+	{
+		let valid = [&harness.owner_lock_hash(rng.below(3) as u8)[..], &harness.tag_type_hash(rng.below(3) as u8), &rng.choose(&[0, 5, u64::MAX]).to_le_bytes(), &rng.choose(&[0, 100, u64::MAX]).to_le_bytes()].concat();
+		random_args(rng, valid)
+	};
+	let data = |rng: &mut Rng| match rng.one_in(5)
+	{
+		true => random_bytes(rng, 25),
+		false => [rng.choose(&[0, 99, 100, u64::MAX]), rng.next_u64(), rng.choose(&[0, 4, 5, u64::MAX])].iter().flat_map(|value| value.to_le_bytes()).collect(),
+	};
+	let generate_case = |rng: &mut Rng|
+	{
+		// The owner often has a cell in the inputs, and the oracle records are cell deps, which usually have one of the
+		// types the args can reference.
+		let mut case = generate(rng, args, 2, &LOCK_POSITIONS, |rng| random_bytes(rng, 2));
+		for owner in 0..3
+		{
+			if rng.one_in(2)
+			{
+				case.inputs.push(CaseCell { owner, ..random_cell(rng, None, Vec::new()) });
+			}
+		}
+		let count = rng.below(4);
+		case.deps = (0..count).map(|_|
+		{
+			let data = data(rng);
+			let mut cell = random_cell(rng, None, data);
+			if !rng.one_in(4)
+			{
+				cell.tag = Some(rng.below(3) as u8);
+			}
+			cell
+		}).collect();
+		case
+	};
+	harness.check(SEED, CASES, generate_case, model_oraclelock);
+}
+
+#[test]
+fn test_models_orderlock()
+{
+	let harness = Harness::new("orderlock");
+	let args = |rng: &mut Rng|
+	{
+		let valid = [&harness.owner_lock_hash(rng.below(4) as u8)[..], &rng.choose(&[0, 1, 2, CKB]).to_le_bytes(), &rng.choose(&[0, 1, 3, u128::MAX]).to_le_bytes()].concat();
+		random_args(rng, valid)
+	};
+	let generate_case = |rng: &mut Rng|
+	{
+		// Orders and residuals usually hold the same token.
+		let mut case = generate(rng, args, 3, &LOCK_POSITIONS, sudt_data);
+		for cell in case.inputs.iter_mut().chain(case.outputs.iter_mut()).filter(|cell| cell.position.is_some())
+		{
+			if !rng.one_in(3)
+			{
+				cell.tag = Some(1);
+			}
+		}
+		split_groups(rng, &mut case, Position::Lock, args);
+		case
+	};
+	harness.check(SEED, CASES, generate_case, model_orderlock);
+}
+
+#[test]
+fn test_models_ratelimitlock()
+{
+	let harness = Harness::new("ratelimitlock");
+	let args = |rng: &mut Rng|
+	{
+		let valid = [&harness.owner_lock_hash(rng.below(4) as u8)[..], &rng.choose(&[0, 61 * CKB, 250 * CKB, u64::MAX]).to_le_bytes()].concat();
+		random_args(rng, valid)
+	};
+	let data = |rng: &mut Rng| match rng.one_in(5)
+	{
+		true => random_bytes(rng, 17),
+		false => [rng.choose(&[0, 1, u64::MAX]).to_le_bytes(), rng.choose(&[0, 189 * CKB, 250 * CKB, 439 * CKB]).to_le_bytes()].concat(),
+	};
+	let generate_case = |rng: &mut Rng|
+	{
+		let mut case = generate(rng, args, 2, &LOCK_POSITIONS, data);
+		for cell in &mut case.inputs
+		{
+			cell.since = rng.choose(&[0, 0, SINCE_RELATIVE_EPOCH_FLAGS | 1, SINCE_RELATIVE_EPOCH_FLAGS, SINCE_ABSOLUTE_EPOCH_FLAGS | 1]);
+		}
+		case
+	};
+	harness.check(SEED, CASES, generate_case, model_ratelimitlock);
+}
+
+#[test]
+fn test_models_timelock()
+{
+	let harness = Harness::new("timelock");
+	let args = |rng: &mut Rng|
+	{
+		let valid = [harness.owner_lock_hash(rng.below(4) as u8).to_vec(), rng.choose(&SINCE_THRESHOLDS).to_le_bytes().to_vec()].concat();
+		random_args(rng, valid)
+	};
+	let generate_case = |rng: &mut Rng|
+	{
+		let mut case = generate(rng, args, 3, &LOCK_POSITIONS, |rng| random_bytes(rng, 2));
+		let threshold = u64_at(&case.args, 32).unwrap_or(0);
+		for cell in &mut case.inputs
+		{
+			cell.since = random_since(rng, threshold);
+		}
+		case
+	};
+	harness.check(SEED, CASES, generate_case, model_timelock);
+}
+
+#[test]
+fn test_models_typeid()
+{
+	let harness = Harness::new("typeid");
+	let generate_case = |rng: &mut Rng|
+	{
+		// The Type ID is usually valid for the first output of the contract, and sometimes for another output.
+		let mut case = generate(rng, |_| Vec::new(), 2, &TYPE_POSITIONS, |rng| random_bytes(rng, 2));
+		let index = match rng.one_in(4)
+		{
+			true => rng.below(3) as usize,
+			false => case.outputs.iter().position(CaseCell::runs_contract).unwrap_or(0),
+		};
+		let valid = match case.inputs.first()
+		{
+			Some(_) => unique_id(&harness.cell_input(&case, 0), index).to_vec(),
+			None => rng.bytes(32),
+		};
+		case.args = random_args(rng, valid);
+		case
+	};
+	harness.check(SEED, CASES, generate_case, model_typeid);
+}
+
+#[test]
+fn test_models_vestinglock()
+{
+	let harness = Harness::new("vestinglock");
+	let args = |rng: &mut Rng|
+	{
+		let schedule = [rng.choose(&[0u64, 10]), rng.choose(&[0, 5]), rng.choose(&[0, 10, 100])];
+		let mut valid = harness.owner_lock_hash(rng.below(4) as u8).to_vec();
+		valid.extend(schedule.iter().flat_map(|value| value.to_le_bytes()));
+		valid.extend_from_slice(&rng.choose(&[0, 1_000 * CKB as u128, u128::MAX]).to_le_bytes());
+		random_args(rng, valid)
+	};
+	let generate_case = |rng: &mut Rng|
+	{
+		let mut case = generate(rng, args, 2, &LOCK_POSITIONS, sudt_data);
+		for cell in &mut case.inputs
+		{
+			cell.since = match rng.one_in(5)
+			{
+				true => rng.choose(&SINCE_SAMPLES),
+				false => SINCE_ABSOLUTE_EPOCH_FLAGS | rng.choose(&[0, 5, 10, 14, 15, 50, 200]),
+			};
+		}
+		case
+	};
+	harness.check(SEED, CASES, generate_case, model_vestinglock);
+}
+
+#[test]
+fn test_models_minimize()
+{
+	// A model of datacap which uses a default limit when the args are not 4 bytes.
+	let model = |group: &Group| -> Verdict
+	{
+		let limit = u32_at(group.args, 0).map(|limit| limit as usize).unwrap_or(10);
+		if group.group_outputs().iter().any(|cell| cell.data.len() > limit)
+		{
+			return Err(error("datacap", "DataLimitExceeded"));
+		}
+		Ok(())
+	};
+	let harness = Harness::new("datacap");
+	let mismatch = harness.find_mismatch(SEED, CASES, generate_datacap, model).expect("mismatch");
+
+	// The case is minimized to empty args and one empty cell.
+	assert_eq!(mismatch.actual, Err(error("datacap", "ArgsLen")));
+	assert!(mismatch.case.args.is_empty());
+	assert_eq!(mismatch.case.inputs.len() + mismatch.case.outputs.len(), 1);
+	assert!(mismatch.case.inputs.iter().chain(mismatch.case.outputs.iter()).all(|cell| cell.data.is_empty() && cell.capacity == 0 && cell.tag.is_none()));
+	assert!(mismatch.case.witnesses.is_empty());
+
+	// The report includes the case as Rust, which can be pasted into a test.
+	let report = mismatch.to_string();
+	assert!(report.contains("datacap returned error 5 (ArgsLen) but the model expected success"), "{}", report);
+	assert!(report.contains("args: vec![],"), "{}", report);
+	assert!(report.contains("owner: 0, tag: None, capacity: 0, since: 0, data: vec![] }"), "{}", report);
+}
+
+#[test]
+fn test_models_counter_overflow()
+{
+	// A counter at the maximum value cannot be incremented, so the contract panics.
+	let case = Case
+	{
+		args: vec![],
+		inputs: vec!
+		[
+			CaseCell { position: Some(Position::Type), args: None, owner: 0, tag: None, capacity: 0, since: 0, data: vec![0xff; 8] },
+		],
+		outputs: vec!
+		[
+			CaseCell { position: Some(Position::Type), args: None, owner: 0, tag: None, capacity: 0, since: 0, data: vec![0x00; 8] },
+		],
+		deps: vec![],
+		witnesses: vec![],
+	};
+	let harness = Harness::new("counter");
+	assert_eq!(harness.run(&case), Err(PANIC_ERROR_CODE));
+	harness.assert_case(&case, model_counter);
+}
@@ -0,0 +1,1858 @@
+use super::*;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_hash::new_blake2b;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::TransactionBuilder;
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+const CLASS_MODE: u8 = 1; // The last byte of the args of a class cell.
+
+// Error Codes
+const ERROR_NFT_ARGSLEN: i8 = 5;
+const ERROR_NFT_INVALID_TRANSACTION_STRUCTURE: i8 = 6;
+const ERROR_NFT_INVALID_NFT_ID: i8 = 7;
+const ERROR_NFT_CONTENT_CHANGED: i8 = 8;
+const ERROR_NFT_CLASS_NOT_FOUND: i8 = 9;
+const ERROR_NFT_INVALID_CLASS_DATA: i8 = 10;
+const ERROR_NFT_CLASS_LIMIT_EXCEEDED: i8 = 11;
+
+/// Calculate an NFT ID from the first input of a transaction and the index of the output cell.
+fn calculate_nft_id(first_input: &CellInput, output_index: u64) -> Bytes
+{
+	let mut nft_id = [0u8; 32];
+	let mut blake2b = new_blake2b();
+	blake2b.update(first_input.as_slice());
+	blake2b.update(&output_index.to_le_bytes());
+	blake2b.finalize(&mut nft_id);
+
+	Bytes::from(nft_id.to_vec())
+}
+
+/// Create the args of a class cell from the class ID.
+fn class_args(class_id: &[u8]) -> Bytes
+{
+	Bytes::from([class_id, &[CLASS_MODE]].concat())
+}
+
+/// Create class cell data from the maximum supply and the issued count.
+fn class_data(max_supply: u64, issued: u64) -> Bytes
+{
+	Bytes::from([max_supply.to_le_bytes(), issued.to_le_bytes()].concat())
+}
+
+#[test]
+fn test_nft_burn()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let nft_type_script = context.build_script(&out_point_nft, Bytes::from(vec![1u8; 32])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build(), Bytes::from(vec![1u8, 2, 3, 4]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let outputs = vec![];
+	let outputs_data: Vec<Bytes> = vec![];
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_burn_multiple()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let nft_type_script = context.build_script(&out_point_nft, Bytes::from(vec![1u8; 32])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build(), Bytes::from(vec![1u8, 2, 3, 4]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build(), Bytes::from(vec![1u8, 2, 3, 4]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let outputs = vec![];
+	let outputs_data: Vec<Bytes> = vec![];
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_nft_create()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script = context.build_script(&out_point_nft, calculate_nft_id(&inputs[0], 0)).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_create_second_output()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script = context.build_script(&out_point_nft, calculate_nft_id(&inputs[0], 1)).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(90_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_create_multiple()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script_1 = context.build_script(&out_point_nft, calculate_nft_id(&inputs[0], 0)).expect("script");
+	let nft_type_script_2 = context.build_script(&out_point_nft, calculate_nft_id(&inputs[0], 1)).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script_1.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script_2.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_create_invalid_nft_id()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script = context.build_script(&out_point_nft, calculate_nft_id(&inputs[0], 1)).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_NFT_ID);
+}
+
+#[test]
+fn test_nft_create_duplicate()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script = context.build_script(&out_point_nft, calculate_nft_id(&inputs[0], 0)).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_nft_create_no_input_cell_id()
+{
+	// The NFT ID was not derived from the first input.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let nft_type_script = context.build_script(&out_point_nft, Bytes::from(vec![1u8; 32])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_NFT_ID);
+}
+
+#[test]
+fn test_nft_transfer()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let nft_type_script = context.build_script(&out_point_nft, Bytes::from(vec![1u8; 32])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build(), Bytes::from(vec![1u8, 2, 3, 4]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_transfer_new_lock()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let nft_type_script = context.build_script(&out_point_nft, Bytes::from(vec![1u8; 32])).expect("script");
+
+	let new_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build(), Bytes::from(vec![1u8, 2, 3, 4]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(new_lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_transfer_content_changed()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let nft_type_script = context.build_script(&out_point_nft, Bytes::from(vec![1u8; 32])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build(), Bytes::from(vec![1u8, 2, 3, 4]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 5]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_CONTENT_CHANGED);
+}
+
+#[test]
+fn test_nft_transfer_content_removed()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let nft_type_script = context.build_script(&out_point_nft, Bytes::from(vec![1u8; 32])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build(), Bytes::from(vec![1u8, 2, 3, 4]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_CONTENT_CHANGED);
+}
+
+#[test]
+fn test_nft_transfer_split()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let nft_type_script = context.build_script(&out_point_nft, Bytes::from(vec![1u8; 32])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build(), Bytes::from(vec![1u8, 2, 3, 4]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_nft_args_len()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let nft_type_script = context.build_script(&out_point_nft, Bytes::from(vec![1u8; 20])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build(), Bytes::from(vec![1u8, 2, 3, 4]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_ARGSLEN);
+}
+
+#[test]
+fn test_nft_create_with_class()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 0));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script_args: Bytes = [calculate_nft_id(&inputs[0], 1), class_type_hash.clone()].concat().into();
+	let nft_type_script = context.build_script(&out_point_nft, nft_type_script_args).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 1));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_create_with_class_last()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 9));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script_args: Bytes = [calculate_nft_id(&inputs[0], 1), class_type_hash.clone()].concat().into();
+	let nft_type_script = context.build_script(&out_point_nft, nft_type_script_args).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 10));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_create_with_class_multiple()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 0));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script_1_args: Bytes = [calculate_nft_id(&inputs[0], 1), class_type_hash.clone()].concat().into();
+	let nft_type_script_1 = context.build_script(&out_point_nft, nft_type_script_1_args).expect("script");
+	let nft_type_script_2_args: Bytes = [calculate_nft_id(&inputs[0], 2), class_type_hash.clone()].concat().into();
+	let nft_type_script_2 = context.build_script(&out_point_nft, nft_type_script_2_args).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 2));
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script_1.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script_2.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_create_with_class_multiple_not_counted()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 0));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script_1_args: Bytes = [calculate_nft_id(&inputs[0], 1), class_type_hash.clone()].concat().into();
+	let nft_type_script_1 = context.build_script(&out_point_nft, nft_type_script_1_args).expect("script");
+	let nft_type_script_2_args: Bytes = [calculate_nft_id(&inputs[0], 2), class_type_hash.clone()].concat().into();
+	let nft_type_script_2 = context.build_script(&out_point_nft, nft_type_script_2_args).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 1));
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script_1.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script_2.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_CLASS_DATA);
+}
+
+#[test]
+fn test_nft_create_with_class_and_transfer()
+{
+	// An existing NFT of the same class is transferred and does not count towards the issued amount.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+	let nft_type_script_1 = context.build_script(&out_point_nft, Bytes::from([vec![1u8; 32], class_type_hash.to_vec()].concat())).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 1));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script_1.clone()).pack()).build(), Bytes::from(vec![1u8, 2, 3, 4]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script_2_args: Bytes = [calculate_nft_id(&inputs[0], 2), class_type_hash.clone()].concat().into();
+	let nft_type_script_2 = context.build_script(&out_point_nft, nft_type_script_2_args).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 2));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script_1.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script_2.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_create_with_class_limit_exceeded()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(1, 1));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script_args: Bytes = [calculate_nft_id(&inputs[0], 1), class_type_hash.clone()].concat().into();
+	let nft_type_script = context.build_script(&out_point_nft, nft_type_script_args).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(1, 2));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_CLASS_LIMIT_EXCEEDED);
+}
+
+#[test]
+fn test_nft_create_with_class_not_updated()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 0));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script_args: Bytes = [calculate_nft_id(&inputs[0], 1), class_type_hash.clone()].concat().into();
+	let nft_type_script = context.build_script(&out_point_nft, nft_type_script_args).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 0));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_CLASS_DATA);
+}
+
+#[test]
+fn test_nft_create_with_class_max_supply_changed()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 0));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script_args: Bytes = [calculate_nft_id(&inputs[0], 1), class_type_hash.clone()].concat().into();
+	let nft_type_script = context.build_script(&out_point_nft, nft_type_script_args).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(11, 1));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_CLASS_DATA);
+}
+
+#[test]
+fn test_nft_create_with_class_invalid_data()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), Bytes::from(10u64.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script_args: Bytes = [calculate_nft_id(&inputs[0], 1), class_type_hash.clone()].concat().into();
+	let nft_type_script = context.build_script(&out_point_nft, nft_type_script_args).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(11u64.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_CLASS_DATA);
+}
+
+#[test]
+fn test_nft_create_with_class_missing_input()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Class and NFT Scripts. The class cell is created in the same transaction, so it is not an input.
+	let class_type_script = context.build_script(&out_point_nft, class_args(&calculate_nft_id(&inputs[0], 0))).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+	let nft_type_script_args: Bytes = [calculate_nft_id(&inputs[0], 1), class_type_hash.clone()].concat().into();
+	let nft_type_script = context.build_script(&out_point_nft, nft_type_script_args).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 0));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_CLASS_NOT_FOUND);
+}
+
+#[test]
+fn test_nft_create_with_class_missing_output()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 0));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script_args: Bytes = [calculate_nft_id(&inputs[0], 0), class_type_hash.clone()].concat().into();
+	let nft_type_script = context.build_script(&out_point_nft, nft_type_script_args).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_CLASS_NOT_FOUND);
+}
+
+#[test]
+fn test_nft_transfer_with_class()
+{
+	// The class cell is only needed when an NFT is created.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+	let nft_type_script = context.build_script(&out_point_nft, Bytes::from([vec![1u8; 32], class_type_hash.to_vec()].concat())).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build(), Bytes::from(vec![1u8, 2, 3, 4]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_burn_with_class()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+	let nft_type_script = context.build_script(&out_point_nft, Bytes::from([vec![1u8; 32], class_type_hash.to_vec()].concat())).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build(), Bytes::from(vec![1u8, 2, 3, 4]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let outputs = vec![];
+	let outputs_data: Vec<Bytes> = vec![];
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_class_create()
+{
+	// A class cell is created with a unique ID and nothing issued.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Class Scripts
+	let class_type_script = context.build_script(&out_point_nft, class_args(&calculate_nft_id(&inputs[0], 0))).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 0));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_class_create_issued()
+{
+	// A new class cell cannot start with a non-zero issued count.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Class Scripts
+	let class_type_script = context.build_script(&out_point_nft, class_args(&calculate_nft_id(&inputs[0], 0))).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 3));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_CLASS_DATA);
+}
+
+#[test]
+fn test_nft_class_create_invalid_id()
+{
+	// A class cell must have a unique ID derived from the first input.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Class Scripts
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 0));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_NFT_ID);
+}
+
+#[test]
+fn test_nft_class_transfer()
+{
+	// A class cell can be transferred without changing the data.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 5));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 5));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_class_reset()
+{
+	// The issued count of a class cell cannot be reset.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 5));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 0));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_CLASS_DATA);
+}
+
+#[test]
+fn test_nft_class_raise_max_supply()
+{
+	// The maximum supply of a class cell cannot be raised.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 10));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(20, 10));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_CLASS_DATA);
+}
+
+#[test]
+fn test_nft_class_issue_without_nft()
+{
+	// The issued count of a class cell cannot increase unless NFTs are created.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 5));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 6));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_INVALID_CLASS_DATA);
+}
+
+#[test]
+fn test_nft_class_burn()
+{
+	// A class cell can be burned, which prevents any more NFTs from being issued.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_nft, class_args(&[1u8; 32])).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 5));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let outputs = vec![];
+	let outputs_data: Vec<Bytes> = vec![];
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_nft_create_with_ungoverned_class()
+{
+	// The class cell must be a class of the NFT contract so the issued count is enforced.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_nft = context.deploy_cell(Loader::default().load_binary("nft"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let nft_dep = CellDep::new_builder().out_point(out_point_nft.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let class_type_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let class_type_hash = class_type_script.calc_script_hash().as_bytes();
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build(), class_data(10, 0));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare NFT Scripts
+	let nft_type_script_args: Bytes = [calculate_nft_id(&inputs[0], 1), class_type_hash.clone()].concat().into();
+	let nft_type_script = context.build_script(&out_point_nft, nft_type_script_args).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(class_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(class_data(10, 1));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(nft_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![1u8, 2, 3, 4]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(nft_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_NFT_CLASS_NOT_FOUND);
+}
@@ -1,5 +1,4 @@
 use super::*;
-use ckb_testtool::{context::Context};
 use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
 use ckb_testtool::ckb_types::core::{TransactionBuilder};
 
@@ -14,7 +13,7 @@ const ERROR_OCCLOCK_ARGSLEN: i8 = 6;
 fn test_occlock_minimum_capacity()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("occlock");
@@ -47,7 +46,7 @@ fn test_occlock_minimum_capacity()
 fn test_occlock_exact_capacity()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("occlock");
@@ -81,7 +80,7 @@ fn test_occlock_exact_capacity()
 fn test_occlock_multi_cell_exact_capacity()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("occlock");
@@ -115,7 +114,7 @@ fn test_occlock_multi_cell_exact_capacity()
 fn test_occlock_multi_cell_exact_capacity_too_few()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("occlock");
@@ -149,7 +148,7 @@ fn test_occlock_multi_cell_exact_capacity_too_few()
 fn test_occlock_multi_cell_exact_capacity_too_many()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("occlock");
@@ -183,7 +182,7 @@ fn test_occlock_multi_cell_exact_capacity_too_many()
 fn test_occlock_over_capacity()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("occlock");
@@ -217,7 +216,7 @@ fn test_occlock_over_capacity()
 fn test_occlock_no_args()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("occlock");
@@ -247,7 +246,7 @@ fn test_occlock_no_args()
 fn test_occlock_wrong_args()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let binary: Bytes = Loader::default().load_binary("occlock");
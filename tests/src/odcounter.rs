@@ -1,5 +1,5 @@
 use super::*;
-use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
 use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
 use ckb_testtool::ckb_types::core::{TransactionBuilder};
 
@@ -17,7 +17,7 @@ const ERROR_ODCOUNTER_INVALID_COUNTER_VALUE: i8 = 9;
 fn test_odcounter_burn()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -56,7 +56,7 @@ fn test_odcounter_burn()
 fn test_odcounter_burn_multiple()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -96,7 +96,7 @@ fn test_odcounter_burn_multiple()
 fn test_odcounter_create()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -135,7 +135,7 @@ fn test_odcounter_create()
 fn test_odcounter_create_no_output_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -174,7 +174,7 @@ fn test_odcounter_create_no_output_data()
 fn test_odcounter_create_invalid_output_data_value()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -213,7 +213,7 @@ fn test_odcounter_create_invalid_output_data_value()
 fn test_odcounter_create_invalid_output_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -252,7 +252,7 @@ fn test_odcounter_create_invalid_output_data()
 fn test_odcounter_transfer()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -296,7 +296,7 @@ fn test_odcounter_transfer()
 fn test_odcounter_transfer_high_value()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -340,7 +340,7 @@ fn test_odcounter_transfer_high_value()
 fn test_odcounter_transfer_plus_2()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -384,7 +384,7 @@ fn test_odcounter_transfer_plus_2()
 fn test_odcounter_transfer_plus_9000()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -428,7 +428,7 @@ fn test_odcounter_transfer_plus_9000()
 fn test_odcounter_transfer_minus_1()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -472,7 +472,7 @@ fn test_odcounter_transfer_minus_1()
 fn test_odcounter_transfer_overflow()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -516,7 +516,7 @@ fn test_odcounter_transfer_overflow()
 fn test_odcounter_transfer_invalid_input_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -560,7 +560,7 @@ fn test_odcounter_transfer_invalid_input_data()
 fn test_odcounter_transfer_invalid_output_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
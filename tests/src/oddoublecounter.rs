@@ -1,5 +1,5 @@
 use super::*;
-use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
 use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
 use ckb_testtool::ckb_types::core::{TransactionBuilder};
 
@@ -18,7 +18,7 @@ const ERROR_ODDOUBLECOUNTER_INVALID_COUNTER_VALUE_2: i8 = 10;
 fn test_oddoublecounter_burn()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -66,7 +66,7 @@ fn test_oddoublecounter_burn()
 fn test_oddoublecounter_burn_multiple()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -114,7 +114,7 @@ fn test_oddoublecounter_burn_multiple()
 fn test_oddoublecounter_create()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -166,7 +166,7 @@ fn test_oddoublecounter_create()
 fn test_oddoublecounter_create_multiple()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -224,7 +224,7 @@ fn test_oddoublecounter_create_multiple()
 fn test_oddoublecounter_create_no_output_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -273,7 +273,7 @@ fn test_oddoublecounter_create_no_output_data()
 fn test_oddoublecounter_create_invalid_output_data_value()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -323,7 +323,7 @@ fn test_oddoublecounter_create_invalid_output_data_value()
 fn test_oddoublecounter_transfer()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -377,7 +377,7 @@ fn test_oddoublecounter_transfer()
 fn test_oddoublecounter_transfer_high_value()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -431,7 +431,7 @@ fn test_oddoublecounter_transfer_high_value()
 fn test_oddoublecounter_transfer_multiple()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -507,7 +507,7 @@ fn test_oddoublecounter_transfer_multiple()
 fn test_oddoublecounter_transfer_multiple_mismatch_cell_count_too_few_outputs()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -565,7 +565,7 @@ fn test_oddoublecounter_transfer_multiple_mismatch_cell_count_too_few_outputs()
 fn test_oddoublecounter_transfer_multiple_mismatch_cell_count_too_many_outputs()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -627,7 +627,7 @@ fn test_oddoublecounter_transfer_multiple_mismatch_cell_count_too_many_outputs()
 fn test_oddoublecounter_transfer_value_1_plus_2()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -681,7 +681,7 @@ fn test_oddoublecounter_transfer_value_1_plus_2()
 fn test_oddoublecounter_transfer_value_2_plus_9000()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -735,7 +735,7 @@ fn test_oddoublecounter_transfer_value_2_plus_9000()
 fn test_oddoublecounter_transfer_overflow()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -789,7 +789,7 @@ fn test_oddoublecounter_transfer_overflow()
 fn test_oddoublecounter_transfer_invalid_input_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -843,7 +843,7 @@ fn test_oddoublecounter_transfer_invalid_input_data()
 fn test_oddoublecounter_transfer_invalid_output_data()
 {
 	// Create Context
-	let mut context = Context::default();
+	let mut context = RecordingContext::default();
 
 	// Deploy Contracts
 	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
@@ -0,0 +1,1370 @@
+use super::*;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::TransactionBuilder;
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+
+// Error Codes
+const ERROR_ORACLE_ARGSLEN: i8 = 5;
+const ERROR_ORACLE_INVALID_TRANSACTION_STRUCTURE: i8 = 6;
+const ERROR_ORACLE_UNAUTHORIZED: i8 = 7;
+const ERROR_ORACLE_INVALID_CELL_DATA: i8 = 8;
+const ERROR_ORACLE_STALE_SEQUENCE: i8 = 9;
+const ERROR_ORACLE_STALE_TIMESTAMP: i8 = 10;
+const ERROR_ORACLELOCK_ORACLE_NOT_FOUND: i8 = 7;
+const ERROR_ORACLELOCK_STALE_RECORD: i8 = 10;
+
+/// Create oracle cell data from a value, timestamp, and sequence.
+fn oracle_data(value: u64, timestamp: u64, sequence: u64) -> Vec<u8>
+{
+	[value.to_le_bytes(), timestamp.to_le_bytes(), sequence.to_le_bytes()].concat()
+}
+
+/// Create oracle lock args from the owner lock hash, the oracle type hash, the minimum sequence, and the threshold.
+fn oraclelock_args(owner_lock_hash: Bytes, oracle_type_hash: Bytes, minimum_sequence: u64, threshold: u64) -> Bytes
+{
+	[owner_lock_hash, oracle_type_hash, Bytes::from(minimum_sequence.to_le_bytes().to_vec()), Bytes::from(threshold.to_le_bytes().to_vec())].concat().into()
+}
+
+#[test]
+fn test_oracle_create()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_oracle_create_unauthorized()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(110_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_UNAUTHORIZED);
+}
+
+#[test]
+fn test_oracle_create_invalid_data()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![0u8; 16]));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_INVALID_CELL_DATA);
+}
+
+#[test]
+fn test_oracle_create_no_data()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_INVALID_CELL_DATA);
+}
+
+#[test]
+fn test_oracle_create_multiple()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_oracle_update()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_700_000_060, 1)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_oracle_update_same_timestamp()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_700_000_000, 1)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_oracle_update_sequence_skip()
+{
+	// The sequence only needs to increase, so skipping values is allowed.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_700_000_060, 10)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_oracle_update_same_sequence()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 5)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_700_000_060, 5)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_STALE_SEQUENCE);
+}
+
+#[test]
+fn test_oracle_update_lower_sequence()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 5)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_700_000_060, 4)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_STALE_SEQUENCE);
+}
+
+#[test]
+fn test_oracle_update_stale_timestamp()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 5)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_699_999_940, 6)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_STALE_TIMESTAMP);
+}
+
+#[test]
+fn test_oracle_update_unauthorized()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_700_000_060, 1)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_UNAUTHORIZED);
+}
+
+#[test]
+fn test_oracle_update_updater_output_only()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(90_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_700_000_060, 1)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_UNAUTHORIZED);
+}
+
+#[test]
+fn test_oracle_update_oracle_owned_by_updater()
+{
+	// The oracle cell itself can use the updater lock.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_700_000_060, 1)));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_oracle_update_invalid_input_data()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(vec![0u8; 16]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_700_000_060, 1)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_INVALID_CELL_DATA);
+}
+
+#[test]
+fn test_oracle_update_invalid_output_data()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![0u8; 32]));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_INVALID_CELL_DATA);
+}
+
+#[test]
+fn test_oracle_update_multiple()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_700_000_060, 1)));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_700_000_060, 1)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_oracle_burn()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(110_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_oracle_burn_unauthorized()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(110_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_UNAUTHORIZED);
+}
+
+#[test]
+fn test_oracle_args_len()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes().slice(0..20)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_700_000_060, 1)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(updater_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_ARGSLEN);
+}
+
+#[test]
+fn test_oracle_read_cell_dep()
+{
+	// Reading the oracle through a cell dep does not require the updater.
+	// The oracle lock reads the record from the cell dep and unlocks because it meets the minimum sequence and the threshold.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let out_point_oraclelock = context.deploy_cell(Loader::default().load_binary("oraclelock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+	let oraclelock_dep = CellDep::new_builder().out_point(out_point_oraclelock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let oraclelock_args = oraclelock_args(owner_lock_script.calc_script_hash().as_bytes(), oracle_type_script.calc_script_hash().as_bytes(), 5, 100);
+	let oraclelock_lock_script = context.build_script(&out_point_oraclelock, oraclelock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(oraclelock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Oracle Cell Dep
+	let oracle_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 5)));
+	let oracle_cell_dep = CellDep::new_builder().out_point(oracle_out_point).build();
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.cell_dep(oraclelock_dep)
+		.cell_dep(oracle_cell_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_oracle_read_cell_dep_with_update()
+{
+	// A cell dep does not authorize an update, even when it uses the updater lock.
+	// The oracle lock accepts the record in the cell dep, so the update is the only failure.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let out_point_oraclelock = context.deploy_cell(Loader::default().load_binary("oraclelock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+	let oraclelock_dep = CellDep::new_builder().out_point(out_point_oraclelock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let oraclelock_args = oraclelock_args(owner_lock_script.calc_script_hash().as_bytes(), oracle_type_script.calc_script_hash().as_bytes(), 5, 100);
+	let oraclelock_lock_script = context.build_script(&out_point_oraclelock, oraclelock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(oraclelock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 5)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(oracle_data(105, 1_700_000_060, 6)));
+
+	// Prepare Oracle Cell Dep
+	let oracle_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 5)));
+	let oracle_cell_dep = CellDep::new_builder().out_point(oracle_out_point).build();
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.cell_dep(oraclelock_dep)
+		.cell_dep(oracle_cell_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLE_UNAUTHORIZED);
+}
+
+#[test]
+fn test_oracle_read_cell_dep_stale()
+{
+	// A record older than the minimum sequence required by the reader is rejected, even though the oracle accepted it.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let out_point_oraclelock = context.deploy_cell(Loader::default().load_binary("oraclelock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+	let oraclelock_dep = CellDep::new_builder().out_point(out_point_oraclelock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let oraclelock_args = oraclelock_args(owner_lock_script.calc_script_hash().as_bytes(), oracle_type_script.calc_script_hash().as_bytes(), 5, 100);
+	let oraclelock_lock_script = context.build_script(&out_point_oraclelock, oraclelock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(oraclelock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Oracle Cell Dep
+	let oracle_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 4)));
+	let oracle_cell_dep = CellDep::new_builder().out_point(oracle_out_point).build();
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.cell_dep(oraclelock_dep)
+		.cell_dep(oracle_cell_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLELOCK_STALE_RECORD);
+}
+
+#[test]
+fn test_oracle_read_cell_dep_forged()
+{
+	// A forged record in a cell with an oracle type that has a different updater is never read.
+	// Its type hash does not match the args of the reader, so the oracle is not found.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let out_point_oraclelock = context.deploy_cell(Loader::default().load_binary("oraclelock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oracle_dep = CellDep::new_builder().out_point(out_point_oracle.clone()).build();
+	let oraclelock_dep = CellDep::new_builder().out_point(out_point_oraclelock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let updater_lock_hash = updater_lock_script.calc_script_hash();
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_hash.as_bytes()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let oraclelock_args = oraclelock_args(owner_lock_script.calc_script_hash().as_bytes(), oracle_type_script.calc_script_hash().as_bytes(), 5, 100);
+	let oraclelock_lock_script = context.build_script(&out_point_oraclelock, oraclelock_args).expect("script");
+	let forger_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![3u8])).expect("script");
+	let forged_type_script = context.build_script(&out_point_oracle, forger_lock_script.calc_script_hash().as_bytes()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(oraclelock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Oracle Cell Dep
+	let oracle_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(forger_lock_script.clone()).type_(Some(forged_type_script.clone()).pack()).build(), Bytes::from(oracle_data(1_000, 1_700_000_000, 9)));
+	let oracle_cell_dep = CellDep::new_builder().out_point(oracle_out_point).build();
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oracle_dep)
+		.cell_dep(oraclelock_dep)
+		.cell_dep(oracle_cell_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLELOCK_ORACLE_NOT_FOUND);
+}
@@ -0,0 +1,612 @@
+use super::*;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::TransactionBuilder;
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+
+// Error Codes
+const ERROR_ORACLELOCK_ARGSLEN: i8 = 5;
+const ERROR_ORACLELOCK_UNAUTHORIZED: i8 = 6;
+const ERROR_ORACLELOCK_ORACLE_NOT_FOUND: i8 = 7;
+const ERROR_ORACLELOCK_MULTIPLE_ORACLES: i8 = 8;
+const ERROR_ORACLELOCK_INVALID_ORACLE_DATA: i8 = 9;
+const ERROR_ORACLELOCK_STALE_RECORD: i8 = 10;
+const ERROR_ORACLELOCK_THRESHOLD_NOT_MET: i8 = 11;
+
+/// Create oracle cell data from a value, timestamp, and sequence.
+fn oracle_data(value: u64, timestamp: u64, sequence: u64) -> Vec<u8>
+{
+	[value.to_le_bytes(), timestamp.to_le_bytes(), sequence.to_le_bytes()].concat()
+}
+
+/// Create oracle lock args from the owner lock hash, the oracle type hash, the minimum sequence, and the threshold.
+fn oraclelock_args(owner_lock_hash: Bytes, oracle_type_hash: Bytes, minimum_sequence: u64, threshold: u64) -> Bytes
+{
+	[owner_lock_hash, oracle_type_hash, Bytes::from(minimum_sequence.to_le_bytes().to_vec()), Bytes::from(threshold.to_le_bytes().to_vec())].concat().into()
+}
+
+#[test]
+fn test_oraclelock_unlock()
+{
+	// The oracle record has the minimum sequence and a value equal to the threshold.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let out_point_oraclelock = context.deploy_cell(Loader::default().load_binary("oraclelock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oraclelock_dep = CellDep::new_builder().out_point(out_point_oraclelock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_script.calc_script_hash().as_bytes()).expect("script");
+	let oracle_type_hash = oracle_type_script.calc_script_hash().as_bytes();
+	let oraclelock_lock_script = context.build_script(&out_point_oraclelock, oraclelock_args(owner_lock_hash, oracle_type_hash, 5, 100)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(oraclelock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Oracle Cell Deps
+	let mut oracle_cell_deps = vec![];
+	let oracle_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 5)));
+	oracle_cell_deps.push(CellDep::new_builder().out_point(oracle_out_point).build());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oraclelock_dep)
+		.cell_deps(oracle_cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_oraclelock_unlock_newer_record()
+{
+	// A record with a higher sequence and value is accepted.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let out_point_oraclelock = context.deploy_cell(Loader::default().load_binary("oraclelock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oraclelock_dep = CellDep::new_builder().out_point(out_point_oraclelock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_script.calc_script_hash().as_bytes()).expect("script");
+	let oracle_type_hash = oracle_type_script.calc_script_hash().as_bytes();
+	let oraclelock_lock_script = context.build_script(&out_point_oraclelock, oraclelock_args(owner_lock_hash, oracle_type_hash, 5, 100)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(oraclelock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Oracle Cell Deps
+	let mut oracle_cell_deps = vec![];
+	let oracle_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(250, 1_700_000_600, 9)));
+	oracle_cell_deps.push(CellDep::new_builder().out_point(oracle_out_point).build());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oraclelock_dep)
+		.cell_deps(oracle_cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_oraclelock_args_len()
+{
+	// The args are missing the threshold.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let out_point_oraclelock = context.deploy_cell(Loader::default().load_binary("oraclelock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oraclelock_dep = CellDep::new_builder().out_point(out_point_oraclelock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_script.calc_script_hash().as_bytes()).expect("script");
+	let oracle_type_hash = oracle_type_script.calc_script_hash().as_bytes();
+	let oraclelock_lock_script = context.build_script(&out_point_oraclelock, [owner_lock_hash, oracle_type_hash, Bytes::from(5u64.to_le_bytes().to_vec())].concat().into()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(oraclelock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Oracle Cell Deps
+	let mut oracle_cell_deps = vec![];
+	let oracle_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 5)));
+	oracle_cell_deps.push(CellDep::new_builder().out_point(oracle_out_point).build());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oraclelock_dep)
+		.cell_deps(oracle_cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLELOCK_ARGSLEN);
+}
+
+#[test]
+fn test_oraclelock_unauthorized()
+{
+	// The owner lock is not present in the inputs.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let out_point_oraclelock = context.deploy_cell(Loader::default().load_binary("oraclelock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oraclelock_dep = CellDep::new_builder().out_point(out_point_oraclelock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_script.calc_script_hash().as_bytes()).expect("script");
+	let oracle_type_hash = oracle_type_script.calc_script_hash().as_bytes();
+	let oraclelock_lock_script = context.build_script(&out_point_oraclelock, oraclelock_args(owner_lock_hash, oracle_type_hash, 5, 100)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(oraclelock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Oracle Cell Deps
+	let mut oracle_cell_deps = vec![];
+	let oracle_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 5)));
+	oracle_cell_deps.push(CellDep::new_builder().out_point(oracle_out_point).build());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oraclelock_dep)
+		.cell_deps(oracle_cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLELOCK_UNAUTHORIZED);
+}
+
+#[test]
+fn test_oraclelock_oracle_not_found()
+{
+	// The oracle cell is not included as a cell dep.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let out_point_oraclelock = context.deploy_cell(Loader::default().load_binary("oraclelock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oraclelock_dep = CellDep::new_builder().out_point(out_point_oraclelock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_script.calc_script_hash().as_bytes()).expect("script");
+	let oracle_type_hash = oracle_type_script.calc_script_hash().as_bytes();
+	let oraclelock_lock_script = context.build_script(&out_point_oraclelock, oraclelock_args(owner_lock_hash, oracle_type_hash, 5, 100)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(oraclelock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oraclelock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLELOCK_ORACLE_NOT_FOUND);
+}
+
+#[test]
+fn test_oraclelock_multiple_oracles()
+{
+	// Two cell deps have the oracle type, so the record to read is ambiguous.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let out_point_oraclelock = context.deploy_cell(Loader::default().load_binary("oraclelock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oraclelock_dep = CellDep::new_builder().out_point(out_point_oraclelock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_script.calc_script_hash().as_bytes()).expect("script");
+	let oracle_type_hash = oracle_type_script.calc_script_hash().as_bytes();
+	let oraclelock_lock_script = context.build_script(&out_point_oraclelock, oraclelock_args(owner_lock_hash, oracle_type_hash, 5, 100)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(oraclelock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Oracle Cell Deps
+	let mut oracle_cell_deps = vec![];
+	let oracle_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 5)));
+	oracle_cell_deps.push(CellDep::new_builder().out_point(oracle_out_point).build());
+	let oracle_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(200, 1_700_000_060, 6)));
+	oracle_cell_deps.push(CellDep::new_builder().out_point(oracle_out_point).build());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oraclelock_dep)
+		.cell_deps(oracle_cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLELOCK_MULTIPLE_ORACLES);
+}
+
+#[test]
+fn test_oraclelock_invalid_oracle_data()
+{
+	// The oracle cell holds only the value and the timestamp.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let out_point_oraclelock = context.deploy_cell(Loader::default().load_binary("oraclelock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oraclelock_dep = CellDep::new_builder().out_point(out_point_oraclelock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_script.calc_script_hash().as_bytes()).expect("script");
+	let oracle_type_hash = oracle_type_script.calc_script_hash().as_bytes();
+	let oraclelock_lock_script = context.build_script(&out_point_oraclelock, oraclelock_args(owner_lock_hash, oracle_type_hash, 5, 100)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(oraclelock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Oracle Cell Deps
+	let mut oracle_cell_deps = vec![];
+	let oracle_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 5)[0..16].to_vec()));
+	oracle_cell_deps.push(CellDep::new_builder().out_point(oracle_out_point).build());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oraclelock_dep)
+		.cell_deps(oracle_cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLELOCK_INVALID_ORACLE_DATA);
+}
+
+#[test]
+fn test_oraclelock_stale_record()
+{
+	// The oracle record is one sequence older than the minimum.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let out_point_oraclelock = context.deploy_cell(Loader::default().load_binary("oraclelock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oraclelock_dep = CellDep::new_builder().out_point(out_point_oraclelock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_script.calc_script_hash().as_bytes()).expect("script");
+	let oracle_type_hash = oracle_type_script.calc_script_hash().as_bytes();
+	let oraclelock_lock_script = context.build_script(&out_point_oraclelock, oraclelock_args(owner_lock_hash, oracle_type_hash, 5, 100)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(oraclelock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Oracle Cell Deps
+	let mut oracle_cell_deps = vec![];
+	let oracle_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(100, 1_700_000_000, 4)));
+	oracle_cell_deps.push(CellDep::new_builder().out_point(oracle_out_point).build());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oraclelock_dep)
+		.cell_deps(oracle_cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLELOCK_STALE_RECORD);
+}
+
+#[test]
+fn test_oraclelock_threshold_not_met()
+{
+	// The oracle value is one below the threshold.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let out_point_oraclelock = context.deploy_cell(Loader::default().load_binary("oraclelock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let oraclelock_dep = CellDep::new_builder().out_point(out_point_oraclelock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let updater_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let oracle_type_script = context.build_script(&out_point_oracle, updater_lock_script.calc_script_hash().as_bytes()).expect("script");
+	let oracle_type_hash = oracle_type_script.calc_script_hash().as_bytes();
+	let oraclelock_lock_script = context.build_script(&out_point_oraclelock, oraclelock_args(owner_lock_hash, oracle_type_hash, 5, 100)).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(oraclelock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Oracle Cell Deps
+	let mut oracle_cell_deps = vec![];
+	let oracle_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(updater_lock_script.clone()).type_(Some(oracle_type_script.clone()).pack()).build(), Bytes::from(oracle_data(99, 1_700_000_000, 5)));
+	oracle_cell_deps.push(CellDep::new_builder().out_point(oracle_out_point).build());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(oraclelock_dep)
+		.cell_deps(oracle_cell_deps)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORACLELOCK_THRESHOLD_NOT_MET);
+}
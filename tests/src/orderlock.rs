@@ -0,0 +1,1761 @@
+use super::*;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::TransactionBuilder;
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+
+// Error Codes
+const ERROR_ORDERLOCK_ARGSLEN: i8 = 5;
+const ERROR_ORDERLOCK_INVALID_EXCHANGE_RATE: i8 = 6;
+const ERROR_ORDERLOCK_INVALID_TRANSACTION_STRUCTURE: i8 = 7;
+const ERROR_ORDERLOCK_INVALID_ORDER_CELL: i8 = 8;
+const ERROR_ORDERLOCK_INVALID_RESIDUAL: i8 = 9;
+const ERROR_ORDERLOCK_INSUFFICIENT_PAYMENT: i8 = 10;
+
+#[test]
+fn test_orderlock_full_fill()
+{
+	// The order sells 1,000 tokens for 500 CKBytes, and the maker receives the payment plus the capacity of the order cell.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(70_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_orderlock_full_fill_extra_payment()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(80_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_orderlock_full_fill_split_payment()
+{
+	// The maker must be paid by the output at the same index as the order cell, so the payment cannot be split.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(40_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INSUFFICIENT_PAYMENT);
+}
+
+#[test]
+fn test_orderlock_full_fill_insufficient_payment()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(69_900_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(30_100_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INSUFFICIENT_PAYMENT);
+}
+
+#[test]
+fn test_orderlock_full_fill_capacity_not_returned()
+{
+	// The maker was paid for the tokens but did not receive the capacity of the order cell.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INSUFFICIENT_PAYMENT);
+}
+
+#[test]
+fn test_orderlock_full_fill_no_payment()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INSUFFICIENT_PAYMENT);
+}
+
+#[test]
+fn test_orderlock_partial_fill()
+{
+	// Buy 400 of the 1,000 tokens for 200 CKBytes, leaving a residual order with 600 tokens.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(600u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(60_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_orderlock_partial_fill_then_full_fill()
+{
+	// The residual order from a partial fill is filled at the same exchange rate.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(600u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(600u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_orderlock_partial_fill_insufficient_payment()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(600u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(19_900_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(60_100_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INSUFFICIENT_PAYMENT);
+}
+
+#[test]
+fn test_orderlock_partial_fill_residual_capacity_decreased()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(19_900_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(600u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(60_100_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INVALID_RESIDUAL);
+}
+
+#[test]
+fn test_orderlock_partial_fill_residual_type_changed()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let other_sudt_type_script = context.build_script(&out_point_sudt, Bytes::from(vec![0u8; 32])).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(other_sudt_type_script.clone()).pack()).build(), Bytes::from(600u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(other_sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(600u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(80_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INVALID_RESIDUAL);
+}
+
+#[test]
+fn test_orderlock_partial_fill_residual_no_type()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(60_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INVALID_RESIDUAL);
+}
+
+#[test]
+fn test_orderlock_partial_fill_nothing_sold()
+{
+	// A residual order with the same amount of tokens does not fill the order.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INVALID_RESIDUAL);
+}
+
+#[test]
+fn test_orderlock_partial_fill_residual_increased()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(500u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_500u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INVALID_RESIDUAL);
+}
+
+#[test]
+fn test_orderlock_partial_fill_multiple_residuals()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(300u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(300u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(400u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(60_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_orderlock_partial_fill_rounding()
+{
+	// The exchange rate is 10 Shannons for 3 tokens, so the payment for 1 token is rounded up to 4 Shannons.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(10u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(3u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(3u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(4_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(2u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_orderlock_partial_fill_rounding_insufficient_payment()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(10u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(3u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(3u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(2u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(3_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INSUFFICIENT_PAYMENT);
+}
+
+#[test]
+fn test_orderlock_multiple_orders()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(200_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(140_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(2_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(60_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_orderlock_full_fill_two_orders()
+{
+	// Two orders with different exchange rates are each paid by the output at the same index as the order cell.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash.clone(), rate_ckb, rate_sudt.clone()].concat().into();
+	let orderlock_lock_script_1 = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+	let rate_ckb = Bytes::from(600_0000_0000u64.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script_2 = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script_1.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script_2.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(200_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(70_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(80_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(2_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_orderlock_full_fill_two_orders_one_payment()
+{
+	// Two orders with different exchange rates cannot both be filled by a single payment to the maker.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash.clone(), rate_ckb, rate_sudt.clone()].concat().into();
+	let orderlock_lock_script_1 = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+	let rate_ckb = Bytes::from(600_0000_0000u64.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script_2 = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script_1.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script_2.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(200_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(80_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(2_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INSUFFICIENT_PAYMENT);
+}
+
+#[test]
+fn test_orderlock_cancel()
+{
+	// The maker can take back the order cell without any payment.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(maker_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_orderlock_cancel_partial()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(600u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(maker_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(600u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_orderlock_maker_output_only()
+{
+	// Sending the tokens to the maker lock is not a cancellation without an input from the maker.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(maker_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INSUFFICIENT_PAYMENT);
+}
+
+#[test]
+fn test_orderlock_order_no_type()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(1_000u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(70_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INVALID_ORDER_CELL);
+}
+
+#[test]
+fn test_orderlock_args_len()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let orderlock_args: Bytes = [maker_lock_hash, Bytes::from(500_0000_0000u64.to_le_bytes().to_vec())].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(70_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_ARGSLEN);
+}
+
+#[test]
+fn test_orderlock_invalid_exchange_rate()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_orderlock = context.deploy_cell(Loader::default().load_binary("orderlock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let orderlock_dep = CellDep::new_builder().out_point(out_point_orderlock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let maker_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let maker_lock_hash = maker_lock_script.calc_script_hash().as_bytes();
+	let issuer_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![2u8])).expect("script");
+	let issuer_lock_hash = issuer_lock_script.calc_script_hash().as_bytes();
+	let sudt_type_script = context.build_script(&out_point_sudt, issuer_lock_hash).expect("script");
+	let rate_ckb = Bytes::from(500_0000_0000u64.to_le_bytes().to_vec());
+	let rate_sudt = Bytes::from(0u128.to_le_bytes().to_vec());
+	let orderlock_args: Bytes = [maker_lock_hash, rate_ckb, rate_sudt].concat().into();
+	let orderlock_lock_script = context.build_script(&out_point_orderlock, orderlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(orderlock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(70_000_000_000_u64.pack()).lock(maker_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(orderlock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_ORDERLOCK_INVALID_EXCHANGE_RATE);
+}
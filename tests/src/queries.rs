@@ -0,0 +1,185 @@
+use super::*;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ::sdk::{sudt, Encoding};
+use indexer::{Indexer, Order, SearchKey, SearchKeyFilter};
+use simulator::Simulator;
+
+/// Create a simulator with the always success lock deployed, returning the simulator and a lock script with no args.
+fn setup() -> (Simulator, Script)
+{
+	let mut simulator = Simulator::default();
+	let out_point_always_success = simulator.deploy(ALWAYS_SUCCESS.clone());
+	let lock_script = simulator.build_script(&out_point_always_success, Bytes::new());
+
+	(simulator, lock_script)
+}
+
+fn with_args(script: &Script, args: &[u8]) -> Script
+{
+	script.clone().as_builder().args(Bytes::from(args.to_vec()).pack()).build()
+}
+
+/// Issue a cell in a new block.
+fn issue(simulator: &mut Simulator, lock_script: &Script, type_script: Option<&Script>, capacity: u64, data: &[u8]) -> ::sdk::Cell
+{
+	simulator.advance(1);
+	let output = CellOutput::new_builder().capacity(capacity.pack()).lock(lock_script.clone()).type_(type_script.cloned().pack()).build();
+
+	simulator.issue(output, Bytes::from(data.to_vec()))
+}
+
+fn out_points(cells: &[indexer::IndexerCell]) -> Vec<OutPoint>
+{
+	cells.iter().map(|cell| cell.out_point.clone()).collect()
+}
+
+#[test]
+fn test_queries_lock()
+{
+	let (mut simulator, lock_script) = setup();
+	let cell_1 = issue(&mut simulator, &with_args(&lock_script, &[1, 1]), None, 100_000_000_000, &[]);
+	let cell_2 = issue(&mut simulator, &with_args(&lock_script, &[1, 2]), None, 200_000_000_000, &[]);
+	let cell_3 = issue(&mut simulator, &with_args(&lock_script, &[2]), None, 300_000_000_000, &[]);
+	let indexer = Indexer::new(&simulator);
+
+	// The args of the search script are matched as a prefix.
+	let cells = indexer.get_cells(&SearchKey::lock(with_args(&lock_script, &[1])), Order::Asc, 10, None).objects;
+	assert_eq!(out_points(&cells), vec![cell_1.out_point.clone(), cell_2.out_point.clone()]);
+	assert_eq!((cells[0].block_number, cells[1].block_number), (1, 2));
+	assert_eq!(cells[1].cell(), cell_2);
+
+	let cells = indexer.get_cells(&SearchKey::lock(with_args(&lock_script, &[1, 2])), Order::Asc, 10, None).objects;
+	assert_eq!(out_points(&cells), vec![cell_2.out_point.clone()]);
+
+	// An empty prefix matches every cell with the lock code.
+	let cells = indexer.get_cells(&SearchKey::lock(lock_script.clone()), Order::Desc, 10, None).objects;
+	assert_eq!(out_points(&cells), vec![cell_3.out_point, cell_2.out_point, cell_1.out_point]);
+
+	// A different code hash does not match.
+	let other_script = lock_script.as_builder().code_hash([1u8; 32].pack()).build();
+	assert!(indexer.get_cells(&SearchKey::lock(other_script), Order::Asc, 10, None).objects.is_empty());
+}
+
+#[test]
+fn test_queries_filter()
+{
+	let (mut simulator, lock_script) = setup();
+	let type_script = with_args(&lock_script, &[9, 9]);
+	let plain = issue(&mut simulator, &lock_script, None, 100_000_000_000, &[]);
+	let typed = issue(&mut simulator, &lock_script, Some(&type_script), 200_000_000_000, &[1, 2, 3]);
+	let large = issue(&mut simulator, &lock_script, None, 500_000_000_000, &[1; 10]);
+	let indexer = Indexer::new(&simulator);
+	let query = |filter: SearchKeyFilter| out_points(&indexer.get_cells(&SearchKey::lock(lock_script.clone()).with_filter(filter), Order::Asc, 10, None).objects);
+
+	// The filter script is the type script when searching by lock, and is matched as a prefix.
+	assert_eq!(query(SearchKeyFilter { script: Some(with_args(&lock_script, &[9])), ..Default::default() }), vec![typed.out_point.clone()]);
+
+	// A cell without a type script has a script length of zero.
+	assert_eq!(query(SearchKeyFilter { script_len_range: Some([0, 1]), ..Default::default() }), vec![plain.out_point.clone(), large.out_point.clone()]);
+
+	// Each range includes the start and excludes the end.
+	assert_eq!(query(SearchKeyFilter { output_data_len_range: Some([3, 10]), ..Default::default() }), vec![typed.out_point.clone()]);
+	assert_eq!(query(SearchKeyFilter { output_capacity_range: Some([100_000_000_000, 500_000_000_000]), ..Default::default() }), vec![plain.out_point.clone(), typed.out_point.clone()]);
+	assert_eq!(query(SearchKeyFilter { block_range: Some([2, 4]), ..Default::default() }), vec![typed.out_point.clone(), large.out_point.clone()]);
+
+	// Searching by type matches the type script, and the filter script is then the lock.
+	let cells = indexer.get_cells(&SearchKey::type_script(type_script).with_filter(SearchKeyFilter { script: Some(lock_script), ..Default::default() }), Order::Asc, 10, None).objects;
+	assert_eq!(out_points(&cells), vec![typed.out_point]);
+}
+
+#[test]
+fn test_queries_pagination()
+{
+	let (mut simulator, lock_script) = setup();
+	let cells: Vec<OutPoint> = (0..5).map(|_| issue(&mut simulator, &lock_script, None, 100_000_000_000, &[]).out_point).collect();
+	let indexer = Indexer::new(&simulator);
+	let search_key = SearchKey::lock(lock_script);
+
+	// Each page continues after the cursor of the previous page.
+	let page_1 = indexer.get_cells(&search_key, Order::Asc, 2, None);
+	let page_2 = indexer.get_cells(&search_key, Order::Asc, 2, Some(&page_1.last_cursor));
+	let page_3 = indexer.get_cells(&search_key, Order::Asc, 2, Some(&page_2.last_cursor));
+	let page_4 = indexer.get_cells(&search_key, Order::Asc, 2, Some(&page_3.last_cursor));
+	assert_eq!(out_points(&page_1.objects), cells[0..2].to_vec());
+	assert_eq!(out_points(&page_2.objects), cells[2..4].to_vec());
+	assert_eq!(out_points(&page_3.objects), cells[4..5].to_vec());
+	assert!(page_4.objects.is_empty());
+	assert!(page_4.last_cursor.is_empty());
+
+	// A cursor can be used in descending order.
+	let page = indexer.get_cells(&search_key, Order::Desc, 2, Some(&page_2.last_cursor));
+	assert_eq!(out_points(&page.objects), vec![cells[2].clone(), cells[1].clone()]);
+}
+
+#[test]
+fn test_queries_totals()
+{
+	let (mut simulator, owner_lock) = setup();
+	let (alice_lock, bob_lock) = (with_args(&owner_lock, &[1]), with_args(&owner_lock, &[2]));
+	let out_point_sudt = simulator.deploy(Loader::default().load_binary("sudt"));
+	let sudt_script = simulator.build_script(&out_point_sudt, sudt::Args { owner_lock_hash: ::sdk::script_hash(&owner_lock) }.encode());
+
+	// Mint tokens to Alice and Bob, then Alice sends some to Bob.
+	let owner = issue(&mut simulator, &owner_lock, None, 1_000_000_000_000, &[]);
+	let tx = simulator.fund(sudt::mint(&owner, sudt_script.clone(), vec![(alice_lock.clone(), 1_000), (bob_lock.clone(), 500)]), owner_lock.clone());
+	let cells = simulator.apply(tx).unwrap();
+	let tx = simulator.fund(sudt::transfer(&cells[0..1], vec![(bob_lock.clone(), 300)]).unwrap(), alice_lock.clone());
+	simulator.apply(tx).unwrap();
+
+	let indexer = Indexer::new(&simulator);
+	assert_eq!(indexer.sudt_balances(&sudt_script), vec![(bob_lock.clone(), Some(800)), (alice_lock.clone(), Some(700))]);
+	assert_eq!(indexer.sudt_balance(&alice_lock, &sudt_script), Some(700));
+	assert_eq!(indexer.sudt_balance(&owner_lock, &sudt_script), Some(0));
+
+	// A token with a longer owner args is a different token, even though the args share a prefix.
+	let other_script = with_args(&sudt_script, &[::sdk::script_hash(&owner_lock).as_slice(), &[0]].concat());
+	assert!(indexer.sudt_balances(&other_script).is_empty());
+
+	// The capacity of spent cells is not counted.
+	let capacity = indexer.get_cells_capacity(&SearchKey::lock(bob_lock.clone()));
+	let expected: u64 = indexer.get_cells(&SearchKey::lock(bob_lock), Order::Asc, 10, None).objects.iter().map(|cell| Unpack::<u64>::unpack(&cell.output.capacity())).sum();
+	assert_eq!(capacity.capacity, expected);
+	assert_eq!((capacity.block_number, capacity.block_hash), (simulator.block_number(), simulator.tip().hash()));
+	assert_eq!(indexer.get_cells_capacity(&SearchKey::lock(with_args(&owner_lock, &[3]))).capacity, 0);
+}
+
+#[test]
+fn test_queries_sudt_balance_overflow()
+{
+	// The owner can mint any amount to each cell, so the cells of a lock can hold more tokens than fit in a u128.
+	let (mut simulator, owner_lock) = setup();
+	let (alice_lock, bob_lock) = (with_args(&owner_lock, &[1]), with_args(&owner_lock, &[2]));
+	let out_point_sudt = simulator.deploy(Loader::default().load_binary("sudt"));
+	let sudt_script = simulator.build_script(&out_point_sudt, sudt::Args { owner_lock_hash: ::sdk::script_hash(&owner_lock) }.encode());
+
+	let owner = issue(&mut simulator, &owner_lock, None, 1_000_000_000_000, &[]);
+	let tx = simulator.fund(sudt::mint(&owner, sudt_script.clone(), vec![(alice_lock.clone(), u128::MAX), (bob_lock.clone(), u128::MAX - 1), (alice_lock.clone(), 1), (bob_lock.clone(), 1)]), owner_lock.clone());
+	simulator.apply(tx).unwrap();
+
+	// The total of Alice overflows, and the total of Bob is exactly the maximum.
+	let indexer = Indexer::new(&simulator);
+	assert_eq!(indexer.sudt_balances(&sudt_script), vec![(alice_lock.clone(), None), (bob_lock.clone(), Some(u128::MAX))]);
+	assert_eq!(indexer.sudt_balance(&alice_lock, &sudt_script), None);
+	assert_eq!(indexer.sudt_balance(&bob_lock, &sudt_script), Some(u128::MAX));
+}
+
+#[test]
+fn test_queries_json()
+{
+	// The search key is serialized as the parameter of the indexer RPC.
+
+	let (_, lock_script) = setup();
+	let type_script = with_args(&lock_script, &[0xab]);
+	let search_key = SearchKey::lock(with_args(&lock_script, &[1, 2])).with_filter(SearchKeyFilter { script: Some(type_script), output_data_len_range: Some([0, 16]), block_range: Some([1, 256]), ..Default::default() });
+	let json = search_key.to_json();
+
+	assert_eq!(json["script"]["args"], "0x0102");
+	assert_eq!(json["script"]["hash_type"], "data1");
+	assert_eq!(json["script_type"], "lock");
+	assert_eq!(json["filter"]["script"]["args"], "0xab");
+	assert_eq!(json["filter"]["output_data_len_range"], serde_json::json!(["0x0", "0x10"]));
+	assert_eq!(json["filter"]["block_range"], serde_json::json!(["0x1", "0x100"]));
+	assert!(json["filter"].get("output_capacity_range").is_none());
+	assert!(SearchKey::type_script(lock_script).to_json().get("filter").is_none());
+}
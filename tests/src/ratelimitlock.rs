@@ -0,0 +1,1323 @@
+use super::*;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::TransactionBuilder;
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+const SINCE_ABSOLUTE_EPOCH: u64 = 0x2000_0000_0000_0000;
+const SINCE_RELATIVE_EPOCH: u64 = 0xA000_0000_0000_0000;
+
+// Error Codes
+const ERROR_RATELIMITLOCK_ARGSLEN: i8 = 5;
+const ERROR_RATELIMITLOCK_INVALID_TRANSACTION_STRUCTURE: i8 = 6;
+const ERROR_RATELIMITLOCK_UNAUTHORIZED: i8 = 7;
+const ERROR_RATELIMITLOCK_INVALID_SINCE: i8 = 8;
+const ERROR_RATELIMITLOCK_INVALID_INPUT_CELL_DATA: i8 = 9;
+const ERROR_RATELIMITLOCK_INVALID_OUTPUT_CELL_DATA: i8 = 10;
+const ERROR_RATELIMITLOCK_LIMIT_EXCEEDED: i8 = 11;
+
+/// Create rate limit cell data from the window number and the amount withdrawn in that window.
+fn ratelimit_data(window: u64, withdrawn: u64) -> Vec<u8>
+{
+	[window.to_le_bytes(), withdrawn.to_le_bytes()].concat()
+}
+
+#[test]
+fn test_ratelimitlock_withdraw()
+{
+	// The limit is 100 CKBytes per window, and 50 CKBytes are withdrawn in window 10.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(95_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(10, 5_000_000_000)));
+	let output = CellOutput::new_builder().capacity(5_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_limit()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(90_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(10, 10_000_000_000)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_limit_exceeded()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(89_900_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(10, 10_100_000_000)));
+	let output = CellOutput::new_builder().capacity(10_100_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_LIMIT_EXCEEDED);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_same_window()
+{
+	// 60 CKBytes were already withdrawn in window 10, so only 40 CKBytes remain.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 6_000_000_000)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(96_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(10, 10_000_000_000)));
+	let output = CellOutput::new_builder().capacity(4_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_same_window_limit_exceeded()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 6_000_000_000)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(95_900_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(10, 10_100_000_000)));
+	let output = CellOutput::new_builder().capacity(4_100_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_LIMIT_EXCEEDED);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_new_window()
+{
+	// The full limit is available again in the next window, one epoch after the cell was created.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 10_000_000_000)));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_RELATIVE_EPOCH | 1).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(90_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(11, 10_000_000_000)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_new_window_limit_exceeded()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 10_000_000_000)));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_RELATIVE_EPOCH | 1).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(89_900_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(11, 10_100_000_000)));
+	let output = CellOutput::new_builder().capacity(10_100_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_LIMIT_EXCEEDED);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_new_window_too_early()
+{
+	// A new window cannot start until at least one epoch after the cell was created.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 10_000_000_000)));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_RELATIVE_EPOCH | (2 << 40) | (1 << 24)).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(90_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(11, 10_000_000_000)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_INVALID_SINCE);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_absolute_since()
+{
+	// An absolute since cannot start a new window, since an idle cell would allow a withdrawal for every window it missed.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | (1 << 40) | 11).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(95_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(11, 5_000_000_000)));
+	let output = CellOutput::new_builder().capacity(5_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_INVALID_SINCE);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_new_window_skipped()
+{
+	// A new window advances by exactly one, even when the cell was idle for many epochs.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_RELATIVE_EPOCH | (1 << 40) | 10).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(95_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(20, 5_000_000_000)));
+	let output = CellOutput::new_builder().capacity(5_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_INVALID_OUTPUT_CELL_DATA);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_window_overflow()
+{
+	// The window number cannot overflow when a new window starts.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(u64::MAX, 10_000_000_000)));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_RELATIVE_EPOCH | 1).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(90_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(0, 10_000_000_000)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_INVALID_INPUT_CELL_DATA);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_accounting_not_updated()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(95_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(10, 0)));
+	let output = CellOutput::new_builder().capacity(5_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_INVALID_OUTPUT_CELL_DATA);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_accounting_wrong_window()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(95_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(11, 5_000_000_000)));
+	let output = CellOutput::new_builder().capacity(5_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_INVALID_OUTPUT_CELL_DATA);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_invalid_input_data()
+{
+	// A cell created with invalid data can still be spent, and is treated as a new cell in window zero.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(vec![0u8; 8]));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(95_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(0, 5_000_000_000)));
+	let output = CellOutput::new_builder().capacity(5_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_invalid_output_data()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(95_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(vec![0u8; 8]));
+	let output = CellOutput::new_builder().capacity(5_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_INVALID_OUTPUT_CELL_DATA);
+}
+
+#[test]
+fn test_ratelimitlock_withdraw_unauthorized()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(95_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(10, 5_000_000_000)));
+	let output = CellOutput::new_builder().capacity(15_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_UNAUTHORIZED);
+}
+
+#[test]
+fn test_ratelimitlock_deposit()
+{
+	// Adding capacity is not a withdrawal, even when the limit has been reached.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 10_000_000_000)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(110_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(10, 10_000_000_000)));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_ratelimitlock_burn()
+{
+	// The cell can be consumed because its capacity is within the limit.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_ratelimitlock_burn_limit_exceeded()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 100_000_000)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_LIMIT_EXCEEDED);
+}
+
+#[test]
+fn test_ratelimitlock_multiple_outputs()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(10, 0)));
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(10, 0)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_ratelimitlock_multiple_inputs()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let limit = Bytes::from(10_000_000_000u64.to_le_bytes().to_vec());
+	let ratelimitlock_args: Bytes = [owner_lock_hash, limit].concat().into();
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(200_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(10, 0)));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_ratelimitlock_args_len()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_ratelimitlock = context.deploy_cell(Loader::default().load_binary("ratelimitlock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let ratelimitlock_dep = CellDep::new_builder().out_point(out_point_ratelimitlock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let owner_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let owner_lock_hash = owner_lock_script.calc_script_hash().as_bytes();
+	let ratelimitlock_args = owner_lock_hash;
+	let ratelimitlock_lock_script = context.build_script(&out_point_ratelimitlock, ratelimitlock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build(), Bytes::from(ratelimit_data(10, 0)));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(95_000_000_000_u64.pack()).lock(ratelimitlock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(ratelimit_data(10, 5_000_000_000)));
+	let output = CellOutput::new_builder().capacity(5_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(ratelimitlock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_RATELIMITLOCK_ARGSLEN);
+}
@@ -0,0 +1,244 @@
+use super::*;
+use std::slice;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ::sdk::{chequelock, counter, sudt, Encoding};
+use simulator::{Error as SimulatorError, Simulator, EPOCH_LENGTH};
+
+// Error Codes
+const ERROR_COUNTER_INVALID_COUNTER_VALUE: i8 = 6;
+const ERROR_SUDT_AMOUNT: i8 = 5;
+
+/// Create a simulator with the always success lock deployed, returning the simulator and a lock script with the args.
+fn setup(args: &[u8]) -> (Simulator, Script)
+{
+	let mut simulator = Simulator::default();
+	let out_point_always_success = simulator.deploy(ALWAYS_SUCCESS.clone());
+	let lock_script = simulator.build_script(&out_point_always_success, Bytes::from(args.to_vec()));
+
+	(simulator, lock_script)
+}
+
+/// Issue a plain cell with the capacity to the lock.
+fn issue(simulator: &mut Simulator, lock_script: &Script, capacity: u64) -> ::sdk::Cell
+{
+	simulator.issue(CellOutput::new_builder().capacity(capacity.pack()).lock(lock_script.clone()).build(), Bytes::new())
+}
+
+/// The sum of the token amounts in the live cells of the type script.
+fn token_supply(simulator: &Simulator, type_script: &Script) -> u128
+{
+	simulator.live_cells().iter().filter(|cell| cell.output.type_().to_opt().as_ref() == Some(type_script)).map(|cell| sudt::Data::decode(&cell.data).unwrap().amount).sum()
+}
+
+#[test]
+fn test_scenarios_counter_history()
+{
+	let (mut simulator, lock_script) = setup(&[]);
+	let out_point_counter = simulator.deploy(Loader::default().load_binary("counter"));
+	let counter_script = simulator.build_script(&out_point_counter, Bytes::new());
+
+	// Create a counter and increment it in five consecutive blocks.
+	let tx = simulator.fund(counter::create(lock_script.clone(), counter_script, &counter::Data { value: 0 }), lock_script.clone());
+	let first = simulator.apply(tx).unwrap().remove(0);
+	let mut cell = first.clone();
+	for _ in 0..5
+	{
+		cell = simulator.apply(counter::transfer(&cell, lock_script.clone()).unwrap()).unwrap().remove(0);
+	}
+	assert_eq!(counter::Data::decode(&cell.data).unwrap().value, 5);
+	assert_eq!(simulator.block_number(), 6);
+
+	// The first cell has already been spent.
+	let err = simulator.apply(counter::transfer(&first, lock_script.clone()).unwrap()).unwrap_err();
+	assert!(matches!(err, SimulatorError::DeadCell(out_point) if out_point == first.out_point));
+
+	// An update which skips a value is rejected by the contract.
+	let output = ::sdk::Output::new(cell.capacity(), lock_script, cell.output.type_().to_opt(), counter::Data { value: 7 }.encode());
+	match simulator.apply(::sdk::transaction::transfer(&[cell.clone()], vec![output])).unwrap_err()
+	{
+		SimulatorError::Script(err) => assert_script_error(err, ERROR_COUNTER_INVALID_COUNTER_VALUE),
+		err => panic!("unexpected error: {}", err),
+	}
+	assert!(simulator.is_live(&cell.out_point));
+	assert_eq!(simulator.block_number(), 6);
+}
+
+#[test]
+fn test_scenarios_sudt_history()
+{
+	let (mut simulator, owner_lock) = setup(&[1]);
+	let (alice_lock, bob_lock) = (owner_lock.clone().as_builder().args(Bytes::from(vec![2]).pack()).build(), owner_lock.clone().as_builder().args(Bytes::from(vec![3]).pack()).build());
+	let out_point_sudt = simulator.deploy(Loader::default().load_binary("sudt"));
+	let sudt_script = simulator.build_script(&out_point_sudt, sudt::Args { owner_lock_hash: ::sdk::script_hash(&owner_lock) }.encode());
+
+	// The owner mints tokens to Alice and Bob.
+	let owner = issue(&mut simulator, &owner_lock, 1_000_000_000_000);
+	let tx = simulator.fund(sudt::mint(&owner, sudt_script.clone(), vec![(alice_lock.clone(), 1_000), (bob_lock.clone(), 500)]), owner_lock.clone());
+	let cells = simulator.apply(tx).unwrap();
+	assert_eq!(token_supply(&simulator, &sudt_script), 1_500);
+
+	// Alice sends some of her tokens to Bob.
+	let tx = simulator.fund(sudt::transfer(&cells[0..1], vec![(bob_lock.clone(), 300)]).unwrap(), alice_lock.clone());
+	let transferred = simulator.apply(tx).unwrap();
+	assert_eq!(sudt::Data::decode(&transferred[1].data).unwrap().amount, 700);
+
+	// Bob merges his cells and burns some tokens, which does not require the owner.
+	let bob_cells = vec![cells[1].clone(), transferred[0].clone()];
+	simulator.apply(sudt::burn(&bob_cells, 100).unwrap()).unwrap();
+	assert_eq!(token_supply(&simulator, &sudt_script), 1_400);
+
+	// Bob cannot create tokens without the owner.
+	let bob_cell = simulator.live_cells().into_iter().find(|cell| cell.output.lock() == bob_lock).unwrap();
+	let output = ::sdk::Output::new(bob_cell.capacity(), bob_lock, Some(sudt_script.clone()), sudt::Data { amount: 10_000 }.encode());
+	match simulator.apply(::sdk::transaction::transfer(&[bob_cell], vec![output])).unwrap_err()
+	{
+		SimulatorError::Script(err) => assert_script_error(err, ERROR_SUDT_AMOUNT),
+		err => panic!("unexpected error: {}", err),
+	}
+	assert_eq!(token_supply(&simulator, &sudt_script), 1_400);
+}
+
+#[test]
+fn test_scenarios_cheque_withdraw()
+{
+	let (mut simulator, sender_lock) = setup(&[1]);
+	let receiver_lock = sender_lock.clone().as_builder().args(Bytes::from(vec![2]).pack()).build();
+	let out_point_chequelock = simulator.deploy(Loader::default().load_binary("chequelock"));
+	let args = chequelock::Args::new(&::sdk::script_hash(&receiver_lock), &::sdk::script_hash(&sender_lock));
+	let cheque_lock = simulator.build_script(&out_point_chequelock, args.encode());
+
+	// The sender writes a cheque, which the receiver does not claim.
+	let tx = ::sdk::transaction::create(vec![::sdk::Output::new(100_000_000_000, cheque_lock, None, Bytes::new())]);
+	let tx = simulator.fund(tx, sender_lock.clone());
+	let cheque = simulator.apply(tx).unwrap().remove(0).with_since(chequelock::withdraw_since());
+
+	// The sender can only withdraw once the lock period has passed.
+	let sender_cell = issue(&mut simulator, &sender_lock, 100_000_000_000);
+	let output = ::sdk::Output::new(200_000_000_000, sender_lock, None, Bytes::new());
+	let tx = ::sdk::transaction::transfer(&[cheque.clone(), sender_cell], vec![output]);
+	simulator.advance_epochs(chequelock::WITHDRAW_LOCK_EPOCHS - 1);
+	assert!(matches!(simulator.apply(tx.clone()).unwrap_err(), SimulatorError::ImmatureSince(0)));
+
+	// The lock period ends six epochs after the block the cheque was committed in, so it ends one block into epoch 6.
+	simulator.advance(EPOCH_LENGTH - 2);
+	assert!(matches!(simulator.apply(tx.clone()).unwrap_err(), SimulatorError::ImmatureSince(0)));
+	simulator.advance(1);
+	simulator.apply(tx).unwrap();
+	assert!(!simulator.is_live(&cheque.out_point));
+}
+
+#[test]
+fn test_scenarios_since()
+{
+	let (mut simulator, lock_script) = setup(&[]);
+	let spend = |cell: &::sdk::Cell, since: u64| ::sdk::transaction::transfer(&[cell.clone().with_since(since)], vec![::sdk::Output::new(cell.capacity(), lock_script.clone(), None, Bytes::new())]);
+
+	// An absolute block number is compared with the block the transaction is committed in.
+	let cell = issue(&mut simulator, &lock_script, 100_000_000_000);
+	assert!(matches!(simulator.apply(spend(&cell, 5)).unwrap_err(), SimulatorError::ImmatureSince(0)));
+	simulator.advance(4);
+	let cell = simulator.apply(spend(&cell, 5)).unwrap().remove(0);
+	assert_eq!(simulator.block_number(), 5);
+
+	// A relative block number is counted from the block the cell was committed in.
+	let relative_blocks = 0x8000_0000_0000_0000 | 3;
+	simulator.advance(1);
+	assert!(matches!(simulator.apply(spend(&cell, relative_blocks)).unwrap_err(), SimulatorError::ImmatureSince(0)));
+	simulator.advance(1);
+	let cell = simulator.apply(spend(&cell, relative_blocks)).unwrap().remove(0);
+
+	// A relative timestamp is in seconds.
+	let relative_seconds = 0xC000_0000_0000_0000 | 60;
+	simulator.advance(6);
+	assert!(matches!(simulator.apply(spend(&cell, relative_seconds)).unwrap_err(), SimulatorError::ImmatureSince(0)));
+	simulator.advance(1);
+	let cell = simulator.apply(spend(&cell, relative_seconds)).unwrap().remove(0);
+
+	// Reserved flags and malformed epochs are invalid.
+	assert!(matches!(simulator.apply(spend(&cell, 0x0100_0000_0000_0000)).unwrap_err(), SimulatorError::InvalidSince(0)));
+	assert!(matches!(simulator.apply(spend(&cell, 0x2000_0000_0000_0000 | (1 << 40) | (2 << 24))).unwrap_err(), SimulatorError::InvalidSince(0)));
+}
+
+#[test]
+fn test_scenarios_rollback()
+{
+	let (mut simulator, lock_script) = setup(&[0]);
+	let other_lock = lock_script.clone().as_builder().args(Bytes::from(vec![1]).pack()).build();
+	let out_point_counter = simulator.deploy(Loader::default().load_binary("counter"));
+	let counter_script = simulator.build_script(&out_point_counter, Bytes::new());
+	let tx = simulator.fund(counter::create(lock_script.clone(), counter_script, &counter::Data { value: 0 }), lock_script.clone());
+	let cell = simulator.apply(tx).unwrap().remove(0);
+
+	// Apply a transfer and an empty epoch, then undo them.
+	let snapshot = simulator.snapshot();
+	let transferred = simulator.apply(counter::transfer(&cell, lock_script).unwrap()).unwrap().remove(0);
+	simulator.advance_epochs(1);
+	simulator.rollback(&snapshot);
+	assert_eq!(simulator.block_number(), 1);
+	assert!(simulator.is_live(&cell.out_point));
+	assert!(!simulator.is_live(&transferred.out_point));
+
+	// A different history can be applied from the same state.
+	let transferred = simulator.apply(counter::transfer(&cell, other_lock.clone()).unwrap()).unwrap().remove(0);
+	assert_eq!(transferred.output.lock(), other_lock);
+	assert_eq!(simulator.live_cell(&transferred.out_point), Some(transferred));
+}
+
+#[test]
+fn test_scenarios_block()
+{
+	let (mut simulator, lock_script) = setup(&[]);
+	let out_point_counter = simulator.deploy(Loader::default().load_binary("counter"));
+	let counter_script = simulator.build_script(&out_point_counter, Bytes::new());
+
+	// A transaction can spend an output of an earlier transaction in the same block.
+	let create_tx = simulator.fund(counter::create(lock_script.clone(), counter_script, &counter::Data { value: 0 }), lock_script.clone());
+	let create_tx = simulator.complete_tx(create_tx);
+	let cell = ::sdk::Cell::new(OutPoint::new(create_tx.hash(), 0), create_tx.output(0).unwrap(), create_tx.outputs_data().get(0).unwrap().unpack());
+	let transfer_tx = counter::transfer(&cell, lock_script.clone()).unwrap();
+	let outputs = simulator.apply_block(vec![create_tx.clone(), transfer_tx.clone()]).unwrap();
+	assert_eq!(simulator.block_number(), 1);
+	assert!(!simulator.is_live(&cell.out_point));
+	assert!(simulator.is_live(&outputs[1][0].out_point));
+
+	// A block with an invalid transaction is rejected as a whole.
+	let next_tx = counter::transfer(&outputs[1][0], lock_script).unwrap();
+	let err = simulator.apply_block(vec![next_tx.clone(), transfer_tx]).unwrap_err();
+	assert!(matches!(err, SimulatorError::DeadCell(out_point) if out_point == cell.out_point));
+	assert_eq!(simulator.block_number(), 1);
+	assert!(simulator.is_live(&outputs[1][0].out_point));
+	simulator.apply(next_tx).unwrap();
+}
+
+#[test]
+fn test_scenarios_invalid()
+{
+	let (mut simulator, lock_script) = setup(&[]);
+	let cell = issue(&mut simulator, &lock_script, 100_000_000_000);
+	let output = |capacity: u64| ::sdk::Output::new(capacity, lock_script.clone(), None, Bytes::new());
+
+	// The outputs cannot be worth more than the inputs.
+	let err = simulator.apply(::sdk::transaction::transfer(slice::from_ref(&cell), vec![output(100_000_000_001)])).unwrap_err();
+	assert!(matches!(err, SimulatorError::InsufficientCapacity { inputs: 100_000_000_000, outputs: 100_000_000_001 }));
+
+	// Each output must be able to hold its lock.
+	let err = simulator.apply(::sdk::transaction::transfer(slice::from_ref(&cell), vec![output(99_000_000_000), output(1_000_000_000)])).unwrap_err();
+	assert!(matches!(err, SimulatorError::InsufficientCellCapacity(1)));
+
+	// A cell can only be spent once.
+	let err = simulator.apply(::sdk::transaction::transfer(&[cell.clone(), cell.clone()], vec![output(100_000_000_000)])).unwrap_err();
+	assert!(matches!(err, SimulatorError::DuplicateInput(_)));
+
+	// A transaction must have inputs and outputs.
+	assert!(matches!(simulator.apply(::sdk::transaction::create(vec![output(100_000_000_000)])).unwrap_err(), SimulatorError::Empty));
+	assert!(matches!(simulator.apply(::sdk::transaction::burn(slice::from_ref(&cell))).unwrap_err(), SimulatorError::Empty));
+
+	// Header deps must be blocks in the chain.
+	let tx = ::sdk::transaction::transfer(slice::from_ref(&cell), vec![output(100_000_000_000)]);
+	let err = simulator.apply(tx.as_advanced_builder().header_dep(Byte32::zero()).build()).unwrap_err();
+	assert!(matches!(err, SimulatorError::UnknownHeader(_)));
+	let tx = tx.as_advanced_builder().header_dep(simulator.tip().hash()).build();
+	simulator.apply(tx).unwrap();
+	assert_eq!(simulator.block_number(), 1);
+}
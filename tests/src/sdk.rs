@@ -0,0 +1,812 @@
+use super::*;
+use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::{HeaderBuilder, HeaderView, TransactionView};
+use ::sdk::{acplock, aggdoublecounter, cellcount, chequelock, counter, daowrapper, data10, datacap, datarange, dataratio, doublecounter, escrowlock, hashlock, icclock, jsoncell};
+use ::sdk::{lockproxylock, nft, occlock, odcounter, oddoublecounter, oracle, oraclelock, orderlock, ratelimitlock, sudt, timelock, typeid, typeproxylock, vestinglock};
+use ::sdk::{Encoding, Error as SdkError};
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+
+// Error Codes
+const ERROR_DATARANGE_DATA_MINIMUM_NOT_MET: i8 = 7;
+const ERROR_HASHLOCK_UNAUTHORIZED: i8 = 5;
+const ERROR_LOCKPROXYLOCK_UNAUTHORIZED: i8 = 6;
+const ERROR_SUDT_AMOUNT: i8 = 5;
+
+/// Deploy the always success lock and the contract, returning the context, a lock script, and the contract out point.
+fn setup(name: &str) -> (Context, Script, OutPoint)
+{
+	let mut context = Context::default();
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_contract = context.deploy_cell(Loader::default().load_binary(name));
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	(context, lock_script, out_point_contract)
+}
+
+/// Verify the transaction and add its outputs to the context as live cells so they can be consumed by another transaction.
+fn commit(context: &mut Context, tx: TransactionView) -> Vec<::sdk::Cell>
+{
+	let tx = context.complete_tx(tx);
+	context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+
+	let mut cells = vec![];
+	for (i, (output, data)) in tx.outputs_with_data_iter().enumerate()
+	{
+		let out_point = OutPoint::new(tx.hash(), i as u32);
+		context.create_cell_with_out_point(out_point.clone(), output.clone(), data.clone());
+		cells.push(::sdk::Cell::new(out_point, output, data));
+	}
+
+	cells
+}
+
+/// Create a live cell which is not from a transaction, such as the cell of a party or a cell to use as the first input.
+fn live_cell(context: &mut Context, lock: Script, type_script: Option<Script>, capacity: u64, data: Bytes) -> ::sdk::Cell
+{
+	let output = CellOutput::new_builder().capacity(capacity.pack()).lock(lock).type_(type_script.pack()).build();
+	let out_point = context.create_cell(output.clone(), data.clone());
+
+	::sdk::Cell::new(out_point, output, data)
+}
+
+/// Build an always success script which is distinguished by the id in its args, for use as the lock or type of a party.
+fn party_lock(context: &mut Context, id: u8) -> Script
+{
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+	context.build_script(&out_point_always_success, Bytes::from(vec![id])).expect("script")
+}
+
+/// Build a header with the block number and Nervos DAO accumulated rate (AR).
+fn dao_header(number: u64, accumulated_rate: u64) -> HeaderView
+{
+	let mut dao = [0u8; 32];
+	dao[8..16].copy_from_slice(&accumulated_rate.to_le_bytes());
+
+	HeaderBuilder::default().number(number.pack()).dao(dao.pack()).build()
+}
+
+#[test]
+fn test_sdk_counter()
+{
+	let (mut context, lock_script, out_point_counter) = setup("counter");
+	let type_script = context.build_script(&out_point_counter, Default::default()).expect("script");
+
+	// Create a counter and increment it twice.
+	let cells = commit(&mut context, counter::create(lock_script.clone(), type_script, &counter::Data { value: 5 }));
+	let cells = commit(&mut context, counter::transfer(&cells[0], lock_script.clone()).unwrap());
+	let cells = commit(&mut context, counter::transfer(&cells[0], lock_script.clone()).unwrap());
+	assert_eq!(counter::Data::decode(&cells[0].data).unwrap(), counter::Data { value: 7 });
+	assert_eq!(cells[0].capacity(), ::sdk::Output::occupied(lock_script.clone(), cells[0].output.type_().to_opt(), cells[0].data.clone()).capacity());
+
+	// A counter at the maximum value cannot be incremented.
+	let cell = ::sdk::Cell { data: counter::Data { value: u64::MAX }.encode(), ..cells[0].clone() };
+	assert!(matches!(counter::transfer(&cell, lock_script), Err(SdkError::InvalidValue(_))));
+}
+
+#[test]
+fn test_sdk_doublecounter()
+{
+	let (mut context, lock_script, out_point_doublecounter) = setup("doublecounter");
+	let type_script = context.build_script(&out_point_doublecounter, Default::default()).expect("script");
+
+	let cells = commit(&mut context, doublecounter::create(lock_script.clone(), type_script, &doublecounter::Data { value_1: 1, value_2: 2 }));
+	let cells = commit(&mut context, doublecounter::transfer(&cells[0], lock_script).unwrap());
+	assert_eq!(doublecounter::Data::decode(&cells[0].data).unwrap(), doublecounter::Data { value_1: 2, value_2: 4 });
+}
+
+#[test]
+fn test_sdk_aggcounter()
+{
+	let (mut context, lock_script, out_point_aggcounter) = setup("aggcounter");
+	let type_script = context.build_script(&out_point_aggcounter, Default::default()).expect("script");
+
+	// Create two counters and increment them in a single transaction.
+	let mut cells = commit(&mut context, ::sdk::aggcounter::create(lock_script.clone(), type_script.clone(), &::sdk::aggcounter::Data { value: 0 }));
+	cells.extend(commit(&mut context, ::sdk::aggcounter::create(lock_script.clone(), type_script, &::sdk::aggcounter::Data { value: 10 })));
+	let cells = commit(&mut context, ::sdk::aggcounter::transfer(&cells, lock_script).unwrap());
+	let values: Vec<u64> = cells.iter().map(|cell| ::sdk::aggcounter::Data::decode(&cell.data).unwrap().value).collect();
+	assert_eq!(values, vec![1, 11]);
+}
+
+#[test]
+fn test_sdk_odcounter()
+{
+	let (mut context, lock_script, out_point_odcounter) = setup("odcounter");
+	let type_script = context.build_script(&out_point_odcounter, Default::default()).expect("script");
+
+	// Create, increment, and burn a counter.
+	let cells = commit(&mut context, odcounter::create(lock_script.clone(), type_script));
+	assert_eq!(odcounter::Data::decode(&cells[0].data).unwrap(), odcounter::Data { value: 0 });
+	let cells = commit(&mut context, odcounter::transfer(&cells[0], lock_script).unwrap());
+	assert_eq!(odcounter::Data::decode(&cells[0].data).unwrap(), odcounter::Data { value: 1 });
+	let cells = commit(&mut context, odcounter::burn(&cells[0]));
+	assert!(cells.is_empty());
+}
+
+#[test]
+fn test_sdk_datarange()
+{
+	let (mut context, lock_script, out_point_datarange) = setup("datarange");
+	let args = datarange::Args { minimum: 2, limit: 4 };
+	let type_script = context.build_script(&out_point_datarange, args.encode()).expect("script");
+
+	// Data within the range can be created and replaced.
+	let cells = commit(&mut context, datarange::create(lock_script.clone(), type_script.clone(), Bytes::from(vec![1u8; 2])).unwrap());
+	let cells = commit(&mut context, datarange::transfer(&cells[0], lock_script.clone(), Bytes::from(vec![1u8; 4])).unwrap());
+	commit(&mut context, datarange::burn(&cells[0]));
+
+	// Data outside the range is rejected before the transaction is built.
+	assert_eq!(datarange::create(lock_script.clone(), type_script.clone(), Bytes::from(vec![1u8; 5])).unwrap_err(), SdkError::InvalidLength(5));
+	assert_eq!(datarange::transfer(&cells[0], lock_script.clone(), Bytes::from(vec![1u8; 1])).unwrap_err(), SdkError::InvalidLength(1));
+
+	// The same data is rejected by the contract.
+	let tx = ::sdk::transaction::create(vec![::sdk::Output::occupied(lock_script, Some(type_script), Bytes::from(vec![1u8; 1]))]);
+	let tx = context.complete_tx(tx);
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_DATARANGE_DATA_MINIMUM_NOT_MET);
+}
+
+#[test]
+fn test_sdk_occlock()
+{
+	let (mut context, lock_script, out_point_occlock) = setup("occlock");
+	let args = occlock::Args { amount: 100_000_000_000, count: 3 };
+	let occlock_script = context.build_script(&out_point_occlock, args.encode()).expect("script");
+
+	// Lock capacity and unlock it by creating the required outputs.
+	let cells = commit(&mut context, occlock::create(occlock_script, 300_000_000_000));
+	let cells = commit(&mut context, occlock::transfer(&cells[0], lock_script).unwrap());
+	assert_eq!(cells.len(), 3);
+	assert!(cells.iter().all(|cell| cell.capacity() == args.amount));
+}
+
+#[test]
+fn test_sdk_hashlock()
+{
+	let (mut context, lock_script, out_point_hashlock) = setup("hashlock");
+	let preimage = Bytes::from("the secret preimage");
+	let hashlock_script = context.build_script(&out_point_hashlock, hashlock::Args::from_preimage(&preimage).encode()).expect("script");
+
+	// Unlock with the correct preimage.
+	let cells = commit(&mut context, hashlock::create(hashlock_script.clone(), 100_000_000_000));
+	commit(&mut context, hashlock::transfer(&cells[0], lock_script.clone(), preimage).unwrap());
+
+	// Unlock with an incorrect preimage.
+	let cells = commit(&mut context, hashlock::create(hashlock_script, 100_000_000_000));
+	let tx = context.complete_tx(hashlock::transfer(&cells[0], lock_script.clone(), Bytes::from("the wrong preimage")).unwrap());
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_HASHLOCK_UNAUTHORIZED);
+
+	// A preimage which is too large to load from the witness.
+	assert_eq!(hashlock::transfer(&cells[0], lock_script, Bytes::from(vec![0u8; 1025])).unwrap_err(), SdkError::InvalidLength(1025));
+}
+
+#[test]
+fn test_sdk_sudt()
+{
+	let (mut context, owner_lock_script, out_point_sudt) = setup("sudt");
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let other_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let args = sudt::Args { owner_lock_hash: ::sdk::script_hash(&owner_lock_script) };
+	let type_script = context.build_script(&out_point_sudt, args.encode()).expect("script");
+
+	// Mint tokens using an owner cell, which is recreated unchanged.
+	let owner_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(owner_lock_script.clone()).build(), Bytes::new());
+	let (owner_output, owner_data) = context.get_cell(&owner_out_point).unwrap();
+	let owner = ::sdk::Cell::new(owner_out_point, owner_output, owner_data);
+	let cells = commit(&mut context, sudt::mint(&owner, type_script.clone(), vec![(other_lock_script.clone(), 600), (other_lock_script.clone(), 400)]));
+	assert_eq!(cells[2].output, owner.output);
+	let token_cells = &cells[0..2];
+
+	// Transfer some of the tokens, returning the rest to the lock of the first cell.
+	let cells = commit(&mut context, sudt::transfer(token_cells, vec![(owner_lock_script.clone(), 250)]).unwrap());
+	let amounts: Vec<u128> = cells.iter().map(|cell| sudt::Data::decode(&cell.data).unwrap().amount).collect();
+	assert_eq!(amounts, vec![250, 750]);
+	assert_eq!(cells[1].output.lock(), other_lock_script);
+
+	// Burn some of the tokens, then all of them.
+	let cells = commit(&mut context, sudt::burn(&cells[1..2], 700).unwrap());
+	assert_eq!(sudt::Data::decode(&cells[0].data).unwrap().amount, 50);
+	let cells = commit(&mut context, sudt::burn(&cells, 50).unwrap());
+	assert!(cells.is_empty());
+
+	// More tokens than are available cannot be transferred or burned.
+	let cells = commit(&mut context, sudt::mint(&owner, type_script.clone(), vec![(other_lock_script.clone(), 100)]));
+	assert_eq!(sudt::transfer(&cells[0..1], vec![(owner_lock_script.clone(), 101)]).unwrap_err(), SdkError::InsufficientAmount(1));
+	assert_eq!(sudt::burn(&cells[0..1], 150).unwrap_err(), SdkError::InsufficientAmount(50));
+
+	// Minting without the owner cell is rejected by the contract.
+	let tx = ::sdk::transaction::create(vec![::sdk::Output::occupied(other_lock_script, Some(type_script), sudt::Data { amount: 100 }.encode())]);
+	let tx = context.complete_tx(tx);
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_SUDT_AMOUNT);
+}
+
+#[test]
+fn test_sdk_typeid()
+{
+	let (mut context, lock_script, out_point_typeid) = setup("typeid");
+
+	// The Type ID is calculated from the first input of the transaction.
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let (input_output, input_data) = context.get_cell(&input_out_point).unwrap();
+	let input = ::sdk::Cell::new(input_out_point, input_output, input_data);
+	let args = typeid::Args::new(&input.input(), 1);
+	let type_script = context.build_script(&out_point_typeid, args.encode()).expect("script");
+	let outputs = vec![::sdk::Output::occupied(lock_script.clone(), None, Bytes::new()), ::sdk::Output::occupied(lock_script, Some(type_script), Bytes::new())];
+	commit(&mut context, ::sdk::transaction::transfer(&[input], outputs));
+}
+
+#[test]
+fn test_sdk_aggdoublecounter()
+{
+	let (mut context, lock_script, out_point_aggdoublecounter) = setup("aggdoublecounter");
+	let type_script = context.build_script(&out_point_aggdoublecounter, Default::default()).expect("script");
+
+	// Create two double counters and increment them in a single transaction.
+	let mut cells = commit(&mut context, aggdoublecounter::create(lock_script.clone(), type_script.clone(), &aggdoublecounter::Data { value_1: 0, value_2: 0 }));
+	cells.extend(commit(&mut context, aggdoublecounter::create(lock_script.clone(), type_script, &aggdoublecounter::Data { value_1: 5, value_2: 10 })));
+	let cells = commit(&mut context, aggdoublecounter::transfer(&cells, lock_script).unwrap());
+	let values: Vec<aggdoublecounter::Data> = cells.iter().map(|cell| aggdoublecounter::Data::decode(&cell.data).unwrap()).collect();
+	assert_eq!(values, vec![aggdoublecounter::Data { value_1: 1, value_2: 2 }, aggdoublecounter::Data { value_1: 6, value_2: 12 }]);
+}
+
+#[test]
+fn test_sdk_oddoublecounter()
+{
+	let (mut context, lock_script, out_point_oddoublecounter) = setup("oddoublecounter");
+	let type_script = context.build_script(&out_point_oddoublecounter, Default::default()).expect("script");
+
+	// Create, increment, and burn a double counter.
+	let cells = commit(&mut context, oddoublecounter::create(lock_script.clone(), type_script));
+	let cells = commit(&mut context, oddoublecounter::transfer(&cells[0], lock_script).unwrap());
+	assert_eq!(oddoublecounter::Data::decode(&cells[0].data).unwrap(), oddoublecounter::Data { value_1: 1, value_2: 2 });
+	let cells = commit(&mut context, oddoublecounter::burn(&cells[0]));
+	assert!(cells.is_empty());
+}
+
+#[test]
+fn test_sdk_cellcount()
+{
+	let (mut context, lock_script, out_point_cellcount) = setup("cellcount");
+	let args = cellcount::Args { source: cellcount::Source::GroupOutput, condition: cellcount::Condition::LessThanOrEqual(3) };
+	let type_script = context.build_script(&out_point_cellcount, args.encode()).expect("script");
+
+	// Create the maximum number of cells, then burn them.
+	let cells = commit(&mut context, cellcount::create(lock_script.clone(), type_script.clone(), 3).unwrap());
+	assert_eq!(cells.len(), 3);
+	commit(&mut context, cellcount::burn(&cells).unwrap());
+
+	// A count which does not satisfy the condition is rejected before the transaction is built.
+	assert!(matches!(cellcount::create(lock_script, type_script, 4), Err(SdkError::InvalidValue(_))));
+}
+
+#[test]
+fn test_sdk_datacap()
+{
+	let (mut context, lock_script, out_point_datacap) = setup("datacap");
+	let type_script = context.build_script(&out_point_datacap, datacap::Args { limit: 4 }.encode()).expect("script");
+
+	// Data within the limit can be created, replaced, and burned.
+	let cells = commit(&mut context, datacap::create(lock_script.clone(), type_script.clone(), Bytes::from(vec![1u8; 4])).unwrap());
+	let cells = commit(&mut context, datacap::transfer(&cells[0], lock_script.clone(), Bytes::from(vec![1u8; 2])).unwrap());
+	commit(&mut context, datacap::burn(&cells[0]));
+
+	// Data over the limit is rejected before the transaction is built.
+	assert_eq!(datacap::create(lock_script, type_script, Bytes::from(vec![1u8; 5])).unwrap_err(), SdkError::InvalidLength(5));
+}
+
+#[test]
+fn test_sdk_data10()
+{
+	let (mut context, lock_script, out_point_data10) = setup("data10");
+	let type_script = context.build_script(&out_point_data10, Bytes::new()).expect("script");
+
+	// Without args the limit is ten bytes.
+	let cells = commit(&mut context, data10::create(lock_script.clone(), type_script, Bytes::from(vec![1u8; 10])).unwrap());
+	assert_eq!(data10::transfer(&cells[0], lock_script.clone(), Bytes::from(vec![1u8; 11])).unwrap_err(), SdkError::InvalidLength(11));
+	let cells = commit(&mut context, data10::transfer(&cells[0], lock_script, Bytes::from(vec![1u8; 5])).unwrap());
+	commit(&mut context, data10::burn(&cells[0]));
+}
+
+#[test]
+fn test_sdk_dataratio()
+{
+	let (mut context, lock_script, out_point_dataratio) = setup("dataratio");
+	let type_script = context.build_script(&out_point_dataratio, dataratio::Args { bytes_per_ckbyte: 10, mode: None }.encode()).expect("script");
+
+	// The cell is created with enough free capacity for the data, rounded up to whole CKBytes.
+	let cells = commit(&mut context, dataratio::create(lock_script.clone(), type_script.clone(), Bytes::from(vec![1u8; 25])).unwrap());
+	let occupied = ::sdk::Output::occupied(lock_script.clone(), Some(type_script), cells[0].data.clone()).capacity();
+	assert_eq!(cells[0].capacity(), occupied + 300_000_000);
+
+	// Smaller data frees capacity, while larger data occupies more of it.
+	let cells = commit(&mut context, dataratio::transfer(&cells[0], lock_script.clone(), Bytes::from(vec![1u8; 20])).unwrap());
+	assert_eq!(dataratio::transfer(&cells[0], lock_script.clone(), Bytes::from(vec![1u8; 26])).unwrap_err(), SdkError::InvalidLength(26));
+	let cells = commit(&mut context, dataratio::transfer(&cells[0], lock_script, Bytes::from(vec![1u8; 25])).unwrap());
+	commit(&mut context, dataratio::burn(&cells[0]));
+}
+
+#[test]
+fn test_sdk_jsoncell()
+{
+	let (mut context, lock_script, out_point_jsoncell) = setup("jsoncell");
+	let type_script = context.build_script(&out_point_jsoncell, Bytes::new()).expect("script");
+
+	let cells = commit(&mut context, jsoncell::create(lock_script.clone(), type_script, &jsoncell::Data { json: "{\"value\":1}".to_string() }));
+	let cells = commit(&mut context, jsoncell::transfer(&cells[0], lock_script, &jsoncell::Data { json: "[1,2,3]".to_string() }));
+	assert_eq!(jsoncell::Data::decode(&cells[0].data).unwrap().json, "[1,2,3]");
+	commit(&mut context, jsoncell::burn(&cells[0]));
+}
+
+#[test]
+fn test_sdk_icclock()
+{
+	let (mut context, lock_script, out_point_icclock) = setup("icclock");
+	let icclock_script = context.build_script(&out_point_icclock, icclock::Args { amount: 100_000_000_000 }.encode()).expect("script");
+
+	// A cell with the required capacity unlocks itself.
+	let cells = commit(&mut context, icclock::create(icclock_script.clone(), 100_000_000_000));
+	commit(&mut context, icclock::transfer(&cells[0], None, lock_script.clone()).unwrap());
+
+	// Any other cell requires a key cell with the required capacity.
+	let cells = commit(&mut context, icclock::create(icclock_script, 200_000_000_000));
+	assert!(matches!(icclock::transfer(&cells[0], None, lock_script.clone()), Err(SdkError::InvalidValue(_))));
+	let key = live_cell(&mut context, lock_script.clone(), None, 100_000_000_000, Bytes::new());
+	let cells = commit(&mut context, icclock::transfer(&cells[0], Some(&key), lock_script.clone()).unwrap());
+	assert_eq!(cells[0].output.lock(), lock_script);
+	assert_eq!(cells[1].output, key.output);
+}
+
+#[test]
+fn test_sdk_timelock()
+{
+	let (mut context, lock_script, out_point_timelock) = setup("timelock");
+	let delegate_lock_script = party_lock(&mut context, 1);
+	let args = timelock::Args { delegate_lock_hash: ::sdk::script_hash(&delegate_lock_script), threshold: 100 };
+	let timelock_script = context.build_script(&out_point_timelock, args.encode()).expect("script");
+
+	// The delegate unlocks the cell once the threshold is reached.
+	let cells = commit(&mut context, timelock::create(timelock_script, 100_000_000_000));
+	let delegate = live_cell(&mut context, delegate_lock_script, None, 100_000_000_000, Bytes::new());
+	let tx = timelock::transfer(&cells[0], &delegate, lock_script.clone()).unwrap();
+	let since: u64 = tx.inputs().get(0).unwrap().since().unpack();
+	assert_eq!(since, 100u64);
+	commit(&mut context, tx);
+
+	// Only the delegate can unlock the cell.
+	let other = live_cell(&mut context, lock_script.clone(), None, 100_000_000_000, Bytes::new());
+	assert!(matches!(timelock::transfer(&cells[0], &other, lock_script), Err(SdkError::InvalidValue(_))));
+}
+
+#[test]
+fn test_sdk_lockproxylock()
+{
+	let (mut context, lock_script, out_point_lockproxylock) = setup("lockproxylock");
+	let owner_lock_script = party_lock(&mut context, 1);
+	let args = lockproxylock::Args { owner_lock_hash: ::sdk::script_hash(&owner_lock_script) };
+	let lockproxylock_script = context.build_script(&out_point_lockproxylock, args.encode()).expect("script");
+
+	// The owner cell unlocks the cell and is recreated unchanged.
+	let cells = commit(&mut context, lockproxylock::create(lockproxylock_script.clone(), 100_000_000_000));
+	let owner = live_cell(&mut context, owner_lock_script, None, 100_000_000_000, Bytes::new());
+	let outputs = commit(&mut context, lockproxylock::transfer(&cells[0], &owner, lock_script.clone()).unwrap());
+	assert_eq!(outputs[1].output, owner.output);
+
+	// Another cell cannot be used as the owner.
+	let cells = commit(&mut context, lockproxylock::create(lockproxylock_script, 100_000_000_000));
+	let other = live_cell(&mut context, lock_script.clone(), None, 100_000_000_000, Bytes::new());
+	assert!(matches!(lockproxylock::transfer(&cells[0], &other, lock_script.clone()), Err(SdkError::InvalidValue(_))));
+
+	// The contract rejects the transfer without the owner cell.
+	let tx = ::sdk::transaction::transfer(&cells, vec![::sdk::Output::new(100_000_000_000, lock_script, None, Bytes::new())]);
+	let tx = context.complete_tx(tx);
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_LOCKPROXYLOCK_UNAUTHORIZED);
+}
+
+#[test]
+fn test_sdk_typeproxylock()
+{
+	let (mut context, lock_script, out_point_typeproxylock) = setup("typeproxylock");
+	let owner_type_script = party_lock(&mut context, 1);
+	let args = typeproxylock::Args { owner_type_hash: ::sdk::script_hash(&owner_type_script) };
+	let typeproxylock_script = context.build_script(&out_point_typeproxylock, args.encode()).expect("script");
+
+	// A cell with the owner type unlocks the cell and is recreated unchanged.
+	let cells = commit(&mut context, typeproxylock::create(typeproxylock_script.clone(), 100_000_000_000));
+	let owner = live_cell(&mut context, lock_script.clone(), Some(owner_type_script), 100_000_000_000, Bytes::new());
+	let outputs = commit(&mut context, typeproxylock::transfer(&cells[0], &owner, lock_script.clone()).unwrap());
+	assert_eq!(outputs[1].output, owner.output);
+
+	// A cell without the owner type cannot unlock the cell.
+	let other = live_cell(&mut context, lock_script.clone(), None, 100_000_000_000, Bytes::new());
+	let cells = commit(&mut context, typeproxylock::create(typeproxylock_script, 100_000_000_000));
+	assert!(matches!(typeproxylock::transfer(&cells[0], &other, lock_script), Err(SdkError::InvalidValue(_))));
+}
+
+#[test]
+fn test_sdk_acplock()
+{
+	let (mut context, lock_script, out_point_acplock) = setup("acplock");
+	let owner_lock_script = party_lock(&mut context, 1);
+	let args = acplock::Args { owner_lock_hash: ::sdk::script_hash(&owner_lock_script), minimum_ckb: Some(100_000_000), minimum_sudt: None };
+	let acplock_script = context.build_script(&out_point_acplock, args.encode()).expect("script");
+
+	// Anyone can pay at least the minimum to the cell.
+	let cells = commit(&mut context, acplock::create(acplock_script, 100_000_000_000, None));
+	let cells = commit(&mut context, acplock::pay(&cells[0], 100_000_000, 0).unwrap());
+	assert_eq!(cells[0].capacity(), 100_100_000_000);
+
+	// Payments under the minimum, or of tokens to a cell without a token type, are rejected.
+	assert!(matches!(acplock::pay(&cells[0], 99_999_999, 0), Err(SdkError::InvalidValue(_))));
+	assert!(matches!(acplock::pay(&cells[0], 100_000_000, 5), Err(SdkError::InvalidValue(_))));
+
+	// The owner withdraws the cell.
+	let owner = live_cell(&mut context, owner_lock_script, None, 100_000_000_000, Bytes::new());
+	let cells = commit(&mut context, acplock::withdraw(&cells[0], &owner, lock_script.clone()).unwrap());
+	assert_eq!(cells[0].output.lock(), lock_script);
+}
+
+#[test]
+fn test_sdk_chequelock()
+{
+	let (mut context, lock_script, out_point_chequelock) = setup("chequelock");
+	let receiver_lock_script = party_lock(&mut context, 1);
+	let sender_lock_script = party_lock(&mut context, 2);
+	let args = chequelock::Args { receiver_lock_hash_prefix: ::sdk::script_hash(&receiver_lock_script)[0..20].try_into().unwrap(), sender_lock_hash_prefix: ::sdk::script_hash(&sender_lock_script)[0..20].try_into().unwrap() };
+	let chequelock_script = context.build_script(&out_point_chequelock, args.encode()).expect("script");
+	let receiver = live_cell(&mut context, receiver_lock_script, None, 100_000_000_000, Bytes::new());
+	let sender = live_cell(&mut context, sender_lock_script, None, 100_000_000_000, Bytes::new());
+
+	// The receiver claims a cheque.
+	let cells = commit(&mut context, chequelock::create(chequelock_script.clone(), 100_000_000_000, None));
+	assert!(matches!(chequelock::claim(&cells[0], &sender, lock_script.clone()), Err(SdkError::InvalidValue(_))));
+	commit(&mut context, chequelock::claim(&cells[0], &receiver, lock_script.clone()).unwrap());
+
+	// The sender withdraws a cheque after the lock period.
+	let cells = commit(&mut context, chequelock::create(chequelock_script, 100_000_000_000, None));
+	let tx = chequelock::withdraw(&cells[0], &sender, lock_script).unwrap();
+	let since: u64 = tx.inputs().get(0).unwrap().since().unpack();
+	assert_eq!(since, chequelock::withdraw_since());
+	commit(&mut context, tx);
+}
+
+#[test]
+fn test_sdk_escrowlock()
+{
+	let (mut context, lock_script, out_point_escrowlock) = setup("escrowlock");
+	let buyer_lock_script = party_lock(&mut context, 1);
+	let seller_lock_script = party_lock(&mut context, 2);
+	let arbiter_lock_script = party_lock(&mut context, 3);
+	let release_lock_script = party_lock(&mut context, 4);
+	let args = escrowlock::Args
+	{
+		buyer_lock_hash: ::sdk::script_hash(&buyer_lock_script),
+		seller_lock_hash: ::sdk::script_hash(&seller_lock_script),
+		arbiter_lock_hash: ::sdk::script_hash(&arbiter_lock_script),
+		release_lock_hash: ::sdk::script_hash(&release_lock_script),
+		timeout: 100,
+	};
+	let escrowlock_script = context.build_script(&out_point_escrowlock, args.encode()).expect("script");
+	let buyer = live_cell(&mut context, buyer_lock_script.clone(), None, 100_000_000_000, Bytes::new());
+	let seller = live_cell(&mut context, seller_lock_script, None, 100_000_000_000, Bytes::new());
+
+	// Two parties release two escrow cells, each paid out at its own index.
+	let mut cells = commit(&mut context, escrowlock::create(escrowlock_script.clone(), 100_000_000_000));
+	cells.extend(commit(&mut context, escrowlock::create(escrowlock_script.clone(), 200_000_000_000)));
+	assert!(matches!(escrowlock::release(&cells, std::slice::from_ref(&buyer), release_lock_script.clone()), Err(SdkError::InvalidValue(_))));
+	let outputs = commit(&mut context, escrowlock::release(&cells, &[buyer, seller], release_lock_script.clone()).unwrap());
+	assert_eq!(outputs[0].capacity(), 100_000_000_000);
+	assert_eq!(outputs[1].capacity(), 200_000_000_000);
+	assert_eq!(outputs[1].output.lock(), release_lock_script);
+
+	// The buyer is refunded after the timeout.
+	let cells = commit(&mut context, escrowlock::create(escrowlock_script, 100_000_000_000));
+	assert!(matches!(escrowlock::refund(&cells, lock_script), Err(SdkError::InvalidValue(_))));
+	let outputs = commit(&mut context, escrowlock::refund(&cells, buyer_lock_script.clone()).unwrap());
+	assert_eq!(outputs[0].output.lock(), buyer_lock_script);
+}
+
+#[test]
+fn test_sdk_orderlock()
+{
+	let (mut context, lock_script, out_point_orderlock) = setup("orderlock");
+	let maker_lock_script = party_lock(&mut context, 1);
+	let taker_lock_script = party_lock(&mut context, 2);
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+	let token_type_script = context.build_script(&out_point_sudt, sudt::Args { owner_lock_hash: ::sdk::script_hash(&lock_script) }.encode()).expect("script");
+	let args = orderlock::Args { maker_lock_hash: ::sdk::script_hash(&maker_lock_script), rate_ckb: 100_000_000, rate_sudt: 1 };
+	let orderlock_script = context.build_script(&out_point_orderlock, args.encode()).expect("script");
+
+	// Mint tokens to an order cell selling 100 tokens for one CKByte each.
+	let owner = live_cell(&mut context, lock_script.clone(), None, 100_000_000_000, Bytes::new());
+	let cells = commit(&mut context, sudt::mint(&owner, token_type_script.clone(), vec![(orderlock_script.clone(), 100)]));
+	let order = cells[0].clone();
+
+	// Amounts which cannot be bought.
+	assert!(matches!(orderlock::fill(&order, 0, maker_lock_script.clone(), taker_lock_script.clone()), Err(SdkError::InvalidValue(_))));
+	assert_eq!(orderlock::fill(&order, 101, maker_lock_script.clone(), taker_lock_script.clone()).unwrap_err(), SdkError::InsufficientAmount(1));
+
+	// A partial fill leaves the rest of the tokens in the order, and a full fill returns its capacity to the maker.
+	let cells = commit(&mut context, orderlock::fill(&order, 40, maker_lock_script.clone(), taker_lock_script.clone()).unwrap());
+	assert_eq!(cells[0].capacity(), 4_000_000_000);
+	assert_eq!(sudt::Data::decode(&cells[1].data).unwrap().amount, 60);
+	assert_eq!(sudt::Data::decode(&cells[2].data).unwrap().amount, 40);
+	let cells = commit(&mut context, orderlock::fill(&cells[1], 60, maker_lock_script.clone(), taker_lock_script).unwrap());
+	assert_eq!(cells[0].capacity(), 6_000_000_000 + order.capacity());
+	assert_eq!(cells.len(), 2);
+
+	// The maker cancels an order.
+	let cells = commit(&mut context, sudt::mint(&owner, token_type_script, vec![(orderlock_script, 100)]));
+	let maker = live_cell(&mut context, maker_lock_script.clone(), None, 100_000_000_000, Bytes::new());
+	let cells = commit(&mut context, orderlock::cancel(&cells[0], &maker, maker_lock_script.clone()).unwrap());
+	assert_eq!(cells[0].output.lock(), maker_lock_script);
+}
+
+#[test]
+fn test_sdk_ratelimitlock()
+{
+	let (mut context, lock_script, out_point_ratelimitlock) = setup("ratelimitlock");
+	let owner_lock_script = party_lock(&mut context, 1);
+	let args = ratelimitlock::Args { owner_lock_hash: ::sdk::script_hash(&owner_lock_script), limit: 50_000_000_000 };
+	let ratelimitlock_script = context.build_script(&out_point_ratelimitlock, args.encode()).expect("script");
+	let owner = live_cell(&mut context, owner_lock_script, None, 100_000_000_000, Bytes::new());
+
+	// Withdraw up to the limit in the first window, then start a new window.
+	let cells = commit(&mut context, ratelimitlock::create(ratelimitlock_script.clone(), 200_000_000_000));
+	let cells = commit(&mut context, ratelimitlock::withdraw(&cells[0], &owner, 30_000_000_000, false, lock_script.clone()).unwrap());
+	assert_eq!(ratelimitlock::Data::decode(&cells[0].data).unwrap(), ratelimitlock::Data { window: 0, withdrawn: 30_000_000_000 });
+	assert!(matches!(ratelimitlock::withdraw(&cells[0], &owner, 30_000_000_000, false, lock_script.clone()), Err(SdkError::InvalidValue(_))));
+	let tx = ratelimitlock::withdraw(&cells[0], &owner, 30_000_000_000, true, lock_script.clone()).unwrap();
+	let since: u64 = tx.inputs().get(0).unwrap().since().unpack();
+	assert_eq!(since, ratelimitlock::NEW_WINDOW_SINCE);
+	let cells = commit(&mut context, tx);
+	assert_eq!(ratelimitlock::Data::decode(&cells[0].data).unwrap(), ratelimitlock::Data { window: 1, withdrawn: 30_000_000_000 });
+	assert_eq!(cells[0].capacity(), 140_000_000_000);
+
+	// Withdrawing the whole cell does not recreate it.
+	let cells = commit(&mut context, ratelimitlock::create(ratelimitlock_script, 40_000_000_000));
+	let cells = commit(&mut context, ratelimitlock::withdraw(&cells[0], &owner, 40_000_000_000, false, lock_script.clone()).unwrap());
+	assert_eq!(cells[0].output.lock(), lock_script);
+	assert_eq!(cells[1].output, owner.output);
+}
+
+#[test]
+fn test_sdk_vestinglock()
+{
+	let (mut context, lock_script, out_point_vestinglock) = setup("vestinglock");
+	let beneficiary_lock_script = party_lock(&mut context, 1);
+	let args = vestinglock::Args { beneficiary_lock_hash: ::sdk::script_hash(&beneficiary_lock_script), start_epoch: 100, cliff: 10, duration: 100, total_amount: 100_000_000_000 };
+	let vestinglock_script = context.build_script(&out_point_vestinglock, args.encode()).expect("script");
+	let beneficiary = live_cell(&mut context, beneficiary_lock_script, None, 100_000_000_000, Bytes::new());
+
+	// Half is vested halfway through the duration, and the rest remains locked.
+	let cells = commit(&mut context, vestinglock::create(vestinglock_script.clone(), 100_000_000_000, None));
+	assert!(matches!(vestinglock::withdraw(&cells[0], &beneficiary, 0x0100_0000, lock_script.clone()), Err(SdkError::InvalidValue(_))));
+	let cells = commit(&mut context, vestinglock::withdraw(&cells[0], &beneficiary, 150, lock_script.clone()).unwrap());
+	assert_eq!(cells[0].output.lock(), vestinglock_script);
+	assert_eq!(cells[0].capacity(), 50_000_000_000);
+	assert_eq!(cells[1].capacity(), 50_000_000_000);
+
+	// Everything is vested once the duration has passed.
+	let cells = commit(&mut context, vestinglock::withdraw(&cells[0], &beneficiary, 200, lock_script.clone()).unwrap());
+	assert_eq!(cells[0].output.lock(), lock_script);
+	assert_eq!(cells[0].capacity(), 50_000_000_000);
+}
+
+#[test]
+fn test_sdk_oracle()
+{
+	let (mut context, lock_script, out_point_oracle) = setup("oracle");
+	let updater_lock_script = party_lock(&mut context, 1);
+	let args = oracle::Args { updater_lock_hash: ::sdk::script_hash(&updater_lock_script) };
+	let type_script = context.build_script(&out_point_oracle, args.encode()).expect("script");
+	let updater = live_cell(&mut context, updater_lock_script, None, 100_000_000_000, Bytes::new());
+	let other = live_cell(&mut context, lock_script.clone(), None, 100_000_000_000, Bytes::new());
+
+	// The updater creates and updates the record.
+	assert!(matches!(oracle::create(lock_script.clone(), type_script.clone(), &other, &oracle::Data { value: 1, timestamp: 10, sequence: 1 }), Err(SdkError::InvalidValue(_))));
+	let cells = commit(&mut context, oracle::create(lock_script, type_script, &updater, &oracle::Data { value: 1, timestamp: 10, sequence: 1 }).unwrap());
+	let cells = commit(&mut context, oracle::update(&cells[0], &updater, &oracle::Data { value: 2, timestamp: 10, sequence: 2 }).unwrap());
+	assert_eq!(oracle::Data::decode(&cells[0].data).unwrap().value, 2);
+
+	// A stale record is rejected before the transaction is built.
+	assert!(matches!(oracle::update(&cells[0], &updater, &oracle::Data { value: 3, timestamp: 20, sequence: 2 }), Err(SdkError::InvalidValue(_))));
+	assert!(matches!(oracle::update(&cells[0], &updater, &oracle::Data { value: 3, timestamp: 9, sequence: 3 }), Err(SdkError::InvalidValue(_))));
+
+	// The updater burns the record.
+	let cells = commit(&mut context, oracle::burn(&cells[0], &updater).unwrap());
+	assert_eq!(cells.len(), 1);
+}
+
+#[test]
+fn test_sdk_oraclelock()
+{
+	let (mut context, lock_script, out_point_oraclelock) = setup("oraclelock");
+	let out_point_oracle = context.deploy_cell(Loader::default().load_binary("oracle"));
+	let owner_lock_script = party_lock(&mut context, 1);
+	let updater_lock_script = party_lock(&mut context, 2);
+	let oracle_type_script = context.build_script(&out_point_oracle, oracle::Args { updater_lock_hash: ::sdk::script_hash(&updater_lock_script) }.encode()).expect("script");
+	let args = oraclelock::Args { owner_lock_hash: ::sdk::script_hash(&owner_lock_script), oracle_type_hash: ::sdk::script_hash(&oracle_type_script), minimum_sequence: 2, threshold: 100 };
+	let oraclelock_script = context.build_script(&out_point_oraclelock, args.encode()).expect("script");
+	let owner = live_cell(&mut context, owner_lock_script, None, 100_000_000_000, Bytes::new());
+	let updater = live_cell(&mut context, updater_lock_script, None, 100_000_000_000, Bytes::new());
+	let cells = commit(&mut context, oraclelock::create(oraclelock_script, 200_000_000_000));
+	let locked = cells[0].clone();
+
+	// A stale record, and a record below the threshold, are rejected before the transaction is built.
+	let cells = commit(&mut context, oracle::create(lock_script.clone(), oracle_type_script, &updater, &oracle::Data { value: 150, timestamp: 10, sequence: 1 }).unwrap());
+	assert!(matches!(oraclelock::transfer(&locked, &owner, &cells[0], lock_script.clone()), Err(SdkError::InvalidValue(_))));
+	let cells = commit(&mut context, oracle::update(&cells[0], &updater, &oracle::Data { value: 50, timestamp: 20, sequence: 2 }).unwrap());
+	assert!(matches!(oraclelock::transfer(&locked, &owner, &cells[0], lock_script.clone()), Err(SdkError::InvalidValue(_))));
+	assert!(matches!(oraclelock::transfer(&locked, &owner, &updater, lock_script.clone()), Err(SdkError::InvalidValue(_))));
+
+	// The owner unlocks the cell once the oracle record meets the threshold.
+	let cells = commit(&mut context, oracle::update(&cells[0], &updater, &oracle::Data { value: 100, timestamp: 30, sequence: 3 }).unwrap());
+	let tx = oraclelock::transfer(&locked, &owner, &cells[0], lock_script.clone()).unwrap();
+	assert!(tx.cell_deps().into_iter().any(|cell_dep| cell_dep.out_point() == cells[0].out_point));
+	let cells = commit(&mut context, tx);
+	assert_eq!(cells[0].output.lock(), lock_script);
+	assert_eq!(cells[0].capacity(), 200_000_000_000);
+}
+
+#[test]
+fn test_sdk_typeid_builders()
+{
+	let (mut context, lock_script, out_point_typeid) = setup("typeid");
+	let code = context.build_script(&out_point_typeid, Bytes::new()).expect("script");
+
+	// Create a cell with a new Type ID, then update its data and burn it.
+	let first = live_cell(&mut context, lock_script.clone(), None, 100_000_000_000, Bytes::new());
+	let cells = commit(&mut context, typeid::create(&code, &first, lock_script.clone(), Bytes::from("version 1")));
+	let type_script = cells[0].output.type_().to_opt().unwrap();
+	assert_eq!(typeid::Args::decode(&type_script.args().raw_data()).unwrap(), typeid::Args::new(&first.input(), 0));
+	assert_eq!(cells[1].output, first.output);
+	let cells = commit(&mut context, typeid::transfer(&cells[0], lock_script, Bytes::from("version 2")).unwrap());
+	assert_eq!(cells[0].output.type_().to_opt(), Some(type_script));
+	commit(&mut context, typeid::burn(&cells[0]));
+}
+
+#[test]
+fn test_sdk_nft()
+{
+	let (mut context, lock_script, out_point_nft) = setup("nft");
+	let code = context.build_script(&out_point_nft, Bytes::new()).expect("script");
+	let other_lock_script = party_lock(&mut context, 1);
+
+	// Create NFTs without a class, then transfer and burn one.
+	let first = live_cell(&mut context, lock_script.clone(), None, 100_000_000_000, Bytes::new());
+	let cells = commit(&mut context, nft::create(&code, &first, lock_script.clone(), &[Bytes::from("one"), Bytes::from("two")], None).unwrap());
+	assert_ne!(cells[0].output.type_().to_opt(), cells[1].output.type_().to_opt());
+	let first = cells[2].clone();
+	let transferred = commit(&mut context, nft::transfer(&cells[0], other_lock_script.clone()).unwrap());
+	assert_eq!(transferred[0].data, cells[0].data);
+	commit(&mut context, nft::burn(&transferred[0]));
+
+	// Create a class with a supply of two and issue both.
+	let cells = commit(&mut context, nft::create_class(&code, &first, lock_script.clone(), 2));
+	let class = cells[0].clone();
+	let first = cells[1].clone();
+	let cells = commit(&mut context, nft::create(&code, &first, other_lock_script, &[Bytes::from("three"), Bytes::from("four")], Some(&class)).unwrap());
+	let class_type_hash = ::sdk::script_hash(&class.output.type_().to_opt().unwrap());
+	let args = nft::Args::decode(&cells[0].output.type_().to_opt().unwrap().args().raw_data()).unwrap();
+	assert_eq!(args.class_type_hash, Some(class_type_hash));
+	assert_eq!(nft::ClassData::decode(&cells[2].data).unwrap(), nft::ClassData { max_supply: 2, issued: 2 });
+
+	// The class cannot issue more than its supply.
+	assert!(matches!(nft::create(&code, &cells[3], lock_script, &[Bytes::from("five")], Some(&cells[2])), Err(SdkError::InvalidValue(_))));
+}
+
+#[test]
+fn test_sdk_daowrapper()
+{
+	let (mut context, lock_script, out_point_daowrapper) = setup("daowrapper");
+	let out_point_typeproxylock = context.deploy_cell(Loader::default().load_binary("typeproxylock"));
+	let dao_type_script = party_lock(&mut context, 1);
+	let pool_lock_template = context.build_script(&out_point_typeproxylock, Bytes::new()).expect("script");
+	let args = daowrapper::Args
+	{
+		dao_type_hash: ::sdk::script_hash(&dao_type_script),
+		pool_lock_code_hash: pool_lock_template.code_hash().unpack().0,
+		pool_lock_hash_type: pool_lock_template.hash_type().into(),
+	};
+	let receipt_type_script = context.build_script(&out_point_daowrapper, args.encode()).expect("script");
+	let deposit_header = dao_header(100, 10_000_000_000_000_000);
+	let withdraw_header = dao_header(200, 11_000_000_000_000_000);
+	context.insert_header(deposit_header.clone());
+	context.insert_header(withdraw_header.clone());
+
+	// Deposit to the pool lock and mint receipts for the capacity, funded by an input since the outputs are limited by the inputs.
+	let funding = live_cell(&mut context, lock_script.clone(), None, 1_000_000_000_000, Bytes::new());
+	let tx = daowrapper::deposit(receipt_type_script.clone(), dao_type_script, lock_script.clone(), 100_000_000_000).unwrap();
+	let cells = commit(&mut context, tx.as_advanced_builder().input(funding.input()).build());
+	assert_eq!(cells[0].output.lock(), daowrapper::pool_lock(&receipt_type_script).unwrap());
+	assert_eq!(daowrapper::Data::decode(&cells[1].data).unwrap().amount, 100_000_000_000);
+	let receipt = cells[1].clone();
+
+	// Start the withdrawal with the receipt, which unlocks the pool lock.
+	let cells = commit(&mut context, daowrapper::prepare_withdraw(&cells[0], &receipt, &deposit_header).unwrap());
+	let withdrawing = cells[0].clone();
+	let receipt = cells[1].clone();
+	context.link_cell_with_block(withdrawing.out_point.clone(), withdraw_header.hash(), 0);
+
+	// Withdraw with interest, burning the receipts for the principal.
+	assert_eq!(daowrapper::withdraw(&withdrawing, &[], &deposit_header, &withdraw_header, lock_script.clone()).unwrap_err(), SdkError::InsufficientAmount(100_000_000_000));
+	let cells = commit(&mut context, daowrapper::withdraw(&withdrawing, std::slice::from_ref(&receipt), &deposit_header, &withdraw_header, lock_script).unwrap());
+	let occupied_capacity = ::sdk::Output::occupied(withdrawing.output.lock(), withdrawing.output.type_().to_opt(), withdrawing.data.clone()).capacity();
+	assert_eq!(cells.len(), 1);
+	assert_eq!(cells[0].capacity(), occupied_capacity + (100_000_000_000 - occupied_capacity) * 11 / 10 + receipt.capacity());
+}
+
+#[test]
+fn test_sdk_encoding()
+{
+	// The args and data are encoded in the same format that the tests build by hand.
+	let beneficiary_lock_hash = [7u8; 32];
+	let args = vestinglock::Args { beneficiary_lock_hash, start_epoch: 100, cliff: 10, duration: 100, total_amount: 100_000_000_000 };
+	let expected: Bytes = [beneficiary_lock_hash.to_vec(), 100u64.to_le_bytes().to_vec(), 10u64.to_le_bytes().to_vec(), 100u64.to_le_bytes().to_vec(), 100_000_000_000u128.to_le_bytes().to_vec()].concat().into();
+	assert_eq!(args.encode(), expected);
+	assert_eq!(vestinglock::Args::decode(&expected).unwrap(), args);
+	assert_eq!(args.vested_amount(105), Some(0));
+	assert_eq!(args.vested_amount(150), Some(50_000_000_000));
+	assert_eq!(args.vested_amount(200), Some(100_000_000_000));
+
+	let args = cellcount::Args { source: cellcount::Source::GroupOutput, condition: cellcount::Condition::Range(2, 5) };
+	let expected: Bytes = [vec![3u8, 6u8], 2u64.to_le_bytes().to_vec(), 5u64.to_le_bytes().to_vec()].concat().into();
+	assert_eq!(args.encode(), expected);
+	assert_eq!(cellcount::Args::decode(&expected).unwrap(), args);
+	assert!(args.condition.is_satisfied(5));
+	assert!(!args.condition.is_satisfied(6));
+
+	// Optional values are omitted from the encoding.
+	let args = ::sdk::acplock::Args { owner_lock_hash: [1u8; 32], minimum_ckb: None, minimum_sudt: Some(5) };
+	assert_eq!(args.encode().len(), 56);
+	assert_eq!(::sdk::acplock::Args::decode(&args.encode()).unwrap(), ::sdk::acplock::Args { minimum_ckb: Some(0), ..args });
+	let args = ::sdk::dataratio::Args { bytes_per_ckbyte: 10, mode: None };
+	assert_eq!(args.encode(), Bytes::from(10u32.to_le_bytes().to_vec()));
+	assert_eq!(args.mode(), ::sdk::dataratio::Mode::PerCell);
+	assert_eq!(::sdk::data10::Args::decode(&[]).unwrap().limit(), 10);
+	assert_eq!(::sdk::data10::Args::decode(&16u64.to_le_bytes()).unwrap().limit(), 10);
+
+	// Contract specific calculations.
+	let args = ::sdk::orderlock::Args { maker_lock_hash: [0u8; 32], rate_ckb: 10, rate_sudt: 3 };
+	assert_eq!(args.payment(3), Some(10));
+	assert_eq!(args.payment(4), Some(14));
+	let data = ::sdk::ratelimitlock::Data { window: 10, withdrawn: 60 };
+	assert_eq!(data.withdraw(false, 40, 100), Some(::sdk::ratelimitlock::Data { window: 10, withdrawn: 100 }));
+	assert_eq!(data.withdraw(false, 41, 100), None);
+	assert_eq!(data.withdraw(true, 100, 100), Some(::sdk::ratelimitlock::Data { window: 11, withdrawn: 100 }));
+	assert_eq!(data.withdraw(true, 101, 100), None);
+	let data = ::sdk::nft::ClassData { max_supply: 3, issued: 2 };
+	assert_eq!(data.issue(1), Some(::sdk::nft::ClassData { max_supply: 3, issued: 3 }));
+	assert_eq!(data.issue(2), None);
+	assert_eq!(::sdk::nft::ClassArgs { class_id: [1u8; 32] }.encode().len(), 33);
+}
+
+#[test]
+fn test_sdk_decode_invalid()
+{
+	// Lengths which the contracts do not accept.
+	assert_eq!(counter::Data::decode(&[0u8; 7]).unwrap_err(), SdkError::InvalidLength(7));
+	assert_eq!(odcounter::Data::decode(&[0u8; 9]).unwrap_err(), SdkError::InvalidLength(9));
+	assert_eq!(datarange::Args::decode(&[0u8; 4]).unwrap_err(), SdkError::InvalidLength(4));
+	assert_eq!(sudt::Args::decode(&[0u8; 33]).unwrap_err(), SdkError::InvalidLength(33));
+	assert_eq!(::sdk::acplock::Args::decode(&[0u8; 48]).unwrap_err(), SdkError::InvalidLength(48));
+	assert_eq!(cellcount::Args::decode(&[0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8]).unwrap_err(), SdkError::InvalidLength(10));
+
+	// Any bytes after the values read by the contract are ignored where the contract allows it.
+	assert_eq!(counter::Data::decode(&[1u8, 0, 0, 0, 0, 0, 0, 0, 9]).unwrap(), counter::Data { value: 1 });
+	assert_eq!(occlock::Args::decode(&[0u8; 20]).unwrap(), occlock::Args { amount: 0, count: 0 });
+
+	// Values which the contracts do not accept.
+	assert!(matches!(cellcount::Args::decode(&[4u8, 0u8]), Err(SdkError::InvalidValue(_))));
+	assert!(matches!(cellcount::Args::decode(&[0u8, 7u8]), Err(SdkError::InvalidValue(_))));
+	assert!(matches!(::sdk::dataratio::Args::decode(&[0u8, 0u8, 0u8, 0u8, 2u8]), Err(SdkError::InvalidValue(_))));
+	assert!(matches!(::sdk::jsoncell::Data::decode(&[0xffu8]), Err(SdkError::InvalidValue(_))));
+	assert!(matches!(::sdk::nft::ClassArgs::decode(&[[0u8; 32].as_slice(), &[2u8]].concat()), Err(SdkError::InvalidValue(_))));
+}
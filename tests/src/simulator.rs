@@ -0,0 +1,424 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use ckb_testtool::ckb_error::Error as ScriptError;
+use ckb_testtool::ckb_types::bytes::Bytes;
+use ckb_testtool::ckb_types::core::{Capacity, EpochNumberWithFraction, HeaderBuilder, HeaderView, TransactionInfo, TransactionView};
+use ckb_testtool::ckb_types::packed::{Byte32, CellOutput, OutPoint, Script};
+use ckb_testtool::ckb_types::prelude::*;
+use ckb_testtool::context::random_out_point;
+use ::sdk::Cell;
+use crate::RecordingContext;
+
+// Constants
+pub const EPOCH_LENGTH: u64 = 1_800; // The number of blocks in each epoch, which is about four hours on mainnet.
+pub const BLOCK_INTERVAL: u64 = 8_000; // The number of milliseconds between blocks.
+const GENESIS_TIMESTAMP: u64 = 1_573_852_190_812; // The timestamp of the mainnet genesis block in milliseconds.
+const MAX_CYCLES: u64 = 100_000_000;
+const SINCE_RELATIVE_FLAG: u64 = 0x8000_0000_0000_0000; // The since is relative to the block the input cell was committed in.
+const SINCE_METRIC_MASK: u64 = 0x6000_0000_0000_0000; // The metric flag bits.
+const SINCE_RESERVED_MASK: u64 = 0x1F00_0000_0000_0000; // The reserved flag bits, which must be zero.
+const SINCE_VALUE_MASK: u64 = 0x00FF_FFFF_FFFF_FFFF; // The value is the lower 56 bits of the since value.
+const SINCE_METRIC_BLOCK_NUMBER: u64 = 0x0000_0000_0000_0000;
+const SINCE_METRIC_EPOCH: u64 = 0x2000_0000_0000_0000;
+const SINCE_METRIC_TIMESTAMP: u64 = 0x4000_0000_0000_0000;
+
+/// Error
+#[derive(Debug)]
+pub enum Error
+{
+	/// The transaction has no inputs or no outputs.
+	Empty,
+	/// An input or cell dep is not a live cell, because it was never created or has already been spent.
+	DeadCell(OutPoint),
+	/// The same cell is used as an input more than once.
+	DuplicateInput(OutPoint),
+	/// A header dep is not a block in the chain.
+	UnknownHeader(Byte32),
+	/// The since of the input at the index has reserved flags set or an unknown metric.
+	InvalidSince(usize),
+	/// The since of the input at the index has not been reached by the block the transaction is committed in.
+	ImmatureSince(usize),
+	/// The output at the index does not have enough capacity for its lock, type, and data.
+	InsufficientCellCapacity(usize),
+	/// The capacity of the outputs is more than the capacity of the inputs.
+	InsufficientCapacity { inputs: u64, outputs: u64 },
+	/// A script failed verification.
+	Script(ScriptError),
+}
+
+impl fmt::Display for Error
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		match self
+		{
+			Self::Empty => write!(f, "the transaction has no inputs or no outputs"),
+			Self::DeadCell(out_point) => write!(f, "dead cell: {}", out_point),
+			Self::DuplicateInput(out_point) => write!(f, "duplicate input: {}", out_point),
+			Self::UnknownHeader(hash) => write!(f, "unknown header: {}", hash),
+			Self::InvalidSince(index) => write!(f, "invalid since on input {}", index),
+			Self::ImmatureSince(index) => write!(f, "immature since on input {}", index),
+			Self::InsufficientCellCapacity(index) => write!(f, "insufficient capacity on output {}", index),
+			Self::InsufficientCapacity { inputs, outputs } => write!(f, "outputs capacity {} is more than inputs capacity {}", outputs, inputs),
+			Self::Script(err) => write!(f, "{}", err),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+/// The state of a simulator, which can be restored with rollback.
+#[derive(Clone)]
+pub struct Snapshot
+{
+	cells: HashMap<OutPoint, (CellOutput, Bytes)>,
+	cells_by_data_hash: HashMap<Byte32, OutPoint>,
+	transaction_infos: HashMap<OutPoint, TransactionInfo>,
+	headers: HashMap<Byte32, HeaderView>,
+	live_cells: HashSet<OutPoint>,
+	tip: HeaderView,
+}
+
+/// An in-memory chain which keeps a live cell set and commits verified transactions in blocks.
+///
+/// Each block advances the block number, epoch, and timestamp, and every committed cell is linked with its block so the
+/// since of an input is checked the same way as on-chain. The simplifications are that every block has the same
+/// interval, epochs have a fixed length, timestamps are compared with the timestamp of the block rather than the median
+/// of previous blocks, and there are no fees, rewards, or DAO interest.
+pub struct Simulator
+{
+	context: RecordingContext,
+	live_cells: HashSet<OutPoint>,
+	tip: HeaderView,
+}
+
+impl Default for Simulator
+{
+	fn default() -> Self
+	{
+		let mut context = RecordingContext::default();
+		let tip = header(0, GENESIS_TIMESTAMP, Byte32::zero());
+		context.insert_header(tip.clone());
+
+		Self { context, live_cells: HashSet::new(), tip }
+	}
+}
+
+impl Simulator
+{
+	/// The verification context, which holds every cell and header, including spent cells.
+	pub fn context(&self) -> &RecordingContext
+	{
+		&self.context
+	}
+
+	/// The header of the latest block.
+	pub fn tip(&self) -> &HeaderView
+	{
+		&self.tip
+	}
+
+	pub fn block_number(&self) -> u64
+	{
+		self.tip.number()
+	}
+
+	pub fn epoch(&self) -> EpochNumberWithFraction
+	{
+		self.tip.epoch()
+	}
+
+	/// Deploy a binary in a live cell, returning the out point to use for its scripts.
+	pub fn deploy(&mut self, binary: Bytes) -> OutPoint
+	{
+		let out_point = self.context.deploy_cell(binary);
+		if !self.context.transaction_infos.contains_key(&out_point)
+		{
+			self.link(&out_point, 0);
+		}
+
+		out_point
+	}
+
+	pub fn build_script(&mut self, out_point: &OutPoint, args: Bytes) -> Script
+	{
+		self.context.build_script(out_point, args).expect("script")
+	}
+
+	/// Add the cell deps for the scripts of the transaction. This changes the transaction hash, so it must be done before
+	/// the out points of the outputs are used by another transaction in the same block.
+	pub fn complete_tx(&mut self, tx: TransactionView) -> TransactionView
+	{
+		self.context.complete_tx(tx)
+	}
+
+	/// Create a live cell without a transaction, in the same way as the cells created in the genesis block.
+	pub fn issue(&mut self, output: CellOutput, data: Bytes) -> Cell
+	{
+		let out_point = random_out_point();
+		self.context.create_cell_with_out_point(out_point.clone(), output.clone(), data.clone());
+		self.link(&out_point, 0);
+
+		Cell::new(out_point, output, data)
+	}
+
+	/// Add an input issued to the lock with the capacity the outputs need beyond the capacity of the inputs.
+	pub fn fund(&mut self, tx: TransactionView, lock: Script) -> TransactionView
+	{
+		let inputs = self.inputs_capacity(&tx);
+		let outputs = tx.outputs_capacity().expect("outputs capacity").as_u64();
+		if inputs >= outputs && !tx.inputs().is_empty()
+		{
+			return tx;
+		}
+
+		let output = CellOutput::new_builder().capacity(outputs.saturating_sub(inputs).pack()).lock(lock).build();
+		let cell = self.issue(output, Bytes::new());
+
+		tx.as_advanced_builder().input(cell.input()).build()
+	}
+
+	pub fn is_live(&self, out_point: &OutPoint) -> bool
+	{
+		self.live_cells.contains(out_point)
+	}
+
+	/// A live cell, or None if the cell was never created or has been spent.
+	pub fn live_cell(&self, out_point: &OutPoint) -> Option<Cell>
+	{
+		if !self.is_live(out_point)
+		{
+			return None;
+		}
+		let (output, data) = self.context.get_cell(out_point)?;
+
+		Some(Cell::new(out_point.clone(), output, data))
+	}
+
+	/// Every live cell, including deployed binaries, in no particular order.
+	pub fn live_cells(&self) -> Vec<Cell>
+	{
+		self.live_cells.iter().filter_map(|out_point| self.live_cell(out_point)).collect()
+	}
+
+	/// Mine empty blocks.
+	pub fn advance(&mut self, blocks: u64)
+	{
+		let number = self.tip.number() + blocks;
+		let timestamp = self.tip.timestamp() + blocks * BLOCK_INTERVAL;
+		self.tip = header(number, timestamp, self.tip.hash());
+		self.context.insert_header(self.tip.clone());
+	}
+
+	/// Mine empty blocks for the number of whole epochs.
+	pub fn advance_epochs(&mut self, epochs: u64)
+	{
+		self.advance(epochs * EPOCH_LENGTH);
+	}
+
+	pub fn snapshot(&self) -> Snapshot
+	{
+		Snapshot
+		{
+			cells: self.context.cells.clone(),
+			cells_by_data_hash: self.context.cells_by_data_hash.clone(),
+			transaction_infos: self.context.transaction_infos.clone(),
+			headers: self.context.headers.clone(),
+			live_cells: self.live_cells.clone(),
+			tip: self.tip.clone(),
+		}
+	}
+
+	/// Restore the chain to the state of the snapshot, undoing every block and cell added after it.
+	pub fn rollback(&mut self, snapshot: &Snapshot)
+	{
+		self.context.cells = snapshot.cells.clone();
+		self.context.cells_by_data_hash = snapshot.cells_by_data_hash.clone();
+		self.context.transaction_infos = snapshot.transaction_infos.clone();
+		self.context.headers = snapshot.headers.clone();
+		self.live_cells = snapshot.live_cells.clone();
+		self.tip = snapshot.tip.clone();
+	}
+
+	/// Verify the transaction and commit it in a new block, returning its outputs as live cells.
+	pub fn apply(&mut self, tx: TransactionView) -> Result<Vec<Cell>, Error>
+	{
+		let mut outputs = self.apply_block(vec![tx])?;
+
+		Ok(outputs.remove(0))
+	}
+
+	/// Verify the transactions in order and commit them together in a new block, returning the outputs of each.
+	/// A transaction can spend the outputs of an earlier transaction in the block. If any transaction fails, the block
+	/// is rejected and the chain is unchanged.
+	pub fn apply_block(&mut self, txs: Vec<TransactionView>) -> Result<Vec<Vec<Cell>>, Error>
+	{
+		let snapshot = self.snapshot();
+		self.advance(1);
+
+		let mut outputs = Vec::new();
+		for (i, tx) in txs.into_iter().enumerate()
+		{
+			// The first transaction in a block is the cellbase.
+			match self.commit(tx, i + 1)
+			{
+				Ok(cells) => outputs.push(cells),
+				Err(err) =>
+				{
+					self.rollback(&snapshot);
+					return Err(err);
+				},
+			}
+		}
+
+		Ok(outputs)
+	}
+
+	/// Verify a transaction against the tip block and update the live cell set.
+	fn commit(&mut self, tx: TransactionView, tx_index: usize) -> Result<Vec<Cell>, Error>
+	{
+		if tx.inputs().is_empty() || tx.outputs().is_empty()
+		{
+			return Err(Error::Empty);
+		}
+
+		// Every input must be a live cell whose since has been reached.
+		let mut inputs = HashSet::new();
+		for (i, input) in tx.inputs().into_iter().enumerate()
+		{
+			let out_point = input.previous_output();
+			if !self.is_live(&out_point)
+			{
+				return Err(Error::DeadCell(out_point));
+			}
+			if !inputs.insert(out_point.clone())
+			{
+				return Err(Error::DuplicateInput(out_point));
+			}
+			self.check_since(i, input.since().unpack(), &out_point)?;
+		}
+
+		// Every cell dep must be a live cell and every header dep must be a block in the chain.
+		let tx = self.complete_tx(tx);
+		if let Some(cell_dep) = tx.cell_deps_iter().find(|cell_dep| !self.is_live(&cell_dep.out_point()))
+		{
+			return Err(Error::DeadCell(cell_dep.out_point()));
+		}
+		if let Some(hash) = tx.header_deps_iter().find(|hash| !self.context.headers.contains_key(hash))
+		{
+			return Err(Error::UnknownHeader(hash));
+		}
+
+		// Every output must be able to hold its own data, and the inputs must pay for the outputs.
+		for (i, (output, data)) in tx.outputs_with_data_iter().enumerate()
+		{
+			let occupied = output.occupied_capacity(Capacity::bytes(data.len()).expect("data capacity")).expect("occupied capacity");
+			if occupied.as_u64() > output.capacity().unpack()
+			{
+				return Err(Error::InsufficientCellCapacity(i));
+			}
+		}
+		let inputs_capacity = self.inputs_capacity(&tx);
+		let outputs_capacity = tx.outputs_capacity().expect("outputs capacity").as_u64();
+		if outputs_capacity > inputs_capacity
+		{
+			return Err(Error::InsufficientCapacity { inputs: inputs_capacity, outputs: outputs_capacity });
+		}
+
+		self.context.verify_tx(&tx, MAX_CYCLES).map_err(Error::Script)?;
+
+		// Spend the inputs and add the outputs to the live cell set.
+		for out_point in &inputs
+		{
+			self.live_cells.remove(out_point);
+		}
+		let mut cells = Vec::new();
+		for (i, (output, data)) in tx.outputs_with_data_iter().enumerate()
+		{
+			let out_point = OutPoint::new(tx.hash(), i as u32);
+			self.context.create_cell_with_out_point(out_point.clone(), output.clone(), data.clone());
+			self.link(&out_point, tx_index);
+			cells.push(Cell::new(out_point, output, data));
+		}
+
+		Ok(cells)
+	}
+
+	/// Commit a cell in the tip block so it is live and can be loaded by header.
+	fn link(&mut self, out_point: &OutPoint, tx_index: usize)
+	{
+		self.context.link_cell_with_block(out_point.clone(), self.tip.hash(), tx_index);
+		self.live_cells.insert(out_point.clone());
+	}
+
+	fn inputs_capacity(&self, tx: &TransactionView) -> u64
+	{
+		tx.input_pts_iter().filter_map(|out_point| self.context.get_cell(&out_point)).map(|(output, _)| Unpack::<u64>::unpack(&output.capacity())).sum()
+	}
+
+	/// Check the since of an input against the tip block, using the block the input cell was committed in for relative values.
+	fn check_since(&self, index: usize, since: u64, out_point: &OutPoint) -> Result<(), Error>
+	{
+		if since == 0
+		{
+			return Ok(());
+		}
+		if since & SINCE_RESERVED_MASK != 0
+		{
+			return Err(Error::InvalidSince(index));
+		}
+
+		let relative = since & SINCE_RELATIVE_FLAG != 0;
+		let value = since & SINCE_VALUE_MASK;
+		let info = self.context.transaction_infos.get(out_point).expect("live cell block");
+		let committed = self.context.headers.get(&info.block_hash).expect("live cell header");
+		let reached = match since & SINCE_METRIC_MASK
+		{
+			SINCE_METRIC_BLOCK_NUMBER =>
+			{
+				let start = if relative { committed.number() } else { 0 };
+				start.checked_add(value).is_some_and(|number| number <= self.tip.number())
+			},
+			SINCE_METRIC_EPOCH =>
+			{
+				let epoch = EpochNumberWithFraction::from_full_value_unchecked(value);
+				if !epoch.is_well_formed_increment()
+				{
+					return Err(Error::InvalidSince(index));
+				}
+				let epoch = epoch.normalize();
+				match relative
+				{
+					true => committed.epoch().to_rational() + epoch.to_rational() <= self.tip.epoch().to_rational(),
+					false => epoch <= self.tip.epoch(),
+				}
+			},
+			SINCE_METRIC_TIMESTAMP =>
+			{
+				// Timestamps in a since are in seconds.
+				let start = if relative { committed.timestamp() / 1_000 } else { 0 };
+				start.checked_add(value).is_some_and(|timestamp| timestamp <= self.tip.timestamp() / 1_000)
+			},
+			_ => return Err(Error::InvalidSince(index)),
+		};
+
+		match reached
+		{
+			true => Ok(()),
+			false => Err(Error::ImmatureSince(index)),
+		}
+	}
+}
+
+/// Build the header of a block at the number, with the epoch derived from the fixed epoch length.
+fn header(number: u64, timestamp: u64, parent_hash: Byte32) -> HeaderView
+{
+	let epoch = EpochNumberWithFraction::new(number / EPOCH_LENGTH, number % EPOCH_LENGTH, EPOCH_LENGTH);
+
+	HeaderBuilder::default()
+		.number(number.pack())
+		.epoch(epoch.full_value().pack())
+		.timestamp(timestamp.pack())
+		.parent_hash(parent_hash)
+		.build()
+}
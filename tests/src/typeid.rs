@@ -0,0 +1,645 @@
+use super::*;
+use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::random_out_point};
+use ckb_testtool::ckb_hash::new_blake2b;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::{ScriptHashType, TransactionBuilder};
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+
+// Error Codes
+const ERROR_COUNTER_INVALID_TRANSACTION_STRUCTURE: i8 = 5;
+const ERROR_TYPEID_ARGSLEN: i8 = 5;
+const ERROR_TYPEID_INVALID_TRANSACTION_STRUCTURE: i8 = 6;
+const ERROR_TYPEID_INVALID_TYPE_ID: i8 = 7;
+
+/// Calculate a Type ID from the first input of a transaction and the index of the output cell.
+fn calculate_type_id(first_input: &CellInput, output_index: u64) -> Bytes
+{
+	let mut type_id = [0u8; 32];
+	let mut blake2b = new_blake2b();
+	blake2b.update(first_input.as_slice());
+	blake2b.update(&output_index.to_le_bytes());
+	blake2b.finalize(&mut type_id);
+
+	Bytes::from(type_id.to_vec())
+}
+
+#[test]
+fn test_typeid_create()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_typeid = context.deploy_cell(Loader::default().load_binary("typeid"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let typeid_dep = CellDep::new_builder().out_point(out_point_typeid.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input.clone());
+
+	// Prepare Type ID Script
+	let type_id = calculate_type_id(&input, 0);
+	let type_script = context.build_script(&out_point_typeid, type_id).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(typeid_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_typeid_create_second_output()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_typeid = context.deploy_cell(Loader::default().load_binary("typeid"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let typeid_dep = CellDep::new_builder().out_point(out_point_typeid.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input.clone());
+
+	// Prepare Type ID Script
+	let type_id = calculate_type_id(&input, 1);
+	let type_script = context.build_script(&out_point_typeid, type_id).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(typeid_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_typeid_create_wrong_output_index()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_typeid = context.deploy_cell(Loader::default().load_binary("typeid"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let typeid_dep = CellDep::new_builder().out_point(out_point_typeid.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input.clone());
+
+	// Prepare Type ID Script
+	let type_id = calculate_type_id(&input, 1);
+	let type_script = context.build_script(&out_point_typeid, type_id).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(typeid_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_TYPEID_INVALID_TYPE_ID);
+}
+
+#[test]
+fn test_typeid_create_wrong_input()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_typeid = context.deploy_cell(Loader::default().load_binary("typeid"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let typeid_dep = CellDep::new_builder().out_point(out_point_typeid.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input.clone());
+
+	// Prepare Type ID Script
+	let type_id = calculate_type_id(&CellInput::new_builder().previous_output(random_out_point()).build(), 0);
+	let type_script = context.build_script(&out_point_typeid, type_id).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(typeid_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_TYPEID_INVALID_TYPE_ID);
+}
+
+#[test]
+fn test_typeid_create_no_args()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_typeid = context.deploy_cell(Loader::default().load_binary("typeid"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let typeid_dep = CellDep::new_builder().out_point(out_point_typeid.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input.clone());
+
+	// Prepare Type ID Script
+	let type_id = Bytes::new();
+	let type_script = context.build_script(&out_point_typeid, type_id).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(typeid_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_TYPEID_ARGSLEN);
+}
+
+#[test]
+fn test_typeid_create_multiple()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_typeid = context.deploy_cell(Loader::default().load_binary("typeid"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let typeid_dep = CellDep::new_builder().out_point(out_point_typeid.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input.clone());
+
+	// Prepare Type ID Script
+	let type_id = calculate_type_id(&input, 0);
+	let type_script = context.build_script(&out_point_typeid, type_id).expect("script");
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(typeid_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_TYPEID_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_typeid_transfer()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_typeid = context.deploy_cell(Loader::default().load_binary("typeid"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let typeid_dep = CellDep::new_builder().out_point(out_point_typeid.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let type_id = calculate_type_id(&CellInput::new_builder().previous_output(random_out_point()).build(), 0);
+	let type_script = context.build_script(&out_point_typeid, type_id).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build(), Bytes::from("Version 1".as_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Version 2".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(typeid_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_typeid_transfer_multiple_inputs()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_typeid = context.deploy_cell(Loader::default().load_binary("typeid"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let typeid_dep = CellDep::new_builder().out_point(out_point_typeid.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let type_id = calculate_type_id(&CellInput::new_builder().previous_output(random_out_point()).build(), 0);
+	let type_script = context.build_script(&out_point_typeid, type_id).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build(), Bytes::from("Version 1".as_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build(), Bytes::from("Version 1".as_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Version 2".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(typeid_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_TYPEID_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_typeid_transfer_multiple_outputs()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_typeid = context.deploy_cell(Loader::default().load_binary("typeid"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let typeid_dep = CellDep::new_builder().out_point(out_point_typeid.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let type_id = calculate_type_id(&CellInput::new_builder().previous_output(random_out_point()).build(), 0);
+	let type_script = context.build_script(&out_point_typeid, type_id).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build(), Bytes::from("Version 1".as_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Version 2".as_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from("Version 2".as_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(typeid_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_TYPEID_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_typeid_burn()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_typeid = context.deploy_cell(Loader::default().load_binary("typeid"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let typeid_dep = CellDep::new_builder().out_point(out_point_typeid.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let type_id = calculate_type_id(&CellInput::new_builder().previous_output(random_out_point()).build(), 0);
+	let type_script = context.build_script(&out_point_typeid, type_id).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build(), Bytes::from("Version 1".as_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let outputs = vec![];
+	let outputs_data: Vec<Bytes> = vec![];
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(typeid_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_typeid_burn_multiple()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_typeid = context.deploy_cell(Loader::default().load_binary("typeid"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let typeid_dep = CellDep::new_builder().out_point(out_point_typeid.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let type_id = calculate_type_id(&CellInput::new_builder().previous_output(random_out_point()).build(), 0);
+	let type_script = context.build_script(&out_point_typeid, type_id).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build(), Bytes::from("Version 1".as_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_script.clone()).pack()).build(), Bytes::from("Version 1".as_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let outputs = vec![];
+	let outputs_data: Vec<Bytes> = vec![];
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(typeid_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_TYPEID_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_typeid_upgrade_contract()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_typeid = context.deploy_cell(Loader::default().load_binary("typeid"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let typeid_dep = CellDep::new_builder().out_point(out_point_typeid.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+
+	// Create a contract cell containing the counter binary, protected by a Type ID.
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	let type_id = calculate_type_id(&input, 0);
+	let type_id_script = context.build_script(&out_point_typeid, type_id).expect("script");
+	let contract_cell = CellOutput::new_builder().capacity(100_000_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(type_id_script.clone()).pack()).build();
+	let tx = TransactionBuilder::default()
+		.input(input)
+		.output(contract_cell.clone())
+		.output_data(Loader::default().load_binary("counter").pack())
+		.cell_dep(always_success_dep.clone())
+		.cell_dep(typeid_dep.clone())
+		.build();
+	let tx = context.complete_tx(tx);
+	context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	let contract_out_point = OutPoint::new(tx.hash(), 0);
+	context.create_cell_with_out_point(contract_out_point.clone(), contract_cell.clone(), Loader::default().load_binary("counter"));
+	let contract_dep = CellDep::new_builder().out_point(contract_out_point.clone()).build();
+
+	// Create a counter cell which references the contract by its type hash instead of its data hash.
+	let counter_script = Script::new_builder()
+		.code_hash(type_id_script.calc_script_hash())
+		.hash_type(ScriptHashType::Type.into())
+		.build();
+	let counter_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).type_(Some(counter_script.clone()).pack()).build(), Bytes::from(0u64.to_le_bytes().to_vec()));
+	let counter_input = CellInput::new_builder().previous_output(counter_out_point).build();
+
+	// The counter binary does not allow burning, so the counter cell cannot be burned yet.
+	// Cell deps are added manually since complete_tx() does not support scripts with a hash type of type.
+	let tx = TransactionBuilder::default()
+		.input(counter_input.clone())
+		.cell_dep(always_success_dep.clone())
+		.cell_dep(contract_dep)
+		.build();
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_COUNTER_INVALID_TRANSACTION_STRUCTURE);
+
+	// Upgrade the contract cell in place to the odcounter binary, which allows burning.
+	let tx = TransactionBuilder::default()
+		.input(CellInput::new_builder().previous_output(contract_out_point).build())
+		.output(contract_cell.clone())
+		.output_data(Loader::default().load_binary("odcounter").pack())
+		.cell_dep(always_success_dep.clone())
+		.cell_dep(typeid_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+	context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	let contract_out_point = OutPoint::new(tx.hash(), 0);
+	context.create_cell_with_out_point(contract_out_point.clone(), contract_cell, Loader::default().load_binary("odcounter"));
+	let contract_dep = CellDep::new_builder().out_point(contract_out_point).build();
+
+	// The same counter cell can now be burned using the upgraded contract.
+	let tx = TransactionBuilder::default()
+		.input(counter_input)
+		.cell_dep(always_success_dep)
+		.cell_dep(contract_dep)
+		.build();
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
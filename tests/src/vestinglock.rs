@@ -0,0 +1,1213 @@
+use super::*;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, packed::*, prelude::*};
+use ckb_testtool::ckb_types::core::{EpochNumberWithFraction, HeaderBuilder, TransactionBuilder};
+
+// Constants
+const MAX_CYCLES: u64 = 100_000_000;
+const SINCE_ABSOLUTE_EPOCH: u64 = 0x2000_0100_0000_0000; // Absolute epoch flags with an epoch length of 1.
+const SINCE_RELATIVE_EPOCH: u64 = 0xA000_0100_0000_0000; // Relative epoch flags with an epoch length of 1.
+
+// Error Codes
+const ERROR_VESTINGLOCK_ARGSLEN: i8 = 5;
+const ERROR_VESTINGLOCK_INVALID_TRANSACTION_STRUCTURE: i8 = 6;
+const ERROR_VESTINGLOCK_UNAUTHORIZED: i8 = 7;
+const ERROR_VESTINGLOCK_MISSING_EPOCH: i8 = 8;
+const ERROR_VESTINGLOCK_INSUFFICIENT_REMAINDER: i8 = 10;
+
+#[test]
+fn test_vestinglock_ckb_withdraw_vested()
+{
+	// At epoch 150, 50 of 100 epochs have passed so half of the 1,000 CKBytes has vested.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 150).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(60_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_vestinglock_ckb_withdraw_too_much()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 150).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(49_900_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(60_100_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_VESTINGLOCK_INSUFFICIENT_REMAINDER);
+}
+
+#[test]
+fn test_vestinglock_ckb_before_cliff()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 105).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(90_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_VESTINGLOCK_INSUFFICIENT_REMAINDER);
+}
+
+#[test]
+fn test_vestinglock_ckb_after_cliff()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 110).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(90_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_vestinglock_ckb_fully_vested()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 200).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(110_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_vestinglock_ckb_second_withdrawal()
+{
+	// Half of the 1,000 CKBytes was already withdrawn. At epoch 175, 750 CKBytes have vested so 250 must remain.
+
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 175).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(25_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(35_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_vestinglock_ckb_second_withdrawal_too_much()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 175).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(24_900_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(35_100_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_VESTINGLOCK_INSUFFICIENT_REMAINDER);
+}
+
+#[test]
+fn test_vestinglock_ckb_header_dep()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(60_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Header Deps
+	let header = HeaderBuilder::default().epoch(EpochNumberWithFraction::new(150, 0, 1).pack()).build();
+	context.insert_header(header.clone());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.header_dep(header.hash())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_vestinglock_ckb_header_dep_later_than_since()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 105).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(60_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Prepare Header Deps
+	let header = HeaderBuilder::default().epoch(EpochNumberWithFraction::new(150, 0, 1).pack()).build();
+	context.insert_header(header.clone());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.header_dep(header.hash())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_vestinglock_ckb_missing_epoch()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_VESTINGLOCK_MISSING_EPOCH);
+}
+
+#[test]
+fn test_vestinglock_ckb_relative_since()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_RELATIVE_EPOCH | 150).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(60_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_VESTINGLOCK_MISSING_EPOCH);
+}
+
+#[test]
+fn test_vestinglock_ckb_no_beneficiary()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 150).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(60_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_VESTINGLOCK_UNAUTHORIZED);
+}
+
+#[test]
+fn test_vestinglock_ckb_remainder_different_lock()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let lock_script = context.build_script(&out_point_always_success, Default::default()).expect("script");
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 150).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(60_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_VESTINGLOCK_INSUFFICIENT_REMAINDER);
+}
+
+#[test]
+fn test_vestinglock_ckb_multiple_vesting_cells()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 100_000_000_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 150).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 150).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(110_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_VESTINGLOCK_INVALID_TRANSACTION_STRUCTURE);
+}
+
+#[test]
+fn test_vestinglock_args_len()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(100_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 150).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(50_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(60_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_VESTINGLOCK_ARGSLEN);
+}
+
+#[test]
+fn test_vestinglock_sudt_withdraw_vested()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 1_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+	let sudt_args: Bytes = [0u8; 32].to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 150).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(500u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(500u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_vestinglock_sudt_withdraw_too_much()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 1_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+	let sudt_args: Bytes = [0u8; 32].to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 150).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(499u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(501u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_VESTINGLOCK_INSUFFICIENT_REMAINDER);
+}
+
+#[test]
+fn test_vestinglock_sudt_before_cliff()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 1_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+	let sudt_args: Bytes = [0u8; 32].to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 105).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(999u128.to_le_bytes().to_vec()));
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_VESTINGLOCK_INSUFFICIENT_REMAINDER);
+}
+
+#[test]
+fn test_vestinglock_sudt_remainder_without_type()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 1_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+	let sudt_args: Bytes = [0u8; 32].to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 150).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::new());
+	let output = CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+	assert_script_error(err, ERROR_VESTINGLOCK_INSUFFICIENT_REMAINDER);
+}
+
+#[test]
+fn test_vestinglock_sudt_fully_vested()
+{
+	// Create Context
+	let mut context = RecordingContext::default();
+
+	// Deploy Contracts
+	let out_point_always_success = context.deploy_cell(ALWAYS_SUCCESS.clone());
+	let out_point_vestinglock = context.deploy_cell(Loader::default().load_binary("vestinglock"));
+	let out_point_sudt = context.deploy_cell(Loader::default().load_binary("sudt"));
+
+	// Prepare Cell Deps
+	let always_success_dep = CellDep::new_builder().out_point(out_point_always_success.clone()).build();
+	let vestinglock_dep = CellDep::new_builder().out_point(out_point_vestinglock.clone()).build();
+	let sudt_dep = CellDep::new_builder().out_point(out_point_sudt.clone()).build();
+
+	// Prepare Scripts
+	let beneficiary_lock_script = context.build_script(&out_point_always_success, Bytes::from(vec![1u8])).expect("script");
+	let beneficiary_lock_hash = beneficiary_lock_script.calc_script_hash().unpack().as_bytes().to_vec();
+	let start_epoch = 100u64.to_le_bytes().to_vec();
+	let cliff = 10u64.to_le_bytes().to_vec();
+	let duration = 100u64.to_le_bytes().to_vec();
+	let total_amount = 1_000u128.to_le_bytes().to_vec();
+	let vestinglock_args: Bytes = [beneficiary_lock_hash, start_epoch, cliff, duration, total_amount].concat().into();
+	let vestinglock_lock_script = context.build_script(&out_point_vestinglock, vestinglock_args).expect("script");
+	let sudt_args: Bytes = [0u8; 32].to_vec().into();
+	let sudt_type_script = context.build_script(&out_point_sudt, sudt_args).expect("script");
+
+	// Prepare Input Cells
+	let mut inputs = vec![];
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(20_000_000_000_u64.pack()).lock(vestinglock_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build(), Bytes::from(1_000u128.to_le_bytes().to_vec()));
+	let input = CellInput::new_builder().previous_output(input_out_point).since((SINCE_ABSOLUTE_EPOCH | 200).pack()).build();
+	inputs.push(input);
+	let input_out_point = context.create_cell(CellOutput::new_builder().capacity(10_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).build(), Bytes::new());
+	let input = CellInput::new_builder().previous_output(input_out_point).build();
+	inputs.push(input);
+
+	// Prepare Output Cells
+	let mut outputs = vec![];
+	let mut outputs_data: Vec<Bytes> = vec![];
+	let output = CellOutput::new_builder().capacity(30_000_000_000_u64.pack()).lock(beneficiary_lock_script.clone()).type_(Some(sudt_type_script.clone()).pack()).build();
+	outputs.push(output);
+	outputs_data.push(Bytes::from(1_000u128.to_le_bytes().to_vec()));
+
+	// Build Transaction
+	let tx = TransactionBuilder::default()
+		.inputs(inputs)
+		.outputs(outputs)
+		.outputs_data(outputs_data.pack())
+		.cell_dep(always_success_dep)
+		.cell_dep(vestinglock_dep)
+		.cell_dep(sudt_dep)
+		.build();
+	let tx = context.complete_tx(tx);
+
+	// Run
+	let _cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+	// println!("consume cycles: {}", cycles);
+}